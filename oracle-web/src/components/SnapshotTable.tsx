@@ -68,7 +68,7 @@ const TableRow = memo(
     } | null;
     allSpotSnapshots: Array<{
       exchange: string;
-      symbol: string;
+      base: string;
       spot: { currency: Currency; price: number } | null;
     }>;
   }) => {
@@ -156,7 +156,7 @@ const TableRow = memo(
                               {allSpotSnapshots
                                 .filter(
                                   (s) =>
-                                    s.symbol === snapshot.symbol &&
+                                    s.base === snapshot.base &&
                                     s.exchange !== snapshot.exchange &&
                                     s.spot !== null
                                 )
@@ -222,7 +222,7 @@ const TableRow = memo(
                 // 빗썸의 현물 가격 찾기
                 const bithumbSpot = allSpotSnapshots.find(
                   (s) =>
-                    s.symbol === snapshot.symbol &&
+                    s.base === snapshot.base &&
                     s.exchange === "Bithumb" &&
                     s.spot !== null
                 );
@@ -383,13 +383,14 @@ const SnapshotTable = ({ snapshots, exchangeRates }: SnapshotTableProps) => {
     }
   };
 
-  // 모든 spot 데이터를 추출 (같은 심볼의 다른 거래소 현물 가격 표시용 및 정렬용)
+  // 모든 spot 데이터를 추출 (같은 기초자산의 다른 거래소 현물 가격 표시용 및 정렬용)
+  // 거래소마다 quote가 다르므로 (BTCUSDT vs BTCKRW) symbol이 아닌 base로 매칭
   const allSpotSnapshots = useMemo(() => {
     return snapshots
       .filter((s) => s.spot !== null)
       .map((s) => ({
         exchange: s.exchange,
-        symbol: s.symbol,
+        base: s.base,
         spot: s.spot,
       }));
   }, [snapshots]);
@@ -430,13 +431,13 @@ const SnapshotTable = ({ snapshots, exchangeRates }: SnapshotTableProps) => {
           // 빗썸 현물 가격과의 차이 계산
           const aBithumbSpot = allSpotSnapshots.find(
             (s) =>
-              s.symbol === a.symbol &&
+              s.base === a.base &&
               s.exchange === "Bithumb" &&
               s.spot !== null
           );
           const bBithumbSpot = allSpotSnapshots.find(
             (s) =>
-              s.symbol === b.symbol &&
+              s.base === b.base &&
               s.exchange === "Bithumb" &&
               s.spot !== null
           );
@@ -590,6 +591,9 @@ const SnapshotTable = ({ snapshots, exchangeRates }: SnapshotTableProps) => {
     if (symbol.endsWith("USDT")) {
       return symbol.slice(0, -4) + "/USDT";
     }
+    if (symbol.endsWith("KRW")) {
+      return symbol.slice(0, -3) + "/KRW";
+    }
     return symbol;
   }, []);
 
@@ -686,8 +690,8 @@ const SnapshotTable = ({ snapshots, exchangeRates }: SnapshotTableProps) => {
       case "Bitget":
         return `https://www.bitget.com/spot/${symbol}`;
       case "Bithumb":
-        // 빗썸은 심볼 형식이 다를 수 있음 (예: BTC_KRW)
-        const bithumbSymbol = symbol.replace("USDT", "KRW");
+        // 빗썸 심볼 형식 (예: BTC_KRW)
+        const bithumbSymbol = symbol.replace(/(USDT|KRW)$/, "_KRW");
         return `https://www.bithumb.com/trade/order/${bithumbSymbol}`;
       default:
         return "#";
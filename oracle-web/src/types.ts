@@ -1,8 +1,21 @@
 export type ExchangeId = 'Binance' | 'Bybit' | 'Okx' | 'Bitget' | 'Bithumb';
 export type Currency = 'USD' | 'KRW' | 'USDT';
 
+export type InstrumentKind = 'Spot' | 'Perp' | 'Delivery';
+
+export interface Instrument {
+  base: string;
+  quote: string;
+  kind: InstrumentKind;
+  exchange: ExchangeId;
+  native_symbol: string; // 거래소 원본 심볼 (예: 1000PEPEUSDT, BTC-USDT-SWAP)
+  multiplier: number; // 1000PEPE -> 1000
+}
+
 export interface PerpData {
+  instrument: Instrument;
   currency: Currency;
+  mark_price: number; // 기초자산 1개 기준 가격
   mark_price: number;
   oi_usd: number;
   vol_24h_usd: number;
@@ -11,6 +24,7 @@ export interface PerpData {
 }
 
 export interface SpotData {
+  instrument: Instrument;
   currency: Currency;
   price: number;
   vol_24h_usd: number;
@@ -21,11 +35,15 @@ export interface ExchangeRates {
   usdt_usd: number; // 1 USDT = ? USD (보통 1.0)
   usdt_krw: number; // 1 USDT = ? KRW (예: 1300.0)
   updated_at: string;
+  sources: { usd_krw: number; usdt_usd: number; usdt_krw: number }; // 환율별 집계에 쓰인 시세 수
+  is_fallback: boolean; // 기본값/오래된 값 사용 중
 }
 
 export interface UnifiedSnapshot {
   exchange: ExchangeId;
   symbol: string;
+  base: string;
+  quote: string;
   currency: Currency;
   perp: PerpData | null;
   spot: SpotData | null;
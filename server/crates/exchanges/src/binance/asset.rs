@@ -2,10 +2,11 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use interface::{ExchangeId, FutureAsset, SpotAsset};
+use interface::{Decimal, ExchangeId, FutureAsset, SpotAsset};
 
-use super::super::{AssetExchange, ExchangeError};
-use super::{generate_signature, get_timestamp, BinanceClient, BASE_URL};
+use super::super::rate_limit::{EndpointClass, RateLimiter};
+use super::super::{decimal::parse_decimal, error::retry_after, AssetExchange, ExchangeError};
+use super::{api_error, generate_signature, get_timestamp, BinanceClient};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,12 +30,12 @@ impl AssetExchange for BinanceClient {
 
     async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
-            ExchangeError::Other(
+            ExchangeError::Auth(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
         let api_secret = self.api_secret.as_ref().ok_or_else(|| {
-            ExchangeError::Other(
+            ExchangeError::Auth(
                 "API secret not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
@@ -48,25 +49,24 @@ impl AssetExchange for BinanceClient {
         let signature = generate_signature(&query_string, api_secret);
         let url = format!(
             "{}{}?{}&signature={}",
-            BASE_URL, endpoint, query_string, signature
+            self.endpoints.spot_rest, endpoint, query_string, signature
         );
 
-        let response = self
-            .http
-            .get(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Spot,
+                20,
+                self.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
             .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Binance API HTTP error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         let account: BinanceAccountResponse =
@@ -82,12 +82,12 @@ impl AssetExchange for BinanceClient {
         let mut assets = Vec::new();
 
         for balance in account.balances {
-            let free: f64 = balance.free.parse().unwrap_or(0.0);
-            let locked: f64 = balance.locked.parse().unwrap_or(0.0);
+            let free = parse_decimal(&balance.free).unwrap_or_default();
+            let locked = parse_decimal(&balance.locked).unwrap_or_default();
             let total = free + locked;
 
             // 잔액이 0인 경우 스킵 (선택사항)
-            if total > 0.0 {
+            if total > Decimal::ZERO {
                 assets.push(SpotAsset {
                     currency: balance.asset,
                     total,
@@ -103,12 +103,12 @@ impl AssetExchange for BinanceClient {
 
     async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
-            ExchangeError::Other(
+            ExchangeError::Auth(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
         let api_secret = self.api_secret.as_ref().ok_or_else(|| {
-            ExchangeError::Other(
+            ExchangeError::Auth(
                 "API secret not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
@@ -118,28 +118,26 @@ impl AssetExchange for BinanceClient {
         let query_string = format!("timestamp={}&recvWindow=50000", timestamp);
         let signature = super::generate_signature(&query_string, api_secret);
 
-        const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            self.endpoints.futures_rest, endpoint, query_string, signature
         );
 
-        let response = self
-            .http
-            .get(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Futures,
+                5,
+                self.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
             .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Futures position API error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         #[derive(Debug, Deserialize)]
@@ -147,6 +145,14 @@ impl AssetExchange for BinanceClient {
         struct PositionRisk {
             symbol: String,
             position_amt: String,
+            #[serde(default)]
+            entry_price: String,
+            #[serde(default)]
+            un_realized_profit: String,
+            #[serde(default)]
+            leverage: String,
+            #[serde(default)]
+            liquidation_price: String,
         }
 
         let positions: Vec<PositionRisk> = serde_json::from_str(&response_text).map_err(|e| {
@@ -160,12 +166,18 @@ impl AssetExchange for BinanceClient {
         let now = Utc::now();
         let mut result = Vec::new();
         for pos in positions {
-            let position_amt: f64 = pos.position_amt.parse().unwrap_or(0.0);
+            let position_amt = parse_decimal(&pos.position_amt).unwrap_or_default();
             // 포지션이 있는 경우만 추가 (0이 아닌 경우)
-            if position_amt.abs() > 1e-10 {
+            if !position_amt.is_zero() {
                 result.push(FutureAsset {
                     symbol: pos.symbol,
                     position_amt,
+                    entry_price: parse_decimal(&pos.entry_price).unwrap_or_default(),
+                    unrealized_pnl: parse_decimal(&pos.un_realized_profit).unwrap_or_default(),
+                    leverage: parse_decimal(&pos.leverage).unwrap_or_default(),
+                    // 청산가가 없으면 "0"으로 온다
+                    liquidation_price: parse_decimal(&pos.liquidation_price)
+                        .filter(|v| *v > Decimal::ZERO),
                     updated_at: now,
                 });
             }
@@ -200,8 +212,11 @@ mod tests {
                     println!("HTTP 오류: {:?}", reqwest_err);
                 }
             }
-            ExchangeError::Other(msg) => {
-                println!("기타 오류: {}", msg);
+            ExchangeError::Auth(msg) => {
+                println!("API 인증 실패: {}", msg);
+            }
+            other => {
+                println!("기타 오류: {}", other);
             }
         }
     }
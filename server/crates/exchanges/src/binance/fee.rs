@@ -2,15 +2,26 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use tokio::sync::RwLock;
 
-use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType};
+use interface::{
+    Decimal, DepositWithdrawalFee, ExchangeId, FeeDiscount, FeeInfo, InstrumentKind, MarketType,
+    NetworkStatus, WalletStatus,
+};
 
-use super::super::FeeExchange;
+use super::super::decimal::parse_decimal;
+use super::super::error::{check_status, retry_after};
+use super::super::instrument::resolve_symbol;
+use super::super::rate_limit::{EndpointClass, RateLimiter};
+use super::super::{FeeExchange, WalletStatusExchange};
 // mod.rs의 BinanceClient를 import하여 FeeExchange trait 구현
-use super::{generate_signature, get_api_credentials, get_timestamp, BinanceClient, SAPI_BASE_URL};
+use super::{api_error, generate_signature, get_timestamp, BinanceClient};
+
+/// BNB로 수수료를 낼 때의 할인율 (현물 25%, USDⓈ-M 선물 10%)
+const SPOT_BNB_DISCOUNT: f64 = 0.25;
+const FUTURES_BNB_DISCOUNT: f64 = 0.10;
 
 /// 입출금 수수료 캐시
 static FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, DepositWithdrawalFee>>>> =
@@ -20,6 +31,14 @@ static FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, DepositWithdr
 static TRADE_FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, FeeInfo>>>> =
     tokio::sync::OnceCell::const_new();
 
+/// 선물 거래 수수료 캐시 (symbol -> FeeInfo)
+static FUTURES_TRADE_FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, FeeInfo>>>> =
+    tokio::sync::OnceCell::const_new();
+
+/// BNB 수수료 차감 설정 캐시 (현물은 Spot, 선물은 Perp 키)
+static BNB_FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<InstrumentKind, bool>>>> =
+    tokio::sync::OnceCell::const_new();
+
 /// 캐시 초기화 (한 번만 실행)
 async fn init_fee_cache() -> Arc<RwLock<HashMap<String, DepositWithdrawalFee>>> {
     FEE_CACHE
@@ -36,12 +55,37 @@ async fn init_trade_fee_cache() -> Arc<RwLock<HashMap<String, FeeInfo>>> {
         .clone()
 }
 
+/// 선물 거래 수수료 캐시 초기화
+async fn init_futures_trade_fee_cache() -> Arc<RwLock<HashMap<String, FeeInfo>>> {
+    FUTURES_TRADE_FEE_CACHE
+        .get_or_init(|| async { Arc::new(RwLock::new(HashMap::new())) })
+        .await
+        .clone()
+}
+
+/// BNB 수수료 설정 캐시 초기화
+async fn init_bnb_fee_cache() -> Arc<RwLock<HashMap<InstrumentKind, bool>>> {
+    BNB_FEE_CACHE
+        .get_or_init(|| async { Arc::new(RwLock::new(HashMap::new())) })
+        .await
+        .clone()
+}
+
+/// BNB 수수료 설정은 현물/선물 계정 단위라 선물 종류는 하나의 키로 묶는다
+fn bnb_fee_cache_key(kind: InstrumentKind) -> InstrumentKind {
+    match kind {
+        InstrumentKind::Spot => InstrumentKind::Spot,
+        _ => InstrumentKind::Perp,
+    }
+}
+
 /// Binance 입출금 수수료 API 응답 (getall 엔드포인트)
 /// 한 번의 호출로 모든 코인의 네트워크 정보까지 포함해서 반환
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinanceCoinInfo {
     coin: String,
+    #[allow(dead_code)]
     #[serde(default)]
     name: Option<String>,
     #[serde(default)]
@@ -61,6 +105,7 @@ struct BinanceNetwork {
     deposit_enable: Option<bool>,
     #[serde(default)]
     withdraw_enable: Option<bool>,
+    #[allow(dead_code)]
     #[serde(default)]
     deposit_tip: Option<String>,
     #[serde(default, rename = "withdrawFee")]
@@ -71,18 +116,48 @@ struct BinanceNetwork {
     max_withdraw_amount: Option<String>,
 }
 
+/// 코인 설정을 네트워크별 입출금 상태로 변환
+/// 코인 단위 스위치(depositAllEnable 등)가 꺼져 있으면 모든 네트워크를 막힌 것으로 본다.
+fn to_wallet_status(info: &BinanceCoinInfo, now: DateTime<Utc>) -> WalletStatus {
+    let coin_deposit = info.deposit_all_enable.unwrap_or(true);
+    let coin_withdraw = info.withdraw_all_enable.unwrap_or(true);
+    WalletStatus {
+        exchange: ExchangeId::Binance,
+        currency: info.coin.to_uppercase(),
+        networks: info
+            .network_list
+            .iter()
+            .map(|network| NetworkStatus {
+                network: network.network.clone(),
+                deposit_enabled: coin_deposit && network.deposit_enable.unwrap_or(false),
+                withdraw_enabled: coin_withdraw && network.withdraw_enable.unwrap_or(false),
+                deposit_fee: Some(Decimal::ZERO), // Binance는 입금 수수료가 없음
+                withdraw_fee: parse_decimal(&network.withdraw_fee),
+                deposit_min: None,
+                withdraw_min: network
+                    .min_withdraw_amount
+                    .as_deref()
+                    .and_then(parse_decimal),
+                withdraw_max: network
+                    .max_withdraw_amount
+                    .as_deref()
+                    .and_then(parse_decimal),
+            })
+            .collect(),
+        updated_at: now,
+    }
+}
+
 impl BinanceClient {
-    /// 입출금 수수료 캐시 초기화 및 업데이트
-    pub async fn refresh_deposit_withdrawal_fees(
-        &self,
-    ) -> Result<HashMap<String, DepositWithdrawalFee>, super::super::ExchangeError> {
+    /// 전체 코인의 네트워크 설정 조회 (GET /sapi/v1/capital/config/getall)
+    async fn fetch_coin_config(&self) -> Result<Vec<BinanceCoinInfo>, super::super::ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
-            super::super::ExchangeError::Other(
+            super::super::ExchangeError::Auth(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
         let api_secret = self.api_secret.as_ref().ok_or_else(|| {
-            super::super::ExchangeError::Other(
+            super::super::ExchangeError::Auth(
                 "API secret not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
@@ -92,29 +167,28 @@ impl BinanceClient {
         let endpoint = "/sapi/v1/capital/config/getall";
         let timestamp = get_timestamp();
         let query_string = format!("timestamp={}&recvWindow=50000", timestamp);
-        let signature = generate_signature(&query_string, &api_secret);
+        let signature = generate_signature(&query_string, api_secret);
         let url = format!(
             "{}{}?{}&signature={}",
-            SAPI_BASE_URL, endpoint, query_string, signature
+            self.endpoints.sapi_rest, endpoint, query_string, signature
         );
 
-        let response = self
-            .http
-            .get(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Wallet,
+                10,
+                self.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
             .await?;
 
         // response.text()는 한 번만 호출 (바디를 소비하므로)
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(super::super::ExchangeError::Other(format!(
-                "Failed to fetch fee API: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         // getall 엔드포인트는 모든 코인의 네트워크 정보까지 포함해서 반환
@@ -127,6 +201,15 @@ impl BinanceClient {
                 ))
             })?;
 
+        Ok(coin_infos)
+    }
+
+    /// 입출금 수수료 캐시 초기화 및 업데이트
+    pub async fn refresh_deposit_withdrawal_fees(
+        &self,
+    ) -> Result<HashMap<String, DepositWithdrawalFee>, super::super::ExchangeError> {
+        let coin_infos = self.fetch_coin_config().await?;
+
         let mut fees = HashMap::new();
         let now = Utc::now();
 
@@ -163,8 +246,8 @@ impl BinanceClient {
 
             if let Some(network) = best_network {
                 let currency = info.coin.to_uppercase();
-                let deposit_fee = 0.0; // Binance는 입금 수수료가 없음
-                let withdrawal_fee = network.withdraw_fee.parse::<f64>().unwrap_or(0.0);
+                let deposit_fee = Decimal::ZERO; // Binance는 입금 수수료가 없음
+                let withdrawal_fee = parse_decimal(&network.withdraw_fee).unwrap_or_default();
 
                 fees.insert(
                     currency.clone(),
@@ -210,12 +293,12 @@ impl BinanceClient {
         &self,
     ) -> Result<HashMap<String, FeeInfo>, super::super::ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
-            super::super::ExchangeError::Other(
+            super::super::ExchangeError::Auth(
                 "API key not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
         let api_secret = self.api_secret.as_ref().ok_or_else(|| {
-            super::super::ExchangeError::Other(
+            super::super::ExchangeError::Auth(
                 "API secret not set. Use BinanceClient::with_credentials()".to_string(),
             )
         })?;
@@ -224,10 +307,10 @@ impl BinanceClient {
         let endpoint = "/sapi/v1/asset/tradeFee";
         let timestamp = get_timestamp();
         let query_string = format!("timestamp={}&recvWindow=50000", timestamp);
-        let signature = generate_signature(&query_string, &api_secret);
+        let signature = generate_signature(&query_string, api_secret);
         let url = format!(
             "{}{}?{}&signature={}",
-            SAPI_BASE_URL, endpoint, query_string, signature
+            self.endpoints.sapi_rest, endpoint, query_string, signature
         );
 
         #[derive(Debug, Deserialize)]
@@ -238,22 +321,16 @@ impl BinanceClient {
             taker_commission: String,
         }
 
-        let response = self
-            .http
-            .get(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Wallet,
+                1,
+                self.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
             .await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let response_text = response.text().await?;
-            return Err(super::super::ExchangeError::Other(format!(
-                "Failed to fetch trade fee API: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
-        }
+        let response = check_status(response, api_error).await?;
 
         let trade_fees: Vec<TradeFeeResponse> = response.json().await?;
 
@@ -267,9 +344,10 @@ impl BinanceClient {
 
         tracing::info!("Parsed {} trade fees from Binance API", fees.len());
 
-        // 캐시 업데이트
+        // 캐시 업데이트 (BNB 차감 설정도 다음 조회 때 다시 읽는다)
         let cache = init_trade_fee_cache().await;
         *cache.write().await = fees.clone();
+        init_bnb_fee_cache().await.write().await.clear();
 
         Ok(fees)
     }
@@ -306,6 +384,142 @@ impl BinanceClient {
             ))
         })
     }
+
+    /// 서명이 필요한 GET 요청 (수수료 관련 조회 공통)
+    async fn signed_get<T: serde::de::DeserializeOwned>(
+        &self,
+        base_url: &str,
+        endpoint: &str,
+        params: &str,
+    ) -> Result<T, super::super::ExchangeError> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| {
+            super::super::ExchangeError::Auth(
+                "API key not set. Use BinanceClient::with_credentials()".to_string(),
+            )
+        })?;
+        let api_secret = self.api_secret.as_ref().ok_or_else(|| {
+            super::super::ExchangeError::Auth(
+                "API secret not set. Use BinanceClient::with_credentials()".to_string(),
+            )
+        })?;
+
+        let timestamp = get_timestamp();
+        let query_string = if params.is_empty() {
+            format!("timestamp={}&recvWindow=50000", timestamp)
+        } else {
+            format!("{}&timestamp={}&recvWindow=50000", params, timestamp)
+        };
+        let signature = generate_signature(&query_string, api_secret);
+        let url = format!(
+            "{}{}?{}&signature={}",
+            base_url, endpoint, query_string, signature
+        );
+
+        let class = if endpoint.starts_with("/sapi") {
+            EndpointClass::Wallet
+        } else {
+            EndpointClass::Futures
+        };
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                class,
+                20,
+                self.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
+            .await?;
+
+        Ok(check_status(response, api_error).await?.json().await?)
+    }
+
+    /// 특정 선물 심볼의 거래 수수료 조회 (GET /fapi/v1/commissionRate, VIP 등급 반영)
+    pub async fn get_futures_trade_fee_for_symbol(
+        &self,
+        symbol: &str,
+    ) -> Result<FeeInfo, super::super::ExchangeError> {
+        let normalized_symbol = symbol.replace("-", "").to_uppercase();
+
+        let cache = init_futures_trade_fee_cache().await;
+        if let Some(fee) = cache.read().await.get(&normalized_symbol) {
+            return Ok(fee.clone());
+        }
+
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommissionRateResponse {
+            maker_commission_rate: String,
+            taker_commission_rate: String,
+        }
+
+        let response: CommissionRateResponse = self
+            .signed_get(
+                &self.endpoints.futures_rest,
+                "/fapi/v1/commissionRate",
+                &format!("symbol={}", normalized_symbol),
+            )
+            .await?;
+
+        let fee = FeeInfo::new(
+            response.maker_commission_rate.parse::<f64>().unwrap_or(0.0),
+            response.taker_commission_rate.parse::<f64>().unwrap_or(0.0),
+        );
+        cache.write().await.insert(normalized_symbol, fee.clone());
+
+        Ok(fee)
+    }
+
+    /// BNB 수수료 차감 설정 여부 (캐시, 없으면 조회)
+    /// 수수료 조회마다 서명 요청을 보내지 않도록 거래 수수료 캐시와 함께 보관한다.
+    async fn cached_bnb_fee_enabled(
+        &self,
+        kind: InstrumentKind,
+    ) -> Result<bool, super::super::ExchangeError> {
+        let key = bnb_fee_cache_key(kind);
+        let cache = init_bnb_fee_cache().await;
+        if let Some(enabled) = cache.read().await.get(&key) {
+            return Ok(*enabled);
+        }
+
+        let enabled = self.is_bnb_fee_enabled(kind).await?;
+        cache.write().await.insert(key, enabled);
+        Ok(enabled)
+    }
+
+    /// BNB 수수료 차감 설정 여부 조회
+    /// 현물: GET /sapi/v1/bnbBurn, 선물: GET /fapi/v1/feeBurn
+    pub async fn is_bnb_fee_enabled(
+        &self,
+        kind: InstrumentKind,
+    ) -> Result<bool, super::super::ExchangeError> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BnbBurnResponse {
+            #[serde(default, rename = "spotBNBBurn")]
+            spot_bnb_burn: bool,
+        }
+
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct FeeBurnResponse {
+            #[serde(default)]
+            fee_burn: bool,
+        }
+
+        match kind {
+            InstrumentKind::Spot => {
+                let response: BnbBurnResponse = self
+                    .signed_get(&self.endpoints.sapi_rest, "/sapi/v1/bnbBurn", "")
+                    .await?;
+                Ok(response.spot_bnb_burn)
+            }
+            _ => {
+                let response: FeeBurnResponse = self
+                    .signed_get(&self.endpoints.futures_rest, "/fapi/v1/feeBurn", "")
+                    .await?;
+                Ok(response.fee_burn)
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -326,6 +540,47 @@ impl FeeExchange for BinanceClient {
         }
     }
 
+    fn get_perp_fee(&self) -> FeeInfo {
+        // USDⓈ-M 선물 기본 요율 (VIP 0): 0.02% 메이커, 0.05% 테이커
+        // 실제 요율은 get_futures_trade_fee_for_symbol()로 조회
+        FeeInfo::new(0.0002, 0.0005)
+    }
+
+    async fn fetch_trade_fee(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<FeeInfo, super::super::ExchangeError> {
+        let normalized_symbol = resolve_symbol(ExchangeId::Binance, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                super::super::ExchangeError::Other(format!(
+                    "Unsupported Binance symbol: {}",
+                    symbol
+                ))
+            })?;
+
+        let (fee, discount_rate) = match kind {
+            InstrumentKind::Spot => (
+                self.get_trade_fee_for_symbol(&normalized_symbol).await?,
+                SPOT_BNB_DISCOUNT,
+            ),
+            _ => (
+                self.get_futures_trade_fee_for_symbol(&normalized_symbol)
+                    .await?,
+                FUTURES_BNB_DISCOUNT,
+            ),
+        };
+
+        if self.cached_bnb_fee_enabled(kind).await? {
+            return Ok(fee.with_discount(FeeDiscount {
+                asset: "BNB".to_string(),
+                rate: discount_rate,
+            }));
+        }
+        Ok(fee)
+    }
+
     async fn get_deposit_withdrawal_fee(
         &self,
         currency: &str,
@@ -343,10 +598,48 @@ impl FeeExchange for BinanceClient {
     }
 }
 
+#[async_trait]
+impl WalletStatusExchange for BinanceClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
+
+    async fn fetch_wallet_status(&self) -> Result<Vec<WalletStatus>, super::super::ExchangeError> {
+        let now = Utc::now();
+        Ok(self
+            .fetch_coin_config()
+            .await?
+            .iter()
+            .map(|info| to_wallet_status(info, now))
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_binance_wallet_status() {
+        let body = r#"[{"coin":"ETH","depositAllEnable":true,"withdrawAllEnable":true,"name":"Ethereum","free":"0","locked":"0","networkList":[{"network":"ETH","coin":"ETH","withdrawIntegerMultiple":"0.00000001","isDefault":true,"depositEnable":true,"withdrawEnable":false,"depositDesc":"","withdrawDesc":"Wallet Maintenance","name":"Ethereum (ERC20)","resetAddressStatus":false,"withdrawFee":"0.0012","withdrawMin":"0.0024","withdrawMax":"10000000000"},{"network":"ARBITRUM","coin":"ETH","depositEnable":true,"withdrawEnable":true,"withdrawFee":"0.0001","withdrawMin":"0.0008","withdrawMax":"9999999"}]},{"coin":"XYZ","depositAllEnable":false,"withdrawAllEnable":true,"networkList":[{"network":"BSC","depositEnable":true,"withdrawEnable":true,"withdrawFee":"1"}]}]"#;
+        let infos: Vec<BinanceCoinInfo> = serde_json::from_str(body).unwrap();
+        let statuses: Vec<_> = infos
+            .iter()
+            .map(|i| to_wallet_status(i, Utc::now()))
+            .collect();
+
+        let eth = &statuses[0];
+        assert_eq!(eth.currency, "ETH");
+        assert!(!eth.networks[0].withdraw_enabled);
+        assert_eq!(eth.networks[0].withdraw_min, Some(Decimal::new(24, 4)));
+        assert_eq!(eth.networks[1].withdraw_fee, Some(Decimal::new(1, 4)));
+        assert!(eth.can_deposit() && eth.can_withdraw());
+
+        // 코인 단위로 입금이 막히면 네트워크 설정과 관계없이 막힌다
+        assert!(!statuses[1].can_deposit());
+        assert!(statuses[1].can_withdraw());
+    }
+
     fn skip_if_no_credentials() {
         if !super::super::has_api_credentials() {
             println!("Skipping test: BINANCE_API_KEY and BINANCE_API_SECRET not set");
@@ -368,8 +661,11 @@ mod tests {
                     println!("HTTP 오류: {:?}", reqwest_err);
                 }
             }
-            super::super::ExchangeError::Other(msg) => {
-                println!("기타 오류: {}", msg);
+            super::super::ExchangeError::Auth(msg) => {
+                println!("API 인증 실패: {}", msg);
+            }
+            other => {
+                println!("기타 오류: {}", other);
             }
         }
     }
@@ -381,7 +677,6 @@ mod tests {
         let client = BinanceClient::with_credentials()
             .map_err(|e| {
                 println!("BinanceClient 생성 실패: {:?}", e);
-                return;
             })
             .unwrap();
         let result = client.refresh_deposit_withdrawal_fees().await;
@@ -423,7 +718,6 @@ mod tests {
         let client = BinanceClient::with_credentials()
             .map_err(|e| {
                 println!("BinanceClient 생성 실패: {:?}", e);
-                return;
             })
             .unwrap();
 
@@ -436,8 +730,8 @@ mod tests {
         match result {
             Ok(fee) => {
                 assert_eq!(fee.currency, "BTC");
-                assert!(fee.deposit_fee >= 0.0);
-                assert!(fee.withdrawal_fee >= 0.0);
+                assert!(fee.deposit_fee >= Decimal::ZERO);
+                assert!(fee.withdrawal_fee >= Decimal::ZERO);
                 println!(
                     "BTC fees - Deposit: {}, Withdrawal: {}",
                     fee.deposit_fee, fee.withdrawal_fee
@@ -448,4 +742,28 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_fetch_trade_fee() {
+        skip_if_no_credentials();
+
+        let Ok(client) = BinanceClient::with_credentials() else {
+            return;
+        };
+
+        for kind in [InstrumentKind::Spot, InstrumentKind::Perp] {
+            match client.fetch_trade_fee("BTC-USDT", kind).await {
+                Ok(fee) => {
+                    assert!(fee.effective(false) <= fee.taker);
+                    println!(
+                        "{:?} BTCUSDT fee - maker: {}, taker: {}, discount: {:?}",
+                        kind, fee.maker, fee.taker, fee.discount
+                    );
+                }
+                Err(e) => {
+                    handle_api_error(&e);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,129 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use interface::{ExchangeId, FundingRecord, InstrumentKind};
+
+use crate::binance::api_error;
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    error::check_status, funding::finish_history, instrument::resolve_symbol, BinanceClient,
+    ExchangeError, FundingHistoryExchange,
+};
+
+/// GET /fapi/v1/fundingRate 한 번에 받을 수 있는 최대 개수
+const PAGE_LIMIT: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceFundingRate {
+    symbol: String,
+    funding_rate: String,
+    funding_time: i64,
+}
+
+/// 응답 하나를 FundingRecord로 변환 (주기는 finish_history에서 채운다)
+fn to_funding_record(rate: BinanceFundingRate) -> Option<FundingRecord> {
+    Some(FundingRecord {
+        exchange: ExchangeId::Binance,
+        symbol: rate.symbol,
+        funding_time: DateTime::from_timestamp_millis(rate.funding_time)?,
+        funding_rate: rate.funding_rate.parse().ok()?,
+        funding_interval_hours: 0,
+    })
+}
+
+#[async_trait]
+impl FundingHistoryExchange for BinanceClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
+
+    async fn fetch_funding_history(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<FundingRecord>, ExchangeError> {
+        let normalized_symbol = resolve_symbol(ExchangeId::Binance, InstrumentKind::Perp, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Binance symbol: {}", symbol))
+            })?;
+
+        // startTime을 함께 보내면 startTime부터 오름차순으로 잘리므로,
+        // endTime만 보내 최신 기록부터 받고 start 이전에 닿을 때까지 거슬러 올라간다.
+        // 주기 계산을 위해 limit보다 하나 더 받는다.
+        let mut records = Vec::new();
+        let mut cursor = end;
+        while records.len() <= limit {
+            let url = format!(
+                "{}/fapi/v1/fundingRate?symbol={}&endTime={}&limit={}",
+                self.endpoints.futures_rest,
+                normalized_symbol,
+                cursor.timestamp_millis(),
+                PAGE_LIMIT
+            );
+            let page: Vec<BinanceFundingRate> = check_status(
+                RateLimiter::global()
+                    .send(
+                        ExchangeId::Binance,
+                        EndpointClass::Futures,
+                        1,
+                        self.http.get(&url),
+                    )
+                    .await?,
+                api_error,
+            )
+            .await?
+            .json()
+            .await?;
+
+            let page_len = page.len();
+            let page: Vec<_> = page.into_iter().filter_map(to_funding_record).collect();
+            let Some(oldest) = page.iter().map(|r| r.funding_time).min() else {
+                break;
+            };
+            records.extend(page);
+
+            if page_len < PAGE_LIMIT || oldest < start {
+                break;
+            }
+            cursor = oldest - Duration::milliseconds(1);
+        }
+
+        Ok(finish_history(records, start, end, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_funding_history_binance() {
+        let client = BinanceClient::new();
+        let end = Utc::now();
+
+        match client
+            .fetch_funding_history("BTC-USDT", end - Duration::days(7), end, 10)
+            .await
+        {
+            Ok(records) => {
+                assert!(records.len() <= 10);
+                assert!(records
+                    .windows(2)
+                    .all(|w| w[0].funding_time < w[1].funding_time));
+                for r in &records {
+                    assert_eq!(r.symbol, "BTCUSDT");
+                    assert!(r.funding_interval_hours > 0);
+                }
+            }
+            Err(e) => {
+                // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                eprintln!("Warning: API call failed: {:?}", e);
+            }
+        }
+    }
+}
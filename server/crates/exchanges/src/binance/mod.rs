@@ -1,19 +1,24 @@
 use std::env;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use serde::Deserialize;
 use sha2::Sha256;
 
 use super::ExchangeError;
+use crate::endpoint::{BinanceEndpoints, Endpoints};
+use crate::error::{from_http_status, truncate_body};
 
 pub mod asset;
 pub mod fee;
+pub mod funding;
+pub mod kline;
 pub mod orderbook;
 pub mod perp;
 pub mod spot;
-
-pub const BASE_URL: &str = "https://api.binance.com";
-pub const SAPI_BASE_URL: &str = "https://api.binance.com";
+pub mod stream;
+pub mod transfer;
 
 /// Binance 통합 클라이언트 (Orderbook, Asset, Fee 모두 지원)
 #[derive(Clone)]
@@ -21,15 +26,28 @@ pub struct BinanceClient {
     pub http: reqwest::Client,
     pub api_key: Option<String>,
     pub api_secret: Option<String>,
+    pub endpoints: BinanceEndpoints,
+}
+
+impl Default for BinanceClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BinanceClient {
     /// 공개 API만 사용하는 경우 (Orderbook 등)
     pub fn new() -> Self {
+        Self::with_endpoints(Endpoints::current().binance.clone())
+    }
+
+    /// 지정한 주소로 공개 API 클라이언트 생성 (테스트넷, 목 서버 등)
+    pub fn with_endpoints(endpoints: BinanceEndpoints) -> Self {
         Self {
             http: reqwest::Client::new(),
             api_key: None,
             api_secret: None,
+            endpoints,
         }
     }
 
@@ -40,6 +58,7 @@ impl BinanceClient {
             http: reqwest::Client::new(),
             api_key: Some(api_key),
             api_secret: Some(api_secret),
+            endpoints: Endpoints::current().binance.clone(),
         })
     }
 }
@@ -67,9 +86,9 @@ pub fn get_timestamp() -> u64 {
 /// 환경변수에서 API 키와 시크릿 가져오기
 pub fn get_api_credentials() -> Result<(String, String), ExchangeError> {
     let api_key = env::var("BINANCE_API_KEY")
-        .map_err(|e| ExchangeError::Other(format!("BINANCE_API_KEY not found: {}", e)))?;
+        .map_err(|e| ExchangeError::Auth(format!("BINANCE_API_KEY not found: {}", e)))?;
     let api_secret = env::var("BINANCE_API_SECRET")
-        .map_err(|e| ExchangeError::Other(format!("BINANCE_API_SECRET not found: {}", e)))?;
+        .map_err(|e| ExchangeError::Auth(format!("BINANCE_API_SECRET not found: {}", e)))?;
     Ok((api_key, api_secret))
 }
 
@@ -78,4 +97,96 @@ pub fn has_api_credentials() -> bool {
     env::var("BINANCE_API_KEY").is_ok() && env::var("BINANCE_API_SECRET").is_ok()
 }
 
+/// Binance 에러 응답 본문 (예: {"code":-2010,"msg":"Account has insufficient balance..."})
+#[derive(Debug, Deserialize)]
+struct BinanceErrorResponse {
+    code: i64,
+    msg: String,
+}
+
+/// Binance 에러 코드를 ExchangeError로 변환 (Spot/Futures 공통)
+pub fn map_error_code(code: i64, msg: &str) -> ExchangeError {
+    match code {
+        // TOO_MANY_REQUESTS, TOO_MANY_ORDERS
+        -1003 | -1015 => ExchangeError::RateLimited { retry_after: None },
+        // 백엔드 응답 타임아웃: 요청은 접수됐을 수 있어 실행 여부를 알 수 없다
+        -1007 => ExchangeError::UnknownStatus(format!("{}: {}", code, msg)),
+        // recvWindow를 벗어난 타임스탬프 (처리되지 않음)
+        -1021 => ExchangeError::Timeout(format!("{}: {}", code, msg)),
+        // 서명/API 키/권한 오류
+        -1002 | -1022 | -2014 | -2015 => ExchangeError::Auth(format!("{}: {}", code, msg)),
+        // 잔고/증거금 부족
+        -2018 | -2019 => ExchangeError::InsufficientBalance(msg.to_string()),
+        // NEW_ORDER_REJECTED는 사유가 메시지에만 담겨 온다
+        -2010 if msg.to_lowercase().contains("insufficient balance") => {
+            ExchangeError::InsufficientBalance(msg.to_string())
+        }
+        // LOT_SIZE/MIN_NOTIONAL 필터, 소수점 자릿수, 최소/최대 수량
+        -1013 | -1111 | -4003 | -4005 | -4164 => ExchangeError::InvalidQuantity(msg.to_string()),
+        _ => ExchangeError::ExchangeReject {
+            code: code.to_string(),
+            msg: msg.to_string(),
+        },
+    }
+}
+
+/// 실패한 HTTP 응답(상태 코드 + 본문)을 ExchangeError로 변환
+pub fn api_error(status: StatusCode, retry_after: Option<Duration>, body: &str) -> ExchangeError {
+    if matches!(status.as_u16(), 418 | 429) {
+        return ExchangeError::RateLimited { retry_after };
+    }
+    if let Ok(err) = serde_json::from_str::<BinanceErrorResponse>(body) {
+        return map_error_code(err.code, &err.msg);
+    }
+    from_http_status(status, retry_after, body).unwrap_or_else(|| {
+        ExchangeError::Other(format!(
+            "Binance API HTTP error: status {}, response: {}",
+            status,
+            truncate_body(body)
+        ))
+    })
+}
+
 // BinanceClient는 mod.rs에 정의되어 있음
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_binance_error_codes() {
+        let body =
+            r#"{"code":-2010,"msg":"Account has insufficient balance for requested action."}"#;
+        assert!(matches!(
+            api_error(StatusCode::BAD_REQUEST, None, body),
+            ExchangeError::InsufficientBalance(_)
+        ));
+
+        let body =
+            r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#;
+        let err = api_error(StatusCode::BAD_REQUEST, None, body);
+        assert!(matches!(err, ExchangeError::Timeout(_)));
+        assert!(err.is_retryable());
+
+        // -1007: 접수됐을 수 있으므로 재시도하면 안 된다
+        let body = r#"{"code":-1007,"msg":"Timeout waiting for response from backend server. Send status unknown; execution status unknown."}"#;
+        let err = api_error(StatusCode::BAD_REQUEST, None, body);
+        assert!(matches!(err, ExchangeError::UnknownStatus(_)));
+        assert!(!err.is_retryable());
+
+        let body = r#"{"code":-4046,"msg":"No need to change margin type."}"#;
+        match api_error(StatusCode::BAD_REQUEST, None, body) {
+            ExchangeError::ExchangeReject { code, .. } => assert_eq!(code, "-4046"),
+            other => panic!("unexpected: {:?}", other),
+        }
+
+        assert!(matches!(
+            map_error_code(-1013, "Filter failure: LOT_SIZE"),
+            ExchangeError::InvalidQuantity(_)
+        ));
+        assert!(matches!(
+            api_error(StatusCode::TOO_MANY_REQUESTS, None, ""),
+            ExchangeError::RateLimited { .. }
+        ));
+    }
+}
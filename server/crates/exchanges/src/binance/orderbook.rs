@@ -1,82 +1,184 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
+use tokio::sync::mpsc;
 
-use interface::{ExchangeId, OrderBook, OrderBookEntry};
+use interface::{ExchangeId, InstrumentKind, OrderBook};
 
-use super::super::{ExchangeError, OrderBookExchange};
-use super::{BinanceClient, BASE_URL};
+use super::super::rate_limit::{EndpointClass, RateLimiter};
+use super::super::{
+    book::{with_reset, BookDelta, BookEvent, BookSnapshot},
+    decimal::{parse_book_levels, parse_level_updates},
+    error::check_status,
+    instrument::resolve_symbol,
+    stream::{stream_forever, WsSubscription},
+    ExchangeError, OrderBookExchange, OrderBookStream,
+};
+use super::{api_error, BinanceClient};
 
-impl BinanceClient {
-    /// 심볼을 Binance 형식으로 변환
-    /// 예: "BTC-KRW" -> "BTCKRW", "BTC-USDT" -> "BTCUSDT"
-    fn normalize_symbol(&self, symbol: &str) -> String {
-        symbol.replace("-", "").to_uppercase()
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceOrderBookResponse {
+    #[serde(default)]
+    last_update_id: u64,
+    bids: Vec<Vec<String>>, // [price, quantity]
+    asks: Vec<Vec<String>>, // [price, quantity]
 }
 
+/// depthUpdate 이벤트 (선물만 직전 이벤트의 u인 pu를 준다)
 #[derive(Debug, Deserialize)]
-struct BinanceOrderBookResponse {
-    bids: Vec<[String; 2]>, // [price, quantity]
-    asks: Vec<[String; 2]>, // [price, quantity]
+struct BinanceDepthUpdate {
+    e: String,
+    #[serde(rename = "U")]
+    first_update_id: u64,
+    #[serde(rename = "u")]
+    last_update_id: u64,
+    pu: Option<u64>,
+    b: Vec<Vec<String>>,
+    a: Vec<Vec<String>>,
+}
+
+fn parse_depth_message(text: &str) -> Vec<BookEvent> {
+    let Ok(update) = serde_json::from_str::<BinanceDepthUpdate>(text) else {
+        return Vec::new();
+    };
+    if update.e != "depthUpdate" {
+        return Vec::new();
+    }
+    let (Ok(bids), Ok(asks)) = (
+        parse_level_updates(&update.b),
+        parse_level_updates(&update.a),
+    ) else {
+        return Vec::new();
+    };
+    vec![BookEvent::Delta(BookDelta {
+        bids,
+        asks,
+        first_sequence: update.first_update_id,
+        last_sequence: update.last_update_id,
+        prev_sequence: update.pu,
+    })]
 }
 
 #[async_trait]
-impl OrderBookExchange for BinanceClient {
+impl OrderBookStream for BinanceClient {
     fn id(&self) -> ExchangeId {
         ExchangeId::Binance
     }
 
-    async fn fetch_orderbook(&self, symbol: &str) -> Result<OrderBook, ExchangeError> {
-        let normalized_symbol = self.normalize_symbol(symbol);
-        let url = format!(
-            "{}/api/v3/depth?symbol={}&limit=100",
-            BASE_URL, normalized_symbol
-        );
-
-        let response = self.http.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let response_text = response.text().await?;
-            return Err(ExchangeError::Other(format!(
-                "Binance API HTTP error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
-        }
+    /// {symbol}@depth@100ms 변경분 스트림 (스냅샷은 REST로 맞춘다)
+    async fn stream_book_events(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+        tx: mpsc::Sender<BookEvent>,
+    ) -> Result<(), ExchangeError> {
+        let base = match kind {
+            InstrumentKind::Spot => &self.endpoints.spot_ws,
+            _ => &self.endpoints.futures_ws,
+        };
+        let subscription = WsSubscription {
+            url: format!("{}/ws/{}@depth@100ms", base, symbol.to_lowercase()),
+            messages: Vec::new(),
+            ping: None,
+        };
+        stream_forever("Binance depth", tx, || {
+            let subscription = subscription.clone();
+            async move { Ok((subscription, with_reset(parse_depth_message))) }
+        })
+        .await
+    }
 
-        let orderbook_response: BinanceOrderBookResponse = response.json().await?;
+    async fn fetch_book_snapshot(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<Option<BookSnapshot>, ExchangeError> {
+        let url = match kind {
+            InstrumentKind::Spot => {
+                format!(
+                    "{}/api/v3/depth?symbol={}&limit=1000",
+                    self.endpoints.spot_rest, symbol
+                )
+            }
+            _ => format!(
+                "{}/fapi/v1/depth?symbol={}&limit=1000",
+                self.endpoints.futures_rest, symbol
+            ),
+        };
 
-        let mut bids = Vec::new();
-        for bid in orderbook_response.bids {
-            let price: f64 = bid[0]
-                .parse()
-                .map_err(|e| ExchangeError::Other(format!("Failed to parse bid price: {}", e)))?;
-            let quantity: f64 = bid[1].parse().map_err(|e| {
-                ExchangeError::Other(format!("Failed to parse bid quantity: {}", e))
-            })?;
-            bids.push(OrderBookEntry { price, quantity });
-        }
+        let (class, weight) = match kind {
+            InstrumentKind::Spot => (EndpointClass::Spot, 50),
+            _ => (EndpointClass::Futures, 20),
+        };
+        let response = check_status(
+            RateLimiter::global()
+                .send(ExchangeId::Binance, class, weight, self.http.get(&url))
+                .await?,
+            api_error,
+        )
+        .await?;
+        let snapshot: BinanceOrderBookResponse = response.json().await?;
+
+        Ok(Some(BookSnapshot {
+            bids: parse_book_levels(&snapshot.bids)?,
+            asks: parse_book_levels(&snapshot.asks)?,
+            sequence: snapshot.last_update_id,
+        }))
+    }
+}
+
+#[async_trait]
+impl OrderBookExchange for BinanceClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
 
-        let mut asks = Vec::new();
-        for ask in orderbook_response.asks {
-            let price: f64 = ask[0]
-                .parse()
-                .map_err(|e| ExchangeError::Other(format!("Failed to parse ask price: {}", e)))?;
-            let quantity: f64 = ask[1].parse().map_err(|e| {
-                ExchangeError::Other(format!("Failed to parse ask quantity: {}", e))
+    async fn fetch_orderbook(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<OrderBook, ExchangeError> {
+        // 예: "BTC-USDT" -> "BTCUSDT" (현물/선물 동일 표기)
+        let normalized_symbol = resolve_symbol(ExchangeId::Binance, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Binance symbol: {}", symbol))
             })?;
-            asks.push(OrderBookEntry { price, quantity });
-        }
+        let url = match kind {
+            InstrumentKind::Spot => format!(
+                "{}/api/v3/depth?symbol={}&limit=100",
+                self.endpoints.spot_rest, normalized_symbol
+            ),
+            _ => format!(
+                "{}/fapi/v1/depth?symbol={}&limit=100",
+                self.endpoints.futures_rest, normalized_symbol
+            ),
+        };
+
+        let class = match kind {
+            InstrumentKind::Spot => EndpointClass::Spot,
+            _ => EndpointClass::Futures,
+        };
+        let response = RateLimiter::global()
+            .send(ExchangeId::Binance, class, 5, self.http.get(&url))
+            .await?;
+
+        let response = check_status(response, api_error).await?;
+
+        let orderbook_response: BinanceOrderBookResponse = response.json().await?;
+
+        let mut bids = parse_book_levels(&orderbook_response.bids)?;
+        let mut asks = parse_book_levels(&orderbook_response.asks)?;
 
         // Binance는 이미 가격 순서대로 정렬되어 있지만, 확실하게 정렬
-        bids.sort_by(|a, b| b.price.partial_cmp(&a.price).unwrap()); // 높은 가격 순
-        asks.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap()); // 낮은 가격 순
+        bids.sort_by_key(|e| std::cmp::Reverse(e.price)); // 높은 가격 순
+        asks.sort_by_key(|e| e.price); // 낮은 가격 순
 
         Ok(OrderBook {
             exchange: ExchangeId::Binance,
             symbol: normalized_symbol,
+            kind,
             bids,
             asks,
             updated_at: Utc::now(),
@@ -93,8 +195,8 @@ mod tests {
             ExchangeError::Http(reqwest_err) => {
                 println!("HTTP 오류: {:?}", reqwest_err);
             }
-            ExchangeError::Other(msg) => {
-                println!("기타 오류: {}", msg);
+            other => {
+                println!("기타 오류: {}", other);
             }
         }
     }
@@ -104,7 +206,10 @@ mod tests {
         let client = BinanceClient::new();
 
         // BTC-USDT 오더북 조회
-        match client.fetch_orderbook("BTC-USDT").await {
+        match client
+            .fetch_orderbook("BTC-USDT", InstrumentKind::Spot)
+            .await
+        {
             Ok(orderbook) => {
                 assert_eq!(orderbook.exchange, ExchangeId::Binance);
                 assert_eq!(orderbook.symbol, "BTCUSDT");
@@ -133,4 +238,51 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_parse_binance_depth_update() {
+        let msg = r#"{"e":"depthUpdate","E":1571889248277,"T":1571889248276,"s":"BTCUSDT","U":390497796,"u":390497878,"pu":390497794,"b":[["7403.89","0.002"],["7403.90","0"]],"a":[["7405.96","3.340"]]}"#;
+        let events = parse_depth_message(msg);
+        let [BookEvent::Delta(delta)] = events.as_slice() else {
+            panic!("expected one delta: {:?}", events);
+        };
+        assert_eq!(delta.first_sequence, 390497796);
+        assert_eq!(delta.last_sequence, 390497878);
+        assert_eq!(delta.prev_sequence, Some(390497794));
+        // 수량 0 레벨은 삭제 표시로 남아 있어야 한다
+        assert_eq!(delta.bids.len(), 2);
+        assert!(delta.bids[1].quantity.is_zero());
+
+        // 현물은 pu가 없다
+        let spot = r#"{"e":"depthUpdate","E":1,"s":"BNBBTC","U":157,"u":160,"b":[],"a":[["0.0026","100"]]}"#;
+        let events = parse_depth_message(spot);
+        assert!(matches!(
+            events.as_slice(),
+            [BookEvent::Delta(BookDelta {
+                prev_sequence: None,
+                ..
+            })]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_orderbook_binance_perp() {
+        let client = BinanceClient::new();
+
+        match client
+            .fetch_orderbook("BTC-USDT", InstrumentKind::Perp)
+            .await
+        {
+            Ok(orderbook) => {
+                assert_eq!(orderbook.kind, InstrumentKind::Perp);
+                assert_eq!(orderbook.symbol, "BTCUSDT");
+                assert!(!orderbook.bids.is_empty(), "Should have bids");
+                assert!(!orderbook.asks.is_empty(), "Should have asks");
+                assert!(orderbook.bids[0].price < orderbook.asks[0].price);
+            }
+            Err(e) => {
+                handle_api_error(&e);
+            }
+        }
+    }
 }
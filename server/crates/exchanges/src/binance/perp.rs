@@ -2,22 +2,66 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use serde::Deserialize;
 
-use crate::{BinanceClient, ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
-
-const BASE_URL: &str = "https://fapi.binance.com";
+use crate::binance::api_error;
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, BinanceClient,
+    ExchangeError, PerpExchange,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpSnapshot};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinancePremiumIndex {
     symbol: String,
     mark_price: String,
+    #[serde(default)]
+    index_price: String,
     last_funding_rate: String,
     next_funding_time: i64,
 }
 
+/// GET /fapi/v1/fundingInfo 항목 (펀딩 주기나 상하한이 조정된 심볼만 내려온다)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceFundingInfo {
+    symbol: String,
+    funding_interval_hours: u32,
+}
+
+/// fundingInfo에 없는 심볼의 펀딩 주기
+const DEFAULT_FUNDING_INTERVAL_HOURS: u32 = 8;
+
+impl BinanceClient {
+    /// 심볼별 펀딩 주기 (조정되지 않은 심볼은 맵에 없고 8시간)
+    async fn fetch_funding_intervals(&self) -> Result<HashMap<String, u32>, ExchangeError> {
+        let infos: Vec<BinanceFundingInfo> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Binance,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(format!(
+                        "{}/fapi/v1/fundingInfo",
+                        self.endpoints.futures_rest
+                    )),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
+        Ok(infos
+            .into_iter()
+            .map(|i| (i.symbol, i.funding_interval_hours))
+            .collect())
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinanceTicker24h {
@@ -35,22 +79,49 @@ impl PerpExchange for BinanceClient {
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
         // 1) funding / mark price info
-        let premium: Vec<BinancePremiumIndex> = self
-            .http
-            .get(format!("{BASE_URL}/fapi/v1/premiumIndex"))
-            .send()
-            .await?
-            .json()
-            .await?;
+        let premium: Vec<BinancePremiumIndex> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Binance,
+                    EndpointClass::Futures,
+                    10,
+                    self.http.get(format!(
+                        "{}/fapi/v1/premiumIndex",
+                        self.endpoints.futures_rest
+                    )),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         // 2) 24h ticker
-        let tickers: Vec<BinanceTicker24h> = self
-            .http
-            .get(format!("{BASE_URL}/fapi/v1/ticker/24hr"))
-            .send()
-            .await?
-            .json()
-            .await?;
+        let tickers: Vec<BinanceTicker24h> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Binance,
+                    EndpointClass::Futures,
+                    40,
+                    self.http.get(format!(
+                        "{}/fapi/v1/ticker/24hr",
+                        self.endpoints.futures_rest
+                    )),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
+
+        // 3) 펀딩 주기 (실패해도 시세는 내보내고 주기만 비워 둔다)
+        let funding_intervals = self
+            .fetch_funding_intervals()
+            .await
+            .map_err(|e| tracing::warn!("Binance funding interval fetch failed: {}", e))
+            .ok();
 
         let mut ticker_map: HashMap<String, BinanceTicker24h> = HashMap::new();
         for t in tickers {
@@ -62,24 +133,31 @@ impl PerpExchange for BinanceClient {
         let mut out = Vec::new();
 
         for p in premium {
-            if !p.symbol.ends_with("USDT") {
-                continue; // 선형 USDT perp만
-            }
+            let instrument =
+                match parse_native_symbol(ExchangeId::Binance, InstrumentKind::Perp, &p.symbol) {
+                    Some(i) if i.quote == "USDT" && i.kind == InstrumentKind::Perp => i,
+                    _ => continue, // 선형 USDT perp만
+                };
 
             let t = match ticker_map.get(&p.symbol) {
                 Some(t) => t,
                 None => continue,
             };
 
-            let mark_price: f64 = match p.mark_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let mark_price = match parse_decimal(&p.mark_price) {
+                Some(v) => v,
+                None => continue,
             };
 
             let funding_rate: f64 = p.last_funding_rate.parse().unwrap_or(0.0);
+            let funding_interval_hours = funding_intervals.as_ref().map(|map| {
+                map.get(&p.symbol)
+                    .copied()
+                    .unwrap_or(DEFAULT_FUNDING_INTERVAL_HOURS)
+            });
 
             let oi_contracts: f64 = t.open_interest.parse().unwrap_or(0.0);
-            let oi_usd = oi_contracts * mark_price;
+            let oi_usd = oi_contracts * mark_price.to_f64().unwrap_or(0.0);
 
             let vol_24h_usd: f64 = t.quote_volume.parse().unwrap_or(0.0);
 
@@ -91,13 +169,17 @@ impl PerpExchange for BinanceClient {
 
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Binance,
-                symbol: p.symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 mark_price,
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours,
+                predicted_funding_rate: None,
+                index_price: parse_decimal(&p.index_price),
                 updated_at: now,
             });
         }
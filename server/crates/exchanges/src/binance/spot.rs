@@ -2,10 +2,13 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use crate::{BinanceClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
-
-const SPOT_BASE_URL: &str = "https://api.binance.com";
+use crate::binance::api_error;
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, BinanceClient,
+    ExchangeError, SpotExchange,
+};
+use interface::{Currency, Decimal, ExchangeId, InstrumentKind, SpotSnapshot};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,29 +25,42 @@ impl SpotExchange for BinanceClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
-        let tickers: Vec<BinanceSpotTicker24h> = self
-            .http
-            .get(format!("{SPOT_BASE_URL}/api/v3/ticker/24hr"))
-            .send()
-            .await?
-            .json()
-            .await?;
+        let tickers: Vec<BinanceSpotTicker24h> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Binance,
+                    EndpointClass::Spot,
+                    80,
+                    self.http
+                        .get(format!("{}/api/v3/ticker/24hr", self.endpoints.spot_rest)),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         let now = Utc::now();
         let mut out = Vec::new();
 
         for ticker in tickers {
-            if !ticker.symbol.ends_with("USDT") {
-                continue; // USDT 페어만
-            }
+            let instrument = match parse_native_symbol(
+                ExchangeId::Binance,
+                InstrumentKind::Spot,
+                &ticker.symbol,
+            ) {
+                Some(i) if i.quote == "USDT" => i,
+                _ => continue, // USDT 페어만
+            };
 
-            let price: f64 = match ticker.last_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let price = match parse_decimal(&ticker.last_price) {
+                Some(v) => v,
+                None => continue,
             };
 
             // price가 0보다 큰 경우만 추가
-            if price <= 0.0 {
+            if price <= Decimal::ZERO {
                 continue;
             }
 
@@ -52,7 +68,8 @@ impl SpotExchange for BinanceClient {
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Binance,
-                symbol: ticker.symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 price,
                 vol_24h_usd,
@@ -89,7 +106,7 @@ mod tests {
                     println!("Binance spot snapshot: {:?}", snapshot);
                     assert_eq!(snapshot.exchange, ExchangeId::Binance);
                     assert!(snapshot.symbol.ends_with("USDT"));
-                    assert!(snapshot.price > 0.0);
+                    assert!(snapshot.price > Decimal::ZERO);
                     assert!(snapshot.vol_24h_usd >= 0.0);
                 }
 
@@ -105,10 +122,8 @@ mod tests {
             Err(e) => {
                 // 네트워크 오류 등은 테스트 실패로 간주하지 않음
                 // 하지만 API 오류는 확인
-                if let ExchangeError::Other(msg) = &e {
-                    if msg.contains("Binance API error") {
-                        panic!("Binance API error: {}", msg);
-                    }
+                if let ExchangeError::ExchangeReject { .. } = &e {
+                    panic!("Binance API error: {}", e);
                 }
                 // 네트워크 오류는 테스트 환경에 따라 실패할 수 있으므로 경고만
                 eprintln!("Warning: fetch_all failed: {:?}", e);
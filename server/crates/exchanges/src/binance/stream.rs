@@ -0,0 +1,208 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+use crate::stream::{stream_forever, WsSubscription};
+use crate::{
+    decimal::parse_decimal, instrument::parse_native_symbol, BinanceClient, ExchangeError,
+    PerpStream, SpotStream,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpTick, SpotTick};
+
+/// 전 종목 mark price(1초) + 24h 미니 티커 (OI는 스트림이 없어 REST 보완에 맡긴다)
+const PERP_STREAMS: &str = "!markPrice@arr@1s/!miniTicker@arr";
+const SPOT_STREAMS: &str = "!miniTicker@arr";
+
+/// combined stream 공통 포맷: {"stream": "...", "data": ...}
+#[derive(Debug, Deserialize)]
+struct CombinedMessage {
+    stream: String,
+    data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct BinanceMarkPriceEvent {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "p")]
+    mark_price: String,
+    #[serde(rename = "i", default)]
+    index_price: String,
+    #[serde(rename = "r", default)]
+    funding_rate: String,
+    #[serde(rename = "T", default)]
+    next_funding_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct BinanceMiniTickerEvent {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "c")]
+    close: String,
+    #[serde(rename = "q")]
+    quote_volume: String,
+}
+
+/// combined stream 메시지 파싱 (구독 응답 등 다른 형식은 None)
+fn parse_events(text: &str) -> Option<(String, serde_json::Value)> {
+    let msg: CombinedMessage = serde_json::from_str(text).ok()?;
+    Some((msg.stream, msg.data))
+}
+
+/// 이벤트 배열 변환 (형식이 다르면 빈 목록)
+fn from_events<T: DeserializeOwned>(data: serde_json::Value) -> Vec<T> {
+    serde_json::from_value(data).unwrap_or_default()
+}
+
+fn parse_perp_message(text: &str) -> Vec<PerpTick> {
+    let now = Utc::now();
+    let perp =
+        |symbol: &str| match parse_native_symbol(ExchangeId::Binance, InstrumentKind::Perp, symbol)
+        {
+            Some(i) if i.quote == "USDT" && i.kind == InstrumentKind::Perp => {
+                let mut tick = PerpTick::new(i, Currency::USDT);
+                tick.updated_at = now;
+                Some(tick)
+            }
+            _ => None, // USDT 무기한만
+        };
+
+    let Some((stream, data)) = parse_events(text) else {
+        return Vec::new();
+    };
+    if stream.starts_with("!markPrice") {
+        from_events::<BinanceMarkPriceEvent>(data)
+            .into_iter()
+            .filter_map(|e| {
+                let mut tick = perp(&e.symbol)?;
+                tick.mark_price = parse_decimal(&e.mark_price);
+                tick.index_price = parse_decimal(&e.index_price);
+                tick.funding_rate = e.funding_rate.parse().ok();
+                tick.next_funding_time = DateTime::from_timestamp_millis(e.next_funding_time)
+                    .filter(|_| e.next_funding_time > 0);
+                Some(tick)
+            })
+            .collect()
+    } else {
+        from_events::<BinanceMiniTickerEvent>(data)
+            .into_iter()
+            .filter_map(|e| {
+                let mut tick = perp(&e.symbol)?;
+                tick.vol_24h_usd = e.quote_volume.parse().ok();
+                Some(tick)
+            })
+            .collect()
+    }
+}
+
+fn parse_spot_message(text: &str) -> Vec<SpotTick> {
+    let Some((_, data)) = parse_events(text) else {
+        return Vec::new();
+    };
+    let now = Utc::now();
+    from_events::<BinanceMiniTickerEvent>(data)
+        .into_iter()
+        .filter_map(|e| {
+            let instrument =
+                parse_native_symbol(ExchangeId::Binance, InstrumentKind::Spot, &e.symbol)
+                    .filter(|i| i.quote == "USDT")?; // USDT 페어만
+            let mut tick = SpotTick::new(instrument, Currency::USDT);
+            tick.price = parse_decimal(&e.close).filter(|p| !p.is_zero());
+            tick.vol_24h_usd = e.quote_volume.parse().ok();
+            tick.updated_at = now;
+            Some(tick)
+        })
+        .collect()
+}
+
+#[async_trait]
+impl PerpStream for BinanceClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
+
+    async fn stream_perps(&self, tx: mpsc::Sender<PerpTick>) -> Result<(), ExchangeError> {
+        // 전 종목 스트림이라 별도 구독 메시지가 필요 없다
+        stream_forever("Binance perp", tx, || async {
+            let subscription = WsSubscription {
+                url: format!(
+                    "{}/stream?streams={}",
+                    self.endpoints.futures_ws, PERP_STREAMS
+                ),
+                messages: Vec::new(),
+                ping: None,
+            };
+            Ok((subscription, parse_perp_message))
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl SpotStream for BinanceClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
+
+    async fn stream_spots(&self, tx: mpsc::Sender<SpotTick>) -> Result<(), ExchangeError> {
+        stream_forever("Binance spot", tx, || async {
+            let subscription = WsSubscription {
+                url: format!("{}/stream?streams={}", self.endpoints.spot_ws, SPOT_STREAMS),
+                messages: Vec::new(),
+                ping: None,
+            };
+            Ok((subscription, parse_spot_message))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_binance_perp_stream() {
+        let mark = r#"{"stream":"!markPrice@arr@1s","data":[
+            {"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"65000.5","i":"64990","P":"65010","r":"0.00010000","T":1562306400000},
+            {"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSD_PERP","p":"65000","r":"0.0001","T":1562306400000}
+        ]}"#;
+        let ticks = parse_perp_message(mark);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].instrument.native_symbol, "BTCUSDT");
+        assert_eq!(ticks[0].mark_price, Some(Decimal::new(650005, 1)));
+        assert_eq!(ticks[0].funding_rate, Some(0.0001));
+        assert_eq!(ticks[0].index_price, Some(Decimal::from(64990)));
+        assert_eq!(
+            ticks[0].next_funding_time.unwrap().timestamp_millis(),
+            1562306400000
+        );
+        assert_eq!(ticks[0].vol_24h_usd, None);
+
+        let mini = r#"{"stream":"!miniTicker@arr","data":[
+            {"e":"24hrMiniTicker","E":123456789,"s":"ETHUSDT","c":"3000","o":"2900","h":"3100","l":"2800","v":"1000","q":"3000000"}
+        ]}"#;
+        let ticks = parse_perp_message(mini);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].vol_24h_usd, Some(3_000_000.0));
+        assert_eq!(ticks[0].mark_price, None);
+
+        assert!(parse_perp_message(r#"{"result":null,"id":1}"#).is_empty());
+    }
+
+    #[test]
+    fn test_parse_binance_spot_stream() {
+        let mini = r#"{"stream":"!miniTicker@arr","data":[
+            {"e":"24hrMiniTicker","E":123456789,"s":"BTCUSDT","c":"65000","q":"1500000"},
+            {"e":"24hrMiniTicker","E":123456789,"s":"ETHBTC","c":"0.05","q":"100"}
+        ]}"#;
+        let ticks = parse_spot_message(mini);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].price, Some(Decimal::from(65000)));
+        assert_eq!(ticks[0].vol_24h_usd, Some(1_500_000.0));
+    }
+}
@@ -0,0 +1,355 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::Deserialize;
+
+use interface::{
+    Decimal, ExchangeId, TransferDirection, TransferRecord, TransferStatus, WithdrawRequest,
+};
+
+use super::super::decimal::parse_decimal;
+use super::super::error::retry_after;
+use super::super::rate_limit::{EndpointClass, RateLimiter};
+use super::super::{ExchangeError, TransferExchange};
+use super::{api_error, generate_signature, get_timestamp, BinanceClient};
+
+/// POST /sapi/v1/capital/withdraw/apply 응답
+#[derive(Debug, Deserialize)]
+struct BinanceWithdrawApplyResponse {
+    id: String,
+}
+
+/// GET /sapi/v1/capital/withdraw/history 항목
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceWithdrawHistory {
+    id: String,
+    amount: String,
+    #[serde(default)]
+    transaction_fee: Option<String>,
+    coin: String,
+    status: i64,
+    address: String,
+    #[serde(default)]
+    address_tag: Option<String>,
+    #[serde(default)]
+    tx_id: Option<String>,
+    apply_time: String, // "2019-10-12 11:12:02" (UTC)
+    #[serde(default)]
+    network: Option<String>,
+    #[serde(default)]
+    withdraw_order_id: Option<String>,
+}
+
+/// GET /sapi/v1/capital/deposit/hisrec 항목
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceDepositHistory {
+    id: String,
+    amount: String,
+    coin: String,
+    network: String,
+    status: i64,
+    address: String,
+    #[serde(default)]
+    address_tag: Option<String>,
+    #[serde(default)]
+    tx_id: Option<String>,
+    insert_time: i64,
+}
+
+/// 출금 status: 0 이메일 발송, 1 취소, 2 승인 대기, 3 거절, 4 처리 중, 5 실패, 6 완료
+fn withdraw_status(status: i64) -> TransferStatus {
+    match status {
+        0 | 2 => TransferStatus::Pending,
+        1 => TransferStatus::Cancelled,
+        3 | 5 => TransferStatus::Failed,
+        6 => TransferStatus::Completed,
+        _ => TransferStatus::Processing,
+    }
+}
+
+/// 입금 status: 0 대기, 6 입금 반영(출금 잠김), 1 완료, 2 거절, 7 잘못된 입금, 8 사용자 확인 대기
+fn deposit_status(status: i64) -> TransferStatus {
+    match status {
+        1 | 6 => TransferStatus::Completed,
+        2 | 7 => TransferStatus::Failed,
+        8 => TransferStatus::Pending,
+        _ => TransferStatus::Processing,
+    }
+}
+
+/// 빈 문자열 태그는 없는 것으로 취급
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
+
+/// 쿼리 값 퍼센트 인코딩 (서명 대상 문자열과 실제 전송 문자열이 같아야 함)
+fn encode_query_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+fn to_withdraw_record(item: BinanceWithdrawHistory) -> TransferRecord {
+    let created_at = NaiveDateTime::parse_from_str(&item.apply_time, "%Y-%m-%d %H:%M:%S")
+        .map(|t| Utc.from_utc_datetime(&t))
+        .unwrap_or_else(|_| Utc::now());
+
+    TransferRecord {
+        exchange: ExchangeId::Binance,
+        direction: TransferDirection::Withdrawal,
+        id: item.id,
+        currency: item.coin,
+        network: item.network.unwrap_or_default(),
+        address: item.address,
+        memo: non_empty(item.address_tag),
+        amount: parse_decimal(&item.amount).unwrap_or(Decimal::ZERO),
+        fee: item.transaction_fee.as_deref().and_then(parse_decimal),
+        txid: non_empty(item.tx_id),
+        status: withdraw_status(item.status),
+        created_at,
+        client_id: non_empty(item.withdraw_order_id),
+    }
+}
+
+fn to_deposit_record(item: BinanceDepositHistory) -> TransferRecord {
+    TransferRecord {
+        exchange: ExchangeId::Binance,
+        direction: TransferDirection::Deposit,
+        id: item.id,
+        currency: item.coin,
+        network: item.network,
+        address: item.address,
+        memo: non_empty(item.address_tag),
+        amount: parse_decimal(&item.amount).unwrap_or(Decimal::ZERO),
+        fee: None,
+        txid: non_empty(item.tx_id),
+        status: deposit_status(item.status),
+        created_at: Utc
+            .timestamp_millis_opt(item.insert_time)
+            .single()
+            .unwrap_or_else(Utc::now),
+        client_id: None,
+    }
+}
+
+impl BinanceClient {
+    /// 서명이 필요한 SAPI 지갑 요청 전송 후 본문 반환
+    /// params: 인코딩 전 (key, value) 목록 (timestamp/recvWindow/signature는 여기서 붙인다)
+    async fn signed_wallet_request(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: &[(&str, String)],
+        weight: u32,
+    ) -> Result<String, ExchangeError> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| {
+            ExchangeError::Auth(
+                "API key not set. Use BinanceClient::with_credentials()".to_string(),
+            )
+        })?;
+        let api_secret = self.api_secret.as_ref().ok_or_else(|| {
+            ExchangeError::Auth(
+                "API secret not set. Use BinanceClient::with_credentials()".to_string(),
+            )
+        })?;
+
+        let mut query_string = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, encode_query_value(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+        if !query_string.is_empty() {
+            query_string.push('&');
+        }
+        query_string.push_str(&format!("timestamp={}&recvWindow=50000", get_timestamp()));
+        let signature = generate_signature(&query_string, api_secret);
+        let url = format!(
+            "{}{}?{}&signature={}",
+            self.endpoints.sapi_rest, endpoint, query_string, signature
+        );
+
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Wallet,
+                weight,
+                self.http
+                    .request(method, &url)
+                    .header("X-MBX-APIKEY", api_key.as_str()),
+            )
+            .await?;
+
+        let status = response.status();
+        let retry_after = retry_after(response.headers());
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(api_error(status, retry_after, &response_text));
+        }
+        Ok(response_text)
+    }
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(body: &str, what: &str) -> Result<T, ExchangeError> {
+    serde_json::from_str(body).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to parse {}: {}, response: {}",
+            what,
+            e,
+            body.chars().take(200).collect::<String>()
+        ))
+    })
+}
+
+#[async_trait]
+impl TransferExchange for BinanceClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Binance
+    }
+
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<TransferRecord, ExchangeError> {
+        // POST /sapi/v1/capital/withdraw/apply
+        let mut params = vec![
+            ("coin", request.currency.clone()),
+            ("network", request.network.clone()),
+            ("address", request.address.clone()),
+            ("amount", request.amount.normalize().to_string()),
+        ];
+        if let Some(memo) = &request.memo {
+            params.push(("addressTag", memo.clone()));
+        }
+        if let Some(client_id) = &request.client_id {
+            params.push(("withdrawOrderId", client_id.clone()));
+        }
+        for (key, value) in &request.extra {
+            params.push((key.as_str(), value.clone()));
+        }
+
+        let body = self
+            .signed_wallet_request(
+                reqwest::Method::POST,
+                "/sapi/v1/capital/withdraw/apply",
+                &params,
+                1,
+            )
+            .await?;
+        let applied: BinanceWithdrawApplyResponse = parse_body(&body, "withdraw response")?;
+
+        Ok(TransferRecord {
+            exchange: ExchangeId::Binance,
+            direction: TransferDirection::Withdrawal,
+            id: applied.id,
+            currency: request.currency.clone(),
+            network: request.network.clone(),
+            address: request.address.clone(),
+            memo: request.memo.clone(),
+            amount: request.amount,
+            fee: None,
+            txid: None,
+            status: TransferStatus::Pending,
+            created_at: Utc::now(),
+            client_id: request.client_id.clone(),
+        })
+    }
+
+    async fn fetch_withdrawal(&self, id: &str) -> Result<Option<TransferRecord>, ExchangeError> {
+        // GET /sapi/v1/capital/withdraw/history?idList=...
+        let body = self
+            .signed_wallet_request(
+                reqwest::Method::GET,
+                "/sapi/v1/capital/withdraw/history",
+                &[("idList", id.to_string())],
+                18,
+            )
+            .await?;
+        let items: Vec<BinanceWithdrawHistory> = parse_body(&body, "withdraw history")?;
+
+        // 신청 직후에는 내역에 아직 반영되지 않았을 수 있다
+        Ok(items
+            .into_iter()
+            .find(|item| item.id == id)
+            .map(to_withdraw_record))
+    }
+
+    async fn find_withdrawal_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<TransferRecord>, ExchangeError> {
+        // GET /sapi/v1/capital/withdraw/history?withdrawOrderId=...
+        let body = self
+            .signed_wallet_request(
+                reqwest::Method::GET,
+                "/sapi/v1/capital/withdraw/history",
+                &[("withdrawOrderId", client_id.to_string())],
+                18,
+            )
+            .await?;
+        let items: Vec<BinanceWithdrawHistory> = parse_body(&body, "withdraw history")?;
+
+        Ok(items
+            .into_iter()
+            .find(|item| item.withdraw_order_id.as_deref() == Some(client_id))
+            .map(to_withdraw_record))
+    }
+
+    async fn fetch_deposits(
+        &self,
+        currency: &str,
+        start: DateTime<Utc>,
+    ) -> Result<Vec<TransferRecord>, ExchangeError> {
+        // GET /sapi/v1/capital/deposit/hisrec (최대 1000건, 기본 조회 범위 90일)
+        let body = self
+            .signed_wallet_request(
+                reqwest::Method::GET,
+                "/sapi/v1/capital/deposit/hisrec",
+                &[
+                    ("coin", currency.to_string()),
+                    ("startTime", start.timestamp_millis().to_string()),
+                    ("limit", "1000".to_string()),
+                ],
+                1,
+            )
+            .await?;
+        let items: Vec<BinanceDepositHistory> = parse_body(&body, "deposit history")?;
+
+        Ok(items.into_iter().map(to_deposit_record).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_binance_transfer_history() {
+        let body = r#"[{"id":"b6ae22b3aa844210a7041aee7589627c","amount":"8.91000000","transactionFee":"0.004","coin":"USDT","status":6,"address":"0x94df8b352de7f46f64b01d3666bf6e936e44ce60","txId":"0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268","applyTime":"2019-10-12 11:12:02","network":"ETH","transferType":0}]"#;
+        let items: Vec<BinanceWithdrawHistory> = parse_body(body, "withdraw history").unwrap();
+        let record = to_withdraw_record(items.into_iter().next().unwrap());
+        assert_eq!(record.direction, TransferDirection::Withdrawal);
+        assert_eq!(record.status, TransferStatus::Completed);
+        assert_eq!(record.fee, Some(Decimal::new(4, 3)));
+        assert_eq!(record.memo, None);
+        assert_eq!(record.created_at.timestamp(), 1570878722);
+
+        let body = r#"[{"id":"769800519366885376","amount":"0.001","coin":"XRP","network":"XRP","status":0,"address":"rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh","addressTag":"101286922","txId":"","insertTime":1661493146000}]"#;
+        let items: Vec<BinanceDepositHistory> = parse_body(body, "deposit history").unwrap();
+        let record = to_deposit_record(items.into_iter().next().unwrap());
+        assert_eq!(record.status, TransferStatus::Processing);
+        assert_eq!(record.memo.as_deref(), Some("101286922"));
+        assert_eq!(record.txid, None);
+    }
+
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(encode_query_value("0xABc-_.~"), "0xABc-_.~");
+        assert_eq!(encode_query_value("a b&c=d"), "a%20b%26c%3Dd");
+    }
+}
@@ -0,0 +1,246 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use interface::{Decimal, ExchangeId, FutureAsset, InstrumentKind, SpotAsset};
+
+use crate::bitget::{api_error, generate_signature, map_error_code};
+use crate::{
+    decimal::parse_decimal, error::check_status, error::truncate_body, instrument::resolve_symbol,
+    AssetExchange, BitgetClient, ExchangeError,
+};
+
+#[derive(Debug, Deserialize)]
+struct BitgetResponse<T> {
+    code: String,
+    msg: String,
+    data: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitgetSpotAsset {
+    coin: String,
+    #[serde(default)]
+    available: String,
+    #[serde(default)]
+    frozen: String, // 미체결 주문에 묶인 수량
+    #[serde(default)]
+    locked: String, // 출금 대기 등으로 잠긴 수량
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetPosition {
+    symbol: String, // V2 표기 (예: "BTCUSDT")
+    #[serde(default)]
+    hold_side: String, // "long", "short"
+    #[serde(default)]
+    total: String,
+    #[serde(default)]
+    open_price_avg: String,
+    #[serde(rename = "unrealizedPL", default)]
+    unrealized_pl: String,
+    #[serde(default)]
+    leverage: String,
+    #[serde(default)]
+    liquidation_price: String,
+}
+
+impl BitgetClient {
+    /// 서명이 필요한 V2 GET 요청
+    pub(crate) async fn signed_get<T: DeserializeOwned>(
+        &self,
+        request_path: &str,
+    ) -> Result<T, ExchangeError> {
+        let missing = |what: &str| {
+            ExchangeError::Auth(format!(
+                "{} not set. Use BitgetClient::with_credentials()",
+                what
+            ))
+        };
+        let api_key = self.api_key.as_ref().ok_or_else(|| missing("API key"))?;
+        let api_secret = self
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| missing("API secret"))?;
+        let passphrase = self
+            .passphrase
+            .as_ref()
+            .ok_or_else(|| missing("API passphrase"))?;
+
+        let timestamp = Utc::now().timestamp_millis() as u64;
+        let signature = generate_signature(timestamp, "GET", request_path, "", api_secret);
+        let url = format!("{}{}", self.endpoints.rest, request_path);
+
+        let response = self
+            .http
+            .get(&url)
+            .header("ACCESS-KEY", api_key.as_str())
+            .header("ACCESS-SIGN", signature)
+            .header("ACCESS-TIMESTAMP", timestamp.to_string())
+            .header("ACCESS-PASSPHRASE", passphrase.as_str())
+            .header("Content-Type", "application/json")
+            .header("locale", "en-US")
+            .send()
+            .await?;
+
+        let response_text = check_status(response, api_error).await?.text().await?;
+        parse_response(&response_text)
+    }
+}
+
+pub(crate) fn parse_response<T: DeserializeOwned>(response_text: &str) -> Result<T, ExchangeError> {
+    let response: BitgetResponse<T> = serde_json::from_str(response_text).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to parse Bitget response: {}, response: {}",
+            e,
+            truncate_body(response_text)
+        ))
+    })?;
+
+    if response.code != "00000" {
+        return Err(map_error_code(&response.code, &response.msg));
+    }
+    response
+        .data
+        .ok_or_else(|| ExchangeError::Other("Bitget response has no data".to_string()))
+}
+
+/// 포지션 하나를 FutureAsset으로 변환 (수량이 0이면 None)
+fn to_future_asset(pos: BitgetPosition, now: DateTime<Utc>) -> Option<FutureAsset> {
+    let total = parse_decimal(&pos.total).unwrap_or_default();
+    if total.is_zero() {
+        return None;
+    }
+    // total은 항상 양수이고 방향은 holdSide로 온다
+    let position_amt = if pos.hold_side == "short" {
+        -total
+    } else {
+        total
+    };
+    // 다른 Bitget 모듈과 같은 V1 표기(BTCUSDT_UMCBL)로 맞춘다
+    let symbol = resolve_symbol(ExchangeId::Bitget, InstrumentKind::Perp, &pos.symbol)
+        .map(|i| i.native_symbol)
+        .unwrap_or(pos.symbol);
+
+    Some(FutureAsset {
+        symbol,
+        position_amt,
+        entry_price: parse_decimal(&pos.open_price_avg).unwrap_or_default(),
+        unrealized_pnl: parse_decimal(&pos.unrealized_pl).unwrap_or_default(),
+        leverage: parse_decimal(&pos.leverage).unwrap_or_default(),
+        liquidation_price: parse_decimal(&pos.liquidation_price).filter(|v| *v > Decimal::ZERO),
+        updated_at: now,
+    })
+}
+
+#[async_trait]
+impl AssetExchange for BitgetClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bitget
+    }
+
+    async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
+        // 현물 계정 잔고: GET /api/v2/spot/account/assets (보유 코인만 반환)
+        let balances: Vec<BitgetSpotAsset> = self.signed_get("/api/v2/spot/account/assets").await?;
+
+        let now = Utc::now();
+        let mut assets = Vec::new();
+
+        for balance in balances {
+            let available = parse_decimal(&balance.available).unwrap_or_default();
+            let in_use = parse_decimal(&balance.frozen).unwrap_or_default()
+                + parse_decimal(&balance.locked).unwrap_or_default();
+            let total = available + in_use;
+
+            // 잔액이 0인 경우 스킵
+            if total > Decimal::ZERO {
+                assets.push(SpotAsset {
+                    currency: balance.coin.to_uppercase(),
+                    total,
+                    available,
+                    in_use,
+                    updated_at: now,
+                });
+            }
+        }
+
+        Ok(assets)
+    }
+
+    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+        // USDT 무기한 포지션: GET /api/v2/mix/position/all-position
+        let positions: Vec<BitgetPosition> = self
+            .signed_get(
+                "/api/v2/mix/position/all-position?productType=USDT-FUTURES&marginCoin=USDT",
+            )
+            .await?;
+
+        let now = Utc::now();
+        Ok(positions
+            .into_iter()
+            .filter_map(|pos| to_future_asset(pos, now))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitget::has_api_credentials;
+
+    #[test]
+    fn test_parse_bitget_positions() {
+        let body = r#"{"code":"00000","msg":"success","requestTime":1716863719031,"data":[
+            {"symbol":"BTCUSDT","holdSide":"short","total":"0.02","openPriceAvg":"65000","unrealizedPL":"-3.1","leverage":"10","liquidationPrice":"70500.5"}
+        ]}"#;
+        let positions: Vec<BitgetPosition> = parse_response(body).unwrap();
+        let asset = to_future_asset(positions.into_iter().next().unwrap(), Utc::now()).unwrap();
+
+        assert_eq!(asset.symbol, "BTCUSDT_UMCBL");
+        assert_eq!(asset.position_amt, Decimal::new(-2, 2));
+        assert_eq!(asset.unrealized_pnl, Decimal::new(-31, 1));
+        assert_eq!(asset.liquidation_price, Some(Decimal::new(705005, 1)));
+
+        let err = parse_response::<Vec<BitgetPosition>>(
+            r#"{"code":"40037","msg":"Apikey does not exist","data":null}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExchangeError::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_assets_bitget() {
+        if !has_api_credentials() {
+            println!(
+                "Skipping test: BITGET_API_KEY, BITGET_API_SECRET and BITGET_API_PASSPHRASE not set"
+            );
+            return;
+        }
+
+        let client = BitgetClient::with_credentials().unwrap();
+        match client.fetch_spots().await {
+            Ok(assets) => {
+                for asset in assets.iter().take(5) {
+                    println!(
+                        "{} - Total: {}, Available: {}, In Use: {}",
+                        asset.currency, asset.total, asset.available, asset.in_use
+                    );
+                }
+            }
+            Err(e) => println!("기타 오류: {}", e),
+        }
+        match client.fetch_futures().await {
+            Ok(positions) => {
+                for pos in positions {
+                    println!(
+                        "{} - {} @ {}",
+                        pos.symbol, pos.position_amt, pos.entry_price
+                    );
+                }
+            }
+            Err(e) => println!("기타 오류: {}", e),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+
+use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, InstrumentKind, MarketType};
+
+use crate::bitget::{api_error, asset::parse_response};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::resolve_symbol, BitgetClient,
+    ExchangeError, FeeExchange,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetTradeRate {
+    maker_fee_rate: String,
+    taker_fee_rate: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitgetCoin {
+    coin: String,
+    chains: Vec<BitgetChain>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetChain {
+    #[serde(default)]
+    withdrawable: String, // "true" / "false"
+    #[serde(default)]
+    withdraw_fee: String,
+}
+
+/// 수수료 응답을 FeeInfo로 변환
+fn to_fee_info(rate: &BitgetTradeRate) -> Option<FeeInfo> {
+    Some(FeeInfo::new(
+        rate.maker_fee_rate.parse::<f64>().ok()?,
+        rate.taker_fee_rate.parse::<f64>().ok()?,
+    ))
+}
+
+/// 출금 가능한 체인 중 가장 싼 출금 수수료를 선택
+fn to_deposit_withdrawal_fee(coin: BitgetCoin) -> Option<DepositWithdrawalFee> {
+    let withdrawal_fee = coin
+        .chains
+        .iter()
+        .filter(|c| c.withdrawable == "true")
+        .filter_map(|c| parse_decimal(&c.withdraw_fee))
+        .min()?;
+
+    Some(DepositWithdrawalFee {
+        currency: coin.coin.to_uppercase(),
+        deposit_fee: Default::default(), // Bitget은 입금 수수료가 없다
+        withdrawal_fee,
+        updated_at: Utc::now(),
+    })
+}
+
+#[async_trait]
+impl FeeExchange for BitgetClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bitget
+    }
+
+    fn get_fee(&self, market_type: MarketType) -> FeeInfo {
+        match market_type {
+            MarketType::USDT => FeeInfo::new(0.001, 0.001), // 0.1% 메이커, 테이커 (VIP 0)
+            _ => FeeInfo::new(0.001, 0.001),                // 기본값: 0.1%
+        }
+    }
+
+    fn get_perp_fee(&self) -> FeeInfo {
+        FeeInfo::new(0.0002, 0.0006) // USDT-M 선물 0.02% 메이커, 0.06% 테이커 (VIP 0)
+    }
+
+    async fn fetch_trade_fee(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<FeeInfo, ExchangeError> {
+        let business_type = match kind {
+            InstrumentKind::Spot => "spot",
+            InstrumentKind::Perp => "mix",
+            InstrumentKind::Delivery => {
+                return Err(ExchangeError::NotImplemented(
+                    "Bitget delivery trade fee".to_string(),
+                ))
+            }
+        };
+        // V2 API는 접미사 없는 심볼(예: "BTCUSDT")을 사용한다
+        let native_symbol = resolve_symbol(ExchangeId::Bitget, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Bitget symbol: {}", symbol))
+            })?;
+        let v2_symbol = native_symbol
+            .split('_')
+            .next()
+            .unwrap_or(&native_symbol)
+            .to_string();
+
+        // 계정 등급이 반영된 심볼별 수수료: GET /api/v2/common/trade-rate
+        let rate: BitgetTradeRate = self
+            .signed_get(&format!(
+                "/api/v2/common/trade-rate?symbol={}&businessType={}",
+                v2_symbol, business_type
+            ))
+            .await?;
+
+        to_fee_info(&rate).ok_or_else(|| {
+            ExchangeError::Other(format!("Invalid Bitget fee rate for {}", native_symbol))
+        })
+    }
+
+    async fn get_deposit_withdrawal_fee(
+        &self,
+        currency: &str,
+    ) -> Result<DepositWithdrawalFee, ExchangeError> {
+        // 코인별 체인 정보 (공개 API): GET /api/v2/spot/public/coins
+        let url = format!(
+            "{}/api/v2/spot/public/coins?coin={}",
+            self.endpoints.rest,
+            currency.to_uppercase()
+        );
+        let response_text = check_status(self.http.get(&url).send().await?, api_error)
+            .await?
+            .text()
+            .await?;
+        let coins: Vec<BitgetCoin> = parse_response(&response_text)?;
+
+        coins
+            .into_iter()
+            .find(|c| c.coin.eq_ignore_ascii_case(currency))
+            .and_then(to_deposit_withdrawal_fee)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!(
+                    "Fee information not found for currency: {}",
+                    currency
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitget::has_api_credentials;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bitget_fees() {
+        let body = r#"{"code":"00000","msg":"success","requestTime":1716863719031,"data":{"makerFeeRate":"0.0002","takerFeeRate":"0.0006"}}"#;
+        let rate: BitgetTradeRate = parse_response(body).unwrap();
+        let fee = to_fee_info(&rate).unwrap();
+        assert_eq!(fee.maker, 0.0002);
+        assert_eq!(fee.taker, 0.0006);
+
+        let body = r#"{"code":"00000","msg":"success","requestTime":1716863719031,"data":[{"coin":"USDT","chains":[
+            {"chain":"ERC20","withdrawable":"true","withdrawFee":"2.5"},
+            {"chain":"TRC20","withdrawable":"true","withdrawFee":"1"},
+            {"chain":"SOL","withdrawable":"false","withdrawFee":"0.3"}
+        ]}]}"#;
+        let coins: Vec<BitgetCoin> = parse_response(body).unwrap();
+        let fee = to_deposit_withdrawal_fee(coins.into_iter().next().unwrap()).unwrap();
+        assert_eq!(fee.withdrawal_fee, Decimal::ONE);
+    }
+
+    #[tokio::test]
+    async fn test_get_deposit_withdrawal_fee_bitget() {
+        let client = BitgetClient::new();
+        match client.get_deposit_withdrawal_fee("USDT").await {
+            Ok(fee) => {
+                assert_eq!(fee.currency, "USDT");
+                assert!(fee.withdrawal_fee >= Decimal::ZERO);
+            }
+            Err(e) => {
+                // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                eprintln!("Warning: API call failed: {:?}", e);
+            }
+        }
+
+        if !has_api_credentials() {
+            println!(
+                "Skipping test: BITGET_API_KEY, BITGET_API_SECRET and BITGET_API_PASSPHRASE not set"
+            );
+            return;
+        }
+
+        let client = BitgetClient::with_credentials().unwrap();
+        for kind in [InstrumentKind::Spot, InstrumentKind::Perp] {
+            match client.fetch_trade_fee("BTC-USDT", kind).await {
+                Ok(fee) => println!(
+                    "{:?} BTCUSDT - maker: {}, taker: {}",
+                    kind, fee.maker, fee.taker
+                ),
+                Err(e) => println!("기타 오류: {}", e),
+            }
+        }
+    }
+}
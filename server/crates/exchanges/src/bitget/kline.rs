@@ -0,0 +1,151 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use interface::{Candle, ExchangeId, InstrumentKind, KlineInterval};
+
+use crate::bitget::{api_error, asset::parse_response};
+use crate::{
+    error::check_status,
+    instrument::resolve_symbol,
+    kline::{finish_candles, page_backward, parse_candle_row, CandleSource, STANDARD_LAYOUT},
+    BitgetClient, ExchangeError, KlineExchange,
+};
+
+/// V2 history-candles 한 번에 받을 수 있는 최대 개수
+const PAGE_LIMIT: usize = 200;
+
+/// V2 현물/선물의 granularity 표기가 다르다
+fn interval_param(kind: InstrumentKind, interval: KlineInterval) -> &'static str {
+    match (kind, interval) {
+        (InstrumentKind::Spot, KlineInterval::M1) => "1min",
+        (InstrumentKind::Spot, KlineInterval::M5) => "5min",
+        (InstrumentKind::Spot, KlineInterval::M15) => "15min",
+        (InstrumentKind::Spot, KlineInterval::M30) => "30min",
+        (InstrumentKind::Spot, KlineInterval::H1) => "1h",
+        (InstrumentKind::Spot, KlineInterval::H4) => "4h",
+        (InstrumentKind::Spot, KlineInterval::D1) => "1day",
+        (_, KlineInterval::M1) => "1m",
+        (_, KlineInterval::M5) => "5m",
+        (_, KlineInterval::M15) => "15m",
+        (_, KlineInterval::M30) => "30m",
+        (_, KlineInterval::H1) => "1H",
+        (_, KlineInterval::H4) => "4H",
+        (_, KlineInterval::D1) => "1D",
+    }
+}
+
+impl BitgetClient {
+    /// open_time이 cursor 이하인 최신 캔들 한 페이지
+    async fn fetch_kline_page(
+        &self,
+        source: CandleSource<'_>,
+        v2_symbol: &str,
+        cursor: DateTime<Utc>,
+    ) -> Result<Vec<Candle>, ExchangeError> {
+        let granularity = interval_param(source.kind, source.interval);
+        let url = match source.kind {
+            InstrumentKind::Spot => format!(
+                "{}/api/v2/spot/market/history-candles?symbol={}&granularity={}&endTime={}&limit={}",
+                self.endpoints.rest,
+                v2_symbol,
+                granularity,
+                cursor.timestamp_millis(),
+                PAGE_LIMIT
+            ),
+            _ => format!(
+                "{}/api/v2/mix/market/history-candles?symbol={}&productType=USDT-FUTURES&granularity={}&endTime={}&limit={}",
+                self.endpoints.rest,
+                v2_symbol,
+                granularity,
+                cursor.timestamp_millis(),
+                PAGE_LIMIT
+            ),
+        };
+        let response_text = check_status(self.http.get(&url).send().await?, api_error)
+            .await?
+            .text()
+            .await?;
+        // 행: [ts, open, high, low, close, baseVol, quoteVol, ...]
+        let rows: Vec<Vec<Value>> = parse_response(&response_text)?;
+
+        rows.iter()
+            .map(|row| parse_candle_row(source, row, STANDARD_LAYOUT))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl KlineExchange for BitgetClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bitget
+    }
+
+    async fn fetch_klines(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+        interval: KlineInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>, ExchangeError> {
+        if kind == InstrumentKind::Delivery {
+            return Err(ExchangeError::NotImplemented(
+                "Bitget delivery klines".to_string(),
+            ));
+        }
+        // 예: 현물 "BTCUSDT_SPBL", USDT 무기한 "BTCUSDT_UMCBL"
+        let normalized_symbol = resolve_symbol(ExchangeId::Bitget, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Bitget symbol: {}", symbol))
+            })?;
+        // V2 API는 접미사 없는 심볼(예: "BTCUSDT")을 사용한다
+        let v2_symbol = normalized_symbol
+            .split('_')
+            .next()
+            .unwrap_or(&normalized_symbol);
+        let source = CandleSource {
+            exchange: ExchangeId::Bitget,
+            symbol: &normalized_symbol,
+            kind,
+            interval,
+        };
+
+        let candles = page_backward(start, end, |cursor| {
+            self.fetch_kline_page(source, v2_symbol, cursor)
+        })
+        .await?;
+
+        Ok(finish_candles(candles, start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_fetch_klines_bitget() {
+        let client = BitgetClient::new();
+        let end = Utc::now();
+        let start = end - Duration::hours(5);
+
+        for kind in [InstrumentKind::Spot, InstrumentKind::Perp] {
+            match client
+                .fetch_klines("BTC-USDT", kind, KlineInterval::M1, start, end)
+                .await
+            {
+                Ok(candles) => {
+                    assert!(candles.len() > PAGE_LIMIT);
+                    assert!(candles.windows(2).all(|w| w[0].open_time < w[1].open_time));
+                }
+                Err(e) => {
+                    // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                    eprintln!("Warning: API call failed: {:?}", e);
+                }
+            }
+        }
+    }
+}
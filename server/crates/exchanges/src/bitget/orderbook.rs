@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+
+use interface::{ExchangeId, InstrumentKind, OrderBook};
+
+use crate::bitget::{api_error, map_error_code};
+use crate::{
+    decimal::parse_book_levels, error::check_status, instrument::resolve_symbol, BitgetClient,
+    ExchangeError, OrderBookExchange,
+};
+
+#[derive(Debug, Deserialize)]
+struct BitgetResponse<T> {
+    code: String,
+    msg: String,
+    data: Option<T>,
+}
+
+/// 현물/선물 depth 응답 공통 형식 (수량은 모두 base 코인 단위)
+#[derive(Debug, Deserialize)]
+struct BitgetDepth {
+    asks: Vec<Vec<String>>, // [price, size]
+    bids: Vec<Vec<String>>, // [price, size]
+}
+
+#[async_trait]
+impl OrderBookExchange for BitgetClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bitget
+    }
+
+    async fn fetch_orderbook(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<OrderBook, ExchangeError> {
+        // 예: 현물 "BTCUSDT_SPBL", USDT 무기한 "BTCUSDT_UMCBL"
+        let normalized_symbol = resolve_symbol(ExchangeId::Bitget, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Bitget symbol: {}", symbol))
+            })?;
+        let url = match kind {
+            InstrumentKind::Spot => format!(
+                "{}/api/spot/v1/market/depth?symbol={}&type=step0&limit=100",
+                self.endpoints.rest, normalized_symbol
+            ),
+            InstrumentKind::Perp => format!(
+                "{}/api/mix/v1/market/depth?symbol={}&limit=100",
+                self.endpoints.rest, normalized_symbol
+            ),
+            InstrumentKind::Delivery => {
+                return Err(ExchangeError::NotImplemented(
+                    "Bitget delivery orderbook".to_string(),
+                ))
+            }
+        };
+
+        let response: BitgetResponse<BitgetDepth> =
+            check_status(self.http.get(&url).send().await?, api_error)
+                .await?
+                .json()
+                .await?;
+
+        if response.code != "00000" {
+            return Err(map_error_code(&response.code, &response.msg));
+        }
+
+        let depth = response.data.ok_or_else(|| {
+            ExchangeError::Other(format!("Empty Bitget orderbook for {}", normalized_symbol))
+        })?;
+
+        let mut bids = parse_book_levels(&depth.bids)?;
+        let mut asks = parse_book_levels(&depth.asks)?;
+
+        bids.sort_by_key(|e| std::cmp::Reverse(e.price)); // 높은 가격 순
+        asks.sort_by_key(|e| e.price); // 낮은 가격 순
+
+        Ok(OrderBook {
+            exchange: ExchangeId::Bitget,
+            symbol: normalized_symbol,
+            kind,
+            bids,
+            asks,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_orderbook_bitget() {
+        let client = BitgetClient::new();
+
+        for (kind, expected) in [
+            (InstrumentKind::Spot, "BTCUSDT_SPBL"),
+            (InstrumentKind::Perp, "BTCUSDT_UMCBL"),
+        ] {
+            match client.fetch_orderbook("BTC-USDT", kind).await {
+                Ok(orderbook) => {
+                    assert_eq!(orderbook.exchange, ExchangeId::Bitget);
+                    assert_eq!(orderbook.symbol, expected);
+                    assert_eq!(orderbook.kind, kind);
+                    assert!(!orderbook.bids.is_empty(), "Should have bids");
+                    assert!(!orderbook.asks.is_empty(), "Should have asks");
+                    assert!(orderbook.bids[0].price < orderbook.asks[0].price);
+                }
+                Err(e) => {
+                    // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                    eprintln!("Warning: API call failed: {:?}", e);
+                }
+            }
+        }
+    }
+}
@@ -3,60 +3,63 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use futures::stream::{self, StreamExt};
+use rust_decimal::prelude::ToPrimitive;
 use serde::Deserialize;
 use tracing;
 
-use crate::{ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
-
-const BASE_URL: &str = "https://api.bitget.com";
+use crate::bitget::{api_error, map_error_code};
+use crate::endpoint::{BitgetEndpoints, Endpoints};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, ExchangeError,
+    PerpExchange,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpSnapshot};
 
+/// Bitget 통합 클라이언트 (시세, Orderbook, Asset)
 #[derive(Clone)]
 pub struct BitgetClient {
     pub(crate) http: reqwest::Client,
+    pub(crate) api_key: Option<String>,
+    pub(crate) api_secret: Option<String>,
+    pub(crate) passphrase: Option<String>,
+    pub(crate) endpoints: BitgetEndpoints,
+}
+
+impl Default for BitgetClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BitgetClient {
+    /// 공개 API만 사용하는 경우 (시세, Orderbook 등)
     pub fn new() -> Self {
+        Self::with_endpoints(Endpoints::current().bitget.clone())
+    }
+
+    /// 지정한 주소로 공개 API 클라이언트 생성 (테스트넷, 목 서버 등)
+    pub fn with_endpoints(endpoints: BitgetEndpoints) -> Self {
         Self {
             http: reqwest::Client::new(),
+            api_key: None,
+            api_secret: None,
+            passphrase: None,
+            endpoints,
         }
     }
-}
 
-/// Bitget의 다음 펀딩 시간 계산
-/// Bitget은 UTC 00:00, 04:00, 08:00, 12:00, 16:00, 20:00에 펀딩이 발생 (4시간 주기)
-fn next_bitget_funding_time(now: DateTime<Utc>) -> DateTime<Utc> {
-    use chrono::Timelike;
-
-    // Bitget funding schedule: 00:00, 04:00, 08:00, 12:00, 16:00, 20:00 UTC
-    let hours = now.hour();
-
-    // 다음 펀딩 시각의 hour 결정
-    let next_hour = if hours < 4 {
-        4
-    } else if hours < 8 {
-        8
-    } else if hours < 12 {
-        12
-    } else if hours < 16 {
-        16
-    } else if hours < 20 {
-        20
-    } else {
-        24 // 내일 00:00
-    };
-
-    let date = now.date_naive();
-    let next_date = if next_hour == 24 {
-        date.succ_opt().unwrap()
-    } else {
-        date
-    };
-
-    let hour = if next_hour == 24 { 0 } else { next_hour };
-
-    DateTime::<Utc>::from_naive_utc_and_offset(next_date.and_hms_opt(hour, 0, 0).unwrap(), Utc)
+    /// 인증이 필요한 API를 사용하는 경우 (Asset 등)
+    pub fn with_credentials() -> Result<Self, ExchangeError> {
+        let (api_key, api_secret, passphrase) = super::get_api_credentials()?;
+        Ok(Self {
+            http: reqwest::Client::new(),
+            api_key: Some(api_key),
+            api_secret: Some(api_secret),
+            passphrase: Some(passphrase),
+            endpoints: Endpoints::current().bitget.clone(),
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -76,7 +79,7 @@ struct BitgetTicker {
     #[serde(default)]
     usdt_volume: String, // 24h volume in USDT
     #[serde(default)]
-    index_price: String, // mark price
+    index_price: String, // 인덱스 가격 (v1 티커에 mark price가 없어 mark로 사용)
     #[serde(default)]
     funding_rate: String,
     #[serde(default)]
@@ -87,6 +90,23 @@ struct BitgetTicker {
     timestamp: String,
 }
 
+/// GET /api/v2/mix/market/current-fund-rate 항목 (심볼은 v2 형식 "BTCUSDT")
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetCurrentFundRate {
+    symbol: String,
+    #[serde(default)]
+    funding_rate_interval: String, // 시간
+    #[serde(default)]
+    next_update: String, // 다음 정산 시각 (ms)
+}
+
+/// 심볼별 펀딩 주기와 다음 정산 시각
+struct BitgetFundingSchedule {
+    interval_hours: Option<u32>,
+    next_funding_time: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BitgetOpenInterest {
@@ -96,6 +116,51 @@ struct BitgetOpenInterest {
     amount: String, // actual open interest in contracts (always positive)
 }
 
+impl BitgetClient {
+    /// USDT 무기한 전체의 펀딩 주기와 다음 정산 시각 (심볼마다 1h/4h/8h로 다르다)
+    async fn fetch_funding_schedules(
+        &self,
+    ) -> Result<HashMap<String, BitgetFundingSchedule>, ExchangeError> {
+        let url = format!(
+            "{}/api/v2/mix/market/current-fund-rate?productType=USDT-FUTURES",
+            self.endpoints.rest
+        );
+        let response: BitgetResponse<Vec<BitgetCurrentFundRate>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bitget,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
+
+        if response.code != "00000" {
+            return Err(map_error_code(&response.code, &response.msg));
+        }
+        Ok(response
+            .data
+            .into_iter()
+            .map(|rate| {
+                let schedule = BitgetFundingSchedule {
+                    interval_hours: rate.funding_rate_interval.parse().ok().filter(|h| *h > 0),
+                    next_funding_time: rate
+                        .next_update
+                        .parse::<i64>()
+                        .ok()
+                        .and_then(DateTime::from_timestamp_millis),
+                };
+                (rate.symbol, schedule)
+            })
+            .collect())
+    }
+}
+
 #[async_trait]
 impl PerpExchange for BitgetClient {
     fn id(&self) -> ExchangeId {
@@ -104,15 +169,30 @@ impl PerpExchange for BitgetClient {
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
         // 1) 티커 정보 (24h 거래량, 마크 가격, 펀딩 레이트)
-        let tickers_url = format!("{BASE_URL}/api/mix/v1/market/tickers?productType=umcbl");
-        let tickers_response: BitgetResponse<Vec<BitgetTicker>> =
-            self.http.get(&tickers_url).send().await?.json().await?;
+        let tickers_url = format!(
+            "{}/api/mix/v1/market/tickers?productType=umcbl",
+            self.endpoints.rest
+        );
+        let tickers_response: BitgetResponse<Vec<BitgetTicker>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bitget,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&tickers_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if tickers_response.code != "00000" {
-            return Err(ExchangeError::Other(format!(
-                "Bitget API error (tickers): {} - {}",
-                tickers_response.code, tickers_response.msg
-            )));
+            return Err(map_error_code(
+                &tickers_response.code,
+                &tickers_response.msg,
+            ));
         }
 
         // 2) 오픈 이너스트 - 각 심볼별로 병렬 조회 (holdingAmount는 net position이므로 실제 OI는 별도 조회 필요)
@@ -128,8 +208,8 @@ impl PerpExchange for BitgetClient {
             .map(|symbol| {
                 let http = self.http.clone();
                 let oi_url = format!(
-                    "{BASE_URL}/api/mix/v1/market/open-interest?symbol={}&productType=umcbl",
-                    symbol
+                    "{}/api/mix/v1/market/open-interest?symbol={}&productType=umcbl",
+                    self.endpoints.rest, symbol
                 );
 
                 async move {
@@ -137,7 +217,15 @@ impl PerpExchange for BitgetClient {
                     tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
                     // HTTP 요청
-                    let resp = match http.get(&oi_url).send().await {
+                    let resp = match RateLimiter::global()
+                        .send(
+                            ExchangeId::Bitget,
+                            EndpointClass::Futures,
+                            1,
+                            http.get(&oi_url),
+                        )
+                        .await
+                    {
                         Ok(r) => r,
                         Err(e) => {
                             tracing::warn!("Failed to fetch OI for {}: {:?}", symbol, e);
@@ -197,11 +285,16 @@ impl PerpExchange for BitgetClient {
             .buffer_unordered(10) // 동시에 최대 10개 요청만 처리 (Cloudflare 차단 방지)
             .collect()
             .await;
+        // 3) 펀딩 주기 (실패해도 시세는 내보내고 주기만 비워 둔다)
+        let funding_schedules = self
+            .fetch_funding_schedules()
+            .await
+            .map_err(|e| tracing::warn!("Bitget funding schedule fetch failed: {}", e))
+            .unwrap_or_default();
+
         let mut oi_map: HashMap<String, BitgetOpenInterest> = HashMap::new();
-        for result in oi_results {
-            if let Some((symbol, oi_data)) = result {
-                oi_map.insert(symbol, oi_data);
-            }
+        for (symbol, oi_data) in oi_results.into_iter().flatten() {
+            oi_map.insert(symbol, oi_data);
         }
 
         let now = Utc::now();
@@ -213,12 +306,16 @@ impl PerpExchange for BitgetClient {
                 continue;
             }
 
-            // 심볼 변환: "BTCUSDT_UMCBL" -> "BTCUSDT"
-            let symbol = ticker.symbol.replace("_UMCBL", "");
+            let instrument =
+                match parse_native_symbol(ExchangeId::Bitget, InstrumentKind::Perp, &ticker.symbol)
+                {
+                    Some(i) => i,
+                    None => continue,
+                };
 
-            let mark_price: f64 = match ticker.index_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let mark_price = match parse_decimal(&ticker.index_price) {
+                Some(v) => v,
+                None => continue,
             };
 
             let funding_rate: f64 = ticker.funding_rate.parse().unwrap_or(0.0);
@@ -229,23 +326,30 @@ impl PerpExchange for BitgetClient {
                 Some(oi_data) => oi_data.amount.parse::<f64>().unwrap_or(0.0).abs(),
                 None => 0.0,
             };
-            let oi_usd = oi_contracts * mark_price;
+            let oi_usd = oi_contracts * mark_price.to_f64().unwrap_or(0.0);
 
             // 24h 거래량은 usdtVolume (USDT 기준)
             let vol_24h_usd: f64 = ticker.usdt_volume.parse().unwrap_or(0.0);
 
-            // 다음 펀딩 시간 계산 (UTC 00:00, 08:00, 16:00)
-            let next_funding_time = Some(next_bitget_funding_time(now));
+            // v1 심볼 "BTCUSDT_UMCBL" -> v2 심볼 "BTCUSDT"
+            let schedule = funding_schedules.get(ticker.symbol.trim_end_matches("_UMCBL"));
+            let funding_interval_hours = schedule.and_then(|s| s.interval_hours);
+            let next_funding_time = schedule.and_then(|s| s.next_funding_time);
 
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Bitget,
-                symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 mark_price,
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours,
+                predicted_funding_rate: None,
+                // v1 티커에는 mark price가 없어 indexPrice를 mark로 쓰므로, 인덱스 가격은 스트림 값만 쓴다
+                index_price: None,
                 updated_at: now,
             });
         }
@@ -257,6 +361,7 @@ impl PerpExchange for BitgetClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use interface::Decimal;
 
     #[test]
     fn test_bitget_client_id() {
@@ -279,7 +384,7 @@ mod tests {
                     println!("Bitget snapshot: {:?}", snapshot);
                     assert_eq!(snapshot.exchange, ExchangeId::Bitget);
                     assert!(snapshot.symbol.ends_with("USDT"));
-                    assert!(snapshot.mark_price > 0.0);
+                    assert!(snapshot.mark_price > Decimal::ZERO);
                     assert!(snapshot.oi_usd >= 0.0);
                     assert!(snapshot.vol_24h_usd >= 0.0);
                 }
@@ -293,10 +398,8 @@ mod tests {
             Err(e) => {
                 // 네트워크 오류 등은 테스트 실패로 간주하지 않음
                 // 하지만 API 오류는 확인
-                if let ExchangeError::Other(msg) = &e {
-                    if msg.contains("Bitget API error") {
-                        panic!("Bitget API error: {}", msg);
-                    }
+                if let ExchangeError::ExchangeReject { .. } = &e {
+                    panic!("Bitget API error: {}", e);
                 }
                 // 네트워크 오류는 테스트 환경에 따라 실패할 수 있으므로 경고만
                 eprintln!("Warning: fetch_all failed: {:?}", e);
@@ -2,10 +2,13 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use crate::{BitgetClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
-
-const BASE_URL: &str = "https://api.bitget.com";
+use crate::bitget::{api_error, map_error_code};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, BitgetClient,
+    ExchangeError, SpotExchange,
+};
+use interface::{Currency, Decimal, ExchangeId, InstrumentKind, SpotSnapshot};
 
 #[derive(Debug, Deserialize)]
 struct BitgetResponse<T> {
@@ -31,32 +34,47 @@ impl SpotExchange for BitgetClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
-        let tickers_url = format!("{BASE_URL}/api/spot/v1/market/tickers");
-        let tickers_response: BitgetResponse<Vec<BitgetSpotTicker>> =
-            self.http.get(&tickers_url).send().await?.json().await?;
+        let tickers_url = format!("{}/api/spot/v1/market/tickers", self.endpoints.rest);
+        let tickers_response: BitgetResponse<Vec<BitgetSpotTicker>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bitget,
+                    EndpointClass::Spot,
+                    1,
+                    self.http.get(&tickers_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if tickers_response.code != "00000" {
-            return Err(ExchangeError::Other(format!(
-                "Bitget API error (spot tickers): {} - {}",
-                tickers_response.code, tickers_response.msg
-            )));
+            return Err(map_error_code(
+                &tickers_response.code,
+                &tickers_response.msg,
+            ));
         }
 
         let now = Utc::now();
         let mut out = Vec::new();
 
         for ticker in tickers_response.data {
-            if !ticker.symbol.ends_with("USDT") {
-                continue; // USDT 페어만
-            }
+            let instrument =
+                match parse_native_symbol(ExchangeId::Bitget, InstrumentKind::Spot, &ticker.symbol)
+                {
+                    Some(i) if i.quote == "USDT" => i,
+                    _ => continue, // USDT 페어만
+                };
 
-            let price: f64 = match ticker.close.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let price = match parse_decimal(&ticker.close) {
+                Some(v) => v,
+                None => continue,
             };
 
             // price가 0보다 큰 경우만 추가
-            if price <= 0.0 {
+            if price <= Decimal::ZERO {
                 continue;
             }
 
@@ -64,7 +82,8 @@ impl SpotExchange for BitgetClient {
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Bitget,
-                symbol: ticker.symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 price,
                 vol_24h_usd,
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::stream::{stream_forever, subscribe_messages, WsSubscription};
+use crate::{
+    decimal::parse_decimal, instrument::resolve_symbol, BitgetClient, ExchangeError, PerpExchange,
+    PerpStream, SpotExchange, SpotStream,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpTick, SpotTick};
+
+/// 구독 메시지 하나에 넣을 채널 수 (메시지 크기 제한 4096 bytes 이내)
+const ARGS_PER_MESSAGE: usize = 20;
+/// 2분 동안 ping이 없으면 서버가 끊으므로 텍스트 "ping"을 보낸다 (응답은 "pong")
+const PING_MESSAGE: &str = "ping";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetPushMessage {
+    arg: BitgetPushArg,
+    data: Vec<BitgetWsTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetPushArg {
+    inst_type: String,
+    channel: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BitgetWsTicker {
+    inst_id: String, // V2 표기 (예: "BTCUSDT")
+    last_pr: Option<String>,
+    mark_price: Option<String>,
+    index_price: Option<String>,
+    funding_rate: Option<String>,
+    next_funding_time: Option<String>,
+    quote_volume: Option<String>,   // 24h 거래대금 (USDT)
+    holding_amount: Option<String>, // 미결제약정 (base 수량)
+}
+
+/// ticker 채널 푸시만 추려낸다 (구독 응답, "pong"은 None)
+fn parse_push(text: &str, inst_type: &str) -> Option<Vec<BitgetWsTicker>> {
+    let msg: BitgetPushMessage = serde_json::from_str(text).ok()?;
+    (msg.arg.channel == "ticker" && msg.arg.inst_type == inst_type).then_some(msg.data)
+}
+
+fn parse_perp_message(text: &str) -> Vec<PerpTick> {
+    let Some(tickers) = parse_push(text, "USDT-FUTURES") else {
+        return Vec::new();
+    };
+    let now = Utc::now();
+
+    tickers
+        .into_iter()
+        .filter_map(|ticker| {
+            // 다른 Bitget 모듈과 같은 V1 표기(BTCUSDT_UMCBL)로 맞춘다
+            let instrument =
+                resolve_symbol(ExchangeId::Bitget, InstrumentKind::Perp, &ticker.inst_id)
+                    .filter(|i| i.quote == "USDT")?;
+            let mut tick = PerpTick::new(instrument, Currency::USDT);
+            tick.mark_price = ticker.mark_price.as_deref().and_then(parse_decimal);
+            tick.index_price = ticker.index_price.as_deref().and_then(parse_decimal);
+            tick.funding_rate = ticker.funding_rate.and_then(|v| v.parse().ok());
+            tick.next_funding_time = ticker
+                .next_funding_time
+                .and_then(|v| v.parse::<i64>().ok())
+                .filter(|ms| *ms > 0)
+                .and_then(DateTime::from_timestamp_millis);
+            tick.vol_24h_usd = ticker.quote_volume.and_then(|v| v.parse().ok());
+            // OI는 base 수량이므로 mark price로 환산
+            tick.oi_usd = match (ticker.holding_amount.as_deref(), tick.mark_price) {
+                (Some(amount), Some(mark)) => amount
+                    .parse::<f64>()
+                    .ok()
+                    .map(|v| v.abs() * mark.to_f64().unwrap_or(0.0)),
+                _ => None,
+            };
+            tick.updated_at = now;
+            Some(tick)
+        })
+        .collect()
+}
+
+fn parse_spot_message(text: &str) -> Vec<SpotTick> {
+    let Some(tickers) = parse_push(text, "SPOT") else {
+        return Vec::new();
+    };
+    let now = Utc::now();
+
+    tickers
+        .into_iter()
+        .filter_map(|ticker| {
+            let instrument =
+                resolve_symbol(ExchangeId::Bitget, InstrumentKind::Spot, &ticker.inst_id)
+                    .filter(|i| i.quote == "USDT")?; // USDT 페어만
+            let mut tick = SpotTick::new(instrument, Currency::USDT);
+            tick.price = ticker
+                .last_pr
+                .as_deref()
+                .and_then(parse_decimal)
+                .filter(|p| !p.is_zero());
+            tick.vol_24h_usd = ticker.quote_volume.and_then(|v| v.parse().ok());
+            tick.updated_at = now;
+            Some(tick)
+        })
+        .collect()
+}
+
+/// V1 심볼("BTCUSDT_UMCBL")을 V2 instId("BTCUSDT")로 바꿔 ticker 채널 구독
+fn ticker_subscription(url: &str, inst_type: &str, native_symbols: &[String]) -> WsSubscription {
+    let args: Vec<serde_json::Value> = native_symbols
+        .iter()
+        .map(|symbol| {
+            let inst_id = symbol.split('_').next().unwrap_or(symbol);
+            json!({ "instType": inst_type, "channel": "ticker", "instId": inst_id })
+        })
+        .collect();
+
+    WsSubscription {
+        url: url.to_string(),
+        messages: subscribe_messages(
+            &args,
+            ARGS_PER_MESSAGE,
+            |chunk| json!({ "op": "subscribe", "args": chunk }),
+        ),
+        ping: Some(PING_MESSAGE.to_string()),
+    }
+}
+
+#[async_trait]
+impl PerpStream for BitgetClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bitget
+    }
+
+    async fn stream_perps(&self, tx: mpsc::Sender<PerpTick>) -> Result<(), ExchangeError> {
+        // 상품별 구독이 필요하므로 REST로 상장 목록을 받아온다
+        stream_forever("Bitget perp", tx, || async {
+            let symbols: Vec<String> = PerpExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            Ok((
+                ticker_subscription(&self.endpoints.public_ws, "USDT-FUTURES", &symbols),
+                parse_perp_message,
+            ))
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl SpotStream for BitgetClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bitget
+    }
+
+    async fn stream_spots(&self, tx: mpsc::Sender<SpotTick>) -> Result<(), ExchangeError> {
+        stream_forever("Bitget spot", tx, || async {
+            let symbols: Vec<String> = SpotExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            Ok((
+                ticker_subscription(&self.endpoints.public_ws, "SPOT", &symbols),
+                parse_spot_message,
+            ))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bitget_perp_stream() {
+        let msg = r#"{"action":"snapshot","arg":{"instType":"USDT-FUTURES","channel":"ticker","instId":"BTCUSDT"},"data":[{"instId":"BTCUSDT","lastPr":"65001","markPrice":"65000","indexPrice":"64980","fundingRate":"0.000068","nextFundingTime":"1695722400000","quoteVolume":"3950000000","holdingAmount":"100"}],"ts":1695715383021}"#;
+        let ticks = parse_perp_message(msg);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].instrument.native_symbol, "BTCUSDT_UMCBL");
+        assert_eq!(ticks[0].mark_price, Some(Decimal::from(65000)));
+        assert_eq!(ticks[0].index_price, Some(Decimal::from(64980)));
+        assert_eq!(ticks[0].funding_rate, Some(0.000068));
+        assert_eq!(ticks[0].oi_usd, Some(6_500_000.0));
+        assert_eq!(ticks[0].vol_24h_usd, Some(3_950_000_000.0));
+
+        // 현물 푸시는 무기한 파서에서 무시
+        let spot = r#"{"action":"snapshot","arg":{"instType":"SPOT","channel":"ticker","instId":"BTCUSDT"},"data":[{"instId":"BTCUSDT","lastPr":"65000"}],"ts":1}"#;
+        assert!(parse_perp_message(spot).is_empty());
+        assert!(parse_perp_message("pong").is_empty());
+    }
+
+    #[test]
+    fn test_parse_bitget_spot_stream() {
+        let msg = r#"{"action":"snapshot","arg":{"instType":"SPOT","channel":"ticker","instId":"ETHUSDT"},"data":[{"instId":"ETHUSDT","lastPr":"3000.5","quoteVolume":"1234567"}],"ts":1695702438018}"#;
+        let ticks = parse_spot_message(msg);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].instrument.native_symbol, "ETHUSDT_SPBL");
+        assert_eq!(ticks[0].price, Some(Decimal::new(30005, 1)));
+    }
+
+    #[test]
+    fn test_bitget_ticker_subscription() {
+        let sub = ticker_subscription(
+            "wss://ws.bitget.com/v2/ws/public",
+            "USDT-FUTURES",
+            &["BTCUSDT_UMCBL".to_string()],
+        );
+        assert_eq!(sub.messages.len(), 1);
+        assert!(sub.messages[0].contains(r#""instId":"BTCUSDT""#));
+        assert!(!sub.messages[0].contains("_UMCBL"));
+    }
+}
@@ -2,10 +2,10 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use interface::{ExchangeId, FutureAsset, SpotAsset};
+use interface::{Decimal, ExchangeId, FutureAsset, SpotAsset};
 
-use super::super::{AssetExchange, ExchangeError};
-use super::{generate_jwt_token, BithumbClient, BASE_URL};
+use super::super::{decimal::parse_decimal, error::retry_after, AssetExchange, ExchangeError};
+use super::{api_error, generate_jwt_token, BithumbClient};
 
 #[derive(Debug, Deserialize)]
 struct BithumbAccount {
@@ -31,19 +31,19 @@ impl AssetExchange for BithumbClient {
 
     async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
-            ExchangeError::Other(
+            ExchangeError::Auth(
                 "API key not set. Use BithumbClient::with_credentials()".to_string(),
             )
         })?;
         let api_secret = self.api_secret.as_ref().ok_or_else(|| {
-            ExchangeError::Other(
+            ExchangeError::Auth(
                 "API secret not set. Use BithumbClient::with_credentials()".to_string(),
             )
         })?;
 
         // 신버전 API: GET /v1/accounts
         let endpoint = "/v1/accounts";
-        let url = format!("{BASE_URL}{}", endpoint);
+        let url = format!("{}{}", self.endpoints.rest, endpoint);
 
         // JWT 토큰 생성 (파라미터가 없으므로 query_hash 없음)
         let jwt_token = generate_jwt_token(api_key, api_secret)?;
@@ -58,15 +58,11 @@ impl AssetExchange for BithumbClient {
             .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
-        // 디버깅을 위해 응답 로깅 (민감한 정보는 제외)
-        if status != 200 {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb API HTTP error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+        if !status.is_success() {
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         // 응답이 배열인지 객체인지 확인
@@ -83,12 +79,12 @@ impl AssetExchange for BithumbClient {
         let mut assets = Vec::new();
 
         for account in accounts {
-            let balance: f64 = account.balance.parse().unwrap_or(0.0);
-            let locked: f64 = account.locked.parse().unwrap_or(0.0);
+            let balance = parse_decimal(&account.balance).unwrap_or_default();
+            let locked = parse_decimal(&account.locked).unwrap_or_default();
             let total = balance + locked;
 
             // total이 0보다 큰 경우만 추가
-            if total > 0.0 {
+            if total > Decimal::ZERO {
                 assets.push(SpotAsset {
                     currency: account.currency.to_uppercase(),
                     total,
@@ -122,16 +118,13 @@ mod tests {
     }
 
     fn handle_api_error(e: &ExchangeError) {
-        if let ExchangeError::Other(msg) = e {
-            if msg.contains("Bithumb API error") {
-                panic!("Bithumb API error: {}", msg);
-            }
-            if msg.contains("not found") {
+        match e {
+            ExchangeError::ExchangeReject { .. } => panic!("Bithumb API error: {}", e),
+            ExchangeError::Auth(msg) if msg.contains("not found") => {
                 eprintln!("Warning: Environment variable not found: {:?}", e);
-                return;
             }
+            _ => eprintln!("Warning: API call failed: {:?}", e),
         }
-        eprintln!("Warning: API call failed: {:?}", e);
     }
 
     #[test]
@@ -158,12 +151,18 @@ mod tests {
                 // 모든 자산이 올바른 형식인지 확인
                 for asset in &assets {
                     assert!(!asset.currency.is_empty(), "currency should not be empty");
-                    assert!(asset.total >= 0.0, "total should be non-negative");
-                    assert!(asset.available >= 0.0, "available should be non-negative");
-                    assert!(asset.in_use >= 0.0, "in_use should be non-negative");
+                    assert!(asset.total >= Decimal::ZERO, "total should be non-negative");
+                    assert!(
+                        asset.available >= Decimal::ZERO,
+                        "available should be non-negative"
+                    );
+                    assert!(
+                        asset.in_use >= Decimal::ZERO,
+                        "in_use should be non-negative"
+                    );
                     assert!(
-                        asset.available + asset.in_use <= asset.total + 0.0001,
-                        "available + in_use should not exceed total (with small tolerance)"
+                        asset.available + asset.in_use <= asset.total,
+                        "available + in_use should not exceed total"
                     );
                 }
 
@@ -2,20 +2,27 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use tokio::sync::RwLock;
 
-use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType};
+use interface::{
+    DepositWithdrawalFee, ExchangeId, FeeInfo, MarketType, NetworkStatus, WalletStatus,
+};
 
-use super::super::FeeExchange;
-use super::{BithumbClient, BASE_URL};
+use super::super::decimal::parse_decimal;
+use super::super::error::check_status;
+use super::super::rate_limit::{EndpointClass, RateLimiter};
+use super::super::{FeeExchange, WalletStatusExchange};
+use super::{api_error, map_error_code, BithumbClient};
 
 const FEE_API_URL: &str = "/v2/fee/inout/ALL";
+const ASSET_STATUS_URL: &str = "/public/assetsstatus/multichain/ALL";
 
 /// API 응답 구조체
 #[derive(Debug, Deserialize)]
 struct FeeApiResponse {
+    #[allow(dead_code)]
     name: String,
     currency: String,
     networks: Vec<NetworkFee>,
@@ -35,6 +42,82 @@ struct NetworkFee {
     withdraw_minimum_quantity: String,
 }
 
+/// 입출금 현황 API 응답 (네트워크별, 1이면 가능)
+#[derive(Debug, Deserialize)]
+struct AssetStatusResponse {
+    status: String,
+    #[serde(default)]
+    data: Vec<AssetStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AssetStatus {
+    currency: String,
+    net_type: String,
+    deposit_status: i32,
+    withdrawal_status: i32,
+}
+
+/// 입출금 현황과 네트워크별 수수료를 합친다
+/// 수수료 API의 네트워크 이름이 현황 API와 다르게 올 수 있어, 이름이 안 맞으면
+/// 양쪽 모두 네트워크가 하나일 때만 짝지어 준다.
+fn to_wallet_statuses(
+    statuses: Vec<AssetStatus>,
+    fees: &[FeeApiResponse],
+    now: DateTime<Utc>,
+) -> Vec<WalletStatus> {
+    let fee_map: HashMap<String, &FeeApiResponse> = fees
+        .iter()
+        .map(|f| (f.currency.to_uppercase(), f))
+        .collect();
+
+    let mut by_currency: HashMap<String, Vec<AssetStatus>> = HashMap::new();
+    for status in statuses {
+        by_currency
+            .entry(status.currency.to_uppercase())
+            .or_default()
+            .push(status);
+    }
+
+    let mut out: Vec<WalletStatus> = by_currency
+        .into_iter()
+        .map(|(currency, statuses)| {
+            let fee_networks = fee_map
+                .get(&currency)
+                .map(|f| f.networks.as_slice())
+                .unwrap_or_default();
+            let single = statuses.len() == 1 && fee_networks.len() == 1;
+            let networks = statuses
+                .into_iter()
+                .map(|status| {
+                    let fee = fee_networks
+                        .iter()
+                        .find(|n| n.net_name.eq_ignore_ascii_case(&status.net_type))
+                        .or(if single { fee_networks.first() } else { None });
+                    NetworkStatus {
+                        network: status.net_type,
+                        deposit_enabled: status.deposit_status == 1,
+                        withdraw_enabled: status.withdrawal_status == 1,
+                        deposit_fee: fee.and_then(|f| parse_decimal(&f.deposit_fee_quantity)),
+                        withdraw_fee: fee.and_then(|f| parse_decimal(&f.withdraw_fee_quantity)),
+                        deposit_min: fee.and_then(|f| parse_decimal(&f.deposit_minimum_quantity)),
+                        withdraw_min: fee.and_then(|f| parse_decimal(&f.withdraw_minimum_quantity)),
+                        withdraw_max: None,
+                    }
+                })
+                .collect();
+            WalletStatus {
+                exchange: ExchangeId::Bithumb,
+                currency,
+                networks,
+                updated_at: now,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| a.currency.cmp(&b.currency));
+    out
+}
+
 /// 입출금 수수료 캐시
 static FEE_CACHE: tokio::sync::OnceCell<Arc<RwLock<HashMap<String, DepositWithdrawalFee>>>> =
     tokio::sync::OnceCell::const_new();
@@ -48,30 +131,27 @@ async fn init_fee_cache() -> Arc<RwLock<HashMap<String, DepositWithdrawalFee>>>
 }
 
 impl BithumbClient {
+    /// 코인별 네트워크 입출금 수수료 조회
+    async fn fetch_fee_networks(&self) -> Result<Vec<FeeApiResponse>, super::super::ExchangeError> {
+        let url = format!("{}{FEE_API_URL}", self.endpoints.rest);
+        let http = reqwest::Client::new();
+        let response = check_status(http.get(&url).send().await?, api_error).await?;
+
+        let response_text = response.text().await?;
+        serde_json::from_str(&response_text).map_err(|e| {
+            super::super::ExchangeError::Other(format!(
+                "Failed to parse fee API response: {}, response: {}",
+                e,
+                response_text.chars().take(200).collect::<String>()
+            ))
+        })
+    }
+
     /// 입출금 수수료 캐시 초기화 및 업데이트
     pub async fn refresh_deposit_withdrawal_fees(
         &self,
     ) -> Result<HashMap<String, DepositWithdrawalFee>, super::super::ExchangeError> {
-        let url = format!("{BASE_URL}{FEE_API_URL}");
-        let http = reqwest::Client::new();
-        let response = http.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(super::super::ExchangeError::Other(format!(
-                "Failed to fetch fee API: status {}",
-                response.status()
-            )));
-        }
-
-        let response_text = response.text().await?;
-        let api_responses: Vec<FeeApiResponse> =
-            serde_json::from_str(&response_text).map_err(|e| {
-                super::super::ExchangeError::Other(format!(
-                    "Failed to parse fee API response: {}, response: {}",
-                    e,
-                    response_text.chars().take(200).collect::<String>()
-                ))
-            })?;
+        let api_responses = self.fetch_fee_networks().await?;
 
         let mut fees = HashMap::new();
         let now = Utc::now();
@@ -82,8 +162,9 @@ impl BithumbClient {
             // 여러 네트워크가 있는 경우, 첫 번째 네트워크 사용 (또는 평균 계산 가능)
             // 일단 첫 번째 네트워크의 수수료 사용
             if let Some(network) = api_response.networks.first() {
-                let deposit_fee = network.deposit_fee_quantity.parse::<f64>().unwrap_or(0.0);
-                let withdrawal_fee = network.withdraw_fee_quantity.parse::<f64>().unwrap_or(0.0);
+                let deposit_fee = parse_decimal(&network.deposit_fee_quantity).unwrap_or_default();
+                let withdrawal_fee =
+                    parse_decimal(&network.withdraw_fee_quantity).unwrap_or_default();
 
                 fees.insert(
                     currency.clone(),
@@ -154,9 +235,67 @@ impl FeeExchange for BithumbClient {
     }
 }
 
+#[async_trait]
+impl WalletStatusExchange for BithumbClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bithumb
+    }
+
+    async fn fetch_wallet_status(&self) -> Result<Vec<WalletStatus>, super::super::ExchangeError> {
+        let url = format!("{}{ASSET_STATUS_URL}", self.endpoints.rest);
+        let response: AssetStatusResponse = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bithumb,
+                    EndpointClass::Spot,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
+        if response.status != "0000" {
+            return Err(map_error_code(&response.status, ""));
+        }
+
+        // 수수료 조회가 실패해도 입출금 가능 여부는 돌려준다
+        let fees = self.fetch_fee_networks().await.unwrap_or_else(|e| {
+            tracing::warn!("Bithumb 입출금 수수료 조회 실패: {}", e);
+            Vec::new()
+        });
+        Ok(to_wallet_statuses(response.data, &fees, Utc::now()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bithumb_wallet_status() {
+        let status = r#"{"status":"0000","data":[{"currency":"USDT","net_type":"ETH","deposit_status":1,"withdrawal_status":0},{"currency":"USDT","net_type":"TRX","deposit_status":1,"withdrawal_status":1},{"currency":"BTC","net_type":"BTC","deposit_status":0,"withdrawal_status":0}]}"#;
+        let fees = r#"[{"name":"테더","currency":"USDT","networks":[{"net_name":"ETH","deposit_fee_quantity":"0","deposit_minimum_quantity":"1","withdraw_fee_quantity":"10","withdraw_minimum_quantity":"20"},{"net_name":"TRX","deposit_fee_quantity":"0","deposit_minimum_quantity":"1","withdraw_fee_quantity":"1","withdraw_minimum_quantity":"5"}]},{"name":"비트코인","currency":"BTC","networks":[{"net_name":"Bitcoin","deposit_fee_quantity":"0","deposit_minimum_quantity":"0.0001","withdraw_fee_quantity":"0.0005","withdraw_minimum_quantity":"0.002"}]}]"#;
+        let response: AssetStatusResponse = serde_json::from_str(status).unwrap();
+        let fees: Vec<FeeApiResponse> = serde_json::from_str(fees).unwrap();
+        let statuses = to_wallet_statuses(response.data, &fees, Utc::now());
+
+        assert_eq!(statuses.len(), 2);
+        let btc = &statuses[0];
+        assert_eq!(btc.currency, "BTC");
+        assert!(!btc.can_deposit() && !btc.can_withdraw());
+        // 이름이 달라도 네트워크가 하나뿐이면 수수료를 붙인다
+        assert_eq!(btc.networks[0].withdraw_fee, Some(Decimal::new(5, 4)));
+
+        let usdt = &statuses[1];
+        assert!(usdt.can_withdraw());
+        let trx = usdt.networks.iter().find(|n| n.network == "TRX").unwrap();
+        assert_eq!(trx.withdraw_fee, Some(Decimal::ONE));
+        assert_eq!(trx.withdraw_min, Some(Decimal::from(5)));
+    }
 
     #[tokio::test]
     async fn test_refresh_deposit_withdrawal_fees() {
@@ -207,8 +346,8 @@ mod tests {
         match result {
             Ok(fee) => {
                 assert_eq!(fee.currency, "BTC");
-                assert!(fee.deposit_fee >= 0.0);
-                assert!(fee.withdrawal_fee >= 0.0);
+                assert!(fee.deposit_fee >= Decimal::ZERO);
+                assert!(fee.withdrawal_fee >= Decimal::ZERO);
                 println!(
                     "BTC fees - Deposit: {}, Withdrawal: {}",
                     fee.deposit_fee, fee.withdrawal_fee
@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, DurationRound, NaiveDateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+use interface::{Candle, ExchangeId, InstrumentKind, KlineInterval};
+
+use super::super::{
+    error::{check_status, truncate_body},
+    instrument::resolve_symbol,
+    kline::{finish_candles, page_backward, parse_candle_row, CandleSource, STANDARD_LAYOUT},
+    rate_limit::{EndpointClass, RateLimiter},
+    ExchangeError, KlineExchange,
+};
+use super::{api_error, BithumbClient};
+
+/// /v1/candles 한 번에 받을 수 있는 최대 개수
+const PAGE_LIMIT: usize = 200;
+
+/// GET /v1/candles/* 응답 항목 (최신 순)
+#[derive(Debug, Deserialize)]
+struct BithumbCandle {
+    /// 캔들 시작 시각 (UTC, 예: "2024-05-28T02:35:00")
+    candle_date_time_utc: String,
+    opening_price: Value,
+    high_price: Value,
+    low_price: Value,
+    trade_price: Value,
+    candle_acc_trade_volume: Value,
+}
+
+/// 간격별 캔들 경로 (분봉은 단위(분)를 경로에 넣는다)
+fn candle_path(interval: KlineInterval) -> &'static str {
+    match interval {
+        KlineInterval::M1 => "/v1/candles/minutes/1",
+        KlineInterval::M5 => "/v1/candles/minutes/5",
+        KlineInterval::M15 => "/v1/candles/minutes/15",
+        KlineInterval::M30 => "/v1/candles/minutes/30",
+        KlineInterval::H1 => "/v1/candles/minutes/60",
+        KlineInterval::H4 => "/v1/candles/minutes/240",
+        KlineInterval::D1 => "/v1/candles/days",
+    }
+}
+
+/// "BTC_KRW" -> "KRW-BTC" (신버전 API 마켓 코드)
+fn market_code(native_symbol: &str) -> String {
+    match native_symbol.split_once('_') {
+        Some((base, quote)) => format!("{}-{}", quote, base),
+        None => native_symbol.to_string(),
+    }
+}
+
+/// 응답 항목을 [ts, open, high, low, close, volume] 행으로 바꿔 공통 파서로 변환
+fn to_candle(source: CandleSource<'_>, item: &BithumbCandle) -> Result<Candle, ExchangeError> {
+    let open_time = NaiveDateTime::parse_from_str(&item.candle_date_time_utc, "%Y-%m-%dT%H:%M:%S")
+        .map_err(|e| {
+            ExchangeError::Other(format!(
+                "Invalid Bithumb candle time {}: {}",
+                item.candle_date_time_utc, e
+            ))
+        })?
+        .and_utc();
+    let row = [
+        Value::from(open_time.timestamp_millis()),
+        item.opening_price.clone(),
+        item.high_price.clone(),
+        item.low_price.clone(),
+        item.trade_price.clone(),
+        item.candle_acc_trade_volume.clone(),
+    ];
+    parse_candle_row(source, &row, STANDARD_LAYOUT)
+}
+
+impl BithumbClient {
+    /// open_time이 cursor 이하인 최신 캔들 한 페이지
+    async fn fetch_kline_page(
+        &self,
+        source: CandleSource<'_>,
+        market: &str,
+        cursor: DateTime<Utc>,
+    ) -> Result<Vec<Candle>, ExchangeError> {
+        // to는 초 단위이고 해당 시각 미만(미포함) 캔들을 최신 순으로 준다
+        let to = cursor
+            .duration_trunc(Duration::seconds(1))
+            .unwrap_or(cursor)
+            + Duration::seconds(1);
+        let url = format!(
+            "{}{}?market={}&to={}&count={}",
+            self.endpoints.rest,
+            candle_path(source.interval),
+            market,
+            to.format("%Y-%m-%dT%H:%M:%SZ"),
+            PAGE_LIMIT
+        );
+        let response_text = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bithumb,
+                    EndpointClass::Spot,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .text()
+        .await?;
+
+        let items: Vec<BithumbCandle> = serde_json::from_str(&response_text).map_err(|e| {
+            ExchangeError::Other(format!(
+                "Failed to parse Bithumb candles response: {}, response: {}",
+                e,
+                truncate_body(&response_text)
+            ))
+        })?;
+        items.iter().map(|item| to_candle(source, item)).collect()
+    }
+}
+
+#[async_trait]
+impl KlineExchange for BithumbClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bithumb
+    }
+
+    async fn fetch_klines(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+        interval: KlineInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>, ExchangeError> {
+        // Bithumb은 현물만 있으므로 선물은 resolve_symbol 단계에서 걸러진다
+        let normalized_symbol = resolve_symbol(ExchangeId::Bithumb, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Bithumb symbol: {}", symbol))
+            })?;
+        let market = market_code(&normalized_symbol);
+        let source = CandleSource {
+            exchange: ExchangeId::Bithumb,
+            symbol: &normalized_symbol,
+            kind,
+            interval,
+        };
+
+        let candles = page_backward(start, end, |cursor| {
+            self.fetch_kline_page(source, &market, cursor)
+        })
+        .await?;
+
+        Ok(finish_candles(candles, start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bithumb_candles() {
+        let body = r#"[{"market":"KRW-BTC","candle_date_time_utc":"2024-05-28T02:35:00",
+            "candle_date_time_kst":"2024-05-28T11:35:00","opening_price":95000000,
+            "high_price":95100000.0,"low_price":94900000,"trade_price":95050000,
+            "timestamp":1716863759000,"candle_acc_trade_price":142575000.5,
+            "candle_acc_trade_volume":1.5,"unit":5}]"#;
+        let items: Vec<BithumbCandle> = serde_json::from_str(body).unwrap();
+        let source = CandleSource {
+            exchange: ExchangeId::Bithumb,
+            symbol: "BTC_KRW",
+            kind: InstrumentKind::Spot,
+            interval: KlineInterval::M5,
+        };
+        let candle = to_candle(source, &items[0]).unwrap();
+        assert_eq!(candle.open_time.timestamp_millis(), 1716863700000);
+        assert_eq!(candle.open, Decimal::from(95_000_000));
+        assert_eq!(candle.high, Decimal::from(95_100_000));
+        assert_eq!(candle.close, Decimal::from(95_050_000));
+        assert_eq!(candle.volume, Decimal::new(15, 1));
+
+        assert_eq!(market_code("BTC_KRW"), "KRW-BTC");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_klines_bithumb() {
+        let client = BithumbClient::new();
+        let end = Utc::now();
+
+        // 200개 페이지를 넘는 범위 (1시간봉 10일 == 240개)
+        match client
+            .fetch_klines(
+                "BTC-KRW",
+                InstrumentKind::Spot,
+                KlineInterval::H1,
+                end - Duration::days(10),
+                end,
+            )
+            .await
+        {
+            Ok(candles) => {
+                assert!(candles.len() > PAGE_LIMIT);
+                assert!(candles.iter().all(|c| c.symbol == "BTC_KRW"));
+                assert!(candles.iter().all(|c| c.low <= c.open && c.open <= c.high));
+                assert!(candles.windows(2).all(|w| w[0].open_time < w[1].open_time));
+            }
+            Err(e) => {
+                // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                eprintln!("Warning: API call failed: {:?}", e);
+            }
+        }
+    }
+}
@@ -1,18 +1,24 @@
 use std::env;
+use std::time::Duration;
 
 use chrono::Utc;
 use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
-use serde::Serialize;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
 use uuid::Uuid;
 
 use super::ExchangeError;
+use crate::endpoint::{BithumbEndpoints, Endpoints};
+use crate::error::{from_http_status, truncate_body};
 
 pub mod asset;
 pub mod fee;
+pub mod kline;
 pub mod orderbook;
 pub mod spot;
-
-pub const BASE_URL: &str = "https://api.bithumb.com";
+pub mod stream;
+pub mod transfer;
 
 #[derive(Debug, Serialize)]
 pub struct JwtPayload {
@@ -37,12 +43,43 @@ pub fn generate_jwt_token(api_key: &str, api_secret: &str) -> Result<String, Exc
         .map_err(|e| ExchangeError::Other(format!("Failed to generate JWT token: {}", e)))
 }
 
+#[derive(Debug, Serialize)]
+pub struct JwtPayloadWithQuery {
+    pub access_key: String,
+    pub nonce: String,
+    pub timestamp: i64,
+    pub query_hash: String,
+    pub query_hash_alg: String,
+}
+
+/// JWT 토큰 생성 (파라미터가 있는 /v1/* 요청용)
+/// query: 요청 파라미터를 "key=value&..." 형태로 이은 문자열 (POST는 JSON 본문의 필드를 같은 형태로)
+pub fn generate_jwt_token_with_query(
+    api_key: &str,
+    api_secret: &str,
+    query: &str,
+) -> Result<String, ExchangeError> {
+    let payload = JwtPayloadWithQuery {
+        access_key: api_key.to_string(),
+        nonce: Uuid::new_v4().to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        query_hash: hex::encode(Sha512::digest(query.as_bytes())),
+        query_hash_alg: "SHA512".to_string(),
+    };
+
+    let header = Header::new(Algorithm::HS256);
+    let encoding_key = EncodingKey::from_secret(api_secret.as_ref());
+
+    encode(&header, &payload, &encoding_key)
+        .map_err(|e| ExchangeError::Other(format!("Failed to generate JWT token: {}", e)))
+}
+
 /// 환경변수에서 API 키와 시크릿 가져오기
 pub fn get_api_credentials() -> Result<(String, String), ExchangeError> {
     let api_key = env::var("BITHUMB_API_KEY")
-        .map_err(|e| ExchangeError::Other(format!("BITHUMB_API_KEY not found: {}", e)))?;
+        .map_err(|e| ExchangeError::Auth(format!("BITHUMB_API_KEY not found: {}", e)))?;
     let api_secret = env::var("BITHUMB_API_SECRET")
-        .map_err(|e| ExchangeError::Other(format!("BITHUMB_API_SECRET not found: {}", e)))?;
+        .map_err(|e| ExchangeError::Auth(format!("BITHUMB_API_SECRET not found: {}", e)))?;
     Ok((api_key, api_secret))
 }
 
@@ -51,21 +88,120 @@ pub fn has_api_credentials() -> bool {
     env::var("BITHUMB_API_KEY").is_ok() && env::var("BITHUMB_API_SECRET").is_ok()
 }
 
+/// 신버전 API 에러 응답 (예: {"error":{"name":"insufficient_funds_bid","message":"..."}})
+#[derive(Debug, Deserialize)]
+struct BithumbV1ErrorResponse {
+    error: BithumbV1Error,
+}
+
+#[derive(Debug, Deserialize)]
+struct BithumbV1Error {
+    name: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// 구버전 API 에러 응답 (예: {"status":"5600","message":"..."})
+#[derive(Debug, Deserialize)]
+struct BithumbLegacyErrorResponse {
+    status: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// Bithumb 에러 코드를 ExchangeError로 변환
+/// code: 구버전 API의 status("5600" 등) 또는 신버전 API의 error.name
+pub fn map_error_code(code: &str, msg: &str) -> ExchangeError {
+    match code {
+        "too_many_requests" => ExchangeError::RateLimited { retry_after: None },
+        "5300"
+        | "jwt_verification"
+        | "expired_access_key"
+        | "invalid_access_key"
+        | "no_authorization_ip"
+        | "out_of_scope" => ExchangeError::Auth(format!("{}: {}", code, msg)),
+        "insufficient_funds_bid" | "insufficient_funds_ask" => {
+            ExchangeError::InsufficientBalance(msg.to_string())
+        }
+        "under_min_total_bid" | "under_min_total_ask" | "invalid_volume" => {
+            ExchangeError::InvalidQuantity(msg.to_string())
+        }
+        // 5600(CUSTOM NOTICE)은 사유가 한글 메시지로만 온다.
+        // "초과"는 최대 주문수량/금액 초과에도 쓰여 잔고 부족으로 보지 않는다
+        "5600" if msg.contains("잔고") || msg.contains("부족") => {
+            ExchangeError::InsufficientBalance(msg.to_string())
+        }
+        "5600"
+            if msg.contains("최소")
+                || msg.contains("최대")
+                || msg.contains("수량")
+                || msg.contains("금액") =>
+        {
+            ExchangeError::InvalidQuantity(msg.to_string())
+        }
+        _ => ExchangeError::ExchangeReject {
+            code: code.to_string(),
+            msg: msg.to_string(),
+        },
+    }
+}
+
+/// 응답 본문에서 Bithumb 에러 코드를 추출해 변환 (에러 형식이 아니면 None)
+pub fn parse_error_body(body: &str) -> Option<ExchangeError> {
+    if let Ok(err) = serde_json::from_str::<BithumbV1ErrorResponse>(body) {
+        return Some(map_error_code(&err.error.name, &err.error.message));
+    }
+    match serde_json::from_str::<BithumbLegacyErrorResponse>(body) {
+        Ok(err) if err.status != "0000" => Some(map_error_code(&err.status, &err.message)),
+        _ => None,
+    }
+}
+
+/// 실패한 HTTP 응답을 ExchangeError로 변환
+pub fn api_error(status: StatusCode, retry_after: Option<Duration>, body: &str) -> ExchangeError {
+    if status.as_u16() == 429 {
+        return ExchangeError::RateLimited { retry_after };
+    }
+    if let Some(err) = parse_error_body(body) {
+        return err;
+    }
+    from_http_status(status, retry_after, body).unwrap_or_else(|| {
+        ExchangeError::Other(format!(
+            "Bithumb API HTTP error: status {}, response: {}",
+            status,
+            truncate_body(body)
+        ))
+    })
+}
+
 /// Bithumb 통합 클라이언트 (Orderbook, Asset, Fee 모두 지원)
 #[derive(Clone)]
 pub struct BithumbClient {
     pub(crate) http: reqwest::Client,
     pub(crate) api_key: Option<String>,
     pub(crate) api_secret: Option<String>,
+    pub(crate) endpoints: BithumbEndpoints,
+}
+
+impl Default for BithumbClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BithumbClient {
     /// 공개 API만 사용하는 경우 (Orderbook 등)
     pub fn new() -> Self {
+        Self::with_endpoints(Endpoints::current().bithumb.clone())
+    }
+
+    /// 지정한 주소로 공개 API 클라이언트 생성 (테스트넷, 목 서버 등)
+    pub fn with_endpoints(endpoints: BithumbEndpoints) -> Self {
         Self {
             http: reqwest::Client::new(),
             api_key: None,
             api_secret: None,
+            endpoints,
         }
     }
 
@@ -76,6 +212,52 @@ impl BithumbClient {
             http: reqwest::Client::new(),
             api_key: Some(api_key),
             api_secret: Some(api_secret),
+            endpoints: Endpoints::current().bithumb.clone(),
         })
     }
+
+    /// 클라이언트가 사용하는 주소 (trade의 주문 모듈이 같은 주소를 쓰도록)
+    pub fn endpoints(&self) -> &BithumbEndpoints {
+        &self.endpoints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_bithumb_error_body() {
+        let body = r#"{"error":{"name":"insufficient_funds_bid","message":"주문가능한 금액(KRW)이 부족합니다."}}"#;
+        assert!(matches!(
+            api_error(StatusCode::BAD_REQUEST, None, body),
+            ExchangeError::InsufficientBalance(_)
+        ));
+
+        let body = r#"{"status":"5300","message":"Invalid Apikey"}"#;
+        assert!(matches!(
+            parse_error_body(body),
+            Some(ExchangeError::Auth(_))
+        ));
+
+        let body = r#"{"status":"5600","message":"최소 주문수량은 0.0001 BTC 입니다."}"#;
+        assert!(matches!(
+            parse_error_body(body),
+            Some(ExchangeError::InvalidQuantity(_))
+        ));
+
+        let body = r#"{"status":"5600","message":"주문 가능 잔고가 부족합니다."}"#;
+        assert!(matches!(
+            parse_error_body(body),
+            Some(ExchangeError::InsufficientBalance(_))
+        ));
+
+        let body = r#"{"status":"5600","message":"최대 주문수량을 초과하였습니다."}"#;
+        assert!(matches!(
+            parse_error_body(body),
+            Some(ExchangeError::InvalidQuantity(_))
+        ));
+
+        assert!(parse_error_body(r#"{"status":"0000","data":{}}"#).is_none());
+    }
 }
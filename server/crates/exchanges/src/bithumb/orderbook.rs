@@ -1,19 +1,20 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
 
-use interface::{ExchangeId, OrderBook, OrderBookEntry};
+use interface::{Decimal, ExchangeId, InstrumentKind, OrderBook, OrderBookEntry};
 
-use super::super::{ExchangeError, OrderBookExchange};
-use super::{BithumbClient, BASE_URL};
-
-impl BithumbClient {
-    /// 심볼을 Bithumb 형식으로 변환
-    /// 예: "BTC-KRW" -> "BTC_KRW"
-    fn normalize_symbol(&self, symbol: &str) -> String {
-        symbol.replace("-", "_").to_uppercase()
-    }
-}
+use super::super::{
+    book::{with_reset, BookDelta, BookEvent, BookSnapshot},
+    decimal::parse_decimal,
+    error::{retry_after, truncate_body},
+    instrument::resolve_symbol,
+    stream::{stream_forever, WsSubscription},
+    ExchangeError, OrderBookExchange, OrderBookStream,
+};
+use super::{api_error, map_error_code, BithumbClient};
 
 #[derive(Debug, Deserialize)]
 struct BithumbOrderBookResponse {
@@ -33,30 +34,140 @@ struct BithumbOrderBookEntry {
     quantity: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct BithumbDepthMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    content: BithumbDepthContent,
+}
+
+#[derive(Debug, Deserialize)]
+struct BithumbDepthContent {
+    list: Vec<BithumbDepthLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BithumbDepthLevel {
+    symbol: String,
+    order_type: String, // "bid" | "ask"
+    price: String,
+    quantity: String, // 해당 가격의 잔량 (0이면 삭제)
+}
+
+/// orderbookdepth 변경분 파서
+/// Bithumb은 update ID를 주지 않으므로 연결마다 1부터 메시지 순번을 매긴다.
+/// 수량이 증감이 아닌 잔량이라, REST 스냅샷 이전 변경분을 다시 반영해도 같은 값이 된다.
+fn depth_parser(symbol: String) -> impl FnMut(&str) -> Vec<BookEvent> + Send {
+    let mut sequence = 0u64;
+    move |text| {
+        // 접속/구독 응답({"status":"0000","resmsg":"..."})은 무시
+        let Ok(msg) = serde_json::from_str::<BithumbDepthMessage>(text) else {
+            return Vec::new();
+        };
+        if msg.kind != "orderbookdepth" {
+            return Vec::new();
+        }
+
+        let mut bids = Vec::new();
+        let mut asks = Vec::new();
+        for level in msg.content.list.iter().filter(|l| l.symbol == symbol) {
+            let (Some(price), Some(quantity)) =
+                (parse_decimal(&level.price), parse_decimal(&level.quantity))
+            else {
+                continue;
+            };
+            match level.order_type.as_str() {
+                "bid" => bids.push(OrderBookEntry { price, quantity }),
+                "ask" => asks.push(OrderBookEntry { price, quantity }),
+                _ => {}
+            }
+        }
+        if bids.is_empty() && asks.is_empty() {
+            return Vec::new();
+        }
+
+        sequence += 1;
+        vec![BookEvent::Delta(BookDelta {
+            bids,
+            asks,
+            first_sequence: sequence,
+            last_sequence: sequence,
+            prev_sequence: None,
+        })]
+    }
+}
+
+#[async_trait]
+impl OrderBookStream for BithumbClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bithumb
+    }
+
+    async fn stream_book_events(
+        &self,
+        symbol: &str,
+        _kind: InstrumentKind,
+        tx: mpsc::Sender<BookEvent>,
+    ) -> Result<(), ExchangeError> {
+        let subscription = WsSubscription {
+            url: self.endpoints.public_ws.clone(),
+            messages: vec![json!({ "type": "orderbookdepth", "symbols": [symbol] }).to_string()],
+            ping: None,
+        };
+        stream_forever("Bithumb depth", tx, || {
+            let subscription = subscription.clone();
+            let parse = with_reset(depth_parser(symbol.to_string()));
+            async move { Ok((subscription, parse)) }
+        })
+        .await
+    }
+
+    /// 변경분 순번이 1부터 시작하므로 REST 호가를 순번 0 스냅샷으로 쓴다
+    async fn fetch_book_snapshot(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<Option<BookSnapshot>, ExchangeError> {
+        let book = self.fetch_orderbook(symbol, kind).await?;
+        Ok(Some(BookSnapshot {
+            bids: book.bids,
+            asks: book.asks,
+            sequence: 0,
+        }))
+    }
+}
+
 #[async_trait]
 impl OrderBookExchange for BithumbClient {
     fn id(&self) -> ExchangeId {
         ExchangeId::Bithumb
     }
 
-    async fn fetch_orderbook(&self, symbol: &str) -> Result<OrderBook, ExchangeError> {
+    async fn fetch_orderbook(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<OrderBook, ExchangeError> {
         // Bithumb 공개 API: GET /public/orderbook/{order_currency}_{payment_currency}
         // 예: /public/orderbook/BTC_KRW
-        let normalized_symbol = self.normalize_symbol(symbol);
+        // Bithumb은 현물만 있으므로 선물 호가는 resolve_symbol 단계에서 걸러진다
+        let normalized_symbol = resolve_symbol(ExchangeId::Bithumb, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Bithumb symbol: {}", symbol))
+            })?;
         let endpoint = format!("/public/orderbook/{}", normalized_symbol);
-        let url = format!("{BASE_URL}{}", endpoint);
+        let url = format!("{}{}", self.endpoints.rest, endpoint);
 
         let response = self.http.get(&url).send().await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
-        if status != 200 {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb API HTTP error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+        if !status.is_success() {
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         let orderbook_response: BithumbOrderBookResponse = serde_json::from_str(&response_text)
@@ -69,10 +180,10 @@ impl OrderBookExchange for BithumbClient {
             })?;
 
         if orderbook_response.status != "0000" {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb API error: status {}",
-                orderbook_response.status
-            )));
+            return Err(map_error_code(
+                &orderbook_response.status,
+                &truncate_body(&response_text),
+            ));
         }
 
         let now = Utc::now();
@@ -83,9 +194,9 @@ impl OrderBookExchange for BithumbClient {
             .bids
             .into_iter()
             .filter_map(|entry| {
-                let price: f64 = entry.price.parse().ok()?;
-                let quantity: f64 = entry.quantity.parse().ok()?;
-                if price > 0.0 && quantity > 0.0 {
+                let price = parse_decimal(&entry.price)?;
+                let quantity = parse_decimal(&entry.quantity)?;
+                if price > Decimal::ZERO && quantity > Decimal::ZERO {
                     Some(OrderBookEntry { price, quantity })
                 } else {
                     None
@@ -99,9 +210,9 @@ impl OrderBookExchange for BithumbClient {
             .asks
             .into_iter()
             .filter_map(|entry| {
-                let price: f64 = entry.price.parse().ok()?;
-                let quantity: f64 = entry.quantity.parse().ok()?;
-                if price > 0.0 && quantity > 0.0 {
+                let price = parse_decimal(&entry.price)?;
+                let quantity = parse_decimal(&entry.quantity)?;
+                if price > Decimal::ZERO && quantity > Decimal::ZERO {
                     Some(OrderBookEntry { price, quantity })
                 } else {
                     None
@@ -110,20 +221,13 @@ impl OrderBookExchange for BithumbClient {
             .collect();
 
         // bids는 가격 내림차순, asks는 가격 오름차순으로 정렬
-        bids.sort_by(|a, b| {
-            b.price
-                .partial_cmp(&a.price)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        asks.sort_by(|a, b| {
-            a.price
-                .partial_cmp(&b.price)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        bids.sort_by_key(|e| std::cmp::Reverse(e.price));
+        asks.sort_by_key(|e| e.price);
 
         Ok(OrderBook {
             exchange: ExchangeId::Bithumb,
             symbol: symbol.to_string(),
+            kind,
             bids,
             asks,
             updated_at: now,
@@ -136,22 +240,46 @@ mod tests {
     use super::*;
 
     fn handle_api_error(e: &ExchangeError) {
-        if let ExchangeError::Other(msg) = e {
-            if msg.contains("Bithumb API error") {
-                panic!("Bithumb API error: {}", msg);
-            }
-            if msg.contains("not found") {
+        match e {
+            ExchangeError::ExchangeReject { .. } => panic!("Bithumb API error: {}", e),
+            ExchangeError::Auth(msg) if msg.contains("not found") => {
                 eprintln!("Warning: Environment variable not found: {:?}", e);
-                return;
             }
+            _ => eprintln!("Warning: API call failed: {:?}", e),
         }
-        eprintln!("Warning: API call failed: {:?}", e);
+    }
+
+    #[test]
+    fn test_parse_bithumb_depth_stream() {
+        let mut parse = depth_parser("BTC_KRW".to_string());
+        let msg = r#"{"type":"orderbookdepth","content":{"list":[{"symbol":"BTC_KRW","orderType":"ask","price":"10593000","quantity":"1.11223318","total":"3"},{"symbol":"BTC_KRW","orderType":"bid","price":"10592000","quantity":"0","total":"0"},{"symbol":"ETH_KRW","orderType":"bid","price":"300000","quantity":"1","total":"1"}],"datetime":"1580268255864325"}}"#;
+        let events = parse(msg);
+        let [BookEvent::Delta(delta)] = events.as_slice() else {
+            panic!("expected delta: {:?}", events);
+        };
+        assert_eq!(delta.first_sequence, 1);
+        assert_eq!(delta.asks.len(), 1);
+        // 다른 심볼은 제외, 수량 0은 삭제 표시로 유지
+        assert_eq!(delta.bids.len(), 1);
+        assert!(delta.bids[0].quantity.is_zero());
+
+        let events = parse(msg);
+        assert!(matches!(
+            events.as_slice(),
+            [BookEvent::Delta(BookDelta {
+                first_sequence: 2,
+                ..
+            })]
+        ));
+        assert!(parse(r#"{"status":"0000","resmsg":"Connected Successfully"}"#).is_empty());
     }
 
     #[tokio::test]
     async fn test_fetch_orderbook_bithumb() {
         let client = BithumbClient::new();
-        let result = client.fetch_orderbook("BTC-KRW").await;
+        let result = client
+            .fetch_orderbook("BTC-KRW", InstrumentKind::Spot)
+            .await;
 
         match result {
             Ok(orderbook) => {
@@ -178,13 +306,19 @@ mod tests {
 
                 // 모든 entry가 유효한지 확인
                 for bid in &orderbook.bids {
-                    assert!(bid.price > 0.0, "bid price should be positive");
-                    assert!(bid.quantity > 0.0, "bid quantity should be positive");
+                    assert!(bid.price > Decimal::ZERO, "bid price should be positive");
+                    assert!(
+                        bid.quantity > Decimal::ZERO,
+                        "bid quantity should be positive"
+                    );
                 }
 
                 for ask in &orderbook.asks {
-                    assert!(ask.price > 0.0, "ask price should be positive");
-                    assert!(ask.quantity > 0.0, "ask quantity should be positive");
+                    assert!(ask.price > Decimal::ZERO, "ask price should be positive");
+                    assert!(
+                        ask.quantity > Decimal::ZERO,
+                        "ask quantity should be positive"
+                    );
                 }
 
                 println!("\nOrderbook for {}:", orderbook.symbol);
@@ -206,4 +340,13 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_fetch_orderbook_bithumb_perp_unsupported() {
+        let client = BithumbClient::new();
+        let result = client
+            .fetch_orderbook("BTC-KRW", InstrumentKind::Perp)
+            .await;
+        assert!(matches!(result, Err(ExchangeError::Other(_))));
+    }
 }
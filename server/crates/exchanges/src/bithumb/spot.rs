@@ -4,10 +4,13 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use crate::{bithumb::BithumbClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
-
-const BASE_URL: &str = "https://api.bithumb.com";
+use crate::bithumb::{api_error, map_error_code, BithumbClient};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, ExchangeError,
+    SpotExchange,
+};
+use interface::{Currency, Decimal, ExchangeId, InstrumentKind, SpotSnapshot};
 
 #[derive(Debug, Deserialize)]
 struct BithumbResponse {
@@ -31,14 +34,24 @@ impl SpotExchange for BithumbClient {
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
         // 빗썸은 원화(KRW) 거래쌍을 제공
-        let url = format!("{BASE_URL}/public/ticker/ALL_KRW");
-        let response: BithumbResponse = self.http.get(&url).send().await?.json().await?;
+        let url = format!("{}/public/ticker/ALL_KRW", self.endpoints.rest);
+        let response: BithumbResponse = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bithumb,
+                    EndpointClass::Spot,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if response.status != "0000" {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb API error: status {}",
-                response.status
-            )));
+            return Err(map_error_code(&response.status, ""));
         }
 
         let now = Utc::now();
@@ -56,17 +69,23 @@ impl SpotExchange for BithumbClient {
                 Err(_) => continue, // 파싱 실패 시 건너뛰기
             };
 
-            // 빗썸은 "BTC", "ETH" 형식이므로 "BTCUSDT"로 변환
-            // 빗썸은 원화 거래쌍이지만, 통일성을 위해 USDT 형식으로 변환
-            let symbol_usdt = format!("{}USDT", symbol);
+            // 빗썸은 "BTC", "ETH" 형식의 원화 거래쌍 -> "BTC_KRW"
+            let instrument = match parse_native_symbol(
+                ExchangeId::Bithumb,
+                InstrumentKind::Spot,
+                &format!("{}_KRW", symbol),
+            ) {
+                Some(i) => i,
+                None => continue,
+            };
 
-            let price: f64 = match ticker.closing_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let price = match parse_decimal(&ticker.closing_price) {
+                Some(v) => v,
+                None => continue,
             };
 
             // price가 0보다 큰 경우만 추가
-            if price <= 0.0 {
+            if price <= Decimal::ZERO {
                 continue;
             }
 
@@ -79,7 +98,8 @@ impl SpotExchange for BithumbClient {
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Bithumb,
-                symbol: symbol_usdt,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::KRW, // 빗썸은 원화 거래쌍
                 price,
                 vol_24h_usd,
@@ -114,16 +134,16 @@ mod tests {
                 // 모든 스냅샷이 Bithumb 거래소인지 확인
                 for snapshot in &snapshots {
                     assert_eq!(snapshot.exchange, ExchangeId::Bithumb);
-                    assert!(snapshot.symbol.ends_with("USDT"));
-                    assert!(snapshot.price > 0.0);
+                    assert!(snapshot.symbol.ends_with("KRW"));
+                    assert!(snapshot.price > Decimal::ZERO);
                     assert!(snapshot.vol_24h_usd >= 0.0);
                 }
 
-                // 심볼이 올바른지 확인 (예: BTCUSDT)
-                let btc_snapshot = snapshots.iter().find(|s| s.symbol == "BTCUSDT");
+                // 심볼이 올바른지 확인 (예: BTCKRW)
+                let btc_snapshot = snapshots.iter().find(|s| s.symbol == "BTCKRW");
                 if let Some(btc) = btc_snapshot {
                     println!(
-                        "Found BTCUSDT spot snapshot: price={}, vol_24h_usd={}",
+                        "Found BTCKRW spot snapshot: price={}, vol_24h_usd={}",
                         btc.price, btc.vol_24h_usd
                     );
                 }
@@ -131,10 +151,8 @@ mod tests {
             Err(e) => {
                 // 네트워크 오류 등은 테스트 실패로 간주하지 않음
                 // 하지만 API 오류는 확인
-                if let ExchangeError::Other(msg) = &e {
-                    if msg.contains("Bithumb API error") {
-                        panic!("Bithumb API error: {}", msg);
-                    }
+                if let ExchangeError::ExchangeReject { .. } = &e {
+                    panic!("Bithumb API error: {}", e);
                 }
                 // 네트워크 오류는 테스트 환경에 따라 실패할 수 있으므로 경고만
                 eprintln!("Warning: fetch_all failed: {:?}", e);
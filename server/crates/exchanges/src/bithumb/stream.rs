@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::bithumb::BithumbClient;
+use crate::stream::{stream_forever, WsSubscription};
+use crate::{
+    decimal::parse_decimal, instrument::parse_native_symbol, ExchangeError, SpotExchange,
+    SpotStream,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, SpotTick};
+
+#[derive(Debug, Deserialize)]
+struct BithumbWsMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    content: BithumbWsTicker,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BithumbWsTicker {
+    symbol: String, // "BTC_KRW"
+    #[serde(default)]
+    tick_type: String,
+    close_price: Option<String>,
+    value: Option<String>, // 누적 거래금액 (원화)
+}
+
+fn parse_spot_message(text: &str) -> Vec<SpotTick> {
+    // 접속/구독 응답({"status":"0000","resmsg":"..."})은 무시
+    let Ok(msg) = serde_json::from_str::<BithumbWsMessage>(text) else {
+        return Vec::new();
+    };
+    if msg.kind != "ticker" || msg.content.tick_type != "24H" {
+        return Vec::new();
+    }
+    let ticker = msg.content;
+    let Some(instrument) =
+        parse_native_symbol(ExchangeId::Bithumb, InstrumentKind::Spot, &ticker.symbol)
+    else {
+        return Vec::new();
+    };
+
+    let mut tick = SpotTick::new(instrument, Currency::KRW);
+    tick.price = ticker
+        .close_price
+        .as_deref()
+        .and_then(parse_decimal)
+        .filter(|p| !p.is_zero());
+    // REST와 같이 원화 거래량을 대략 1 USD = 1300 KRW로 환산
+    tick.vol_24h_usd = ticker
+        .value
+        .and_then(|v| v.parse::<f64>().ok())
+        .map(|krw| krw / 1300.0);
+    vec![tick]
+}
+
+#[async_trait]
+impl SpotStream for BithumbClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bithumb
+    }
+
+    async fn stream_spots(&self, tx: mpsc::Sender<SpotTick>) -> Result<(), ExchangeError> {
+        // 구독 메시지 하나에 전체 심볼을 담는다
+        stream_forever("Bithumb spot", tx, || async {
+            let symbols: Vec<String> = SpotExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            let subscription = WsSubscription {
+                url: self.endpoints.public_ws.clone(),
+                messages: vec![json!({
+                    "type": "ticker",
+                    "symbols": symbols,
+                    "tickTypes": ["24H"],
+                })
+                .to_string()],
+                ping: None,
+            };
+            Ok((subscription, parse_spot_message))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bithumb_spot_stream() {
+        let msg = r#"{"type":"ticker","content":{"tickType":"24H","date":"20240101","time":"121530","openPrice":"88000000","closePrice":"90000000","lowPrice":"87000000","highPrice":"91000000","value":"130000000000","volume":"1500.5","symbol":"BTC_KRW","chgRate":"2.2"}}"#;
+        let ticks = parse_spot_message(msg);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].currency, Currency::KRW);
+        assert_eq!(ticks[0].instrument.base, "BTC");
+        assert_eq!(ticks[0].price, Some(Decimal::from(90_000_000)));
+        assert_eq!(ticks[0].vol_24h_usd, Some(100_000_000.0));
+
+        assert!(
+            parse_spot_message(r#"{"status":"0000","resmsg":"Connected Successfully"}"#).is_empty()
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use interface::{
+    Decimal, ExchangeId, TransferDirection, TransferRecord, TransferStatus, WithdrawRequest,
+};
+
+use super::super::decimal::parse_decimal;
+use super::super::error::retry_after;
+use super::super::rate_limit::{EndpointClass, RateLimiter};
+use super::super::{ExchangeError, TransferExchange};
+use super::{api_error, generate_jwt_token_with_query, BithumbClient};
+
+/// /v1/withdraw, /v1/withdraws/coin, /v1/deposits 공통 항목
+#[derive(Debug, Deserialize)]
+struct BithumbTransfer {
+    uuid: String,
+    currency: String,
+    #[serde(default)]
+    net_type: Option<String>,
+    #[serde(default)]
+    txid: Option<String>,
+    state: String,
+    created_at: String, // "2024-05-01T12:00:00+09:00"
+    amount: String,
+    #[serde(default)]
+    fee: Option<String>,
+}
+
+/// 출금 state: WAITING, PROCESSING, DONE, FAILED, CANCELED, REJECTED
+/// 입금 state: PROCESSING, ACCEPTED, CANCELLED, REJECTED, TRAVEL_RULE_SUSPECTED, REFUNDING, REFUNDED
+fn transfer_status(state: &str) -> TransferStatus {
+    match state {
+        "DONE" | "ACCEPTED" => TransferStatus::Completed,
+        "CANCELED" | "CANCELLED" => TransferStatus::Cancelled,
+        "FAILED" | "REJECTED" | "REFUNDING" | "REFUNDED" => TransferStatus::Failed,
+        "PROCESSING" => TransferStatus::Processing,
+        // 트래블룰 검증 대기 등 사용자 조치가 필요한 상태 포함
+        _ => TransferStatus::Pending,
+    }
+}
+
+fn to_record(item: BithumbTransfer, direction: TransferDirection) -> TransferRecord {
+    TransferRecord {
+        exchange: ExchangeId::Bithumb,
+        direction,
+        id: item.uuid,
+        network: item.net_type.unwrap_or_else(|| item.currency.clone()),
+        currency: item.currency,
+        address: String::new(), // 조회 응답에는 주소가 없다
+        memo: None,
+        amount: parse_decimal(&item.amount).unwrap_or(Decimal::ZERO),
+        fee: item.fee.as_deref().and_then(parse_decimal),
+        txid: item.txid.filter(|t| !t.is_empty()),
+        status: transfer_status(&item.state),
+        created_at: DateTime::parse_from_rfc3339(&item.created_at)
+            .map(|t| t.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now()),
+        client_id: None,
+    }
+}
+
+/// query_hash 대상 문자열 ("key=value&...", BTreeMap이라 키 이름 순. POST JSON 본문도 같은 순서로 직렬화된다)
+fn to_query_string(params: &BTreeMap<String, String>) -> String {
+    params
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(body: &str, what: &str) -> Result<T, ExchangeError> {
+    serde_json::from_str(body).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to parse Bithumb {}: {}, response: {}",
+            what,
+            e,
+            body.chars().take(200).collect::<String>()
+        ))
+    })
+}
+
+impl BithumbClient {
+    /// JWT(query_hash 포함) 인증 요청 전송 후 본문 반환
+    /// GET은 params를 쿼리 문자열로, POST는 JSON 본문으로 보낸다.
+    async fn signed_v1_request(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: &BTreeMap<String, String>,
+    ) -> Result<String, ExchangeError> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| {
+            ExchangeError::Auth(
+                "API key not set. Use BithumbClient::with_credentials()".to_string(),
+            )
+        })?;
+        let api_secret = self.api_secret.as_ref().ok_or_else(|| {
+            ExchangeError::Auth(
+                "API secret not set. Use BithumbClient::with_credentials()".to_string(),
+            )
+        })?;
+
+        let query = to_query_string(params);
+        let jwt_token = generate_jwt_token_with_query(api_key, api_secret, &query)?;
+        let url = format!("{}{}", self.endpoints.rest, endpoint);
+
+        let request = if method == reqwest::Method::GET {
+            self.http.get(format!("{}?{}", url, query))
+        } else {
+            self.http.request(method, &url).json(params)
+        };
+
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Bithumb,
+                EndpointClass::Spot,
+                1,
+                request
+                    .header("Authorization", format!("Bearer {}", jwt_token))
+                    .header("Content-Type", "application/json; charset=utf-8"),
+            )
+            .await?;
+
+        let status = response.status();
+        let retry_after = retry_after(response.headers());
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(api_error(status, retry_after, &response_text));
+        }
+        Ok(response_text)
+    }
+}
+
+#[async_trait]
+impl TransferExchange for BithumbClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bithumb
+    }
+
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<TransferRecord, ExchangeError> {
+        // POST /v1/withdraws/coin (출금 주소는 빗썸에 미리 등록된 주소만 허용됨)
+        let mut params: BTreeMap<String, String> = request.extra.clone();
+        params.insert("currency".to_string(), request.currency.clone());
+        params.insert("net_type".to_string(), request.network.clone());
+        params.insert("amount".to_string(), request.amount.normalize().to_string());
+        params.insert("address".to_string(), request.address.clone());
+        if let Some(memo) = &request.memo {
+            params.insert("secondary_address".to_string(), memo.clone());
+        }
+
+        let body = self
+            .signed_v1_request(reqwest::Method::POST, "/v1/withdraws/coin", &params)
+            .await?;
+        let item: BithumbTransfer = parse_body(&body, "withdraw response")?;
+
+        let mut record = to_record(item, TransferDirection::Withdrawal);
+        record.address = request.address.clone();
+        record.memo = request.memo.clone();
+        // 빗썸은 클라이언트 출금 ID를 받지 않지만 신청 전에 저장한 기록과 잇기 위해 남긴다
+        record.client_id = request.client_id.clone();
+        Ok(record)
+    }
+
+    async fn fetch_withdrawal(&self, id: &str) -> Result<Option<TransferRecord>, ExchangeError> {
+        // GET /v1/withdraw?uuid=...
+        let params = BTreeMap::from([("uuid".to_string(), id.to_string())]);
+        let body = match self
+            .signed_v1_request(reqwest::Method::GET, "/v1/withdraw", &params)
+            .await
+        {
+            Ok(body) => body,
+            // 신청 직후 아직 조회되지 않는 출금 (예: withdraw_not_found)
+            Err(ExchangeError::ExchangeReject { code, .. }) if code.ends_with("not_found") => {
+                return Ok(None)
+            }
+            Err(e) => return Err(e),
+        };
+        let item: BithumbTransfer = parse_body(&body, "withdraw")?;
+
+        Ok(Some(to_record(item, TransferDirection::Withdrawal)))
+    }
+
+    async fn fetch_deposits(
+        &self,
+        currency: &str,
+        start: DateTime<Utc>,
+    ) -> Result<Vec<TransferRecord>, ExchangeError> {
+        // GET /v1/deposits?currency=...&limit=100 (최신순, 시작 시각 필터는 없어서 받아서 거른다)
+        let params = BTreeMap::from([
+            ("currency".to_string(), currency.to_string()),
+            ("limit".to_string(), "100".to_string()),
+        ]);
+        let body = self
+            .signed_v1_request(reqwest::Method::GET, "/v1/deposits", &params)
+            .await?;
+        let items: Vec<BithumbTransfer> = parse_body(&body, "deposits")?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| to_record(item, TransferDirection::Deposit))
+            .filter(|record| record.created_at >= start)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bithumb_transfers() {
+        let body = r#"{"type":"withdraw","uuid":"9f432943-54e0-40b7-825f-b6fec8b42b79","currency":"XRP","net_type":"XRP","txid":null,"state":"PROCESSING","created_at":"2024-05-01T12:00:00+09:00","done_at":null,"amount":"100.0","fee":"0.4","transaction_type":"default"}"#;
+        let item: BithumbTransfer = parse_body(body, "withdraw").unwrap();
+        let record = to_record(item, TransferDirection::Withdrawal);
+        assert_eq!(record.status, TransferStatus::Processing);
+        assert_eq!(record.fee, Some(Decimal::new(4, 1)));
+        assert_eq!(record.txid, None);
+        assert_eq!(record.created_at.timestamp(), 1714532400);
+
+        let body = r#"[{"type":"deposit","uuid":"94332e99-3a87-4a35-ad98-28b0c969f830","currency":"USDT","net_type":"TRX","txid":"abc","state":"ACCEPTED","created_at":"2024-05-02T09:00:00+09:00","done_at":"2024-05-02T09:05:00+09:00","amount":"500.0","fee":"0.0","transaction_type":"default"}]"#;
+        let items: Vec<BithumbTransfer> = parse_body(body, "deposits").unwrap();
+        let record = to_record(
+            items.into_iter().next().unwrap(),
+            TransferDirection::Deposit,
+        );
+        assert_eq!(record.status, TransferStatus::Completed);
+        assert_eq!(record.network, "TRX");
+        assert_eq!(
+            transfer_status("TRAVEL_RULE_SUSPECTED"),
+            TransferStatus::Pending
+        );
+    }
+}
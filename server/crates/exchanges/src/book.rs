@@ -0,0 +1,529 @@
+//! WebSocket 변경분으로 메모리에 유지하는 L2 호가
+//!
+//! 거래소별 `OrderBookStream` 구현은 스냅샷/변경분(`BookEvent`)만 보내고,
+//! 순서 검증(update ID 연속성), REST 스냅샷과의 동기화, 누락 시 재동기화는
+//! `OrderBookManager`가 맡는다. 전략은 `OrderBookManager::book`으로 REST 왕복 없이
+//! 현재 호가와 시퀀스 번호, 경과 시간을 읽는다.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use tokio::{
+    sync::{mpsc, RwLock},
+    task::JoinHandle,
+};
+use tracing::warn;
+
+use interface::{Decimal, ExchangeError, ExchangeId, InstrumentKind, OrderBook, OrderBookEntry};
+
+use crate::{instrument::resolve_symbol, OrderBookStream};
+
+/// 전략에 제공하는 호가 깊이 (REST fetch_orderbook과 같은 100 레벨)
+pub const PUBLISHED_DEPTH: usize = 100;
+/// 스냅샷을 기다리는 동안 쌓아둘 최대 변경분 수
+const MAX_BUFFERED_DELTAS: usize = 1000;
+/// 스트림 변경분 채널 크기
+const BOOK_CHANNEL_CAPACITY: usize = 1024;
+/// 누락 감지 후 다시 구독하기까지 대기 시간 (REST 스냅샷 요청 제한 회피)
+const RESYNC_DELAY: Duration = Duration::from_secs(3);
+
+/// 호가 전체 스냅샷 (sequence는 거래소의 update ID)
+#[derive(Debug, Clone)]
+pub struct BookSnapshot {
+    pub bids: Vec<OrderBookEntry>,
+    pub asks: Vec<OrderBookEntry>,
+    pub sequence: u64,
+}
+
+/// 호가 변경분 (수량 0은 해당 가격 레벨 삭제)
+/// first_sequence..=last_sequence 범위의 update를 담고, 직전 변경분의 last_sequence를
+/// 알려주는 거래소(Binance 선물 pu, OKX prevSeqId)는 prev_sequence로 연속성을 확인한다.
+#[derive(Debug, Clone)]
+pub struct BookDelta {
+    pub bids: Vec<OrderBookEntry>,
+    pub asks: Vec<OrderBookEntry>,
+    pub first_sequence: u64,
+    pub last_sequence: u64,
+    pub prev_sequence: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum BookEvent {
+    /// 새 연결 시작 (이전 연결의 상태는 버리고 스냅샷부터 다시 맞춘다)
+    Reset,
+    Snapshot(BookSnapshot),
+    Delta(BookDelta),
+}
+
+/// 메모리에서 읽은 호가 (book.updated_at은 마지막 변경분을 반영한 시각)
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    pub book: OrderBook,
+    pub sequence: u64,
+}
+
+impl LocalOrderBook {
+    /// 마지막 변경분 이후 경과 시간
+    pub fn age(&self) -> chrono::Duration {
+        Utc::now() - self.book.updated_at
+    }
+}
+
+/// 연결마다 첫 메시지 앞에 BookEvent::Reset을 붙이는 파서 래퍼
+pub(crate) fn with_reset<P>(mut parse: P) -> impl FnMut(&str) -> Vec<BookEvent> + Send
+where
+    P: FnMut(&str) -> Vec<BookEvent> + Send,
+{
+    let mut first = true;
+    move |text| {
+        let mut events = parse(text);
+        if std::mem::take(&mut first) {
+            events.insert(0, BookEvent::Reset);
+        }
+        events
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SyncState {
+    /// 스냅샷 대기 중 (변경분은 버퍼에 쌓는다)
+    #[default]
+    Waiting,
+    /// REST 스냅샷 직후, 스냅샷에 걸친 첫 변경분을 찾는 중
+    Bridging,
+    Live,
+}
+
+/// 호가 한 개의 동기화 상태 머신
+#[derive(Debug, Default)]
+struct BookSync {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    sequence: u64,
+    state: SyncState,
+    buffer: Vec<BookDelta>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl BookSync {
+    /// 스트림 이벤트 반영 (호가가 바뀌었으면 true, 순서가 어긋나면 에러)
+    fn on_event(&mut self, event: BookEvent) -> Result<bool, ExchangeError> {
+        match event {
+            BookEvent::Reset => {
+                *self = Self::default();
+                Ok(false)
+            }
+            BookEvent::Snapshot(snapshot) => {
+                // 스트림이 주는 스냅샷은 그 자체로 최신이므로 쌓인 변경분은 버린다
+                self.buffer.clear();
+                self.replace(snapshot, SyncState::Live);
+                Ok(true)
+            }
+            BookEvent::Delta(delta) if self.state == SyncState::Waiting => {
+                if self.buffer.len() >= MAX_BUFFERED_DELTAS {
+                    return Err(ExchangeError::Other(
+                        "orderbook snapshot did not arrive in time".to_string(),
+                    ));
+                }
+                self.buffer.push(delta);
+                Ok(false)
+            }
+            BookEvent::Delta(delta) => self.apply_delta(delta),
+        }
+    }
+
+    /// REST 스냅샷 반영 후 버퍼에 쌓인 변경분을 순서대로 이어 붙인다
+    fn on_snapshot(&mut self, snapshot: BookSnapshot) -> Result<bool, ExchangeError> {
+        self.replace(snapshot, SyncState::Bridging);
+        for delta in std::mem::take(&mut self.buffer) {
+            self.apply_delta(delta)?;
+        }
+        Ok(true)
+    }
+
+    fn replace(&mut self, snapshot: BookSnapshot, state: SyncState) {
+        self.bids.clear();
+        self.asks.clear();
+        apply_levels(&mut self.bids, &snapshot.bids);
+        apply_levels(&mut self.asks, &snapshot.asks);
+        self.sequence = snapshot.sequence;
+        self.state = state;
+        self.updated_at = Some(Utc::now());
+    }
+
+    fn apply_delta(&mut self, delta: BookDelta) -> Result<bool, ExchangeError> {
+        let continuous = match (self.state, delta.prev_sequence) {
+            // 스냅샷에 이미 포함된 변경분
+            (SyncState::Bridging, _) if delta.last_sequence <= self.sequence => return Ok(false),
+            // 스냅샷 직후 첫 변경분은 스냅샷 시퀀스를 걸치기만 하면 된다
+            (SyncState::Bridging, _) => delta.first_sequence <= self.sequence + 1,
+            // OKX: 호가 변화가 없으면 seqId == prevSeqId인 빈 변경분을 보낸다
+            (_, Some(prev)) if prev == self.sequence && delta.last_sequence == prev => {
+                return Ok(false)
+            }
+            // 시퀀스가 뒤로 가면 (OKX 점검 후 seqId 초기화 등) 누락과 같이 다시 맞춘다
+            (_, Some(prev)) => prev == self.sequence && delta.last_sequence > prev,
+            (_, None) => {
+                delta.first_sequence == self.sequence + 1 && delta.last_sequence > self.sequence
+            }
+        };
+        if !continuous {
+            return Err(ExchangeError::Other(format!(
+                "orderbook sequence gap: local {}, update {}..={} (prev {:?})",
+                self.sequence, delta.first_sequence, delta.last_sequence, delta.prev_sequence
+            )));
+        }
+
+        apply_levels(&mut self.bids, &delta.bids);
+        apply_levels(&mut self.asks, &delta.asks);
+        self.sequence = delta.last_sequence;
+        self.state = SyncState::Live;
+        self.updated_at = Some(Utc::now());
+        Ok(true)
+    }
+
+    /// 상위 depth 레벨로 OrderBook 생성 (동기화 전이면 None)
+    fn view(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+        kind: InstrumentKind,
+        depth: usize,
+    ) -> Option<LocalOrderBook> {
+        if self.state == SyncState::Waiting {
+            return None;
+        }
+        let entry = |(price, quantity): (&Decimal, &Decimal)| OrderBookEntry {
+            price: *price,
+            quantity: *quantity,
+        };
+        Some(LocalOrderBook {
+            book: OrderBook {
+                exchange,
+                symbol: symbol.to_string(),
+                kind,
+                bids: self.bids.iter().rev().take(depth).map(entry).collect(),
+                asks: self.asks.iter().take(depth).map(entry).collect(),
+                updated_at: self.updated_at?,
+            },
+            sequence: self.sequence,
+        })
+    }
+}
+
+fn apply_levels(side: &mut BTreeMap<Decimal, Decimal>, levels: &[OrderBookEntry]) {
+    for level in levels {
+        if level.quantity.is_zero() {
+            side.remove(&level.price);
+        } else {
+            side.insert(level.price, level.quantity);
+        }
+    }
+}
+
+/// (거래소, 상품 종류, 거래소 원본 심볼)
+type BookKey = (ExchangeId, InstrumentKind, String);
+type SharedBooks = Arc<RwLock<HashMap<BookKey, LocalOrderBook>>>;
+
+/// 구독한 심볼들의 호가를 메모리에 유지
+#[derive(Default)]
+pub struct OrderBookManager {
+    books: SharedBooks,
+    tasks: Mutex<HashMap<BookKey, JoinHandle<()>>>,
+}
+
+impl OrderBookManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 호가 구독 시작 (이미 구독 중이면 무시)
+    /// symbol은 fetch_orderbook과 같이 "BTC-USDT", "BTCUSDT" 등 아무 표기나 받는다.
+    pub fn watch(
+        &self,
+        client: Arc<dyn OrderBookStream>,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<(), ExchangeError> {
+        let key = book_key(client.id(), kind, symbol)?;
+        let mut tasks = self.tasks.lock().unwrap();
+        if tasks.get(&key).is_some_and(|task| !task.is_finished()) {
+            return Ok(());
+        }
+        let handle = tokio::spawn(run_book(client, key.clone(), self.books.clone()));
+        tasks.insert(key, handle);
+        Ok(())
+    }
+
+    /// 호가 구독 중지
+    pub async fn unwatch(&self, exchange: ExchangeId, symbol: &str, kind: InstrumentKind) {
+        let Ok(key) = book_key(exchange, kind, symbol) else {
+            return;
+        };
+        let task = self.tasks.lock().unwrap().remove(&key);
+        if let Some(task) = task {
+            task.abort();
+        }
+        self.books.write().await.remove(&key);
+    }
+
+    /// 현재 호가 (동기화되지 않았거나 구독하지 않은 심볼은 None)
+    pub async fn book(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Option<LocalOrderBook> {
+        let key = book_key(exchange, kind, symbol).ok()?;
+        self.books.read().await.get(&key).cloned()
+    }
+}
+
+impl Drop for OrderBookManager {
+    fn drop(&mut self) {
+        if let Ok(tasks) = self.tasks.get_mut() {
+            for task in tasks.values() {
+                task.abort();
+            }
+        }
+    }
+}
+
+fn book_key(
+    exchange: ExchangeId,
+    kind: InstrumentKind,
+    symbol: &str,
+) -> Result<BookKey, ExchangeError> {
+    let native = resolve_symbol(exchange, kind, symbol)
+        .map(|i| i.native_symbol)
+        .ok_or_else(|| {
+            ExchangeError::Other(format!("Unsupported {:?} symbol: {}", exchange, symbol))
+        })?;
+    Ok((exchange, kind, native))
+}
+
+/// 호가 하나를 유지하는 루프
+/// 순서가 어긋나면 구독을 통째로 끊고 다시 구독해 스냅샷부터 맞춘다.
+async fn run_book(client: Arc<dyn OrderBookStream>, key: BookKey, books: SharedBooks) {
+    let (exchange, kind, symbol) = key.clone();
+
+    loop {
+        let (tx, mut rx) = mpsc::channel(BOOK_CHANNEL_CAPACITY);
+        let stream = {
+            let client = client.clone();
+            let symbol = symbol.clone();
+            tokio::spawn(async move { client.stream_book_events(&symbol, kind, tx).await })
+        };
+        let mut sync = BookSync::default();
+        let mut snapshot_task: Option<JoinHandle<Result<Option<BookSnapshot>, ExchangeError>>> =
+            None;
+
+        let error = loop {
+            let changed = tokio::select! {
+                event = rx.recv() => {
+                    let Some(event) = event else {
+                        break ExchangeError::Other("orderbook stream ended".to_string());
+                    };
+                    if matches!(event, BookEvent::Reset) {
+                        // 새 연결: 이전 호가는 더 이상 믿을 수 없으므로 내리고 스냅샷을 다시 받는다
+                        books.write().await.remove(&key);
+                        if let Some(task) = snapshot_task.take() {
+                            task.abort();
+                        }
+                        let client = client.clone();
+                        let symbol = symbol.clone();
+                        snapshot_task = Some(tokio::spawn(async move {
+                            client.fetch_book_snapshot(&symbol, kind).await
+                        }));
+                    }
+                    sync.on_event(event)
+                }
+                result = async { snapshot_task.as_mut().unwrap().await }, if snapshot_task.is_some() => {
+                    snapshot_task = None;
+                    match result {
+                        Ok(Ok(Some(snapshot))) => sync.on_snapshot(snapshot),
+                        // 스트림이 직접 스냅샷을 주는 거래소
+                        Ok(Ok(None)) => Ok(false),
+                        Ok(Err(e)) => Err(e),
+                        Err(e) => Err(ExchangeError::Other(format!(
+                            "orderbook snapshot task failed: {}",
+                            e
+                        ))),
+                    }
+                }
+            };
+
+            match changed {
+                Ok(true) => {
+                    if let Some(view) = sync.view(exchange, &symbol, kind, PUBLISHED_DEPTH) {
+                        books.write().await.insert(key.clone(), view);
+                    }
+                }
+                Ok(false) => {}
+                Err(e) => break e,
+            }
+        };
+
+        stream.abort();
+        if let Some(task) = snapshot_task {
+            task.abort();
+        }
+        books.write().await.remove(&key);
+        warn!(
+            "{:?} {:?} {} 호가 재동기화: {}",
+            exchange, kind, symbol, error
+        );
+        tokio::time::sleep(RESYNC_DELAY).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: i64, quantity: i64) -> OrderBookEntry {
+        OrderBookEntry {
+            price: Decimal::from(price),
+            quantity: Decimal::from(quantity),
+        }
+    }
+
+    fn delta(first: u64, last: u64, prev: Option<u64>, bids: Vec<OrderBookEntry>) -> BookEvent {
+        BookEvent::Delta(BookDelta {
+            bids,
+            asks: Vec::new(),
+            first_sequence: first,
+            last_sequence: last,
+            prev_sequence: prev,
+        })
+    }
+
+    fn best_bid(sync: &BookSync) -> Option<(Decimal, Decimal)> {
+        sync.bids.iter().next_back().map(|(p, q)| (*p, *q))
+    }
+
+    #[test]
+    fn test_rest_snapshot_bridging() {
+        // Binance 현물: 스냅샷 전에 받은 변경분은 버퍼에 쌓았다가 lastUpdateId 이후만 반영
+        let mut sync = BookSync::default();
+        sync.on_event(BookEvent::Reset).unwrap();
+        assert!(!sync
+            .on_event(delta(95, 99, None, vec![level(100, 9)]))
+            .unwrap());
+        assert!(!sync
+            .on_event(delta(100, 104, None, vec![level(101, 1)]))
+            .unwrap());
+        assert!(sync
+            .view(ExchangeId::Binance, "BTCUSDT", InstrumentKind::Spot, 10)
+            .is_none());
+
+        let snapshot = BookSnapshot {
+            bids: vec![level(100, 5)],
+            asks: vec![level(102, 3)],
+            sequence: 101,
+        };
+        assert!(sync.on_snapshot(snapshot).unwrap());
+        assert_eq!(sync.sequence, 104);
+        assert_eq!(
+            best_bid(&sync),
+            Some((Decimal::from(101), Decimal::from(1)))
+        );
+        // 스냅샷 이전(95..=99) 변경분은 무시되어 100 레벨은 스냅샷 값 유지
+        assert_eq!(sync.bids[&Decimal::from(100)], Decimal::from(5));
+
+        // 이후로는 first == 직전 last + 1 이어야 한다
+        assert!(sync
+            .on_event(delta(105, 106, None, vec![level(101, 0)]))
+            .unwrap());
+        assert_eq!(
+            best_bid(&sync),
+            Some((Decimal::from(100), Decimal::from(5)))
+        );
+        assert!(sync.on_event(delta(108, 110, None, vec![])).is_err());
+
+        let view = sync
+            .view(ExchangeId::Binance, "BTCUSDT", InstrumentKind::Spot, 10)
+            .unwrap();
+        assert_eq!(view.sequence, 106);
+        assert_eq!(view.book.asks[0].price, Decimal::from(102));
+        assert!(view.age() >= chrono::Duration::zero());
+    }
+
+    #[test]
+    fn test_prev_sequence_and_stream_snapshot() {
+        // OKX/Bybit: 스트림이 스냅샷을 직접 보낸다
+        let mut sync = BookSync::default();
+        sync.on_event(BookEvent::Reset).unwrap();
+        let snapshot = BookSnapshot {
+            bids: vec![level(100, 1), level(99, 2)],
+            asks: vec![level(101, 1)],
+            sequence: 10,
+        };
+        assert!(sync.on_event(BookEvent::Snapshot(snapshot)).unwrap());
+        assert!(sync
+            .on_event(delta(15, 15, Some(10), vec![level(100, 3)]))
+            .unwrap());
+        assert_eq!(
+            best_bid(&sync),
+            Some((Decimal::from(100), Decimal::from(3)))
+        );
+        // 변화가 없을 때 오는 seqId == prevSeqId 변경분은 무시
+        assert!(!sync.on_event(delta(15, 15, Some(15), vec![])).unwrap());
+        // prevSeqId가 직전 seqId와 다르면 누락
+        assert!(sync.on_event(delta(20, 20, Some(16), vec![])).is_err());
+
+        // 새 스냅샷은 언제든 호가를 통째로 교체
+        let mut sync = BookSync::default();
+        sync.on_event(BookEvent::Snapshot(BookSnapshot {
+            bids: vec![level(1, 1)],
+            asks: vec![],
+            sequence: 500,
+        }))
+        .unwrap();
+        sync.on_event(BookEvent::Snapshot(BookSnapshot {
+            bids: vec![level(2, 1)],
+            asks: vec![],
+            sequence: 1,
+        }))
+        .unwrap();
+        assert_eq!(sync.bids.len(), 1);
+        assert_eq!(sync.sequence, 1);
+    }
+
+    #[test]
+    fn test_sequence_reset_resyncs() {
+        // OKX 점검 후에는 seqId가 prevSeqId보다 작아진다: 버리지 않고 재동기화해야 한다
+        let mut sync = BookSync::default();
+        sync.on_event(BookEvent::Snapshot(BookSnapshot {
+            bids: vec![level(100, 1)],
+            asks: vec![],
+            sequence: 1000,
+        }))
+        .unwrap();
+        assert!(sync
+            .on_event(delta(3, 3, Some(1000), vec![level(100, 2)]))
+            .is_err());
+
+        // prev_sequence가 없는 거래소도 이미 지난 시퀀스가 다시 오면 누락으로 본다
+        let mut sync = BookSync::default();
+        sync.on_snapshot(BookSnapshot {
+            bids: vec![level(100, 1)],
+            asks: vec![],
+            sequence: 50,
+        })
+        .unwrap();
+        assert!(sync.on_event(delta(51, 52, None, vec![])).unwrap());
+        assert!(sync.on_event(delta(1, 2, None, vec![])).is_err());
+    }
+
+    #[test]
+    fn test_with_reset() {
+        let mut parse = with_reset(|_: &str| Vec::new());
+        assert!(matches!(parse("{}").as_slice(), [BookEvent::Reset]));
+        assert!(parse("{}").is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use interface::{Decimal, ExchangeId, FutureAsset, SpotAsset};
+
+use crate::bybit::{api_error, generate_signature, map_error_code, RECV_WINDOW};
+use crate::{
+    decimal::parse_decimal, error::check_status, error::truncate_body, AssetExchange, BybitClient,
+    ExchangeError,
+};
+
+/// V5 공통 응답 (에러일 때 result가 빈 객체로 오므로 retCode 확인 후 변환)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitResponse {
+    ret_code: i64,
+    ret_msg: String,
+    #[serde(default)]
+    result: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitWalletBalanceResult {
+    list: Vec<BybitWalletAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitWalletAccount {
+    coin: Vec<BybitCoinBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitCoinBalance {
+    coin: String,
+    #[serde(default)]
+    wallet_balance: String,
+    #[serde(default)]
+    locked: String, // 현물 미체결 주문에 묶인 수량
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitPositionResult {
+    list: Vec<BybitPosition>,
+    #[serde(default)]
+    next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitPosition {
+    symbol: String,
+    #[serde(default)]
+    side: String, // "Buy", "Sell", 포지션이 없으면 ""
+    #[serde(default)]
+    size: String,
+    #[serde(default)]
+    avg_price: String,
+    #[serde(default)]
+    unrealised_pnl: String,
+    #[serde(default)]
+    leverage: String,
+    #[serde(default)]
+    liq_price: String,
+}
+
+impl BybitClient {
+    /// 서명이 필요한 V5 GET 요청
+    pub(crate) async fn signed_get<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query_string: &str,
+    ) -> Result<T, ExchangeError> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| {
+            ExchangeError::Auth("API key not set. Use BybitClient::with_credentials()".to_string())
+        })?;
+        let api_secret = self.api_secret.as_ref().ok_or_else(|| {
+            ExchangeError::Auth(
+                "API secret not set. Use BybitClient::with_credentials()".to_string(),
+            )
+        })?;
+
+        let timestamp = Utc::now().timestamp_millis() as u64;
+        let signature = generate_signature(timestamp, api_key, api_secret, query_string);
+        let url = format!("{}{}?{}", self.endpoints.rest, endpoint, query_string);
+
+        let response = self
+            .http
+            .get(&url)
+            .header("X-BAPI-API-KEY", api_key.as_str())
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", RECV_WINDOW)
+            .header("X-BAPI-SIGN", signature)
+            .send()
+            .await?;
+
+        let response_text = check_status(response, api_error).await?.text().await?;
+        parse_response(&response_text)
+    }
+}
+
+pub(crate) fn parse_response<T: DeserializeOwned>(response_text: &str) -> Result<T, ExchangeError> {
+    let response: BybitResponse = serde_json::from_str(response_text).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to parse Bybit response: {}, response: {}",
+            e,
+            truncate_body(response_text)
+        ))
+    })?;
+
+    if response.ret_code != 0 {
+        return Err(map_error_code(response.ret_code, &response.ret_msg));
+    }
+
+    serde_json::from_value(response.result).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to parse Bybit result: {}, response: {}",
+            e,
+            truncate_body(response_text)
+        ))
+    })
+}
+
+/// 포지션 하나를 FutureAsset으로 변환 (수량이 0이면 None)
+fn to_future_asset(pos: BybitPosition, now: chrono::DateTime<Utc>) -> Option<FutureAsset> {
+    let size = parse_decimal(&pos.size).unwrap_or_default();
+    if size.is_zero() {
+        return None;
+    }
+    // size는 항상 양수이고 방향은 side로 온다
+    let position_amt = if pos.side == "Sell" { -size } else { size };
+
+    Some(FutureAsset {
+        symbol: pos.symbol,
+        position_amt,
+        entry_price: parse_decimal(&pos.avg_price).unwrap_or_default(),
+        unrealized_pnl: parse_decimal(&pos.unrealised_pnl).unwrap_or_default(),
+        leverage: parse_decimal(&pos.leverage).unwrap_or_default(),
+        liquidation_price: parse_decimal(&pos.liq_price).filter(|v| *v > Decimal::ZERO),
+        updated_at: now,
+    })
+}
+
+#[async_trait]
+impl AssetExchange for BybitClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bybit
+    }
+
+    async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
+        // 통합 계정(UTA) 지갑 잔고: GET /v5/account/wallet-balance
+        let result: BybitWalletBalanceResult = self
+            .signed_get("/v5/account/wallet-balance", "accountType=UNIFIED")
+            .await?;
+
+        let now = Utc::now();
+        let mut assets = Vec::new();
+
+        for account in result.list {
+            for coin in account.coin {
+                let total = parse_decimal(&coin.wallet_balance).unwrap_or_default();
+                let locked = parse_decimal(&coin.locked).unwrap_or_default();
+
+                // 잔액이 0인 경우 스킵
+                if total > Decimal::ZERO {
+                    assets.push(SpotAsset {
+                        currency: coin.coin,
+                        total,
+                        available: (total - locked).max(Decimal::ZERO),
+                        in_use: locked,
+                        updated_at: now,
+                    });
+                }
+            }
+        }
+
+        Ok(assets)
+    }
+
+    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+        // USDT 무기한 포지션: GET /v5/position/list (한 페이지 최대 200개)
+        let now = Utc::now();
+        let mut result = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            let mut query_string = "category=linear&settleCoin=USDT&limit=200".to_string();
+            if !cursor.is_empty() {
+                query_string.push_str(&format!("&cursor={}", cursor));
+            }
+
+            let page: BybitPositionResult =
+                self.signed_get("/v5/position/list", &query_string).await?;
+
+            result.extend(
+                page.list
+                    .into_iter()
+                    .filter_map(|pos| to_future_asset(pos, now)),
+            );
+
+            if page.next_page_cursor.is_empty() || page.next_page_cursor == cursor {
+                break;
+            }
+            cursor = page.next_page_cursor;
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bybit::has_api_credentials;
+
+    #[test]
+    fn test_parse_bybit_positions() {
+        let body = r#"{"retCode":0,"retMsg":"OK","result":{"category":"linear","nextPageCursor":"","list":[
+            {"symbol":"BTCUSDT","side":"Sell","size":"0.01","avgPrice":"65000","unrealisedPnl":"-1.5","leverage":"10","liqPrice":"71000"},
+            {"symbol":"ETHUSDT","side":"","size":"0","avgPrice":"0","unrealisedPnl":"0","leverage":"10","liqPrice":""}
+        ]}}"#;
+        let page: BybitPositionResult = parse_response(body).unwrap();
+        let now = Utc::now();
+        let positions: Vec<_> = page
+            .list
+            .into_iter()
+            .filter_map(|p| to_future_asset(p, now))
+            .collect();
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].position_amt, Decimal::new(-1, 2));
+        assert_eq!(positions[0].liquidation_price, Some(Decimal::from(71000)));
+
+        let err = parse_response::<BybitPositionResult>(
+            r#"{"retCode":10003,"retMsg":"API key is invalid.","result":{}}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExchangeError::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_assets_bybit() {
+        if !has_api_credentials() {
+            println!("Skipping test: BYBIT_API_KEY and BYBIT_API_SECRET not set");
+            return;
+        }
+
+        let client = BybitClient::with_credentials().unwrap();
+        match client.fetch_spots().await {
+            Ok(assets) => {
+                for asset in assets.iter().take(5) {
+                    println!(
+                        "{} - Total: {}, Available: {}, In Use: {}",
+                        asset.currency, asset.total, asset.available, asset.in_use
+                    );
+                }
+            }
+            Err(e) => println!("기타 오류: {}", e),
+        }
+        match client.fetch_futures().await {
+            Ok(positions) => {
+                for pos in positions {
+                    println!(
+                        "{} - {} @ {}",
+                        pos.symbol, pos.position_amt, pos.entry_price
+                    );
+                }
+            }
+            Err(e) => println!("기타 오류: {}", e),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+
+use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, InstrumentKind, MarketType};
+
+use crate::{
+    decimal::parse_decimal, instrument::resolve_symbol, BybitClient, ExchangeError, FeeExchange,
+};
+
+#[derive(Debug, Deserialize)]
+struct BybitFeeRateResult {
+    list: Vec<BybitFeeRate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitFeeRate {
+    #[allow(dead_code)]
+    symbol: String,
+    maker_fee_rate: String,
+    taker_fee_rate: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitCoinInfoResult {
+    rows: Vec<BybitCoinInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitCoinInfo {
+    coin: String,
+    chains: Vec<BybitChainInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitChainInfo {
+    #[serde(default)]
+    withdraw_fee: String,
+    #[serde(default)]
+    chain_withdraw: String, // "1"이면 출금 가능
+}
+
+/// 수수료 응답을 FeeInfo로 변환
+fn to_fee_info(rate: &BybitFeeRate) -> Option<FeeInfo> {
+    Some(FeeInfo::new(
+        rate.maker_fee_rate.parse::<f64>().ok()?,
+        rate.taker_fee_rate.parse::<f64>().ok()?,
+    ))
+}
+
+/// 출금 가능한 체인 중 가장 싼 출금 수수료를 선택
+fn to_deposit_withdrawal_fee(info: BybitCoinInfo) -> Option<DepositWithdrawalFee> {
+    let withdrawal_fee = info
+        .chains
+        .iter()
+        .filter(|c| c.chain_withdraw == "1")
+        .filter_map(|c| parse_decimal(&c.withdraw_fee))
+        .min()?;
+
+    Some(DepositWithdrawalFee {
+        currency: info.coin.to_uppercase(),
+        deposit_fee: Default::default(), // Bybit은 입금 수수료가 없다
+        withdrawal_fee,
+        updated_at: Utc::now(),
+    })
+}
+
+#[async_trait]
+impl FeeExchange for BybitClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bybit
+    }
+
+    fn get_fee(&self, market_type: MarketType) -> FeeInfo {
+        match market_type {
+            MarketType::USDT => FeeInfo::new(0.001, 0.001), // 0.1% 메이커, 테이커 (VIP 0)
+            _ => FeeInfo::new(0.001, 0.001),                // 기본값: 0.1%
+        }
+    }
+
+    fn get_perp_fee(&self) -> FeeInfo {
+        FeeInfo::new(0.0002, 0.00055) // linear 0.02% 메이커, 0.055% 테이커 (VIP 0)
+    }
+
+    async fn fetch_trade_fee(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<FeeInfo, ExchangeError> {
+        let category = match kind {
+            InstrumentKind::Spot => "spot",
+            InstrumentKind::Perp => "linear",
+            InstrumentKind::Delivery => {
+                return Err(ExchangeError::NotImplemented(
+                    "Bybit delivery trade fee".to_string(),
+                ))
+            }
+        };
+        let normalized_symbol = resolve_symbol(ExchangeId::Bybit, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported Bybit symbol: {}", symbol)))?;
+
+        // 계정 등급이 반영된 심볼별 수수료: GET /v5/account/fee-rate
+        let result: BybitFeeRateResult = self
+            .signed_get(
+                "/v5/account/fee-rate",
+                &format!("category={}&symbol={}", category, normalized_symbol),
+            )
+            .await?;
+
+        result.list.first().and_then(to_fee_info).ok_or_else(|| {
+            ExchangeError::Other(format!("Missing Bybit fee rate for {}", normalized_symbol))
+        })
+    }
+
+    async fn get_deposit_withdrawal_fee(
+        &self,
+        currency: &str,
+    ) -> Result<DepositWithdrawalFee, ExchangeError> {
+        // 코인별 체인 정보: GET /v5/asset/coin/query-info
+        let result: BybitCoinInfoResult = self
+            .signed_get(
+                "/v5/asset/coin/query-info",
+                &format!("coin={}", currency.to_uppercase()),
+            )
+            .await?;
+
+        result
+            .rows
+            .into_iter()
+            .find(|row| row.coin.eq_ignore_ascii_case(currency))
+            .and_then(to_deposit_withdrawal_fee)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!(
+                    "Fee information not found for currency: {}",
+                    currency
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bybit::{asset::parse_response, has_api_credentials};
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bybit_fees() {
+        let body = r#"{"retCode":0,"retMsg":"OK","result":{"list":[
+            {"symbol":"BTCUSDT","takerFeeRate":"0.00055","makerFeeRate":"0.0002"}
+        ]}}"#;
+        let result: BybitFeeRateResult = parse_response(body).unwrap();
+        let fee = to_fee_info(&result.list[0]).unwrap();
+        assert_eq!(fee.maker, 0.0002);
+        assert_eq!(fee.taker, 0.00055);
+
+        let body = r#"{"retCode":0,"retMsg":"","result":{"rows":[{"coin":"USDT","chains":[
+            {"chain":"ETH","withdrawFee":"4","chainWithdraw":"1"},
+            {"chain":"TRX","withdrawFee":"1","chainWithdraw":"1"},
+            {"chain":"SOL","withdrawFee":"0.5","chainWithdraw":"0"}
+        ]}]}}"#;
+        let result: BybitCoinInfoResult = parse_response(body).unwrap();
+        let fee = to_deposit_withdrawal_fee(result.rows.into_iter().next().unwrap()).unwrap();
+        assert_eq!(fee.withdrawal_fee, Decimal::ONE);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_trade_fee_bybit() {
+        if !has_api_credentials() {
+            println!("Skipping test: BYBIT_API_KEY and BYBIT_API_SECRET not set");
+            return;
+        }
+
+        let client = BybitClient::with_credentials().unwrap();
+        for kind in [InstrumentKind::Spot, InstrumentKind::Perp] {
+            match client.fetch_trade_fee("BTC-USDT", kind).await {
+                Ok(fee) => println!(
+                    "{:?} BTCUSDT - maker: {}, taker: {}",
+                    kind, fee.maker, fee.taker
+                ),
+                Err(e) => println!("기타 오류: {}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+use interface::{ExchangeId, FundingRecord, InstrumentKind};
+
+use crate::bybit::{api_error, asset::parse_response};
+use crate::{
+    error::check_status, funding::finish_history, instrument::resolve_symbol, BybitClient,
+    ExchangeError, FundingHistoryExchange,
+};
+
+/// GET /v5/market/funding/history 한 번에 받을 수 있는 최대 개수
+const PAGE_LIMIT: usize = 200;
+
+#[derive(Debug, Deserialize)]
+struct BybitFundingHistoryResult {
+    list: Vec<BybitFundingRate>, // 최신 순
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitFundingRate {
+    symbol: String,
+    funding_rate: String,
+    funding_rate_timestamp: String,
+}
+
+/// 응답 하나를 FundingRecord로 변환 (주기는 finish_history에서 채운다)
+fn to_funding_record(rate: BybitFundingRate) -> Option<FundingRecord> {
+    Some(FundingRecord {
+        exchange: ExchangeId::Bybit,
+        symbol: rate.symbol,
+        funding_time: DateTime::from_timestamp_millis(rate.funding_rate_timestamp.parse().ok()?)?,
+        funding_rate: rate.funding_rate.parse().ok()?,
+        funding_interval_hours: 0,
+    })
+}
+
+#[async_trait]
+impl FundingHistoryExchange for BybitClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bybit
+    }
+
+    async fn fetch_funding_history(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<FundingRecord>, ExchangeError> {
+        let normalized_symbol = resolve_symbol(ExchangeId::Bybit, InstrumentKind::Perp, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported Bybit symbol: {}", symbol)))?;
+
+        // [start, cursor] 범위의 최신 기록부터 받아 과거로 넘어간다
+        // 주기 계산을 위해 limit보다 하나 더 받는다.
+        let mut records = Vec::new();
+        let mut cursor = end;
+        while records.len() <= limit && cursor >= start {
+            let url = format!(
+                "{}/v5/market/funding/history?category=linear&symbol={}&startTime={}&endTime={}&limit={}",
+                self.endpoints.rest,
+                normalized_symbol,
+                start.timestamp_millis(),
+                cursor.timestamp_millis(),
+                PAGE_LIMIT
+            );
+            let response_text = check_status(self.http.get(&url).send().await?, api_error)
+                .await?
+                .text()
+                .await?;
+            let page: BybitFundingHistoryResult = parse_response(&response_text)?;
+
+            let page_len = page.list.len();
+            let page: Vec<_> = page
+                .list
+                .into_iter()
+                .filter_map(to_funding_record)
+                .collect();
+            let Some(oldest) = page.iter().map(|r| r.funding_time).min() else {
+                break;
+            };
+            records.extend(page);
+
+            if page_len < PAGE_LIMIT {
+                break;
+            }
+            cursor = oldest - Duration::milliseconds(1);
+        }
+
+        Ok(finish_history(records, start, end, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bybit_funding_history() {
+        let body = r#"{"retCode":0,"retMsg":"OK","result":{"category":"linear","list":[
+            {"symbol":"BTCUSDT","fundingRate":"0.0001","fundingRateTimestamp":"1716883200000"},
+            {"symbol":"BTCUSDT","fundingRate":"-0.00005","fundingRateTimestamp":"1716854400000"}
+        ]}}"#;
+        let page: BybitFundingHistoryResult = parse_response(body).unwrap();
+        let records: Vec<_> = page
+            .list
+            .into_iter()
+            .filter_map(to_funding_record)
+            .collect();
+        let start = records[1].funding_time;
+        let records = finish_history(records, start, Utc::now(), 10);
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].funding_rate, -0.00005);
+        assert_eq!(records[1].funding_interval_hours, 8);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_funding_history_bybit() {
+        let client = BybitClient::new();
+        let end = Utc::now();
+
+        match client
+            .fetch_funding_history("BTC-USDT", end - Duration::days(7), end, 10)
+            .await
+        {
+            Ok(records) => {
+                assert!(records.len() <= 10);
+                assert!(records
+                    .windows(2)
+                    .all(|w| w[0].funding_time < w[1].funding_time));
+            }
+            Err(e) => {
+                // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                eprintln!("Warning: API call failed: {:?}", e);
+            }
+        }
+    }
+}
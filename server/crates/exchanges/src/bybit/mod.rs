@@ -1,4 +1,96 @@
+use std::env;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use interface::ExchangeError;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::error::{from_http_status, truncate_body};
+
+pub mod asset;
+pub mod fee;
+pub mod funding;
+pub mod kline;
+pub mod orderbook;
 pub mod perp;
 pub mod spot;
+pub mod stream;
 
 pub use perp::BybitClient;
+
+/// 서명 요청의 유효 시간 (밀리초)
+pub const RECV_WINDOW: &str = "5000";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Bybit V5 API 서명 생성
+/// payload: timestamp + api_key + recv_window + (GET은 쿼리 문자열, POST는 JSON 본문)
+pub fn generate_signature(
+    timestamp: u64,
+    api_key: &str,
+    api_secret: &str,
+    payload: &str,
+) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(api_secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{}{}{}{}", timestamp, api_key, RECV_WINDOW, payload).as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// 환경변수에서 API 키와 시크릿 가져오기
+pub fn get_api_credentials() -> Result<(String, String), ExchangeError> {
+    let api_key = env::var("BYBIT_API_KEY")
+        .map_err(|e| ExchangeError::Auth(format!("BYBIT_API_KEY not found: {}", e)))?;
+    let api_secret = env::var("BYBIT_API_SECRET")
+        .map_err(|e| ExchangeError::Auth(format!("BYBIT_API_SECRET not found: {}", e)))?;
+    Ok((api_key, api_secret))
+}
+
+/// 환경변수가 설정되어 있는지 확인
+pub fn has_api_credentials() -> bool {
+    env::var("BYBIT_API_KEY").is_ok() && env::var("BYBIT_API_SECRET").is_ok()
+}
+
+/// Bybit 에러 응답 본문 (예: {"retCode":10006,"retMsg":"Too many visits!"})
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitErrorResponse {
+    ret_code: i64,
+    ret_msg: String,
+}
+
+/// Bybit retCode를 ExchangeError로 변환
+pub fn map_error_code(code: i64, msg: &str) -> ExchangeError {
+    match code {
+        10006 | 10018 => ExchangeError::RateLimited { retry_after: None },
+        // 요청 시간이 recv_window를 벗어남
+        10002 => ExchangeError::Timeout(format!("{}: {}", code, msg)),
+        10003 | 10004 | 10005 | 10007 | 10010 => ExchangeError::Auth(format!("{}: {}", code, msg)),
+        110004 | 110007 | 110012 | 170131 => ExchangeError::InsufficientBalance(msg.to_string()),
+        170136 | 170137 | 170140 => ExchangeError::InvalidQuantity(msg.to_string()),
+        _ => ExchangeError::ExchangeReject {
+            code: code.to_string(),
+            msg: msg.to_string(),
+        },
+    }
+}
+
+/// 실패한 HTTP 응답을 ExchangeError로 변환
+pub fn api_error(status: StatusCode, retry_after: Option<Duration>, body: &str) -> ExchangeError {
+    // Bybit은 IP 요청 한도 초과 시 403을 반환한다
+    if matches!(status.as_u16(), 403 | 429) {
+        return ExchangeError::RateLimited { retry_after };
+    }
+    if let Ok(err) = serde_json::from_str::<BybitErrorResponse>(body) {
+        return map_error_code(err.ret_code, &err.ret_msg);
+    }
+    from_http_status(status, retry_after, body).unwrap_or_else(|| {
+        ExchangeError::Other(format!(
+            "Bybit API HTTP error: status {}, response: {}",
+            status,
+            truncate_body(body)
+        ))
+    })
+}
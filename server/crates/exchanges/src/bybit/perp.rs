@@ -1,23 +1,60 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use serde::Deserialize;
 
-use crate::{ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
-
-const BASE_URL: &str = "https://api.bybit.com";
+use crate::bybit::{api_error, asset::parse_response, map_error_code};
+use crate::endpoint::{BybitEndpoints, Endpoints};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, ExchangeError,
+    PerpExchange,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpSnapshot};
 
+/// Bybit 통합 클라이언트 (시세, Orderbook, Asset)
 #[derive(Clone)]
 pub struct BybitClient {
     pub(crate) http: reqwest::Client,
+    pub(crate) api_key: Option<String>,
+    pub(crate) api_secret: Option<String>,
+    pub(crate) endpoints: BybitEndpoints,
+}
+
+impl Default for BybitClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl BybitClient {
+    /// 공개 API만 사용하는 경우 (시세, Orderbook 등)
     pub fn new() -> Self {
+        Self::with_endpoints(Endpoints::current().bybit.clone())
+    }
+
+    /// 지정한 주소로 공개 API 클라이언트 생성 (테스트넷, 목 서버 등)
+    pub fn with_endpoints(endpoints: BybitEndpoints) -> Self {
         Self {
             http: reqwest::Client::new(),
+            api_key: None,
+            api_secret: None,
+            endpoints,
         }
     }
+
+    /// 인증이 필요한 API를 사용하는 경우 (Asset 등)
+    pub fn with_credentials() -> Result<Self, ExchangeError> {
+        let (api_key, api_secret) = super::get_api_credentials()?;
+        Ok(Self {
+            http: reqwest::Client::new(),
+            api_key: Some(api_key),
+            api_secret: Some(api_secret),
+            endpoints: Endpoints::current().bybit.clone(),
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -49,6 +86,69 @@ struct BybitTicker {
     turnover24h: String,
     #[serde(default)]
     next_funding_time: String,
+    #[serde(default)]
+    index_price: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitInstrumentsResult {
+    list: Vec<BybitInstrument>,
+    #[serde(default)]
+    next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitInstrument {
+    symbol: String,
+    #[serde(default)]
+    funding_interval: u32, // 분 단위
+}
+
+impl BybitClient {
+    /// 선형 무기한 심볼별 펀딩 주기 (시간), GET /v5/market/instruments-info (한 페이지 최대 1000개)
+    async fn fetch_funding_intervals(&self) -> Result<HashMap<String, u32>, ExchangeError> {
+        let mut intervals = HashMap::new();
+        let mut cursor = String::new();
+        loop {
+            let mut url = format!(
+                "{}/v5/market/instruments-info?category=linear&limit=1000",
+                self.endpoints.rest
+            );
+            if !cursor.is_empty() {
+                url.push_str(&format!("&cursor={}", cursor));
+            }
+            let text = check_status(
+                RateLimiter::global()
+                    .send(
+                        ExchangeId::Bybit,
+                        EndpointClass::Futures,
+                        1,
+                        self.http.get(&url),
+                    )
+                    .await?,
+                api_error,
+            )
+            .await?
+            .text()
+            .await?;
+            let page: BybitInstrumentsResult = parse_response(&text)?;
+
+            intervals.extend(
+                page.list
+                    .into_iter()
+                    .filter(|i| i.funding_interval >= 60)
+                    .map(|i| (i.symbol, i.funding_interval / 60)),
+            );
+
+            if page.next_page_cursor.is_empty() || page.next_page_cursor == cursor {
+                break;
+            }
+            cursor = page.next_page_cursor;
+        }
+        Ok(intervals)
+    }
 }
 
 #[async_trait]
@@ -58,33 +158,55 @@ impl PerpExchange for BybitClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
-        let url = format!("{BASE_URL}/v5/market/tickers?category=linear");
-        let response: BybitTickerResponse = self.http.get(&url).send().await?.json().await?;
+        let url = format!("{}/v5/market/tickers?category=linear", self.endpoints.rest);
+        let response: BybitTickerResponse = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bybit,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if response.ret_code != 0 {
-            return Err(ExchangeError::Other(format!(
-                "Bybit API error: {} - {}",
-                response.ret_code, response.ret_msg
-            )));
+            return Err(map_error_code(response.ret_code.into(), &response.ret_msg));
         }
 
+        // 펀딩 주기 (실패해도 시세는 내보내고 주기만 비워 둔다)
+        let funding_intervals = self
+            .fetch_funding_intervals()
+            .await
+            .map_err(|e| tracing::warn!("Bybit funding interval fetch failed: {}", e))
+            .unwrap_or_default();
+
         let now = Utc::now();
         let mut out = Vec::new();
 
         for ticker in response.result.list {
-            if !ticker.symbol.ends_with("USDT") {
-                continue; // 선형 USDT perp만
-            }
+            let instrument = match parse_native_symbol(
+                ExchangeId::Bybit,
+                InstrumentKind::Perp,
+                &ticker.symbol,
+            ) {
+                Some(i) if i.quote == "USDT" && i.kind == InstrumentKind::Perp => i,
+                _ => continue, // 선형 USDT perp만
+            };
 
-            let mark_price: f64 = match ticker.mark_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let mark_price = match parse_decimal(&ticker.mark_price) {
+                Some(v) => v,
+                None => continue,
             };
 
             let funding_rate: f64 = ticker.funding_rate.parse().unwrap_or(0.0);
 
             let oi_contracts: f64 = ticker.open_interest.parse().unwrap_or(0.0);
-            let oi_usd = oi_contracts * mark_price;
+            let oi_usd = oi_contracts * mark_price.to_f64().unwrap_or(0.0);
 
             let vol_24h_usd: f64 = ticker.turnover24h.parse().unwrap_or(0.0);
 
@@ -93,20 +215,24 @@ impl PerpExchange for BybitClient {
                     .next_funding_time
                     .parse::<i64>()
                     .ok()
-                    .and_then(|ts| DateTime::from_timestamp_millis(ts))
+                    .and_then(DateTime::from_timestamp_millis)
             } else {
                 None
             };
 
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Bybit,
-                symbol: ticker.symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 mark_price,
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours: funding_intervals.get(&ticker.symbol).copied(),
+                predicted_funding_rate: None,
+                index_price: parse_decimal(&ticker.index_price),
                 updated_at: now,
             });
         }
@@ -2,10 +2,13 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use crate::{BybitClient, ExchangeError, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
-
-const BASE_URL: &str = "https://api.bybit.com";
+use crate::bybit::{api_error, map_error_code};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, BybitClient,
+    ExchangeError, SpotExchange,
+};
+use interface::{Currency, Decimal, ExchangeId, InstrumentKind, SpotSnapshot};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -39,31 +42,46 @@ impl SpotExchange for BybitClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
-        let url = format!("{BASE_URL}/v5/market/tickers?category=spot");
-        let response: BybitSpotTickerResponse = self.http.get(&url).send().await?.json().await?;
+        let url = format!("{}/v5/market/tickers?category=spot", self.endpoints.rest);
+        let response: BybitSpotTickerResponse = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Bybit,
+                    EndpointClass::Spot,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if response.ret_code != 0 {
-            return Err(ExchangeError::Other(format!(
-                "Bybit API error (spot): {} - {}",
-                response.ret_code, response.ret_msg
-            )));
+            return Err(map_error_code(response.ret_code.into(), &response.ret_msg));
         }
 
         let now = Utc::now();
         let mut out = Vec::new();
 
         for ticker in response.result.list {
-            if !ticker.symbol.ends_with("USDT") {
-                continue; // USDT 페어만
-            }
+            let instrument = match parse_native_symbol(
+                ExchangeId::Bybit,
+                InstrumentKind::Spot,
+                &ticker.symbol,
+            ) {
+                Some(i) if i.quote == "USDT" => i,
+                _ => continue, // USDT 페어만
+            };
 
-            let price: f64 = match ticker.last_price.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let price = match parse_decimal(&ticker.last_price) {
+                Some(v) => v,
+                None => continue,
             };
 
             // price가 0보다 큰 경우만 추가
-            if price <= 0.0 {
+            if price <= Decimal::ZERO {
                 continue;
             }
 
@@ -71,7 +89,8 @@ impl SpotExchange for BybitClient {
 
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Bybit,
-                symbol: ticker.symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 price,
                 vol_24h_usd,
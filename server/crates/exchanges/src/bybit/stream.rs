@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::stream::{stream_forever, subscribe_messages, WsSubscription};
+use crate::{
+    decimal::parse_decimal, instrument::parse_native_symbol, BybitClient, ExchangeError,
+    PerpExchange, PerpStream, SpotExchange, SpotStream,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpTick, SpotTick};
+
+/// 현물은 구독 메시지 하나에 최대 10개 토픽까지 허용
+const TOPICS_PER_MESSAGE: usize = 10;
+const PING_MESSAGE: &str = r#"{"op":"ping"}"#;
+
+#[derive(Debug, Deserialize)]
+struct BybitTickerMessage {
+    topic: String,
+    data: BybitTickerData,
+}
+
+/// snapshot은 전체 필드, delta는 바뀐 필드만 온다
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitTickerData {
+    symbol: String,
+    last_price: Option<String>,
+    mark_price: Option<String>,
+    index_price: Option<String>,
+    funding_rate: Option<String>,
+    next_funding_time: Option<String>,
+    turnover24h: Option<String>,
+    open_interest_value: Option<String>,
+}
+
+fn parse_ticker(text: &str) -> Option<BybitTickerData> {
+    let msg: BybitTickerMessage = serde_json::from_str(text).ok()?;
+    msg.topic.starts_with("tickers.").then_some(msg.data)
+}
+
+fn parse_perp_message(text: &str) -> Vec<PerpTick> {
+    let Some(data) = parse_ticker(text) else {
+        return Vec::new();
+    };
+    let instrument =
+        match parse_native_symbol(ExchangeId::Bybit, InstrumentKind::Perp, &data.symbol) {
+            Some(i) if i.quote == "USDT" && i.kind == InstrumentKind::Perp => i,
+            _ => return Vec::new(), // 선형 USDT perp만
+        };
+
+    let mut tick = PerpTick::new(instrument, Currency::USDT);
+    tick.mark_price = data.mark_price.as_deref().and_then(parse_decimal);
+    tick.index_price = data.index_price.as_deref().and_then(parse_decimal);
+    tick.funding_rate = data.funding_rate.and_then(|v| v.parse().ok());
+    tick.next_funding_time = data
+        .next_funding_time
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|ms| *ms > 0)
+        .and_then(DateTime::from_timestamp_millis);
+    tick.vol_24h_usd = data.turnover24h.and_then(|v| v.parse().ok());
+    tick.oi_usd = data.open_interest_value.and_then(|v| v.parse().ok());
+    vec![tick]
+}
+
+fn parse_spot_message(text: &str) -> Vec<SpotTick> {
+    let Some(data) = parse_ticker(text) else {
+        return Vec::new();
+    };
+    let instrument =
+        match parse_native_symbol(ExchangeId::Bybit, InstrumentKind::Spot, &data.symbol) {
+            Some(i) if i.quote == "USDT" => i,
+            _ => return Vec::new(), // USDT 페어만
+        };
+
+    let mut tick = SpotTick::new(instrument, Currency::USDT);
+    tick.price = data
+        .last_price
+        .as_deref()
+        .and_then(parse_decimal)
+        .filter(|p| !p.is_zero());
+    tick.vol_24h_usd = data.turnover24h.and_then(|v| v.parse().ok());
+    vec![tick]
+}
+
+/// tickers.{symbol} 토픽 구독 메시지
+fn ticker_subscription(url: &str, symbols: &[String]) -> WsSubscription {
+    let topics: Vec<String> = symbols.iter().map(|s| format!("tickers.{}", s)).collect();
+    WsSubscription {
+        url: url.to_string(),
+        messages: subscribe_messages(
+            &topics,
+            TOPICS_PER_MESSAGE,
+            |args| json!({ "op": "subscribe", "args": args }),
+        ),
+        ping: Some(PING_MESSAGE.to_string()),
+    }
+}
+
+#[async_trait]
+impl PerpStream for BybitClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bybit
+    }
+
+    async fn stream_perps(&self, tx: mpsc::Sender<PerpTick>) -> Result<(), ExchangeError> {
+        // 전 종목 토픽이 없으므로 REST로 상장 심볼을 받아 각각 구독
+        stream_forever("Bybit perp", tx, || async {
+            let symbols: Vec<String> = PerpExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            Ok((
+                ticker_subscription(&self.endpoints.linear_ws, &symbols),
+                parse_perp_message,
+            ))
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl SpotStream for BybitClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Bybit
+    }
+
+    async fn stream_spots(&self, tx: mpsc::Sender<SpotTick>) -> Result<(), ExchangeError> {
+        stream_forever("Bybit spot", tx, || async {
+            let symbols: Vec<String> = SpotExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            Ok((
+                ticker_subscription(&self.endpoints.spot_ws, &symbols),
+                parse_spot_message,
+            ))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_bybit_perp_stream() {
+        let snapshot = r#"{"topic":"tickers.BTCUSDT","type":"snapshot","data":{"symbol":"BTCUSDT","lastPrice":"65001","markPrice":"65000.5","indexPrice":"64990.1","fundingRate":"0.0001","nextFundingTime":"1673280000000","turnover24h":"123456789.5","openInterestValue":"987654321"},"cs":1,"ts":1673272861686}"#;
+        let ticks = parse_perp_message(snapshot);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].mark_price, Some(Decimal::new(650005, 1)));
+        assert_eq!(ticks[0].index_price, Some(Decimal::new(649901, 1)));
+        assert_eq!(ticks[0].funding_rate, Some(0.0001));
+        assert_eq!(ticks[0].oi_usd, Some(987_654_321.0));
+        assert_eq!(ticks[0].vol_24h_usd, Some(123_456_789.5));
+        assert_eq!(
+            ticks[0].next_funding_time.unwrap().timestamp_millis(),
+            1673280000000
+        );
+
+        // delta는 바뀐 필드만 포함
+        let delta = r#"{"topic":"tickers.BTCUSDT","type":"delta","data":{"symbol":"BTCUSDT","markPrice":"65010"},"cs":2,"ts":1673272861786}"#;
+        let ticks = parse_perp_message(delta);
+        assert_eq!(ticks[0].mark_price, Some(Decimal::from(65010)));
+        assert_eq!(ticks[0].funding_rate, None);
+        assert_eq!(ticks[0].oi_usd, None);
+
+        let pong = r#"{"success":true,"ret_msg":"pong","conn_id":"abc","op":"ping"}"#;
+        assert!(parse_perp_message(pong).is_empty());
+    }
+
+    #[test]
+    fn test_parse_bybit_spot_stream() {
+        let msg = r#"{"topic":"tickers.ETHUSDT","type":"snapshot","data":{"symbol":"ETHUSDT","lastPrice":"3000.5","turnover24h":"5000000"},"ts":1673853746003}"#;
+        let ticks = parse_spot_message(msg);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].instrument.native_symbol, "ETHUSDT");
+        assert_eq!(ticks[0].price, Some(Decimal::new(30005, 1)));
+        assert_eq!(ticks[0].vol_24h_usd, Some(5_000_000.0));
+    }
+
+    #[test]
+    fn test_bybit_ticker_subscription() {
+        let symbols: Vec<String> = (0..25).map(|i| format!("C{}USDT", i)).collect();
+        let sub = ticker_subscription("wss://stream.bybit.com/v5/public/spot", &symbols);
+        assert_eq!(sub.messages.len(), 3);
+        assert!(sub.messages[0].contains(r#""tickers.C0USDT""#));
+    }
+}
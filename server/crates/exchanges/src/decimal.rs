@@ -0,0 +1,87 @@
+//! 거래소 응답의 가격/수량 문자열을 Decimal로 변환하는 헬퍼
+
+use std::str::FromStr;
+
+use interface::{Decimal, ExchangeError, OrderBookEntry};
+
+/// "0.00100000", "1.2e-5" 형식 모두 지원 (f64를 거치지 않으므로 자릿수 손실 없음)
+pub fn parse_decimal(s: &str) -> Option<Decimal> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    Decimal::from_str(s)
+        .or_else(|_| Decimal::from_scientific(s))
+        .ok()
+}
+
+/// 호가 레벨 배열([가격, 수량, ...])을 OrderBookEntry로 변환
+/// 거래소마다 뒤에 붙는 필드(주문 수 등)는 무시하고, 수량이 0인 레벨은 제외한다.
+pub fn parse_book_levels(levels: &[Vec<String>]) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+    let mut out = parse_level_updates(levels)?;
+    out.retain(|e| e.quantity > Decimal::ZERO);
+    Ok(out)
+}
+
+/// 호가 변경분 레벨 배열 변환 (수량 0은 해당 가격 레벨 삭제를 뜻하므로 그대로 둔다)
+pub fn parse_level_updates(levels: &[Vec<String>]) -> Result<Vec<OrderBookEntry>, ExchangeError> {
+    let mut out = Vec::with_capacity(levels.len());
+    for level in levels {
+        let (price, quantity) = match level.as_slice() {
+            [price, quantity, ..] => (price, quantity),
+            _ => {
+                return Err(ExchangeError::Other(format!(
+                    "Invalid orderbook level: {:?}",
+                    level
+                )))
+            }
+        };
+        let price = parse_decimal(price).ok_or_else(|| {
+            ExchangeError::Other(format!("Failed to parse orderbook price: {}", price))
+        })?;
+        let quantity = parse_decimal(quantity).ok_or_else(|| {
+            ExchangeError::Other(format!("Failed to parse orderbook quantity: {}", quantity))
+        })?;
+        out.push(OrderBookEntry { price, quantity });
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("0.00100000"), Some(Decimal::new(1, 3)));
+        assert_eq!(parse_decimal("1.2e-5"), Some(Decimal::new(12, 6)));
+        assert_eq!(parse_decimal(""), None);
+        assert_eq!(parse_decimal("abc"), None);
+    }
+
+    #[test]
+    fn test_parse_book_levels() {
+        // OKX 형식: [가격, 수량, 폐기 필드, 주문 수]
+        let levels = vec![
+            vec![
+                "65000.1".to_string(),
+                "0.5".to_string(),
+                "0".to_string(),
+                "3".to_string(),
+            ],
+            vec!["64999.9".to_string(), "0".to_string()],
+        ];
+        let entries = parse_book_levels(&levels).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].price, Decimal::new(650001, 1));
+        assert_eq!(entries[0].quantity, Decimal::new(5, 1));
+
+        // 변경분은 삭제 표시(수량 0)를 유지
+        let updates = parse_level_updates(&levels).unwrap();
+        assert_eq!(updates.len(), 2);
+        assert!(updates[1].quantity.is_zero());
+
+        assert!(parse_book_levels(&[vec!["1".to_string()]]).is_err());
+        assert!(parse_book_levels(&[vec!["x".to_string(), "1".to_string()]]).is_err());
+    }
+}
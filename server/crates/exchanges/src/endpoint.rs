@@ -0,0 +1,272 @@
+//! 거래소 REST/WebSocket 주소 설정
+//!
+//! 모든 클라이언트는 `Endpoints::current()`의 주소로 만들어진다. 프로필은
+//! `ENDPOINT_PROFILE` 환경변수(mainnet | testnet | mock)로 고르며, 코드 수정 없이
+//! Binance 테스트넷이나 로컬 목 서버를 대상으로 전체 흐름을 돌릴 수 있다.
+
+use std::{env, fmt, str::FromStr, sync::OnceLock};
+
+use tracing::{info, warn};
+
+use interface::ExchangeError;
+
+/// mock 프로필에서 목 서버 주소를 지정하는 환경변수
+pub const MOCK_URL_ENV: &str = "MOCK_ENDPOINT_URL";
+/// 목 서버 기본 주소
+pub const DEFAULT_MOCK_URL: &str = "http://127.0.0.1:18090";
+
+static CURRENT: OnceLock<Endpoints> = OnceLock::new();
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointProfile {
+    #[default]
+    Mainnet,
+    /// Binance 현물/선물 테스트넷 (Bybit도 테스트넷, 테스트넷이 없는 거래소는 메인넷 공개 API)
+    Testnet,
+    /// 로컬 목 서버 (거래소별 경로 접두사 /binance, /bybit, ...)
+    Mock,
+}
+
+impl FromStr for EndpointProfile {
+    type Err = ExchangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "mainnet" | "" => Ok(Self::Mainnet),
+            "testnet" => Ok(Self::Testnet),
+            "mock" | "local" => Ok(Self::Mock),
+            other => Err(ExchangeError::Other(format!(
+                "Unknown endpoint profile: {} (expected mainnet, testnet or mock)",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for EndpointProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Mainnet => "mainnet",
+            Self::Testnet => "testnet",
+            Self::Mock => "mock",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Binance 주소 (WebSocket은 /ws, /stream 경로를 붙이기 전의 호스트)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinanceEndpoints {
+    pub spot_rest: String,
+    /// 지갑/수수료 등 /sapi 엔드포인트 (테스트넷에는 없다)
+    pub sapi_rest: String,
+    pub futures_rest: String,
+    pub spot_ws: String,
+    pub futures_ws: String,
+    /// WebSocket API (User Data Stream 구독)
+    pub ws_api: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BybitEndpoints {
+    pub rest: String,
+    pub linear_ws: String,
+    pub spot_ws: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OkxEndpoints {
+    pub rest: String,
+    pub public_ws: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitgetEndpoints {
+    pub rest: String,
+    pub public_ws: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BithumbEndpoints {
+    pub rest: String,
+    pub public_ws: String,
+}
+
+/// 거래소별 주소 모음
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoints {
+    pub profile: EndpointProfile,
+    pub binance: BinanceEndpoints,
+    pub bybit: BybitEndpoints,
+    pub okx: OkxEndpoints,
+    pub bitget: BitgetEndpoints,
+    pub bithumb: BithumbEndpoints,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self::mainnet()
+    }
+}
+
+impl Endpoints {
+    pub fn mainnet() -> Self {
+        Self {
+            profile: EndpointProfile::Mainnet,
+            binance: BinanceEndpoints {
+                spot_rest: "https://api.binance.com".to_string(),
+                sapi_rest: "https://api.binance.com".to_string(),
+                futures_rest: "https://fapi.binance.com".to_string(),
+                spot_ws: "wss://stream.binance.com:9443".to_string(),
+                futures_ws: "wss://fstream.binance.com".to_string(),
+                ws_api: "wss://ws-api.binance.com/ws-api/v3".to_string(),
+            },
+            bybit: BybitEndpoints {
+                rest: "https://api.bybit.com".to_string(),
+                linear_ws: "wss://stream.bybit.com/v5/public/linear".to_string(),
+                spot_ws: "wss://stream.bybit.com/v5/public/spot".to_string(),
+            },
+            okx: OkxEndpoints {
+                rest: "https://www.okx.com".to_string(),
+                public_ws: "wss://ws.okx.com:8443/ws/v5/public".to_string(),
+            },
+            bitget: BitgetEndpoints {
+                rest: "https://api.bitget.com".to_string(),
+                public_ws: "wss://ws.bitget.com/v2/ws/public".to_string(),
+            },
+            bithumb: BithumbEndpoints {
+                rest: "https://api.bithumb.com".to_string(),
+                public_ws: "wss://pubwss.bithumb.com/pub/ws".to_string(),
+            },
+        }
+    }
+
+    /// Binance 현물/선물 테스트넷
+    /// /sapi는 테스트넷이 없으므로 현물 테스트넷으로 보내 실패하게 둔다 (실계좌 호출 방지).
+    pub fn testnet() -> Self {
+        let mainnet = Self::mainnet();
+        Self {
+            profile: EndpointProfile::Testnet,
+            binance: BinanceEndpoints {
+                spot_rest: "https://testnet.binance.vision".to_string(),
+                sapi_rest: "https://testnet.binance.vision".to_string(),
+                futures_rest: "https://testnet.binancefuture.com".to_string(),
+                spot_ws: "wss://stream.testnet.binance.vision".to_string(),
+                futures_ws: "wss://fstream.binancefuture.com".to_string(),
+                ws_api: "wss://ws-api.testnet.binance.vision/ws-api/v3".to_string(),
+            },
+            bybit: BybitEndpoints {
+                rest: "https://api-testnet.bybit.com".to_string(),
+                linear_ws: "wss://stream-testnet.bybit.com/v5/public/linear".to_string(),
+                spot_ws: "wss://stream-testnet.bybit.com/v5/public/spot".to_string(),
+            },
+            ..mainnet
+        }
+    }
+
+    /// 로컬 목 서버 (예: "http://127.0.0.1:18090")
+    /// 거래소 경로가 겹치지 않도록 /binance, /bybit 같은 접두사를 붙인다.
+    pub fn mock(base_url: &str) -> Self {
+        let rest = base_url.trim_end_matches('/');
+        let ws = match rest.split_once("://") {
+            Some(("https", host)) => format!("wss://{}", host),
+            Some((_, host)) => format!("ws://{}", host),
+            None => format!("ws://{}", rest),
+        };
+        Self {
+            profile: EndpointProfile::Mock,
+            binance: BinanceEndpoints {
+                spot_rest: format!("{rest}/binance"),
+                sapi_rest: format!("{rest}/binance"),
+                futures_rest: format!("{rest}/binance"),
+                spot_ws: format!("{ws}/binance"),
+                futures_ws: format!("{ws}/binance-futures"),
+                ws_api: format!("{ws}/binance/ws-api/v3"),
+            },
+            bybit: BybitEndpoints {
+                rest: format!("{rest}/bybit"),
+                linear_ws: format!("{ws}/bybit/v5/public/linear"),
+                spot_ws: format!("{ws}/bybit/v5/public/spot"),
+            },
+            okx: OkxEndpoints {
+                rest: format!("{rest}/okx"),
+                public_ws: format!("{ws}/okx/ws/v5/public"),
+            },
+            bitget: BitgetEndpoints {
+                rest: format!("{rest}/bitget"),
+                public_ws: format!("{ws}/bitget/v2/ws/public"),
+            },
+            bithumb: BithumbEndpoints {
+                rest: format!("{rest}/bithumb"),
+                public_ws: format!("{ws}/bithumb/pub/ws"),
+            },
+        }
+    }
+
+    /// 프로필 이름으로 생성 (mock은 MOCK_ENDPOINT_URL 또는 기본 주소)
+    pub fn for_profile(profile: EndpointProfile) -> Self {
+        match profile {
+            EndpointProfile::Mainnet => Self::mainnet(),
+            EndpointProfile::Testnet => Self::testnet(),
+            EndpointProfile::Mock => {
+                let url = env::var(MOCK_URL_ENV).unwrap_or_else(|_| DEFAULT_MOCK_URL.to_string());
+                Self::mock(&url)
+            }
+        }
+    }
+
+    /// ENDPOINT_PROFILE 환경변수로 생성 (없으면 mainnet)
+    pub fn from_env() -> Result<Self, ExchangeError> {
+        let profile = match env::var("ENDPOINT_PROFILE") {
+            Ok(value) => value.parse()?,
+            Err(_) => EndpointProfile::Mainnet,
+        };
+        Ok(Self::for_profile(profile))
+    }
+
+    /// 프로세스 전체에서 쓰는 주소 설정 (처음 호출할 때 환경변수로 정해진다)
+    pub fn current() -> &'static Endpoints {
+        CURRENT.get_or_init(|| {
+            let endpoints = Self::from_env().unwrap_or_else(|e| {
+                warn!("{} - mainnet 주소를 사용합니다", e);
+                Self::mainnet()
+            });
+            info!("거래소 주소 프로필: {}", endpoints.profile);
+            endpoints
+        })
+    }
+
+    /// 클라이언트를 만들기 전에 주소 설정을 직접 지정 (이미 정해졌으면 에러)
+    pub fn init(endpoints: Endpoints) -> Result<(), ExchangeError> {
+        CURRENT
+            .set(endpoints)
+            .map_err(|_| ExchangeError::Other("Endpoints are already initialized".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_profiles() {
+        assert_eq!(
+            "TESTNET".parse::<EndpointProfile>().unwrap(),
+            EndpointProfile::Testnet
+        );
+        assert!("staging".parse::<EndpointProfile>().is_err());
+
+        let testnet = Endpoints::for_profile(EndpointProfile::Testnet);
+        assert_eq!(
+            testnet.binance.futures_rest,
+            "https://testnet.binancefuture.com"
+        );
+        // 테스트넷이 없는 거래소는 메인넷 공개 API
+        assert_eq!(testnet.okx, Endpoints::mainnet().okx);
+
+        let mock = Endpoints::mock("http://localhost:9000/");
+        assert_eq!(mock.binance.spot_rest, "http://localhost:9000/binance");
+        assert_eq!(mock.binance.spot_ws, "ws://localhost:9000/binance");
+        assert_eq!(mock.okx.public_ws, "ws://localhost:9000/okx/ws/v5/public");
+    }
+}
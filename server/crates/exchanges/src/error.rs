@@ -0,0 +1,83 @@
+//! 거래소 공통 에러 변환 헬퍼
+//!
+//! 거래소별 고유 에러 코드 매핑은 각 거래소 모듈(`binance::map_error_code` 등)에 있고,
+//! 여기에는 HTTP 상태 코드/헤더처럼 거래소와 무관한 부분만 둔다.
+
+use std::time::Duration;
+
+use interface::ExchangeError;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Response, StatusCode};
+
+/// 거래소별 HTTP 실패 응답 변환 함수 (`binance::api_error` 등)
+pub type ApiErrorMapper = fn(StatusCode, Option<Duration>, &str) -> ExchangeError;
+
+/// 에러 메시지에 포함할 응답 본문 (앞 200자)
+pub fn truncate_body(body: &str) -> String {
+    body.chars().take(200).collect()
+}
+
+/// Retry-After 헤더 (초 단위) 파싱
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// 거래소 고유 코드로 분류할 수 없을 때 HTTP 상태 코드만으로 에러 분류
+pub fn from_http_status(
+    status: StatusCode,
+    retry_after: Option<Duration>,
+    body: &str,
+) -> Option<ExchangeError> {
+    let msg = format!("status {}, response: {}", status, truncate_body(body));
+    match status.as_u16() {
+        // Binance는 반복적으로 429를 무시하면 418(IP 밴)을 반환한다
+        418 | 429 => Some(ExchangeError::RateLimited { retry_after }),
+        401 | 403 => Some(ExchangeError::Auth(msg)),
+        408 | 504 => Some(ExchangeError::Timeout(msg)),
+        _ => None,
+    }
+}
+
+/// 실패 상태 코드면 본문을 읽어 거래소별 에러로 변환하고, 성공이면 응답을 그대로 돌려준다
+pub async fn check_status(
+    response: Response,
+    map_error: ApiErrorMapper,
+) -> Result<Response, ExchangeError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let retry_after = retry_after(response.headers());
+    let body = response.text().await.unwrap_or_default();
+    Err(map_error(status, retry_after, &body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_from_http_status() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        let retry = retry_after(&headers);
+        assert_eq!(retry, Some(Duration::from_secs(7)));
+
+        match from_http_status(StatusCode::TOO_MANY_REQUESTS, retry, "") {
+            Some(ExchangeError::RateLimited { retry_after }) => {
+                assert_eq!(retry_after, Some(Duration::from_secs(7)))
+            }
+            other => panic!("unexpected: {:?}", other),
+        }
+        assert!(matches!(
+            from_http_status(StatusCode::UNAUTHORIZED, None, "{}"),
+            Some(ExchangeError::Auth(_))
+        ));
+        assert!(from_http_status(StatusCode::BAD_REQUEST, None, "{}").is_none());
+    }
+}
@@ -1,67 +1,447 @@
-use chrono::Utc;
-use eyre::Result;
+//! USD/KRW, USDT/USD, USDT/KRW 환율 수집
+//!
+//! 환율마다 여러 소스를 동시에 조회해 중앙값으로 합치고, 중앙값에서 크게 벗어난
+//! 값은 버린다. 소스끼리 어긋나 남는 값이 없으면(소스 2개가 4% 넘게 차이 나는 경우 등)
+//! 마지막 집계 값에 가장 가까운 시세를 쓰고 `degraded`를 켠다. 모든 소스가 실패하면
+//! 마지막 집계 값을 유지하되, 오래되었거나 집계한 적이 없으면 `is_fallback`을 켜서
+//! 호출자가 알 수 있게 한다.
+
+use std::{sync::Mutex, time::Duration};
+
+use chrono::{DateTime, Utc};
+use eyre::{eyre, Result};
+use futures::future::{join_all, BoxFuture, FutureExt};
 use serde::Deserialize;
-use tracing::info;
+use tracing::{info, warn};
+
+use interface::{ExchangeId, ExchangeRateSources, ExchangeRates};
 
-use interface::ExchangeRates;
+use crate::endpoint::Endpoints;
+use crate::rate_limit::{EndpointClass, RateLimiter};
 
 const EXCHANGE_RATE_API_URL: &str = "https://api.exchangerate-api.com/v4/latest/USD";
-const BITHUMB_API_URL: &str = "https://api.bithumb.com/public/ticker/USDT_KRW";
+const OPEN_ER_API_URL: &str = "https://open.er-api.com/v6/latest/USD";
+const FRANKFURTER_URL: &str = "https://api.frankfurter.app/latest?from=USD&to=KRW";
+const CURRENCY_API_URL: &str =
+    "https://cdn.jsdelivr.net/npm/@fawazahmed0/currency-api@latest/v1/currencies/usd.json";
+const UPBIT_TICKER_URL: &str = "https://api.upbit.com/v1/ticker?markets=KRW-USDT";
+const COINONE_TICKER_URL: &str = "https://api.coinone.co.kr/public/v2/ticker_new/KRW/USDT";
+const COINBASE_PRICE_URL: &str = "https://api.coinbase.com/v2/prices/USDT-USD/spot";
+const KRAKEN_TICKER_URL: &str = "https://api.kraken.com/0/public/Ticker?pair=USDTUSD";
+
 const FALLBACK_USD_KRW: f64 = 1300.0; // 대략 1 USD = 1300 KRW
 const FALLBACK_USDT_USD: f64 = 1.0; // USDT는 보통 USD와 1:1
 const FALLBACK_USDT_KRW: f64 = 1300.0; // 대략 1 USDT = 1300 KRW
 
+/// 소스 하나당 요청 제한 시간
+const SOURCE_TIMEOUT: Duration = Duration::from_secs(5);
+/// 중앙값에서 이 비율 이상 벗어난 값은 이상치로 버린다
+const MAX_DEVIATION: f64 = 0.02;
+/// 모든 소스가 실패해도 이 시간까지는 마지막 집계 값을 정상 값으로 본다
+const MAX_STALE: chrono::Duration = chrono::Duration::minutes(10);
+
+/// 환율별 허용 범위 (응답 단위가 바뀌거나 0이 오는 경우를 걸러낸다)
+const USD_KRW_RANGE: (f64, f64) = (500.0, 5000.0);
+const USDT_USD_RANGE: (f64, f64) = (0.5, 1.5);
+const USDT_KRW_RANGE: (f64, f64) = (500.0, 5000.0);
+
+/// 환율별 마지막 집계 결과 (usd_krw, usdt_usd, usdt_krw)
+static LAST_RATES: Mutex<Option<[RateQuote; 3]>> = Mutex::new(None);
+
+type SourceFuture = BoxFuture<'static, Result<f64>>;
+
+/// 환율 하나의 집계 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RateQuote {
+    value: f64,
+    sources: usize,
+    updated_at: DateTime<Utc>,
+    is_fallback: bool,
+    degraded: bool,
+}
+
+/// 허용 범위 안의 유효한 시세
+fn in_range(samples: &[f64], range: (f64, f64)) -> Vec<f64> {
+    samples
+        .iter()
+        .copied()
+        .filter(|v| v.is_finite() && *v >= range.0 && *v <= range.1)
+        .collect()
+}
+
+/// 범위를 벗어난 값과 이상치를 버린 중앙값, 사용한 시세 수
+fn median_without_outliers(samples: &[f64], range: (f64, f64)) -> Option<(f64, usize)> {
+    let valid = in_range(samples, range);
+    let center = median(valid.clone())?;
+    let kept: Vec<f64> = valid
+        .into_iter()
+        .filter(|v| ((v - center) / center).abs() <= MAX_DEVIATION)
+        .collect();
+    let count = kept.len();
+    Some((median(kept)?, count))
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    })
+}
+
+/// 새 시세로 집계하고, 시세가 없으면 마지막 값(오래되면 fallback 표시) 또는 기본값
+fn resolve(
+    samples: &[f64],
+    range: (f64, f64),
+    last: Option<RateQuote>,
+    default: f64,
+    now: DateTime<Utc>,
+) -> RateQuote {
+    if let Some((value, sources)) = median_without_outliers(samples, range) {
+        return RateQuote {
+            value,
+            sources,
+            updated_at: now,
+            is_fallback: false,
+            degraded: false,
+        };
+    }
+
+    // 시세는 있지만 서로 어긋나 모두 이상치로 빠진 경우
+    let valid = in_range(samples, range);
+    if let Some(center) = median(valid.clone()) {
+        return match last {
+            // 마지막 집계 값에 가장 가까운 시세를 쓴다
+            Some(last) => RateQuote {
+                value: valid
+                    .into_iter()
+                    .min_by(|a, b| (a - last.value).abs().total_cmp(&(b - last.value).abs()))
+                    .unwrap_or(center),
+                sources: 1,
+                updated_at: now,
+                is_fallback: false,
+                degraded: true,
+            },
+            // 기준이 없으면 어느 쪽이 맞는지 알 수 없으므로 중앙값을 쓰되 fallback 표시
+            None => RateQuote {
+                value: center,
+                sources: 0,
+                updated_at: now,
+                is_fallback: true,
+                degraded: true,
+            },
+        };
+    }
+
+    match last {
+        Some(last) => RateQuote {
+            sources: 0,
+            is_fallback: last.is_fallback || now - last.updated_at > MAX_STALE,
+            ..last
+        },
+        None => RateQuote {
+            value: default,
+            sources: 0,
+            updated_at: now,
+            is_fallback: true,
+            degraded: false,
+        },
+    }
+}
+
+/// 소스를 동시에 조회해 성공한 값만 모은다
+async fn collect_samples(pair: &str, sources: Vec<(&'static str, SourceFuture)>) -> Vec<f64> {
+    let (names, futures): (Vec<_>, Vec<_>) = sources.into_iter().unzip();
+    let results = join_all(
+        futures
+            .into_iter()
+            .map(|f| tokio::time::timeout(SOURCE_TIMEOUT, f)),
+    )
+    .await;
+
+    names
+        .into_iter()
+        .zip(results)
+        .filter_map(|(name, result)| match result {
+            Ok(Ok(value)) => Some(value),
+            Ok(Err(e)) => {
+                warn!("{} 환율 소스 {} 실패: {}", pair, name, e);
+                None
+            }
+            Err(_) => {
+                warn!("{} 환율 소스 {} 시간 초과", pair, name);
+                None
+            }
+        })
+        .collect()
+}
+
+fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(SOURCE_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
+
+// ---- USD/KRW ----
+
 #[derive(Debug, Deserialize)]
-struct ExchangeRateApiResponse {
-    rates: ExchangeRateRates,
+struct FxRatesResponse {
+    rates: FxRates,
 }
 
 #[derive(Debug, Deserialize)]
-struct ExchangeRateRates {
+struct FxRates {
     #[serde(rename = "KRW")]
     krw: Option<f64>,
 }
 
-/// USD/KRW 환율을 가져옵니다.
-/// 1 USD = ? KRW 형식으로 반환합니다.
-pub async fn fetch_usd_krw_rate() -> Result<f64> {
-    let client = reqwest::Client::new();
-    let response = client.get(EXCHANGE_RATE_API_URL).send().await?;
-    let data: ExchangeRateApiResponse = response.json().await?;
+#[derive(Debug, Deserialize)]
+struct CurrencyApiResponse {
+    usd: CurrencyApiRates,
+}
 
-    let usd_krw = data.rates.krw.unwrap_or(FALLBACK_USD_KRW);
+#[derive(Debug, Deserialize)]
+struct CurrencyApiRates {
+    krw: Option<f64>,
+}
+
+/// {"rates": {"KRW": ...}} 형식의 환율 API (exchangerate-api, open.er-api, frankfurter)
+async fn fetch_fx_rates_krw(client: reqwest::Client, url: &'static str) -> Result<f64> {
+    let data: FxRatesResponse = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    data.rates.krw.ok_or_else(|| eyre!("KRW rate missing"))
+}
+
+async fn fetch_currency_api_krw(client: reqwest::Client) -> Result<f64> {
+    let data: CurrencyApiResponse = client
+        .get(CURRENCY_API_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    data.usd.krw.ok_or_else(|| eyre!("KRW rate missing"))
+}
 
+fn usd_krw_sources(client: &reqwest::Client) -> Vec<(&'static str, SourceFuture)> {
+    vec![
+        (
+            "exchangerate-api",
+            fetch_fx_rates_krw(client.clone(), EXCHANGE_RATE_API_URL).boxed(),
+        ),
+        (
+            "open.er-api",
+            fetch_fx_rates_krw(client.clone(), OPEN_ER_API_URL).boxed(),
+        ),
+        (
+            "frankfurter",
+            fetch_fx_rates_krw(client.clone(), FRANKFURTER_URL).boxed(),
+        ),
+        (
+            "currency-api",
+            fetch_currency_api_krw(client.clone()).boxed(),
+        ),
+    ]
+}
+
+/// USD/KRW 환율을 가져옵니다.
+/// 1 USD = ? KRW 형식으로 반환합니다. (여러 환율 API의 중앙값)
+pub async fn fetch_usd_krw_rate() -> Result<f64> {
+    let samples = collect_samples("USD/KRW", usd_krw_sources(&http_client())).await;
+    let (usd_krw, sources) = median_without_outliers(&samples, USD_KRW_RANGE)
+        .ok_or_else(|| eyre!("No USD/KRW source available"))?;
     info!(
-        "USD/KRW 환율 가져오기 성공: {} (1 USD = {} KRW)",
-        usd_krw, usd_krw
+        "USD/KRW 환율 가져오기 성공: {} (1 USD = {} KRW, 소스 {}개)",
+        usd_krw, usd_krw, sources
     );
     Ok(usd_krw)
 }
 
+// ---- USDT/USD ----
+
 #[derive(Debug, Deserialize)]
 struct BinancePriceResponse {
     price: String,
 }
 
-/// USDT/USD 환율을 가져옵니다.
-/// Binance Spot에서 USDC/USDT 가격을 가져와서 역으로 계산합니다.
-pub async fn fetch_usdt_usd_rate() -> Result<f64> {
-    let client = reqwest::Client::new();
-    let url = "https://api.binance.com/api/v3/ticker/price?symbol=USDCUSDT";
+#[derive(Debug, Deserialize)]
+struct BybitTickerResponse {
+    result: BybitTickerList,
+}
 
-    let response = client.get(url).send().await?;
-    let data: BinancePriceResponse = response.json().await?;
-    let usdc_usdt = data.price.parse::<f64>()?;
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitTickerList {
+    list: Vec<BybitTicker>,
+}
 
-    // USDC/USDT 가격의 역수 = USDT/USD (USDC는 USD와 1:1)
-    let usdt_usd = 1.0 / usdc_usdt;
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitTicker {
+    last_price: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OkxTickerResponse {
+    data: Vec<OkxTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OkxTicker {
+    last: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinbasePriceResponse {
+    data: CoinbasePrice,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinbasePrice {
+    amount: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KrakenTickerResponse {
+    result: std::collections::HashMap<String, KrakenTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KrakenTicker {
+    /// 마지막 체결 [가격, 수량]
+    c: Vec<String>,
+}
+
+/// USDC/USDT 가격의 역수 = USDT/USD (USDC는 USD와 1:1)
+fn invert(usdc_usdt: f64) -> Result<f64> {
+    if usdc_usdt > 0.0 {
+        Ok(1.0 / usdc_usdt)
+    } else {
+        Err(eyre!("Invalid USDC/USDT price: {}", usdc_usdt))
+    }
+}
+
+async fn fetch_binance_usdt_usd(client: reqwest::Client) -> Result<f64> {
+    let url = format!(
+        "{}/api/v3/ticker/price?symbol=USDCUSDT",
+        Endpoints::current().binance.spot_rest
+    );
+    let data: BinancePriceResponse = RateLimiter::global()
+        .send(
+            ExchangeId::Binance,
+            EndpointClass::Spot,
+            2,
+            client.get(&url),
+        )
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    invert(data.price.parse()?)
+}
+
+async fn fetch_bybit_usdt_usd(client: reqwest::Client) -> Result<f64> {
+    let url = format!(
+        "{}/v5/market/tickers?category=spot&symbol=USDCUSDT",
+        Endpoints::current().bybit.rest
+    );
+    let data: BybitTickerResponse = RateLimiter::global()
+        .send(ExchangeId::Bybit, EndpointClass::Spot, 1, client.get(&url))
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let ticker = data
+        .result
+        .list
+        .first()
+        .ok_or_else(|| eyre!("USDCUSDT ticker missing"))?;
+    invert(ticker.last_price.parse()?)
+}
+
+async fn fetch_okx_usdt_usd(client: reqwest::Client) -> Result<f64> {
+    let url = format!(
+        "{}/api/v5/market/ticker?instId=USDC-USDT",
+        Endpoints::current().okx.rest
+    );
+    let data: OkxTickerResponse = RateLimiter::global()
+        .send(ExchangeId::Okx, EndpointClass::Spot, 1, client.get(&url))
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let ticker = data
+        .data
+        .first()
+        .ok_or_else(|| eyre!("USDC-USDT ticker missing"))?;
+    invert(ticker.last.parse()?)
+}
+
+async fn fetch_coinbase_usdt_usd(client: reqwest::Client) -> Result<f64> {
+    let data: CoinbasePriceResponse = client
+        .get(COINBASE_PRICE_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(data.data.amount.parse()?)
+}
+
+async fn fetch_kraken_usdt_usd(client: reqwest::Client) -> Result<f64> {
+    let data: KrakenTickerResponse = client
+        .get(KRAKEN_TICKER_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    // 페어 키가 "USDTZUSD"처럼 정규화되어 오므로 첫 항목을 쓴다
+    let price = data
+        .result
+        .values()
+        .next()
+        .and_then(|t| t.c.first())
+        .ok_or_else(|| eyre!("USDTUSD ticker missing"))?;
+    Ok(price.parse()?)
+}
+
+fn usdt_usd_sources(client: &reqwest::Client) -> Vec<(&'static str, SourceFuture)> {
+    vec![
+        ("binance", fetch_binance_usdt_usd(client.clone()).boxed()),
+        ("bybit", fetch_bybit_usdt_usd(client.clone()).boxed()),
+        ("okx", fetch_okx_usdt_usd(client.clone()).boxed()),
+        ("coinbase", fetch_coinbase_usdt_usd(client.clone()).boxed()),
+        ("kraken", fetch_kraken_usdt_usd(client.clone()).boxed()),
+    ]
+}
+
+/// USDT/USD 환율을 가져옵니다.
+/// 거래소 USDC/USDT 가격의 역수와 USDT/USD 직접 시세의 중앙값입니다.
+pub async fn fetch_usdt_usd_rate() -> Result<f64> {
+    let samples = collect_samples("USDT/USD", usdt_usd_sources(&http_client())).await;
+    let (usdt_usd, sources) = median_without_outliers(&samples, USDT_USD_RANGE)
+        .ok_or_else(|| eyre!("No USDT/USD source available"))?;
     info!(
-        "Binance에서 USDT/USD 환율 가져오기 성공: {} (USDC/USDT: {})",
-        usdt_usd, usdc_usdt
+        "USDT/USD 환율 가져오기 성공: {} (소스 {}개)",
+        usdt_usd, sources
     );
     Ok(usdt_usd)
 }
 
+// ---- USDT/KRW ----
+
 #[derive(Debug, Deserialize)]
 struct BithumbTickerResponse {
     status: String,
@@ -74,36 +454,273 @@ struct BithumbTickerData {
     closing_price: String,
 }
 
-/// USDT/KRW 환율을 가져옵니다.
-/// Bithumb에서 USDT/KRW 가격을 가져옵니다.
-/// 1 USDT = ? KRW 형식으로 반환합니다.
-pub async fn fetch_usdt_krw_rate() -> Result<f64> {
-    let client = reqwest::Client::new();
-    let response = client.get(BITHUMB_API_URL).send().await?;
-    let data: BithumbTickerResponse = response.json().await?;
+#[derive(Debug, Deserialize)]
+struct UpbitTicker {
+    trade_price: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CoinoneTickerResponse {
+    tickers: Vec<CoinoneTicker>,
+}
 
+#[derive(Debug, Deserialize)]
+struct CoinoneTicker {
+    last: String,
+}
+
+async fn fetch_bithumb_usdt_krw(client: reqwest::Client) -> Result<f64> {
+    let url = format!(
+        "{}/public/ticker/USDT_KRW",
+        Endpoints::current().bithumb.rest
+    );
+    let data: BithumbTickerResponse = RateLimiter::global()
+        .send(
+            ExchangeId::Bithumb,
+            EndpointClass::Spot,
+            1,
+            client.get(&url),
+        )
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
     if data.status != "0000" {
-        return Ok(FALLBACK_USDT_KRW);
+        return Err(eyre!("Bithumb status {}", data.status));
     }
+    Ok(data.data.closing_price.parse()?)
+}
+
+async fn fetch_upbit_usdt_krw(client: reqwest::Client) -> Result<f64> {
+    let data: Vec<UpbitTicker> = client
+        .get(UPBIT_TICKER_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    data.first()
+        .map(|t| t.trade_price)
+        .ok_or_else(|| eyre!("KRW-USDT ticker missing"))
+}
+
+async fn fetch_coinone_usdt_krw(client: reqwest::Client) -> Result<f64> {
+    let data: CoinoneTickerResponse = client
+        .get(COINONE_TICKER_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let ticker = data
+        .tickers
+        .first()
+        .ok_or_else(|| eyre!("USDT ticker missing"))?;
+    Ok(ticker.last.parse()?)
+}
 
-    let usdt_krw = data.data.closing_price.parse::<f64>()?;
+fn usdt_krw_sources(client: &reqwest::Client) -> Vec<(&'static str, SourceFuture)> {
+    vec![
+        ("bithumb", fetch_bithumb_usdt_krw(client.clone()).boxed()),
+        ("upbit", fetch_upbit_usdt_krw(client.clone()).boxed()),
+        ("coinone", fetch_coinone_usdt_krw(client.clone()).boxed()),
+    ]
+}
+
+/// USDT/KRW 환율을 가져옵니다.
+/// 국내 거래소(Bithumb, Upbit, Coinone) USDT/KRW 가격의 중앙값입니다.
+/// 1 USDT = ? KRW 형식으로 반환합니다.
+pub async fn fetch_usdt_krw_rate() -> Result<f64> {
+    let samples = collect_samples("USDT/KRW", usdt_krw_sources(&http_client())).await;
+    let (usdt_krw, sources) = median_without_outliers(&samples, USDT_KRW_RANGE)
+        .ok_or_else(|| eyre!("No USDT/KRW source available"))?;
     info!(
-        "Bithumb에서 USDT/KRW 환율 가져오기 성공: {} (1 USDT = {} KRW)",
-        usdt_krw, usdt_krw
+        "USDT/KRW 환율 가져오기 성공: {} (1 USDT = {} KRW, 소스 {}개)",
+        usdt_krw, usdt_krw, sources
     );
     Ok(usdt_krw)
 }
 
+/// 세 환율 집계 결과를 하나로 묶는다 (시각은 가장 오래된 집계 기준)
+fn to_exchange_rates(quotes: [RateQuote; 3], now: DateTime<Utc>) -> ExchangeRates {
+    let [usd_krw, usdt_usd, usdt_krw] = quotes;
+    let mut rates = ExchangeRates {
+        usd_krw: usd_krw.value,
+        usdt_usd: usdt_usd.value,
+        usdt_krw: usdt_krw.value,
+        updated_at: quotes
+            .iter()
+            .map(|q| q.updated_at)
+            .min()
+            .unwrap_or_default(),
+        sources: ExchangeRateSources {
+            usd_krw: usd_krw.sources,
+            usdt_usd: usdt_usd.sources,
+            usdt_krw: usdt_krw.sources,
+        },
+        is_fallback: quotes.iter().any(|q| q.is_fallback),
+        degraded: quotes.iter().any(|q| q.degraded),
+        age_ms: 0,
+    };
+    rates.refresh_age(now);
+    rates
+}
+
 /// 모든 환율 정보를 가져옵니다.
+/// 소스가 모두 실패한 환율은 마지막 값을 유지하고, 오래되면 `is_fallback`을 켭니다.
 pub async fn fetch_all_exchange_rates() -> ExchangeRates {
-    let usd_krw = fetch_usd_krw_rate().await.unwrap_or(FALLBACK_USD_KRW);
-    let usdt_usd = fetch_usdt_usd_rate().await.unwrap_or(FALLBACK_USDT_USD);
-    let usdt_krw = fetch_usdt_krw_rate().await.unwrap_or(FALLBACK_USDT_KRW);
-
-    ExchangeRates {
-        usd_krw,
-        usdt_usd,
-        usdt_krw,
-        updated_at: Utc::now(),
+    let client = http_client();
+    let (usd_krw, usdt_usd, usdt_krw) = tokio::join!(
+        collect_samples("USD/KRW", usd_krw_sources(&client)),
+        collect_samples("USDT/USD", usdt_usd_sources(&client)),
+        collect_samples("USDT/KRW", usdt_krw_sources(&client)),
+    );
+
+    let now = Utc::now();
+    let quotes = {
+        let mut last = LAST_RATES.lock().unwrap();
+        let prev = last.map(|q| q.map(Some)).unwrap_or([None; 3]);
+        let quotes = [
+            resolve(&usd_krw, USD_KRW_RANGE, prev[0], FALLBACK_USD_KRW, now),
+            resolve(&usdt_usd, USDT_USD_RANGE, prev[1], FALLBACK_USDT_USD, now),
+            resolve(&usdt_krw, USDT_KRW_RANGE, prev[2], FALLBACK_USDT_KRW, now),
+        ];
+        *last = Some(quotes);
+        quotes
+    };
+
+    let rates = to_exchange_rates(quotes, now);
+    if rates.is_fallback || rates.degraded {
+        warn!(
+            "환율 fallback {} / degraded {}: USD/KRW {} ({}개), USDT/USD {} ({}개), USDT/KRW {} ({}개)",
+            rates.is_fallback,
+            rates.degraded,
+            rates.usd_krw,
+            rates.sources.usd_krw,
+            rates.usdt_usd,
+            rates.sources.usdt_usd,
+            rates.usdt_krw,
+            rates.sources.usdt_krw
+        );
+    } else {
+        info!(
+            "환율 집계: USD/KRW {} ({}개), USDT/USD {} ({}개), USDT/KRW {} ({}개)",
+            rates.usd_krw,
+            rates.sources.usd_krw,
+            rates.usdt_usd,
+            rates.sources.usdt_usd,
+            rates.usdt_krw,
+            rates.sources.usdt_krw
+        );
+    }
+    rates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_median_without_outliers() {
+        // 범위를 벗어난 값(0, 1300배 단위 오류)과 2% 넘게 벗어난 값은 버린다
+        let samples = [1380.0, 1382.0, 1379.5, 1500.0, 0.0, 1_794_000.0];
+        let (value, sources) = median_without_outliers(&samples, USD_KRW_RANGE).unwrap();
+        assert_eq!(sources, 3);
+        assert_eq!(value, 1380.0);
+
+        let (value, sources) = median_without_outliers(&[1.0, 1.002], USDT_USD_RANGE).unwrap();
+        assert_eq!(sources, 2);
+        assert!((value - 1.001).abs() < 1e-12);
+
+        assert!(median_without_outliers(&[f64::NAN], USDT_USD_RANGE).is_none());
+    }
+
+    #[test]
+    fn test_resolve_staleness_and_fallback() {
+        let now = Utc::now();
+
+        // 집계한 적이 없으면 기본값 + fallback
+        let quote = resolve(&[], USD_KRW_RANGE, None, FALLBACK_USD_KRW, now);
+        assert!(quote.is_fallback);
+        assert_eq!(quote.value, FALLBACK_USD_KRW);
+
+        // 최근 값은 유지 (시각은 그대로라 age가 늘어난다)
+        let last = RateQuote {
+            value: 1390.0,
+            sources: 3,
+            updated_at: now - chrono::Duration::minutes(1),
+            is_fallback: false,
+            degraded: false,
+        };
+        let quote = resolve(&[], USD_KRW_RANGE, Some(last), FALLBACK_USD_KRW, now);
+        assert_eq!(quote.value, 1390.0);
+        assert_eq!(quote.sources, 0);
+        assert_eq!(quote.updated_at, last.updated_at);
+        assert!(!quote.is_fallback);
+
+        // 오래된 값은 유지하되 fallback 표시
+        let stale = RateQuote {
+            updated_at: now - chrono::Duration::minutes(30),
+            ..last
+        };
+        let quote = resolve(&[], USD_KRW_RANGE, Some(stale), FALLBACK_USD_KRW, now);
+        assert_eq!(quote.value, 1390.0);
+        assert!(quote.is_fallback);
+
+        // 새 시세가 오면 정상으로 돌아온다
+        let quote = resolve(&[1400.0], USD_KRW_RANGE, Some(stale), FALLBACK_USD_KRW, now);
+        assert_eq!(quote.value, 1400.0);
+        assert!(!quote.is_fallback);
+
+        let rates = to_exchange_rates(
+            [
+                quote,
+                resolve(&[1.0], USDT_USD_RANGE, None, FALLBACK_USDT_USD, now),
+                resolve(&[], USDT_KRW_RANGE, None, FALLBACK_USDT_KRW, now),
+            ],
+            now,
+        );
+        assert!(rates.is_fallback);
+        assert!(!rates.degraded);
+        assert_eq!(rates.sources.usd_krw, 1);
+        assert_eq!(rates.sources.usdt_krw, 0);
+        assert_eq!(rates.age_ms, 0);
+
+        // 유지한 오래된 값의 나이가 그대로 드러난다
+        let rates = to_exchange_rates([stale, quote, quote], now);
+        assert_eq!(rates.age_ms, 30 * 60 * 1000);
+    }
+
+    #[test]
+    fn test_resolve_disagreeing_sources() {
+        let now = Utc::now();
+        let last = RateQuote {
+            value: 1390.0,
+            sources: 2,
+            updated_at: now - chrono::Duration::minutes(1),
+            is_fallback: false,
+            degraded: false,
+        };
+
+        // 두 소스가 4% 넘게 차이 나면 둘 다 중앙값에서 2% 넘게 벗어난다
+        let samples = [1385.0, 1450.0];
+        assert!(median_without_outliers(&samples, USD_KRW_RANGE).is_none());
+
+        // 마지막 값에 가까운 쪽을 쓰고 degraded 표시
+        let quote = resolve(&samples, USD_KRW_RANGE, Some(last), FALLBACK_USD_KRW, now);
+        assert_eq!(quote.value, 1385.0);
+        assert_eq!(quote.sources, 1);
+        assert_eq!(quote.updated_at, now);
+        assert!(quote.degraded);
+        assert!(!quote.is_fallback);
+
+        // 기준 값이 없으면 중앙값 + fallback
+        let quote = resolve(&samples, USD_KRW_RANGE, None, FALLBACK_USD_KRW, now);
+        assert_eq!(quote.value, 1417.5);
+        assert!(quote.degraded);
+        assert!(quote.is_fallback);
+
+        let rates = to_exchange_rates([quote, quote, quote], now);
+        assert!(rates.degraded);
     }
 }
@@ -0,0 +1,87 @@
+//! 펀딩비 히스토리 공통 처리
+//!
+//! 거래소마다 응답 정렬 순서와 페이지 크기가 다르고, 대부분 정산 주기를 함께 주지 않는다.
+//! 각 거래소 구현은 받은 기록을 `finish_history`에 넘겨 순서/범위/주기를 맞춘다.
+
+use chrono::{DateTime, Utc};
+
+use interface::FundingRecord;
+
+/// 주기를 알 수 없을 때 사용하는 기본 펀딩 주기 (시간)
+pub const DEFAULT_FUNDING_INTERVAL_HOURS: u32 = 8;
+
+/// 거래소 응답을 FundingHistoryExchange 규약에 맞게 정리
+/// - 시간 오름차순 정렬, 같은 시각 중복 제거
+/// - funding_interval_hours가 0인 기록은 인접 기록과의 시간 차이로 채운다
+/// - [start, end] 범위에서 가장 최신 limit개만 남긴다
+pub fn finish_history(
+    mut records: Vec<FundingRecord>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    limit: usize,
+) -> Vec<FundingRecord> {
+    records.sort_by_key(|r| r.funding_time);
+    records.dedup_by_key(|r| r.funding_time);
+
+    let times: Vec<_> = records.iter().map(|r| r.funding_time).collect();
+    for (i, record) in records.iter_mut().enumerate() {
+        if record.funding_interval_hours > 0 {
+            continue;
+        }
+        // 이전 정산과의 간격, 첫 기록이면 다음 정산과의 간격
+        let gap = match i {
+            0 => times.get(1).map(|next| *next - times[0]),
+            _ => Some(times[i] - times[i - 1]),
+        };
+        record.funding_interval_hours = gap
+            .map(|g| ((g.num_minutes() + 30) / 60) as u32)
+            .filter(|h| (1..=24).contains(h))
+            .unwrap_or(DEFAULT_FUNDING_INTERVAL_HOURS);
+    }
+
+    records.retain(|r| r.funding_time >= start && r.funding_time <= end);
+    let skip = records.len().saturating_sub(limit);
+    records.split_off(skip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use interface::ExchangeId;
+
+    fn record(hour: i64, interval: u32) -> FundingRecord {
+        FundingRecord {
+            exchange: ExchangeId::Binance,
+            symbol: "BTCUSDT".to_string(),
+            funding_time: Utc.timestamp_opt(0, 0).unwrap() + Duration::hours(hour),
+            funding_rate: 0.0001,
+            funding_interval_hours: interval,
+        }
+    }
+
+    #[test]
+    fn test_finish_history() {
+        // 8시간 주기에서 4시간 주기로 바뀐 심볼, 역순 + 중복 응답
+        let records = vec![
+            record(28, 0),
+            record(24, 0),
+            record(16, 0),
+            record(16, 0),
+            record(8, 0),
+            record(0, 0),
+        ];
+        let start = Utc.timestamp_opt(0, 0).unwrap();
+        let end = start + Duration::hours(28);
+
+        let all = finish_history(records.clone(), start, end, 100);
+        let hours: Vec<_> = all.iter().map(|r| r.funding_interval_hours).collect();
+        assert_eq!(hours, vec![8, 8, 8, 8, 4]);
+
+        // 최신 2개만, 범위 밖 기록은 주기 계산에만 쓰인다
+        let latest = finish_history(records, start + Duration::hours(1), end, 2);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[0].funding_time, start + Duration::hours(24));
+        assert_eq!(latest[1].funding_interval_hours, 4);
+    }
+}
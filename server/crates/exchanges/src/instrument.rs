@@ -0,0 +1,347 @@
+//! 거래소별 심볼 <-> Instrument 변환
+//!
+//! - Binance / Bybit: "BTCUSDT" (현물/선물 동일), 만기 선물은 "BTCUSDT_250328" / "BTCUSDT-28MAR25"
+//! - OKX: "BTC-USDT" (현물), "BTC-USDT-SWAP" (무기한), "BTC-USDT-250328" (만기)
+//! - Bitget: "BTCUSDT_SPBL" (현물), "BTCUSDT_UMCBL" (USDT 무기한), v2 API는 "BTCUSDT"
+//! - Bithumb: "BTC_KRW", "KRW-BTC" 또는 "BTC" (원화 마켓)
+
+use interface::{ExchangeId, Instrument, InstrumentKind};
+
+/// 붙여쓰기 심볼에서 인식하는 견적 자산 (긴 것부터 매칭)
+const KNOWN_QUOTES: &[&str] = &[
+    "FDUSD", "USDT", "USDC", "BUSD", "TUSD", "KRW", "USD", "BTC", "ETH", "BNB", "EUR", "TRY",
+];
+
+/// Bithumb 기본 견적 자산
+const BITHUMB_DEFAULT_QUOTE: &str = "KRW";
+
+/// "BTCUSDT" -> ("BTC", "USDT")
+/// 견적 자산은 긴 것부터 매칭하므로 "USDCUSDT" -> ("USDC", "USDT"), "BTCFDUSD" -> ("BTC", "FDUSD")
+pub fn split_concat_symbol(symbol: &str) -> Option<(String, String)> {
+    let symbol = symbol.to_uppercase();
+    let mut quotes: Vec<&str> = KNOWN_QUOTES.to_vec();
+    quotes.sort_by_key(|q| std::cmp::Reverse(q.len()));
+
+    quotes
+        .into_iter()
+        .filter(|quote| symbol.len() > quote.len())
+        .find_map(|quote| {
+            symbol
+                .strip_suffix(quote)
+                .map(|base| (base.to_string(), quote.to_string()))
+        })
+}
+
+/// 구분자("-", "_", "/")가 있는 심볼이면 분리, 없으면 붙여쓰기 규칙으로 분리
+/// 사용자 입력("BTC-USDT", "BTC/USDT", "BTCUSDT")을 받을 때 사용한다.
+pub fn split_any_symbol(symbol: &str) -> Option<(String, String)> {
+    let upper = symbol.to_uppercase();
+    let parts: Vec<&str> = upper
+        .split(['-', '_', '/'])
+        .filter(|p| !p.is_empty())
+        .collect();
+    match parts.as_slice() {
+        [single] => split_concat_symbol(single),
+        [base, quote, ..] => Some((base.to_string(), quote.to_string())),
+        _ => None,
+    }
+}
+
+/// 거래소 원본 심볼을 Instrument 로 변환
+/// kind 는 심볼만으로 구분되지 않는 거래소(Binance, Bybit 등)를 위해 호출 측에서 지정한다.
+/// 심볼에서 만기/무기한 여부가 드러나면 그쪽을 우선한다.
+pub fn parse_native_symbol(
+    exchange: ExchangeId,
+    kind: InstrumentKind,
+    native: &str,
+) -> Option<Instrument> {
+    let (base, quote, kind) = split_native_symbol(exchange, kind, native)?;
+    Some(Instrument::new(exchange, kind, &base, &quote, native))
+}
+
+/// 거래소 원본 심볼을 (base, quote, kind)로 분리 (base는 배수 접두어 포함 그대로)
+fn split_native_symbol(
+    exchange: ExchangeId,
+    kind: InstrumentKind,
+    native: &str,
+) -> Option<(String, String, InstrumentKind)> {
+    let (base, quote, kind) = match exchange {
+        ExchangeId::Binance | ExchangeId::Bybit => parse_concat_with_expiry(native, kind)?,
+        ExchangeId::Okx => parse_okx(native)?,
+        ExchangeId::Bitget => parse_bitget(native, kind)?,
+        ExchangeId::Bithumb => {
+            let (base, quote) = parse_bithumb(native)?;
+            (base, quote, InstrumentKind::Spot)
+        }
+    };
+
+    if base.is_empty() || quote.is_empty() {
+        return None;
+    }
+
+    Some((base, quote, kind))
+}
+
+/// base/quote 로부터 거래소 원본 심볼 생성 (parse_native_symbol 의 역변환)
+/// base 는 거래소 표기 그대로(배수 접두어 포함) 넘겨야 한다. 만기 선물은 만기일이 필요하므로 None.
+pub fn to_native_symbol(
+    exchange: ExchangeId,
+    kind: InstrumentKind,
+    base: &str,
+    quote: &str,
+) -> Option<String> {
+    if kind == InstrumentKind::Delivery {
+        return None;
+    }
+
+    let base = base.to_uppercase();
+    let quote = quote.to_uppercase();
+
+    let native = match (exchange, kind) {
+        (ExchangeId::Binance | ExchangeId::Bybit, _) => format!("{}{}", base, quote),
+        (ExchangeId::Okx, InstrumentKind::Perp) => format!("{}-{}-SWAP", base, quote),
+        (ExchangeId::Okx, _) => format!("{}-{}", base, quote),
+        (ExchangeId::Bitget, InstrumentKind::Perp) => format!("{}{}_UMCBL", base, quote),
+        (ExchangeId::Bitget, _) => format!("{}{}_SPBL", base, quote),
+        (ExchangeId::Bithumb, InstrumentKind::Spot) => format!("{}_{}", base, quote),
+        (ExchangeId::Bithumb, _) => return None,
+    };
+
+    Some(native)
+}
+
+/// 사용자 입력 심볼("BTC-USDT", "BTCUSDT", "1000PEPEUSDT")을 특정 거래소의 Instrument 로 해석
+pub fn resolve_symbol(
+    exchange: ExchangeId,
+    kind: InstrumentKind,
+    symbol: &str,
+) -> Option<Instrument> {
+    let (base, quote) = match parse_native_symbol(exchange, kind, symbol) {
+        // 만기물은 만기일을 다시 만들 수 없으므로 원본 그대로 사용
+        Some(inst) if inst.kind == InstrumentKind::Delivery => return Some(inst),
+        Some(inst) => (native_base(&inst), inst.quote),
+        None => split_any_symbol(symbol)?,
+    };
+    let native = to_native_symbol(exchange, kind, &base, &quote)?;
+    parse_native_symbol(exchange, kind, &native)
+}
+
+/// 배수 상품에서 쓰이는 base 접두어 (counterpart 후보 탐색용)
+const MULTIPLIER_PREFIXES: &[&str] = &["1000", "10000", "1000000", "1M"];
+
+/// 같은 거래소의 다른 상품 종류로 변환 (예: Binance 선물 1000PEPEUSDT -> 현물 PEPEUSDT)
+/// 배수 접두어는 상품마다 달라(현물 PEPEUSDT / 선물 1000PEPEUSDT, 현물·선물 모두 1000SATSUSDT)
+/// 원래 표기, 접두어 없는 base, 알려진 접두어 순으로 후보를 만들고 is_listed(원본 심볼)를
+/// 만족하는 첫 후보를 고른다.
+pub fn counterpart(
+    instrument: &Instrument,
+    kind: InstrumentKind,
+    is_listed: impl Fn(&str) -> bool,
+) -> Option<Instrument> {
+    let mut bases = vec![native_base(instrument), instrument.base.clone()];
+    bases.extend(
+        MULTIPLIER_PREFIXES
+            .iter()
+            .map(|prefix| format!("{}{}", prefix, instrument.base)),
+    );
+    bases.dedup();
+
+    bases.iter().find_map(|base| {
+        let native = to_native_symbol(instrument.exchange, kind, base, &instrument.quote)?;
+        if !is_listed(&native) {
+            return None;
+        }
+        parse_native_symbol(instrument.exchange, kind, &native)
+    })
+}
+
+/// 거래소 표기 그대로의 base (배수 접두어 포함, 예: "1000PEPE", "1MBABYDOGE")
+pub fn native_base(instrument: &Instrument) -> String {
+    if let Some((base, _, _)) = split_native_symbol(
+        instrument.exchange,
+        instrument.kind,
+        &instrument.native_symbol,
+    ) {
+        return base;
+    }
+    if instrument.multiplier > 1 {
+        format!("{}{}", instrument.multiplier, instrument.base)
+    } else {
+        instrument.base.clone()
+    }
+}
+
+/// "BTCUSDT", "BTCUSDT_250328", "BTCUSDT-28MAR25"
+fn parse_concat_with_expiry(
+    native: &str,
+    kind: InstrumentKind,
+) -> Option<(String, String, InstrumentKind)> {
+    let upper = native.to_uppercase();
+    match upper.split_once(['_', '-']) {
+        Some((pair, expiry)) if expiry.chars().any(|c| c.is_ascii_digit()) => {
+            let (base, quote) = split_concat_symbol(pair)
+                // Bybit 인버스 만기물: "BTC-27DEC24" (USD 정산)
+                .unwrap_or_else(|| (pair.to_string(), "USD".to_string()));
+            Some((base, quote, InstrumentKind::Delivery))
+        }
+        Some(_) => None,
+        None => {
+            let (base, quote) = split_concat_symbol(&upper)?;
+            Some((base, quote, kind))
+        }
+    }
+}
+
+/// "BTC-USDT", "BTC-USDT-SWAP", "BTC-USDT-250328"
+fn parse_okx(native: &str) -> Option<(String, String, InstrumentKind)> {
+    let upper = native.to_uppercase();
+    let parts: Vec<&str> = upper.split('-').collect();
+    match parts.as_slice() {
+        [base, quote] => Some((base.to_string(), quote.to_string(), InstrumentKind::Spot)),
+        [base, quote, "SWAP"] => Some((base.to_string(), quote.to_string(), InstrumentKind::Perp)),
+        [base, quote, expiry] if expiry.chars().all(|c| c.is_ascii_digit()) => Some((
+            base.to_string(),
+            quote.to_string(),
+            InstrumentKind::Delivery,
+        )),
+        _ => None,
+    }
+}
+
+/// "BTCUSDT_UMCBL", "BTCUSDT_SPBL", "BTCUSDT"
+fn parse_bitget(native: &str, kind: InstrumentKind) -> Option<(String, String, InstrumentKind)> {
+    let upper = native.to_uppercase();
+    let (pair, kind) = match upper.split_once('_') {
+        Some((pair, "SPBL")) => (pair, InstrumentKind::Spot),
+        Some((pair, "UMCBL" | "DMCBL" | "CMCBL")) => (pair, InstrumentKind::Perp),
+        Some(_) => return None,
+        None => (upper.as_str(), kind),
+    };
+    let (base, quote) = split_concat_symbol(pair)?;
+    Some((base, quote, kind))
+}
+
+/// "BTC_KRW", "BTC-KRW", "KRW-BTC"(v1 마켓 코드), "BTC"
+fn parse_bithumb(native: &str) -> Option<(String, String)> {
+    let upper = native.to_uppercase();
+    let parts: Vec<&str> = upper.split(['_', '-']).collect();
+    match parts.as_slice() {
+        // v1 마켓 코드는 견적 자산이 앞에 온다 ("KRW-BTC")
+        [quote, base] if *quote == "KRW" || (*quote == "BTC" && !KNOWN_QUOTES.contains(base)) => {
+            Some((base.to_string(), quote.to_string()))
+        }
+        [base, quote] => Some((base.to_string(), quote.to_string())),
+        [single] => match split_concat_symbol(single) {
+            Some((base, quote)) if quote == BITHUMB_DEFAULT_QUOTE => Some((base, quote)),
+            _ => Some((single.to_string(), BITHUMB_DEFAULT_QUOTE.to_string())),
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_concat_symbol() {
+        assert_eq!(
+            split_concat_symbol("BTCUSDT"),
+            Some(("BTC".to_string(), "USDT".to_string()))
+        );
+        assert_eq!(
+            split_concat_symbol("USDCUSDT"),
+            Some(("USDC".to_string(), "USDT".to_string()))
+        );
+        assert_eq!(
+            split_concat_symbol("BTCFDUSD"),
+            Some(("BTC".to_string(), "FDUSD".to_string()))
+        );
+        assert_eq!(
+            split_concat_symbol("ETHBTC"),
+            Some(("ETH".to_string(), "BTC".to_string()))
+        );
+        assert_eq!(split_concat_symbol("USDT"), None);
+    }
+
+    #[test]
+    fn test_parse_native_symbol_per_exchange() {
+        let binance =
+            parse_native_symbol(ExchangeId::Binance, InstrumentKind::Perp, "1000PEPEUSDT").unwrap();
+        assert_eq!(binance.base, "PEPE");
+        assert_eq!(binance.quote, "USDT");
+        assert_eq!(binance.multiplier, 1000);
+        assert_eq!(binance.native_symbol, "1000PEPEUSDT");
+
+        let okx =
+            parse_native_symbol(ExchangeId::Okx, InstrumentKind::Perp, "BTC-USDT-SWAP").unwrap();
+        assert_eq!(okx.symbol(), "BTCUSDT");
+        assert_eq!(okx.kind, InstrumentKind::Perp);
+
+        let okx_spot =
+            parse_native_symbol(ExchangeId::Okx, InstrumentKind::Perp, "BTC-USDT").unwrap();
+        assert_eq!(okx_spot.kind, InstrumentKind::Spot);
+
+        let okx_delivery =
+            parse_native_symbol(ExchangeId::Okx, InstrumentKind::Perp, "BTC-USD-250328").unwrap();
+        assert_eq!(okx_delivery.kind, InstrumentKind::Delivery);
+
+        let bitget =
+            parse_native_symbol(ExchangeId::Bitget, InstrumentKind::Spot, "BTCUSDT_UMCBL").unwrap();
+        assert_eq!(bitget.symbol(), "BTCUSDT");
+        assert_eq!(bitget.kind, InstrumentKind::Perp);
+
+        let binance_delivery =
+            parse_native_symbol(ExchangeId::Binance, InstrumentKind::Perp, "BTCUSDT_250328")
+                .unwrap();
+        assert_eq!(binance_delivery.kind, InstrumentKind::Delivery);
+        assert_eq!(binance_delivery.symbol(), "BTCUSDT");
+
+        for native in ["BTC", "BTC_KRW", "KRW-BTC", "btc-krw"] {
+            let bithumb =
+                parse_native_symbol(ExchangeId::Bithumb, InstrumentKind::Spot, native).unwrap();
+            assert_eq!(bithumb.base, "BTC", "{}", native);
+            assert_eq!(bithumb.quote, "KRW", "{}", native);
+        }
+    }
+
+    #[test]
+    fn test_resolve_symbol_and_counterpart() {
+        let okx = resolve_symbol(ExchangeId::Okx, InstrumentKind::Spot, "BTCUSDT").unwrap();
+        assert_eq!(okx.native_symbol, "BTC-USDT");
+
+        let bithumb = resolve_symbol(ExchangeId::Bithumb, InstrumentKind::Spot, "BTC-KRW").unwrap();
+        assert_eq!(bithumb.native_symbol, "BTC_KRW");
+
+        // 현물은 PEPEUSDT, 1000SATSUSDT, 선물은 1000PEPEUSDT, 1000SATSUSDT, 1MBABYDOGEUSDT
+        let spot_listed = |native: &str| ["PEPEUSDT", "1000SATSUSDT"].contains(&native);
+        let perp_listed =
+            |native: &str| ["1000PEPEUSDT", "1000SATSUSDT", "1MBABYDOGEUSDT"].contains(&native);
+
+        let perp =
+            resolve_symbol(ExchangeId::Binance, InstrumentKind::Perp, "1000PEPEUSDT").unwrap();
+        let spot = counterpart(&perp, InstrumentKind::Spot, spot_listed).unwrap();
+        assert_eq!(spot.native_symbol, "PEPEUSDT");
+        assert_eq!(spot.multiplier, 1);
+
+        let back = counterpart(&spot, InstrumentKind::Perp, perp_listed).unwrap();
+        assert_eq!(back.native_symbol, "1000PEPEUSDT");
+        assert_eq!(back.multiplier, 1000);
+
+        // 현물에도 배수 접두어가 붙은 상품
+        let sats =
+            resolve_symbol(ExchangeId::Binance, InstrumentKind::Perp, "1000SATSUSDT").unwrap();
+        let sats_spot = counterpart(&sats, InstrumentKind::Spot, spot_listed).unwrap();
+        assert_eq!(sats_spot.native_symbol, "1000SATSUSDT");
+        assert_eq!(sats_spot.base, "SATS");
+
+        // "1M" 접두어 (100만 배)
+        let babydoge =
+            resolve_symbol(ExchangeId::Binance, InstrumentKind::Perp, "1MBABYDOGEUSDT").unwrap();
+        assert_eq!(babydoge.base, "BABYDOGE");
+        assert_eq!(babydoge.multiplier, 1_000_000);
+        assert_eq!(babydoge.native_symbol, "1MBABYDOGEUSDT");
+        assert_eq!(native_base(&babydoge), "1MBABYDOGE");
+        assert!(counterpart(&babydoge, InstrumentKind::Spot, spot_listed).is_none());
+    }
+}
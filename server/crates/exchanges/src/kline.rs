@@ -0,0 +1,170 @@
+//! 캔들(OHLCV) 공통 처리
+//!
+//! 거래소마다 캔들을 `[시각, 값...]` 배열로 주지만 필드 순서, 숫자/문자열 표기,
+//! 정렬 순서와 페이지 크기가 다르다. 각 거래소 구현은 행을 `parse_candle_row`로
+//! 변환하고, 여러 페이지를 `page_backward`로 이어 받은 뒤 `finish_candles`로 정리한다.
+
+use std::future::Future;
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use serde_json::Value;
+
+use interface::{Candle, Decimal, ExchangeError, ExchangeId, InstrumentKind, KlineInterval};
+
+use crate::decimal::parse_decimal;
+
+/// 한 번의 호출에서 받을 수 있는 최대 캔들 수 (1분봉 약 3개월)
+pub const MAX_CANDLES: usize = 150_000;
+
+/// 행 안에서 [시각(ms), open, high, low, close, volume]의 위치
+pub type CandleLayout = [usize; 6];
+
+/// 대부분 거래소의 순서: [ts, open, high, low, close, volume, ...]
+pub const STANDARD_LAYOUT: CandleLayout = [0, 1, 2, 3, 4, 5];
+
+/// 캔들 행이 어느 상품의 것인지
+#[derive(Debug, Clone, Copy)]
+pub struct CandleSource<'a> {
+    pub exchange: ExchangeId,
+    pub symbol: &'a str,
+    pub kind: InstrumentKind,
+    pub interval: KlineInterval,
+}
+
+fn value_decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::String(s) => parse_decimal(s),
+        Value::Number(n) => parse_decimal(&n.to_string()),
+        _ => None,
+    }
+}
+
+/// 캔들 행 하나를 Candle로 변환 (시각/가격은 숫자, 문자열 모두 허용)
+pub fn parse_candle_row(
+    source: CandleSource<'_>,
+    row: &[Value],
+    layout: CandleLayout,
+) -> Result<Candle, ExchangeError> {
+    let field = |i: usize| row.get(layout[i]).and_then(value_decimal);
+    let invalid = || ExchangeError::Other(format!("Invalid candle row: {:?}", row));
+
+    let open_time = field(0)
+        .and_then(|ts| ts.to_i64())
+        .and_then(DateTime::from_timestamp_millis)
+        .ok_or_else(invalid)?;
+
+    Ok(Candle {
+        exchange: source.exchange,
+        symbol: source.symbol.to_string(),
+        kind: source.kind,
+        interval: source.interval,
+        open_time,
+        open: field(1).ok_or_else(invalid)?,
+        high: field(2).ok_or_else(invalid)?,
+        low: field(3).ok_or_else(invalid)?,
+        close: field(4).ok_or_else(invalid)?,
+        volume: field(5).ok_or_else(invalid)?,
+    })
+}
+
+/// 최신 순으로 페이지를 주는 API를 end부터 start까지 거슬러 올라가며 모두 받는다.
+/// fetch(cursor)는 open_time이 cursor 이하인 캔들 한 페이지를 돌려줘야 한다.
+pub async fn page_backward<F, Fut>(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    mut fetch: F,
+) -> Result<Vec<Candle>, ExchangeError>
+where
+    F: FnMut(DateTime<Utc>) -> Fut,
+    Fut: Future<Output = Result<Vec<Candle>, ExchangeError>>,
+{
+    let mut candles = Vec::new();
+    let mut cursor = end;
+    while cursor >= start && candles.len() < MAX_CANDLES {
+        let page = fetch(cursor).await?;
+        let Some(oldest) = page.iter().map(|c| c.open_time).min() else {
+            break;
+        };
+        candles.extend(page);
+        // 더 과거로 진행하지 못하면 (같은 페이지 반복) 멈춘다
+        if oldest > cursor {
+            break;
+        }
+        cursor = oldest - Duration::milliseconds(1);
+    }
+    Ok(candles)
+}
+
+/// 시간 오름차순 정렬, 같은 시각 중복 제거 후 [start, end] 범위만 남긴다
+pub fn finish_candles(
+    mut candles: Vec<Candle>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Vec<Candle> {
+    candles.sort_by_key(|c| c.open_time);
+    candles.dedup_by_key(|c| c.open_time);
+    candles.retain(|c| c.open_time >= start && c.open_time <= end);
+    candles.truncate(MAX_CANDLES);
+    candles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SOURCE: CandleSource<'static> = CandleSource {
+        exchange: ExchangeId::Bithumb,
+        symbol: "BTC_KRW",
+        kind: InstrumentKind::Spot,
+        interval: KlineInterval::M1,
+    };
+
+    #[test]
+    fn test_parse_candle_row() {
+        // Bithumb: [ts, open, close, high, low, volume], ts는 숫자
+        let row = json!([1716863700000i64, "100", "103", "105", "99", "1.5"]);
+        let candle = parse_candle_row(SOURCE, row.as_array().unwrap(), [0, 1, 3, 4, 2, 5]).unwrap();
+        assert_eq!(candle.open_time.timestamp_millis(), 1716863700000);
+        assert_eq!(candle.high, Decimal::from(105));
+        assert_eq!(candle.close, Decimal::from(103));
+        assert_eq!(candle.volume, Decimal::new(15, 1));
+
+        let short = json!(["1716863700000", "100"]);
+        assert!(parse_candle_row(SOURCE, short.as_array().unwrap(), STANDARD_LAYOUT).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_page_backward() {
+        // 10분 동안의 1분봉을 최신 순으로 3개씩 주는 가짜 API
+        let base = DateTime::UNIX_EPOCH;
+        let all: Vec<_> = (0..10)
+            .map(|i| {
+                let ts = (base + Duration::minutes(i)).timestamp_millis();
+                let row = json!([ts, "1", "1", "1", "1", "1"]);
+                parse_candle_row(SOURCE, row.as_array().unwrap(), STANDARD_LAYOUT).unwrap()
+            })
+            .collect();
+
+        let start = base + Duration::minutes(2);
+        let end = base + Duration::minutes(8);
+        let candles = page_backward(start, end, |cursor| {
+            let mut page: Vec<_> = all
+                .iter()
+                .filter(|c| c.open_time <= cursor)
+                .cloned()
+                .collect();
+            page.reverse();
+            page.truncate(3);
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+
+        let candles = finish_candles(candles, start, end);
+        assert_eq!(candles.len(), 7);
+        assert_eq!(candles[0].open_time, start);
+        assert_eq!(candles[6].open_time, end);
+    }
+}
@@ -1,16 +1,30 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+
+use book::{BookEvent, BookSnapshot};
 
 use interface::{
-    DepositWithdrawalFee, ExchangeError, ExchangeId, FeeInfo, FutureAsset, MarketType, OrderBook,
-    PerpSnapshot, SpotAsset, SpotSnapshot,
+    Candle, DepositWithdrawalFee, ExchangeError, ExchangeId, FeeInfo, FundingRecord, FutureAsset,
+    InstrumentKind, KlineInterval, MarketType, OrderBook, PerpSnapshot, PerpTick, SpotAsset,
+    SpotSnapshot, SpotTick, TransferRecord, WalletStatus, WithdrawRequest,
 };
 
 pub mod binance;
 pub mod bitget;
 pub mod bithumb;
+pub mod book;
 pub mod bybit;
+pub mod decimal;
+pub mod endpoint;
+pub mod error;
 pub mod exchange_rate;
+pub mod funding;
+pub mod instrument;
+pub mod kline;
 pub mod okx;
+pub mod rate_limit;
+pub mod stream;
 
 #[async_trait]
 pub trait PerpExchange: Send + Sync {
@@ -26,6 +40,47 @@ pub trait SpotExchange: Send + Sync {
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError>;
 }
 
+#[async_trait]
+pub trait OrderBookStream: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 호가 스냅샷/변경분 WebSocket 스트림 (symbol은 거래소 원본 심볼)
+    /// 연결될 때마다 BookEvent::Reset을 먼저 보내고, tx의 수신 측이 닫힐 때까지 재연결한다.
+    async fn stream_book_events(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+        tx: mpsc::Sender<BookEvent>,
+    ) -> Result<(), ExchangeError>;
+
+    /// 변경분을 이어 붙일 REST 스냅샷 (스트림이 스냅샷을 직접 주는 거래소는 None)
+    async fn fetch_book_snapshot(
+        &self,
+        _symbol: &str,
+        _kind: InstrumentKind,
+    ) -> Result<Option<BookSnapshot>, ExchangeError> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+pub trait PerpStream: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 무기한 선물 시세(mark price, 펀딩비, 거래량 등) WebSocket 스트림
+    /// 메시지가 올 때마다 변경분을 tx로 보낸다. 연결이 끊기면 스스로 재연결/재구독하며,
+    /// tx의 수신 측이 닫힐 때까지 반환하지 않는다.
+    async fn stream_perps(&self, tx: mpsc::Sender<PerpTick>) -> Result<(), ExchangeError>;
+}
+
+#[async_trait]
+pub trait SpotStream: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 현물 시세(가격, 거래량) WebSocket 스트림 (동작은 PerpStream과 같다)
+    async fn stream_spots(&self, tx: mpsc::Sender<SpotTick>) -> Result<(), ExchangeError>;
+}
+
 #[async_trait]
 pub trait AssetExchange: Send + Sync {
     fn id(&self) -> ExchangeId;
@@ -41,17 +96,43 @@ pub trait OrderBookExchange: Send + Sync {
 
     /// 특정 심볼의 Orderbook 조회
     /// symbol: 거래쌍 (예: "BTC-KRW", "USDT-KRW")
-    async fn fetch_orderbook(&self, symbol: &str) -> Result<OrderBook, ExchangeError>;
+    /// kind: 같은 심볼의 현물/무기한 호가 중 선택 (지원하지 않는 종류는 에러)
+    async fn fetch_orderbook(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<OrderBook, ExchangeError>;
 }
 
 #[async_trait]
 pub trait FeeExchange: Send + Sync {
     fn id(&self) -> ExchangeId;
 
-    /// 특정 마켓 타입의 거래 수수료 정보 조회
+    /// 특정 마켓 타입의 기본 거래 수수료 (VIP 등급/할인 미반영 공시 요율)
     /// market_type: 마켓 타입 (KRW, USDT, BTC 등)
     fn get_fee(&self, market_type: MarketType) -> FeeInfo;
 
+    /// USDT 무기한 선물의 기본 거래 수수료 (VIP 등급/할인 미반영 공시 요율)
+    /// 선물이 없는 거래소는 USDT 마켓 현물 요율을 돌려준다.
+    fn get_perp_fee(&self) -> FeeInfo {
+        self.get_fee(MarketType::USDT)
+    }
+
+    /// 특정 심볼/상품 종류의 실제 거래 수수료 조회 (계정 VIP 등급, 심볼별 요율, 할인 반영)
+    /// symbol: 거래쌍 (예: "BTC-USDT"), kind: 현물/무기한 중 실제 거래할 레그
+    /// 기본 구현은 심볼의 quote 마켓 기준 get_fee 값을 돌려준다.
+    async fn fetch_trade_fee(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<FeeInfo, ExchangeError> {
+        let _ = kind;
+        let quote = instrument::split_any_symbol(symbol)
+            .map(|(_, quote)| quote)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported symbol: {}", symbol)))?;
+        Ok(self.get_fee(MarketType::from_quote(&quote)))
+    }
+
     /// 특정 통화의 입출금 수수료 조회
     /// currency: 통화 코드 (예: "BTC", "ETH")
     async fn get_deposit_withdrawal_fee(
@@ -60,6 +141,82 @@ pub trait FeeExchange: Send + Sync {
     ) -> Result<DepositWithdrawalFee, ExchangeError>;
 }
 
+#[async_trait]
+pub trait WalletStatusExchange: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 전체 코인의 네트워크별 입출금 가능 여부, 수수료, 한도
+    /// 입출금이 막힌 코인은 프리미엄이 있어도 재정거래할 수 없으므로 진입 전에 확인한다.
+    async fn fetch_wallet_status(&self) -> Result<Vec<WalletStatus>, ExchangeError>;
+}
+
+#[async_trait]
+pub trait TransferExchange: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 출금 신청. 반환값의 id로 fetch_withdrawal을 호출해 진행 상태를 확인한다.
+    /// 주소 검증은 하지 않으므로 호출하는 쪽에서 화이트리스트를 확인해야 한다.
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<TransferRecord, ExchangeError>;
+
+    /// 출금 한 건의 현재 상태 조회
+    /// 신청 직후에는 내역에 아직 없을 수 있어 None을 돌려준다 (실패가 아니라 대기 중으로 볼 것)
+    async fn fetch_withdrawal(&self, id: &str) -> Result<Option<TransferRecord>, ExchangeError>;
+
+    /// 클라이언트 출금 ID(WithdrawRequest::client_id)로 출금 조회 (접수되지 않았으면 None)
+    /// 출금 신청 응답을 받지 못했을 때 실제로 접수됐는지 확인하는 용도
+    async fn find_withdrawal_by_client_id(
+        &self,
+        client_id: &str,
+    ) -> Result<Option<TransferRecord>, ExchangeError> {
+        Err(ExchangeError::NotImplemented(format!(
+            "{:?} withdrawal lookup by client id {}",
+            self.id(),
+            client_id
+        )))
+    }
+
+    /// start 이후 입금 내역 조회 (최신 것부터 거래소 한도만큼)
+    async fn fetch_deposits(
+        &self,
+        currency: &str,
+        start: DateTime<Utc>,
+    ) -> Result<Vec<TransferRecord>, ExchangeError>;
+}
+
+#[async_trait]
+pub trait FundingHistoryExchange: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 무기한 선물의 과거 펀딩비 정산 기록 조회
+    /// symbol: 거래쌍 (예: "BTC-USDT")
+    /// [start, end] 범위에서 가장 최신 것부터 최대 limit개를 시간 오름차순으로 돌려준다.
+    /// 더 과거 기록은 첫 기록 시각 직전을 end로 다시 호출해 이어서 받는다.
+    async fn fetch_funding_history(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<FundingRecord>, ExchangeError>;
+}
+
+#[async_trait]
+pub trait KlineExchange: Send + Sync {
+    fn id(&self) -> ExchangeId;
+
+    /// 현물/무기한 캔들 조회
+    /// symbol: 거래쌍 (예: "BTC-USDT"), open_time이 [start, end] 범위인 캔들을 시간 오름차순으로 돌려준다.
+    /// 거래소의 페이지 한도와 관계없이 내부에서 페이지를 넘겨 범위 전체를 받는다 (최대 kline::MAX_CANDLES개).
+    async fn fetch_klines(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+        interval: KlineInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Candle>, ExchangeError>;
+}
+
 // Convenience re-exports
 pub use binance::BinanceClient;
 pub use bitget::BitgetClient;
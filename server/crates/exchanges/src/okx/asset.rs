@@ -0,0 +1,258 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use interface::{Decimal, ExchangeId, FutureAsset, SpotAsset};
+
+use crate::okx::{api_error, generate_signature, map_error_code};
+use crate::{
+    decimal::parse_decimal, error::check_status, error::truncate_body, AssetExchange,
+    ExchangeError, OkxClient,
+};
+
+#[derive(Debug, Deserialize)]
+struct OkxResponse<T> {
+    code: String,
+    msg: String,
+    // 에러 응답에서는 data가 빠질 수 있다
+    #[serde(default = "Vec::new")]
+    data: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OkxBalance {
+    details: Vec<OkxBalanceDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxBalanceDetail {
+    ccy: String,
+    #[serde(default)]
+    cash_bal: String, // 현금 잔고
+    #[serde(default)]
+    avail_bal: String, // 사용 가능한 잔고
+    #[serde(default)]
+    frozen_bal: String, // 주문/증거금에 묶인 잔고
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxPosition {
+    inst_id: String,
+    #[serde(default)]
+    pos: String, // 계약 수, net 모드에서는 부호로 방향 표시
+    #[serde(default)]
+    pos_side: String, // "net", "long", "short"
+    #[serde(default)]
+    avg_px: String,
+    #[serde(default)]
+    upl: String,
+    #[serde(default)]
+    lever: String,
+    #[serde(default)]
+    liq_px: String,
+}
+
+impl OkxClient {
+    /// 서명이 필요한 V5 GET 요청
+    pub(crate) async fn signed_get<T: DeserializeOwned>(
+        &self,
+        request_path: &str,
+    ) -> Result<Vec<T>, ExchangeError> {
+        let missing = |what: &str| {
+            ExchangeError::Auth(format!(
+                "{} not set. Use OkxClient::with_credentials()",
+                what
+            ))
+        };
+        let api_key = self.api_key.as_ref().ok_or_else(|| missing("API key"))?;
+        let api_secret = self
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| missing("API secret"))?;
+        let passphrase = self
+            .passphrase
+            .as_ref()
+            .ok_or_else(|| missing("API passphrase"))?;
+
+        let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string();
+        let signature = generate_signature(&timestamp, "GET", request_path, "", api_secret);
+        let url = format!("{}{}", self.endpoints.rest, request_path);
+
+        let response = self
+            .http
+            .get(&url)
+            .header("OK-ACCESS-KEY", api_key.as_str())
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", timestamp)
+            .header("OK-ACCESS-PASSPHRASE", passphrase.as_str())
+            .send()
+            .await?;
+
+        let response_text = check_status(response, api_error).await?.text().await?;
+        parse_response(&response_text)
+    }
+}
+
+pub(crate) fn parse_response<T: DeserializeOwned>(
+    response_text: &str,
+) -> Result<Vec<T>, ExchangeError> {
+    let response: OkxResponse<T> = serde_json::from_str(response_text).map_err(|e| {
+        ExchangeError::Other(format!(
+            "Failed to parse OKX response: {}, response: {}",
+            e,
+            truncate_body(response_text)
+        ))
+    })?;
+
+    if response.code != "0" {
+        return Err(map_error_code(&response.code, &response.msg));
+    }
+    Ok(response.data)
+}
+
+/// 포지션 하나를 FutureAsset으로 변환 (계약 수를 base 수량으로 환산, 수량이 0이면 None)
+fn to_future_asset(pos: OkxPosition, ct_val: Decimal, now: DateTime<Utc>) -> Option<FutureAsset> {
+    let contracts = parse_decimal(&pos.pos).unwrap_or_default();
+    if contracts.is_zero() {
+        return None;
+    }
+    // long/short 모드에서는 pos가 항상 양수이고 방향은 posSide로 온다
+    let contracts = if pos.pos_side == "short" {
+        -contracts.abs()
+    } else {
+        contracts
+    };
+
+    Some(FutureAsset {
+        symbol: pos.inst_id,
+        position_amt: (contracts * ct_val).normalize(),
+        entry_price: parse_decimal(&pos.avg_px).unwrap_or_default(),
+        unrealized_pnl: parse_decimal(&pos.upl).unwrap_or_default(),
+        leverage: parse_decimal(&pos.lever).unwrap_or_default(),
+        liquidation_price: parse_decimal(&pos.liq_px).filter(|v| *v > Decimal::ZERO),
+        updated_at: now,
+    })
+}
+
+#[async_trait]
+impl AssetExchange for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn fetch_spots(&self) -> Result<Vec<SpotAsset>, ExchangeError> {
+        // 트레이딩 계정 잔고: GET /api/v5/account/balance
+        let balances: Vec<OkxBalance> = self.signed_get("/api/v5/account/balance").await?;
+
+        let now = Utc::now();
+        let mut assets = Vec::new();
+
+        for detail in balances.into_iter().flat_map(|b| b.details) {
+            let total = parse_decimal(&detail.cash_bal).unwrap_or_default();
+            let in_use = parse_decimal(&detail.frozen_bal).unwrap_or_default();
+            // 계정 모드에 따라 availBal이 비어 있을 수 있다
+            let available = parse_decimal(&detail.avail_bal)
+                .unwrap_or_else(|| (total - in_use).max(Decimal::ZERO));
+
+            // 잔액이 0인 경우 스킵
+            if total > Decimal::ZERO {
+                assets.push(SpotAsset {
+                    currency: detail.ccy,
+                    total,
+                    available,
+                    in_use,
+                    updated_at: now,
+                });
+            }
+        }
+
+        Ok(assets)
+    }
+
+    async fn fetch_futures(&self) -> Result<Vec<FutureAsset>, ExchangeError> {
+        // 무기한 포지션: GET /api/v5/account/positions?instType=SWAP
+        let positions: Vec<OkxPosition> = self
+            .signed_get("/api/v5/account/positions?instType=SWAP")
+            .await?;
+
+        let now = Utc::now();
+        let mut result = Vec::new();
+        for pos in positions {
+            // Binance/Bybit와 같이 USDT 마진 무기한만 다룬다
+            if !pos.inst_id.ends_with("-USDT-SWAP") {
+                continue;
+            }
+            let ct_val = self.contract_value(&pos.inst_id).await?;
+            if let Some(asset) = to_future_asset(pos, ct_val, now) {
+                result.push(asset);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::okx::has_api_credentials;
+
+    #[test]
+    fn test_parse_okx_positions() {
+        let body = r#"{"code":"0","msg":"","data":[
+            {"instId":"BTC-USDT-SWAP","pos":"-3","posSide":"net","avgPx":"65000","upl":"-1.5","lever":"5","liqPx":"78000"},
+            {"instId":"ETH-USDT-SWAP","pos":"2","posSide":"short","avgPx":"3000","upl":"0.2","lever":"3","liqPx":""}
+        ]}"#;
+        let positions: Vec<OkxPosition> = parse_response(body).unwrap();
+        let now = Utc::now();
+        let mut positions = positions.into_iter();
+
+        let btc = to_future_asset(positions.next().unwrap(), Decimal::new(1, 2), now).unwrap();
+        assert_eq!(btc.position_amt, Decimal::new(-3, 2));
+        assert_eq!(btc.liquidation_price, Some(Decimal::from(78000)));
+
+        let eth = to_future_asset(positions.next().unwrap(), Decimal::new(1, 1), now).unwrap();
+        assert_eq!(eth.position_amt, Decimal::new(-2, 1));
+        assert_eq!(eth.liquidation_price, None);
+
+        let err =
+            parse_response::<OkxPosition>(r#"{"code":"50111","msg":"Invalid OK-ACCESS-KEY"}"#)
+                .unwrap_err();
+        assert!(matches!(err, ExchangeError::Auth(_)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_assets_okx() {
+        if !has_api_credentials() {
+            println!("Skipping test: OKX_API_KEY, OKX_API_SECRET and OKX_API_PASSPHRASE not set");
+            return;
+        }
+
+        let client = OkxClient::with_credentials().unwrap();
+        match client.fetch_spots().await {
+            Ok(assets) => {
+                for asset in assets.iter().take(5) {
+                    println!(
+                        "{} - Total: {}, Available: {}, In Use: {}",
+                        asset.currency, asset.total, asset.available, asset.in_use
+                    );
+                }
+            }
+            Err(e) => println!("기타 오류: {}", e),
+        }
+        match client.fetch_futures().await {
+            Ok(positions) => {
+                for pos in positions {
+                    println!(
+                        "{} - {} @ {}",
+                        pos.symbol, pos.position_amt, pos.entry_price
+                    );
+                }
+            }
+            Err(e) => println!("기타 오류: {}", e),
+        }
+    }
+}
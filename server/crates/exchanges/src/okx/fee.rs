@@ -0,0 +1,193 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+
+use interface::{DepositWithdrawalFee, ExchangeId, FeeInfo, InstrumentKind, MarketType};
+
+use crate::{
+    decimal::parse_decimal, instrument::resolve_symbol, ExchangeError, FeeExchange, OkxClient,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxTradeFee {
+    #[serde(default)]
+    level: String, // 계정 등급 (예: "Lv1")
+    #[serde(default)]
+    maker: String,
+    #[serde(default)]
+    taker: String,
+    #[serde(default)]
+    maker_u: String, // USDT 마진 계약 메이커 요율
+    #[serde(default)]
+    taker_u: String, // USDT 마진 계약 테이커 요율
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxCurrency {
+    ccy: String,
+    #[serde(default)]
+    can_wd: bool,
+    #[serde(default)]
+    fee: String,
+    #[serde(default)]
+    min_fee: String, // 구 버전 필드, fee가 비어 있을 때 사용
+}
+
+/// OKX 요율은 부과될 때 음수, 리베이트일 때 양수로 온다
+fn parse_rate(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok().map(|v| -v)
+}
+
+/// 수수료 응답을 FeeInfo로 변환 (무기한은 USDT 마진 요율 사용)
+fn to_fee_info(fee: &OkxTradeFee, kind: InstrumentKind) -> Option<FeeInfo> {
+    let (maker, taker) = match kind {
+        InstrumentKind::Spot => (&fee.maker, &fee.taker),
+        _ => (&fee.maker_u, &fee.taker_u),
+    };
+    let info = FeeInfo::new(parse_rate(maker)?, parse_rate(taker)?);
+    Some(if fee.level.is_empty() {
+        info
+    } else {
+        info.with_tier(fee.level.as_str())
+    })
+}
+
+/// 출금 가능한 체인 중 가장 싼 출금 수수료를 선택
+fn to_deposit_withdrawal_fee(
+    currency: &str,
+    chains: &[OkxCurrency],
+) -> Option<DepositWithdrawalFee> {
+    let withdrawal_fee = chains
+        .iter()
+        .filter(|c| c.can_wd && c.ccy.eq_ignore_ascii_case(currency))
+        .filter_map(|c| parse_decimal(&c.fee).or_else(|| parse_decimal(&c.min_fee)))
+        .min()?;
+
+    Some(DepositWithdrawalFee {
+        currency: currency.to_uppercase(),
+        deposit_fee: Default::default(), // OKX는 입금 수수료가 없다
+        withdrawal_fee,
+        updated_at: Utc::now(),
+    })
+}
+
+#[async_trait]
+impl FeeExchange for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    fn get_fee(&self, market_type: MarketType) -> FeeInfo {
+        match market_type {
+            MarketType::USDT => FeeInfo::new(0.0008, 0.001), // 0.08% 메이커, 0.1% 테이커 (Lv1)
+            _ => FeeInfo::new(0.0008, 0.001),                // 기본값
+        }
+    }
+
+    fn get_perp_fee(&self) -> FeeInfo {
+        FeeInfo::new(0.0002, 0.0005) // SWAP 0.02% 메이커, 0.05% 테이커 (Lv1)
+    }
+
+    async fn fetch_trade_fee(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<FeeInfo, ExchangeError> {
+        if kind == InstrumentKind::Delivery {
+            return Err(ExchangeError::NotImplemented(
+                "OKX delivery trade fee".to_string(),
+            ));
+        }
+        // 현물은 instId, SWAP은 instFamily(예: "BTC-USDT") 단위로 조회한다
+        let inst = resolve_symbol(ExchangeId::Okx, kind, symbol)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported OKX symbol: {}", symbol)))?;
+        let request_path = match kind {
+            InstrumentKind::Spot => format!(
+                "/api/v5/account/trade-fee?instType=SPOT&instId={}",
+                inst.native_symbol
+            ),
+            _ => format!(
+                "/api/v5/account/trade-fee?instType=SWAP&instFamily={}",
+                inst.native_symbol.trim_end_matches("-SWAP")
+            ),
+        };
+
+        let fees: Vec<OkxTradeFee> = self.signed_get(&request_path).await?;
+        fees.first()
+            .and_then(|fee| to_fee_info(fee, kind))
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Missing OKX trade fee for {}", inst.native_symbol))
+            })
+    }
+
+    async fn get_deposit_withdrawal_fee(
+        &self,
+        currency: &str,
+    ) -> Result<DepositWithdrawalFee, ExchangeError> {
+        // 체인별 출금 정보: GET /api/v5/asset/currencies
+        let chains: Vec<OkxCurrency> = self
+            .signed_get(&format!(
+                "/api/v5/asset/currencies?ccy={}",
+                currency.to_uppercase()
+            ))
+            .await?;
+
+        to_deposit_withdrawal_fee(currency, &chains).ok_or_else(|| {
+            ExchangeError::Other(format!(
+                "Fee information not found for currency: {}",
+                currency
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::okx::{asset::parse_response, has_api_credentials};
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_okx_fees() {
+        let body = r#"{"code":"0","msg":"","data":[
+            {"instType":"SWAP","level":"Lv1","maker":"","taker":"","makerU":"-0.0002","takerU":"-0.0005","ts":"1716863719031"}
+        ]}"#;
+        let fees: Vec<OkxTradeFee> = parse_response(body).unwrap();
+        let fee = to_fee_info(&fees[0], InstrumentKind::Perp).unwrap();
+        assert_eq!(fee.maker, 0.0002);
+        assert_eq!(fee.taker, 0.0005);
+        assert_eq!(fee.tier.as_deref(), Some("Lv1"));
+        assert!(to_fee_info(&fees[0], InstrumentKind::Spot).is_none());
+
+        let body = r#"{"code":"0","msg":"","data":[
+            {"ccy":"USDT","chain":"USDT-ERC20","canWd":true,"fee":"3"},
+            {"ccy":"USDT","chain":"USDT-TRC20","canWd":true,"fee":"","minFee":"1.5"},
+            {"ccy":"USDT","chain":"USDT-Solana","canWd":false,"fee":"0.5"}
+        ]}"#;
+        let chains: Vec<OkxCurrency> = parse_response(body).unwrap();
+        let fee = to_deposit_withdrawal_fee("usdt", &chains).unwrap();
+        assert_eq!(fee.currency, "USDT");
+        assert_eq!(fee.withdrawal_fee, Decimal::new(15, 1));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_trade_fee_okx() {
+        if !has_api_credentials() {
+            println!("Skipping test: OKX_API_KEY, OKX_API_SECRET and OKX_API_PASSPHRASE not set");
+            return;
+        }
+
+        let client = OkxClient::with_credentials().unwrap();
+        for kind in [InstrumentKind::Spot, InstrumentKind::Perp] {
+            match client.fetch_trade_fee("BTC-USDT", kind).await {
+                Ok(fee) => println!(
+                    "{:?} BTC-USDT - maker: {}, taker: {}, tier: {:?}",
+                    kind, fee.maker, fee.taker, fee.tier
+                ),
+                Err(e) => println!("기타 오류: {}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use interface::{ExchangeId, FundingRecord, InstrumentKind};
+
+use crate::okx::{api_error, asset::parse_response};
+use crate::{
+    error::check_status, funding::finish_history, instrument::resolve_symbol, ExchangeError,
+    FundingHistoryExchange, OkxClient,
+};
+
+/// GET /api/v5/public/funding-rate-history 한 번에 받을 수 있는 최대 개수
+const PAGE_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxFundingRate {
+    inst_id: String,
+    #[serde(default)]
+    funding_rate: String,
+    #[serde(default)]
+    realized_rate: String, // 실제 정산된 요율
+    funding_time: String,
+}
+
+/// 응답 하나를 FundingRecord로 변환 (주기는 finish_history에서 채운다)
+fn to_funding_record(rate: OkxFundingRate) -> Option<FundingRecord> {
+    let funding_rate = rate
+        .realized_rate
+        .parse()
+        .or_else(|_| rate.funding_rate.parse())
+        .ok()?;
+    Some(FundingRecord {
+        exchange: ExchangeId::Okx,
+        symbol: rate.inst_id,
+        funding_time: DateTime::from_timestamp_millis(rate.funding_time.parse().ok()?)?,
+        funding_rate,
+        funding_interval_hours: 0,
+    })
+}
+
+#[async_trait]
+impl FundingHistoryExchange for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn fetch_funding_history(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<FundingRecord>, ExchangeError> {
+        // 예: "BTC-USDT-SWAP"
+        let inst_id = resolve_symbol(ExchangeId::Okx, InstrumentKind::Perp, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported OKX symbol: {}", symbol)))?;
+
+        // after는 해당 시각보다 과거 기록을 최신 순으로 준다 (최근 3개월까지만 보관)
+        // 주기 계산을 위해 limit보다 하나 더 받는다.
+        let mut records = Vec::new();
+        let mut after = end.timestamp_millis() + 1;
+        while records.len() <= limit {
+            let url = format!(
+                "{}/api/v5/public/funding-rate-history?instId={}&after={}&limit={}",
+                self.endpoints.rest, inst_id, after, PAGE_LIMIT
+            );
+            let response_text = check_status(self.http.get(&url).send().await?, api_error)
+                .await?
+                .text()
+                .await?;
+            let page: Vec<OkxFundingRate> = parse_response(&response_text)?;
+
+            let page_len = page.len();
+            let page: Vec<_> = page.into_iter().filter_map(to_funding_record).collect();
+            let Some(oldest) = page.iter().map(|r| r.funding_time).min() else {
+                break;
+            };
+            records.extend(page);
+
+            if page_len < PAGE_LIMIT || oldest < start {
+                break;
+            }
+            after = oldest.timestamp_millis();
+        }
+
+        Ok(finish_history(records, start, end, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_parse_okx_funding_history() {
+        let body = r#"{"code":"0","msg":"","data":[
+            {"instId":"BTC-USDT-SWAP","instType":"SWAP","fundingRate":"0.0001","realizedRate":"0.00009","fundingTime":"1716883200000","method":"current_period"},
+            {"instId":"BTC-USDT-SWAP","instType":"SWAP","fundingRate":"0.0001","realizedRate":"","fundingTime":"1716868800000","method":"current_period"}
+        ]}"#;
+        let page: Vec<OkxFundingRate> = parse_response(body).unwrap();
+        let records: Vec<_> = page.into_iter().filter_map(to_funding_record).collect();
+        let start = records[1].funding_time;
+        let records = finish_history(records, start, Utc::now(), 10);
+
+        assert_eq!(records[0].funding_rate, 0.0001);
+        assert_eq!(records[1].funding_rate, 0.00009);
+        assert_eq!(records[1].funding_interval_hours, 4);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_funding_history_okx() {
+        let client = OkxClient::new();
+        let end = Utc::now();
+
+        match client
+            .fetch_funding_history("BTC-USDT", end - Duration::days(7), end, 10)
+            .await
+        {
+            Ok(records) => {
+                assert!(records.len() <= 10);
+                assert!(records.iter().all(|r| r.symbol == "BTC-USDT-SWAP"));
+            }
+            Err(e) => {
+                // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                eprintln!("Warning: API call failed: {:?}", e);
+            }
+        }
+    }
+}
@@ -1,4 +1,100 @@
+use std::env;
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use interface::ExchangeError;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::error::{from_http_status, truncate_body};
+
+pub mod asset;
+pub mod fee;
+pub mod funding;
+pub mod kline;
+pub mod orderbook;
 pub mod perp;
 pub mod spot;
+pub mod stream;
 
 pub use perp::OkxClient;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// OKX V5 API 서명 생성
+/// timestamp: ISO 8601 밀리초 (예: "2020-12-08T09:08:57.715Z")
+/// request_path: 쿼리 문자열을 포함한 경로 (예: "/api/v5/account/balance?ccy=BTC")
+pub fn generate_signature(
+    timestamp: &str,
+    method: &str,
+    request_path: &str,
+    body: &str,
+    api_secret: &str,
+) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(api_secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{}{}{}{}", timestamp, method, request_path, body).as_bytes());
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// 환경변수에서 API 키, 시크릿, 패스프레이즈 가져오기
+pub fn get_api_credentials() -> Result<(String, String, String), ExchangeError> {
+    let api_key = env::var("OKX_API_KEY")
+        .map_err(|e| ExchangeError::Auth(format!("OKX_API_KEY not found: {}", e)))?;
+    let api_secret = env::var("OKX_API_SECRET")
+        .map_err(|e| ExchangeError::Auth(format!("OKX_API_SECRET not found: {}", e)))?;
+    let passphrase = env::var("OKX_API_PASSPHRASE")
+        .map_err(|e| ExchangeError::Auth(format!("OKX_API_PASSPHRASE not found: {}", e)))?;
+    Ok((api_key, api_secret, passphrase))
+}
+
+/// 환경변수가 설정되어 있는지 확인
+pub fn has_api_credentials() -> bool {
+    env::var("OKX_API_KEY").is_ok()
+        && env::var("OKX_API_SECRET").is_ok()
+        && env::var("OKX_API_PASSPHRASE").is_ok()
+}
+
+/// OKX 에러 응답 본문 (예: {"code":"50011","msg":"Too Many Requests"})
+#[derive(Debug, Deserialize)]
+struct OkxErrorResponse {
+    code: String,
+    msg: String,
+}
+
+/// OKX 에러 코드를 ExchangeError로 변환
+pub fn map_error_code(code: &str, msg: &str) -> ExchangeError {
+    match code {
+        "50011" | "50061" => ExchangeError::RateLimited { retry_after: None },
+        // 엔드포인트 타임아웃, 만료된 타임스탬프
+        "50004" | "50102" => ExchangeError::Timeout(format!("{}: {}", code, msg)),
+        "50100" | "50101" | "50103" | "50104" | "50105" | "50111" | "50113" => {
+            ExchangeError::Auth(format!("{}: {}", code, msg))
+        }
+        "51008" | "51127" | "51131" => ExchangeError::InsufficientBalance(msg.to_string()),
+        "51020" | "51121" => ExchangeError::InvalidQuantity(msg.to_string()),
+        _ => ExchangeError::ExchangeReject {
+            code: code.to_string(),
+            msg: msg.to_string(),
+        },
+    }
+}
+
+/// 실패한 HTTP 응답을 ExchangeError로 변환
+pub fn api_error(status: StatusCode, retry_after: Option<Duration>, body: &str) -> ExchangeError {
+    if status.as_u16() == 429 {
+        return ExchangeError::RateLimited { retry_after };
+    }
+    if let Ok(err) = serde_json::from_str::<OkxErrorResponse>(body) {
+        return map_error_code(&err.code, &err.msg);
+    }
+    from_http_status(status, retry_after, body).unwrap_or_else(|| {
+        ExchangeError::Other(format!(
+            "OKX API HTTP error: status {}, response: {}",
+            status,
+            truncate_body(body)
+        ))
+    })
+}
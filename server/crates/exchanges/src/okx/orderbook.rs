@@ -0,0 +1,309 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use interface::{Decimal, ExchangeId, InstrumentKind, OrderBook, OrderBookEntry};
+
+use crate::book::{with_reset, BookDelta, BookEvent, BookSnapshot};
+use crate::okx::{api_error, map_error_code};
+use crate::stream::{stream_forever, WsSubscription};
+use crate::{
+    decimal::{parse_book_levels, parse_decimal, parse_level_updates},
+    error::check_status,
+    instrument::resolve_symbol,
+    ExchangeError, OkxClient, OrderBookExchange, OrderBookStream,
+};
+
+const PING_MESSAGE: &str = "ping";
+
+#[derive(Debug, Deserialize)]
+struct OkxResponse<T> {
+    code: String,
+    msg: String,
+    // 에러 응답에서는 data가 빠질 수 있다
+    #[serde(default = "Vec::new")]
+    data: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OkxOrderBook {
+    asks: Vec<Vec<String>>, // [price, size, 폐기 필드, 주문 수]
+    bids: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxInstrument {
+    #[serde(default)]
+    ct_val: String,
+}
+
+impl OkxClient {
+    /// SWAP 계약 1개당 base 수량 (예: BTC-USDT-SWAP = 0.01 BTC)
+    pub(crate) async fn contract_value(&self, inst_id: &str) -> Result<Decimal, ExchangeError> {
+        if let Some(ct_val) = self.ct_val_cache.read().await.get(inst_id) {
+            return Ok(*ct_val);
+        }
+
+        let url = format!(
+            "{}/api/v5/public/instruments?instType=SWAP&instId={inst_id}",
+            self.endpoints.rest
+        );
+        let response: OkxResponse<OkxInstrument> =
+            check_status(self.http.get(&url).send().await?, api_error)
+                .await?
+                .json()
+                .await?;
+
+        if response.code != "0" {
+            return Err(map_error_code(&response.code, &response.msg));
+        }
+
+        let ct_val = response
+            .data
+            .first()
+            .and_then(|i| parse_decimal(&i.ct_val))
+            .filter(|v| *v > Decimal::ZERO)
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Missing OKX contract value for {}", inst_id))
+            })?;
+
+        self.ct_val_cache
+            .write()
+            .await
+            .insert(inst_id.to_string(), ct_val);
+        Ok(ct_val)
+    }
+}
+
+/// 계약 수 단위 호가를 base 수량으로 환산
+fn contracts_to_base(entries: &mut [OrderBookEntry], ct_val: Decimal) {
+    for entry in entries {
+        entry.quantity = (entry.quantity * ct_val).normalize();
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OkxBookPush {
+    action: String, // "snapshot" | "update"
+    data: Vec<OkxBookPushData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxBookPushData {
+    #[serde(default)]
+    asks: Vec<Vec<String>>,
+    #[serde(default)]
+    bids: Vec<Vec<String>>,
+    seq_id: i64,
+    prev_seq_id: i64, // snapshot은 -1
+}
+
+/// books 채널 푸시 (update는 prevSeqId가 직전 seqId와 같아야 연속)
+/// SWAP은 ct_val로 계약 수를 base 수량으로 바꾼다.
+fn parse_book_message(text: &str, ct_val: Option<Decimal>) -> Vec<BookEvent> {
+    let Ok(msg) = serde_json::from_str::<OkxBookPush>(text) else {
+        return Vec::new();
+    };
+
+    msg.data
+        .into_iter()
+        .filter_map(|data| {
+            let mut bids = parse_level_updates(&data.bids).ok()?;
+            let mut asks = parse_level_updates(&data.asks).ok()?;
+            if let Some(ct_val) = ct_val {
+                contracts_to_base(&mut bids, ct_val);
+                contracts_to_base(&mut asks, ct_val);
+            }
+            let sequence = u64::try_from(data.seq_id).ok()?;
+
+            match msg.action.as_str() {
+                "snapshot" => Some(BookEvent::Snapshot(BookSnapshot {
+                    bids,
+                    asks,
+                    sequence,
+                })),
+                "update" => Some(BookEvent::Delta(BookDelta {
+                    bids,
+                    asks,
+                    first_sequence: sequence,
+                    last_sequence: sequence,
+                    prev_sequence: u64::try_from(data.prev_seq_id).ok(),
+                })),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[async_trait]
+impl OrderBookStream for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    /// books 채널 (400 레벨, 스트림이 스냅샷을 직접 보낸다)
+    async fn stream_book_events(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+        tx: mpsc::Sender<BookEvent>,
+    ) -> Result<(), ExchangeError> {
+        if kind == InstrumentKind::Delivery {
+            return Err(ExchangeError::NotImplemented(
+                "OKX delivery orderbook stream".to_string(),
+            ));
+        }
+        let subscription = WsSubscription {
+            url: self.endpoints.public_ws.clone(),
+            messages: vec![json!({
+                "op": "subscribe",
+                "args": [{ "channel": "books", "instId": symbol }],
+            })
+            .to_string()],
+            ping: Some(PING_MESSAGE.to_string()),
+        };
+        stream_forever("OKX depth", tx, || async {
+            let ct_val = match kind {
+                InstrumentKind::Perp => Some(self.contract_value(symbol).await?),
+                _ => None,
+            };
+            Ok((
+                subscription.clone(),
+                with_reset(move |text: &str| parse_book_message(text, ct_val)),
+            ))
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl OrderBookExchange for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn fetch_orderbook(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<OrderBook, ExchangeError> {
+        if kind == InstrumentKind::Delivery {
+            return Err(ExchangeError::NotImplemented(
+                "OKX delivery orderbook".to_string(),
+            ));
+        }
+        // 예: 현물 "BTC-USDT", 무기한 "BTC-USDT-SWAP"
+        let inst_id = resolve_symbol(ExchangeId::Okx, kind, symbol)
+            .map(|i| i.native_symbol)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported OKX symbol: {}", symbol)))?;
+        let url = format!(
+            "{}/api/v5/market/books?instId={inst_id}&sz=100",
+            self.endpoints.rest
+        );
+
+        let response: OkxResponse<OkxOrderBook> =
+            check_status(self.http.get(&url).send().await?, api_error)
+                .await?
+                .json()
+                .await?;
+
+        if response.code != "0" {
+            return Err(map_error_code(&response.code, &response.msg));
+        }
+
+        let book =
+            response.data.into_iter().next().ok_or_else(|| {
+                ExchangeError::Other(format!("Empty OKX orderbook for {}", inst_id))
+            })?;
+
+        let mut bids = parse_book_levels(&book.bids)?;
+        let mut asks = parse_book_levels(&book.asks)?;
+
+        // SWAP 호가 수량은 계약 수이므로 다른 거래소와 맞추기 위해 base 수량으로 바꾼다
+        if kind == InstrumentKind::Perp {
+            let ct_val = self.contract_value(&inst_id).await?;
+            contracts_to_base(&mut bids, ct_val);
+            contracts_to_base(&mut asks, ct_val);
+        }
+
+        bids.sort_by_key(|e| std::cmp::Reverse(e.price)); // 높은 가격 순
+        asks.sort_by_key(|e| e.price); // 낮은 가격 순
+
+        Ok(OrderBook {
+            exchange: ExchangeId::Okx,
+            symbol: inst_id,
+            kind,
+            bids,
+            asks,
+            updated_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_okx_orderbook_response() {
+        let body = r#"{"code":"0","msg":"","data":[{"asks":[["65001.2","12","0","4"]],"bids":[["65000.9","3","0","1"]],"ts":"1716863719031"}]}"#;
+        let response: OkxResponse<OkxOrderBook> = serde_json::from_str(body).unwrap();
+        let book = response.data.into_iter().next().unwrap();
+
+        let mut asks = parse_book_levels(&book.asks).unwrap();
+        contracts_to_base(&mut asks, Decimal::new(1, 2));
+        assert_eq!(asks[0].price, Decimal::new(650012, 1));
+        assert_eq!(asks[0].quantity, Decimal::new(12, 2));
+    }
+
+    #[test]
+    fn test_parse_okx_book_stream() {
+        let snapshot = r#"{"arg":{"channel":"books","instId":"BTC-USDT-SWAP"},"action":"snapshot","data":[{"asks":[["65001","10","0","2"]],"bids":[["65000","5","0","1"]],"ts":"1597026383085","checksum":-855196043,"prevSeqId":-1,"seqId":123456}]}"#;
+        let events = parse_book_message(snapshot, Some(Decimal::new(1, 2)));
+        let [BookEvent::Snapshot(book)] = events.as_slice() else {
+            panic!("expected snapshot: {:?}", events);
+        };
+        assert_eq!(book.sequence, 123456);
+        assert_eq!(book.asks[0].quantity, Decimal::new(1, 1));
+
+        let update = r#"{"arg":{"channel":"books","instId":"BTC-USDT"},"action":"update","data":[{"asks":[["65001","0","0","0"]],"bids":[],"ts":"1597026383085","checksum":1,"prevSeqId":123456,"seqId":123457}]}"#;
+        let events = parse_book_message(update, None);
+        let [BookEvent::Delta(delta)] = events.as_slice() else {
+            panic!("expected delta: {:?}", events);
+        };
+        assert_eq!(delta.prev_sequence, Some(123456));
+        assert_eq!(delta.last_sequence, 123457);
+        assert!(delta.asks[0].quantity.is_zero());
+
+        assert!(parse_book_message("pong", None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_orderbook_okx() {
+        let client = OkxClient::new();
+
+        for (kind, expected) in [
+            (InstrumentKind::Spot, "BTC-USDT"),
+            (InstrumentKind::Perp, "BTC-USDT-SWAP"),
+        ] {
+            match client.fetch_orderbook("BTC-USDT", kind).await {
+                Ok(orderbook) => {
+                    assert_eq!(orderbook.exchange, ExchangeId::Okx);
+                    assert_eq!(orderbook.symbol, expected);
+                    assert_eq!(orderbook.kind, kind);
+                    assert!(!orderbook.bids.is_empty(), "Should have bids");
+                    assert!(!orderbook.asks.is_empty(), "Should have asks");
+                    assert!(orderbook.bids[0].price < orderbook.asks[0].price);
+                }
+                Err(e) => {
+                    // 네트워크 오류일 수 있으므로 테스트 실패로 처리하지 않음
+                    eprintln!("Warning: API call failed: {:?}", e);
+                }
+            }
+        }
+    }
+}
@@ -4,48 +4,104 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use futures_util::{SinkExt, StreamExt};
+use rust_decimal::prelude::ToPrimitive;
 use serde::Deserialize;
 use serde_json::json;
 use tokio::sync::RwLock;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
-use crate::{ExchangeError, PerpExchange};
-use interface::{Currency, ExchangeId, PerpSnapshot};
-
-const BASE_URL: &str = "https://www.okx.com";
-const WS_URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
+use crate::endpoint::{Endpoints, OkxEndpoints};
+use crate::okx::{api_error, map_error_code};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, ExchangeError,
+    PerpExchange,
+};
+use interface::{Currency, Decimal, ExchangeId, InstrumentKind, PerpSnapshot};
 
 #[derive(Debug, Clone)]
 pub(crate) struct FundingInfo {
     funding_rate: f64,
     next_funding_time: Option<DateTime<Utc>>,
+    funding_interval_hours: Option<u32>,
+    predicted_funding_rate: Option<f64>,
+}
+
+/// funding-rate 채널의 이번 정산 시각(fundingTime)과 그다음 정산 시각(nextFundingTime) 차이로 펀딩 주기(시간) 계산
+pub(crate) fn funding_interval_hours(funding_time: &str, next_funding_time: &str) -> Option<u32> {
+    let current: i64 = funding_time.parse().ok()?;
+    let next: i64 = next_funding_time.parse().ok()?;
+    let hours = (next - current) / 3_600_000;
+    (hours > 0).then_some(hours as u32)
+}
+
+/// 다음 주기 예상 펀딩비 (OKX가 값을 주지 않으면 빈 문자열)
+pub(crate) fn predicted_funding_rate(next_funding_rate: &str) -> Option<f64> {
+    next_funding_rate.parse().ok()
 }
 
 #[derive(Clone)]
 pub struct OkxClient {
     pub(crate) http: reqwest::Client,
     pub(crate) funding_cache: Arc<RwLock<HashMap<String, FundingInfo>>>,
+    /// SWAP instId별 계약 단위(ctVal), 호가/포지션 수량을 base 단위로 환산할 때 사용
+    pub(crate) ct_val_cache: Arc<RwLock<HashMap<String, Decimal>>>,
+    pub(crate) api_key: Option<String>,
+    pub(crate) api_secret: Option<String>,
+    pub(crate) passphrase: Option<String>,
+    pub(crate) endpoints: OkxEndpoints,
+}
+
+impl Default for OkxClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl OkxClient {
     pub fn new() -> Self {
+        Self::with_endpoints(Endpoints::current().okx.clone())
+    }
+
+    /// 지정한 주소로 공개 API 클라이언트 생성 (테스트넷, 목 서버 등)
+    pub fn with_endpoints(endpoints: OkxEndpoints) -> Self {
         let funding_cache = Arc::new(RwLock::new(HashMap::new()));
         let cache_clone = funding_cache.clone();
+        let ws_endpoints = endpoints.clone();
 
         // WebSocket 연결을 백그라운드 태스크로 시작
         tokio::spawn(async move {
-            Self::start_websocket(cache_clone).await;
+            Self::start_websocket(ws_endpoints, cache_clone).await;
         });
 
         Self {
             http: reqwest::Client::new(),
             funding_cache,
+            ct_val_cache: Arc::new(RwLock::new(HashMap::new())),
+            api_key: None,
+            api_secret: None,
+            passphrase: None,
+            endpoints,
         }
     }
 
-    async fn start_websocket(cache: Arc<RwLock<HashMap<String, FundingInfo>>>) {
+    /// 인증이 필요한 API를 사용하는 경우 (Asset 등)
+    pub fn with_credentials() -> Result<Self, ExchangeError> {
+        let (api_key, api_secret, passphrase) = super::get_api_credentials()?;
+        Ok(Self {
+            api_key: Some(api_key),
+            api_secret: Some(api_secret),
+            passphrase: Some(passphrase),
+            ..Self::new()
+        })
+    }
+
+    async fn start_websocket(
+        endpoints: OkxEndpoints,
+        cache: Arc<RwLock<HashMap<String, FundingInfo>>>,
+    ) {
         loop {
-            match Self::connect_and_subscribe(cache.clone()).await {
+            match Self::connect_and_subscribe(&endpoints, cache.clone()).await {
                 Ok(_) => {
                     tracing::warn!("OKX WebSocket 연결이 종료되었습니다. 재연결 시도...");
                 }
@@ -60,18 +116,31 @@ impl OkxClient {
     }
 
     async fn connect_and_subscribe(
+        endpoints: &OkxEndpoints,
         cache: Arc<RwLock<HashMap<String, FundingInfo>>>,
     ) -> eyre::Result<()> {
         // WebSocket 연결
-        let (ws_stream, _) = connect_async(WS_URL).await?;
+        let (ws_stream, _) = connect_async(endpoints.public_ws.as_str()).await?;
         let (mut write, mut read) = ws_stream.split();
         tracing::info!("OKX WebSocket 연결 성공");
 
         // 먼저 모든 USDT-SWAP 심볼 목록 가져오기
         let http = reqwest::Client::new();
-        let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SWAP");
-        let response: OkxResponse<Vec<OkxTicker>> =
-            http.get(&tickers_url).send().await?.json().await?;
+        let tickers_url = format!("{}/api/v5/market/tickers?instType=SWAP", endpoints.rest);
+        let response: OkxResponse<Vec<OkxTicker>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Okx,
+                    EndpointClass::Futures,
+                    1,
+                    http.get(&tickers_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if response.code != "0" {
             return Err(eyre::eyre!(
@@ -167,7 +236,11 @@ impl OkxClient {
             #[serde(rename = "instId")]
             inst_id: String,
             funding_rate: String,
+            #[serde(default)]
+            funding_time: String,
             next_funding_time: String,
+            #[serde(default)]
+            next_funding_rate: String,
         }
 
         let response: WsResponse = match serde_json::from_str(text) {
@@ -187,11 +260,16 @@ impl OkxClient {
                 .next_funding_time
                 .parse::<i64>()
                 .ok()
-                .and_then(|ts| DateTime::from_timestamp_millis(ts));
+                .and_then(DateTime::from_timestamp_millis);
 
             let funding_info = FundingInfo {
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours: funding_interval_hours(
+                    &data.funding_time,
+                    &data.next_funding_time,
+                ),
+                predicted_funding_rate: predicted_funding_rate(&data.next_funding_rate),
             };
 
             let mut guard = cache.write().await;
@@ -234,6 +312,15 @@ struct OkxMarkPrice {
     // fundingRate와 nextFundingTime은 제공하지 않음
 }
 
+/// GET /api/v5/market/index-tickers 항목 (instId는 "BTC-USDT" 형식)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxIndexTicker {
+    inst_id: String,
+    #[serde(default)]
+    idx_px: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct OkxOpenInterest {
@@ -244,6 +331,39 @@ struct OkxOpenInterest {
     oi_ccy: String, // open interest in quote currency (USDT)
 }
 
+impl OkxClient {
+    /// USDT 인덱스 가격 ("BTC-USDT" -> 가격)
+    async fn fetch_usdt_index_prices(&self) -> Result<HashMap<String, Decimal>, ExchangeError> {
+        let url = format!(
+            "{}/api/v5/market/index-tickers?quoteCcy=USDT",
+            self.endpoints.rest
+        );
+        let response: OkxResponse<Vec<OkxIndexTicker>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Okx,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
+
+        if response.code != "0" {
+            return Err(map_error_code(&response.code, &response.msg));
+        }
+        Ok(response
+            .data
+            .into_iter()
+            .filter_map(|t| Some((t.inst_id, parse_decimal(&t.idx_px)?)))
+            .collect())
+    }
+}
+
 #[async_trait]
 impl PerpExchange for OkxClient {
     fn id(&self) -> ExchangeId {
@@ -252,41 +372,90 @@ impl PerpExchange for OkxClient {
 
     async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
         // 1) 티커 정보 (24h 거래량)
-        let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SWAP");
-        let tickers_response: OkxResponse<Vec<OkxTicker>> =
-            self.http.get(&tickers_url).send().await?.json().await?;
+        let tickers_url = format!(
+            "{}/api/v5/market/tickers?instType=SWAP",
+            self.endpoints.rest
+        );
+        let tickers_response: OkxResponse<Vec<OkxTicker>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Okx,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&tickers_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if tickers_response.code != "0" {
-            return Err(ExchangeError::Other(format!(
-                "OKX API error (tickers): {} - {}",
-                tickers_response.code, tickers_response.msg
-            )));
+            return Err(map_error_code(
+                &tickers_response.code,
+                &tickers_response.msg,
+            ));
         }
 
         // 2) 마크 가격
-        let mark_price_url = format!("{BASE_URL}/api/v5/public/mark-price?instType=SWAP");
-        let mark_price_response: OkxResponse<Vec<OkxMarkPrice>> =
-            self.http.get(&mark_price_url).send().await?.json().await?;
+        let mark_price_url = format!(
+            "{}/api/v5/public/mark-price?instType=SWAP",
+            self.endpoints.rest
+        );
+        let mark_price_response: OkxResponse<Vec<OkxMarkPrice>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Okx,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&mark_price_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if mark_price_response.code != "0" {
-            return Err(ExchangeError::Other(format!(
-                "OKX API error (mark-price): {} - {}",
-                mark_price_response.code, mark_price_response.msg
-            )));
+            return Err(map_error_code(
+                &mark_price_response.code,
+                &mark_price_response.msg,
+            ));
         }
 
         // 3) 오픈 이너스트
-        let oi_url = format!("{BASE_URL}/api/v5/public/open-interest?instType=SWAP");
-        let oi_response: OkxResponse<Vec<OkxOpenInterest>> =
-            self.http.get(&oi_url).send().await?.json().await?;
+        let oi_url = format!(
+            "{}/api/v5/public/open-interest?instType=SWAP",
+            self.endpoints.rest
+        );
+        let oi_response: OkxResponse<Vec<OkxOpenInterest>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Okx,
+                    EndpointClass::Futures,
+                    1,
+                    self.http.get(&oi_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if oi_response.code != "0" {
-            return Err(ExchangeError::Other(format!(
-                "OKX API error (open-interest): {} - {}",
-                oi_response.code, oi_response.msg
-            )));
+            return Err(map_error_code(&oi_response.code, &oi_response.msg));
         }
 
+        // 4) USDT 인덱스 가격 (실패해도 시세는 내보내고 인덱스만 비워 둔다)
+        let index_map: HashMap<String, Decimal> = self
+            .fetch_usdt_index_prices()
+            .await
+            .map_err(|e| tracing::warn!("OKX index price fetch failed: {}", e))
+            .unwrap_or_default();
+
         // 맵으로 변환하여 조회 속도 향상
         let mut ticker_map: HashMap<String, OkxTicker> = HashMap::new();
         for ticker in tickers_response.data {
@@ -324,9 +493,9 @@ impl PerpExchange for OkxClient {
                 None => continue,
             };
 
-            let mark_price: f64 = match mark_price_data.mark_px.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let mark_price = match parse_decimal(&mark_price_data.mark_px) {
+                Some(v) => v,
+                None => continue,
             };
 
             // 펀딩 레이트와 next_funding_time은 WebSocket에서 가져옴
@@ -338,6 +507,9 @@ impl PerpExchange for OkxClient {
                 .unwrap_or_else(|| ticker.funding_rate.parse().unwrap_or(0.0));
 
             let next_funding_time = funding_info.and_then(|info| info.next_funding_time);
+            let funding_interval_hours = funding_info.and_then(|info| info.funding_interval_hours);
+            let predicted_funding_rate = funding_info.and_then(|info| info.predicted_funding_rate);
+            let index_price = index_map.get(inst_id.trim_end_matches("-SWAP")).copied();
 
             // 오픈 이너스트는 oi_ccy (USDT 기준)를 우선 사용, 없으면 oi * mark_price
             let oi_usd = match oi_map.get(inst_id) {
@@ -346,7 +518,7 @@ impl PerpExchange for OkxClient {
                         oi_data.oi_ccy.parse().unwrap_or(0.0)
                     } else {
                         let oi_contracts: f64 = oi_data.oi.parse().unwrap_or(0.0);
-                        oi_contracts * mark_price
+                        oi_contracts * mark_price.to_f64().unwrap_or(0.0)
                     }
                 }
                 None => 0.0,
@@ -359,18 +531,26 @@ impl PerpExchange for OkxClient {
                 ticker.vol_24h.parse().unwrap_or(0.0)
             };
 
-            // OKX는 "BTC-USDT-SWAP" 형식이므로 "BTCUSDT"로 변환
-            let symbol = inst_id.replace("-USDT-SWAP", "USDT").replace("-", "");
+            // OKX는 "BTC-USDT-SWAP" 형식
+            let instrument =
+                match parse_native_symbol(ExchangeId::Okx, InstrumentKind::Perp, inst_id) {
+                    Some(i) => i,
+                    None => continue,
+                };
 
             out.push(PerpSnapshot {
                 exchange: ExchangeId::Okx,
-                symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 mark_price,
                 oi_usd,
                 vol_24h_usd,
                 funding_rate,
                 next_funding_time,
+                funding_interval_hours,
+                predicted_funding_rate,
+                index_price,
                 updated_at: now,
             });
         }
@@ -382,6 +562,7 @@ impl PerpExchange for OkxClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use interface::Decimal;
 
     #[test]
     fn test_okx_client_id() {
@@ -431,7 +612,7 @@ mod tests {
                     for snapshot in &snapshots {
                         assert_eq!(snapshot.exchange, ExchangeId::Okx);
                         assert!(snapshot.symbol.ends_with("USDT"));
-                        assert!(snapshot.mark_price > 0.0);
+                        assert!(snapshot.mark_price > Decimal::ZERO);
                         assert!(snapshot.oi_usd >= 0.0);
                         assert!(snapshot.vol_24h_usd >= 0.0);
                     }
@@ -473,10 +654,8 @@ mod tests {
                 Err(e) => {
                     // 네트워크 오류 등은 테스트 실패로 간주하지 않음
                     // 하지만 API 오류는 확인
-                    if let ExchangeError::Other(msg) = &e {
-                        if msg.contains("OKX API error") {
-                            panic!("OKX API error: {}", msg);
-                        }
+                    if let ExchangeError::ExchangeReject { .. } = &e {
+                        panic!("OKX API error: {}", e);
                     }
                     // 네트워크 오류는 테스트 환경에 따라 실패할 수 있으므로 경고만
                     eprintln!("Warning: fetch_all failed: {:?}", e);
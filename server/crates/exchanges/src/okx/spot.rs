@@ -2,10 +2,13 @@ use async_trait::async_trait;
 use chrono::Utc;
 use serde::Deserialize;
 
-use crate::{ExchangeError, OkxClient, SpotExchange};
-use interface::{Currency, ExchangeId, SpotSnapshot};
-
-const BASE_URL: &str = "https://www.okx.com";
+use crate::okx::{api_error, map_error_code};
+use crate::rate_limit::{EndpointClass, RateLimiter};
+use crate::{
+    decimal::parse_decimal, error::check_status, instrument::parse_native_symbol, ExchangeError,
+    OkxClient, SpotExchange,
+};
+use interface::{Currency, Decimal, ExchangeId, InstrumentKind, SpotSnapshot};
 
 #[derive(Debug, Deserialize)]
 struct OkxResponse<T> {
@@ -31,43 +34,59 @@ impl SpotExchange for OkxClient {
     }
 
     async fn fetch_all(&self) -> Result<Vec<SpotSnapshot>, ExchangeError> {
-        let tickers_url = format!("{BASE_URL}/api/v5/market/tickers?instType=SPOT");
-        let tickers_response: OkxResponse<Vec<OkxSpotTicker>> =
-            self.http.get(&tickers_url).send().await?.json().await?;
+        let tickers_url = format!(
+            "{}/api/v5/market/tickers?instType=SPOT",
+            self.endpoints.rest
+        );
+        let tickers_response: OkxResponse<Vec<OkxSpotTicker>> = check_status(
+            RateLimiter::global()
+                .send(
+                    ExchangeId::Okx,
+                    EndpointClass::Spot,
+                    1,
+                    self.http.get(&tickers_url),
+                )
+                .await?,
+            api_error,
+        )
+        .await?
+        .json()
+        .await?;
 
         if tickers_response.code != "0" {
-            return Err(ExchangeError::Other(format!(
-                "OKX API error (spot tickers): {} - {}",
-                tickers_response.code, tickers_response.msg
-            )));
+            return Err(map_error_code(
+                &tickers_response.code,
+                &tickers_response.msg,
+            ));
         }
 
         let now = Utc::now();
         let mut out = Vec::new();
 
         for ticker in tickers_response.data {
-            if !ticker.inst_id.ends_with("-USDT") {
-                continue; // USDT 페어만
-            }
+            // OKX는 "BTC-USDT" 형식
+            let instrument =
+                match parse_native_symbol(ExchangeId::Okx, InstrumentKind::Spot, &ticker.inst_id) {
+                    Some(i) if i.quote == "USDT" => i,
+                    _ => continue, // USDT 페어만
+                };
 
-            let price: f64 = match ticker.last.parse() {
-                Ok(v) => v,
-                Err(_) => continue,
+            let price = match parse_decimal(&ticker.last) {
+                Some(v) => v,
+                None => continue,
             };
 
             // price가 0보다 큰 경우만 추가
-            if price <= 0.0 {
+            if price <= Decimal::ZERO {
                 continue;
             }
 
             let vol_24h_usd: f64 = ticker.vol_ccy_24h.parse().unwrap_or(0.0);
 
-            // OKX는 "BTC-USDT" 형식이므로 "BTCUSDT"로 변환
-            let symbol = ticker.inst_id.replace("-USDT", "USDT").replace("-", "");
-
             out.push(SpotSnapshot {
                 exchange: ExchangeId::Okx,
-                symbol,
+                symbol: instrument.symbol(),
+                instrument,
                 currency: Currency::USDT,
                 price,
                 vol_24h_usd,
@@ -0,0 +1,250 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::mpsc;
+
+use crate::okx::perp::{funding_interval_hours, predicted_funding_rate};
+use crate::stream::{stream_forever, subscribe_messages, WsSubscription};
+use crate::{
+    decimal::parse_decimal, instrument::parse_native_symbol, ExchangeError, OkxClient,
+    PerpExchange, PerpStream, SpotExchange, SpotStream,
+};
+use interface::{Currency, ExchangeId, InstrumentKind, PerpTick, SpotTick};
+
+/// 구독 메시지 하나에 넣을 채널 수 (메시지 크기 제한 4096 bytes 이내)
+const ARGS_PER_MESSAGE: usize = 20;
+/// 30초 동안 메시지가 없으면 서버가 끊으므로 텍스트 "ping"을 보낸다 (응답은 "pong")
+const PING_MESSAGE: &str = "ping";
+/// 무기한 시세 채널 (OI는 REST 보완에 맡긴다)
+const PERP_CHANNELS: [&str; 3] = ["tickers", "mark-price", "funding-rate"];
+
+#[derive(Debug, Deserialize)]
+struct OkxPushMessage {
+    arg: OkxPushArg,
+    data: Vec<OkxPushData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OkxPushArg {
+    channel: String,
+}
+
+/// 채널마다 필드가 다르므로 모두 Option으로 받는다
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OkxPushData {
+    inst_id: String,
+    last: Option<String>,
+    vol_ccy24h: Option<String>, // 24h 거래량 (REST와 같이 USDT 기준으로 취급)
+    mark_px: Option<String>,
+    funding_rate: Option<String>,
+    funding_time: Option<String>,
+    next_funding_time: Option<String>,
+    next_funding_rate: Option<String>,
+}
+
+/// 구독 응답({"event":"subscribe",...})이나 "pong"은 None
+fn parse_push(text: &str) -> Option<OkxPushMessage> {
+    serde_json::from_str(text).ok()
+}
+
+fn parse_perp_message(text: &str) -> Vec<PerpTick> {
+    let Some(msg) = parse_push(text) else {
+        return Vec::new();
+    };
+    let now = Utc::now();
+
+    msg.data
+        .into_iter()
+        .filter_map(|data| {
+            let instrument =
+                parse_native_symbol(ExchangeId::Okx, InstrumentKind::Perp, &data.inst_id)
+                    .filter(|i| data.inst_id.ends_with("-USDT-SWAP") && i.quote == "USDT")?;
+            let mut tick = PerpTick::new(instrument, Currency::USDT);
+            tick.updated_at = now;
+
+            match msg.arg.channel.as_str() {
+                "tickers" => {
+                    tick.vol_24h_usd = data.vol_ccy24h.and_then(|v| v.parse().ok());
+                }
+                "mark-price" => {
+                    tick.mark_price = data.mark_px.as_deref().and_then(parse_decimal);
+                }
+                "funding-rate" => {
+                    tick.funding_rate = data.funding_rate.and_then(|v| v.parse().ok());
+                    if let (Some(current), Some(next)) =
+                        (&data.funding_time, &data.next_funding_time)
+                    {
+                        tick.funding_interval_hours = funding_interval_hours(current, next);
+                    }
+                    tick.predicted_funding_rate = data
+                        .next_funding_rate
+                        .as_deref()
+                        .and_then(predicted_funding_rate);
+                    tick.next_funding_time = data
+                        .next_funding_time
+                        .and_then(|v| v.parse::<i64>().ok())
+                        .and_then(DateTime::from_timestamp_millis);
+                }
+                _ => return None,
+            }
+            Some(tick)
+        })
+        .collect()
+}
+
+fn parse_spot_message(text: &str) -> Vec<SpotTick> {
+    let Some(msg) = parse_push(text) else {
+        return Vec::new();
+    };
+    if msg.arg.channel != "tickers" {
+        return Vec::new();
+    }
+    let now = Utc::now();
+
+    msg.data
+        .into_iter()
+        .filter_map(|data| {
+            let instrument =
+                parse_native_symbol(ExchangeId::Okx, InstrumentKind::Spot, &data.inst_id)
+                    .filter(|i| i.quote == "USDT")?; // USDT 페어만
+            let mut tick = SpotTick::new(instrument, Currency::USDT);
+            tick.price = data
+                .last
+                .as_deref()
+                .and_then(parse_decimal)
+                .filter(|p| !p.is_zero());
+            tick.vol_24h_usd = data.vol_ccy24h.and_then(|v| v.parse().ok());
+            tick.updated_at = now;
+            Some(tick)
+        })
+        .collect()
+}
+
+/// 채널 x 상품 조합 구독
+fn subscription(url: &str, channels: &[&str], inst_ids: &[String]) -> WsSubscription {
+    let args: Vec<serde_json::Value> = channels
+        .iter()
+        .flat_map(|channel| {
+            inst_ids
+                .iter()
+                .map(move |inst_id| json!({ "channel": channel, "instId": inst_id }))
+        })
+        .collect();
+
+    WsSubscription {
+        url: url.to_string(),
+        messages: subscribe_messages(
+            &args,
+            ARGS_PER_MESSAGE,
+            |chunk| json!({ "op": "subscribe", "args": chunk }),
+        ),
+        ping: Some(PING_MESSAGE.to_string()),
+    }
+}
+
+#[async_trait]
+impl PerpStream for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn stream_perps(&self, tx: mpsc::Sender<PerpTick>) -> Result<(), ExchangeError> {
+        // 상품별 구독이 필요하므로 REST로 상장 목록을 받아온다
+        stream_forever("OKX perp", tx, || async {
+            let inst_ids: Vec<String> = PerpExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            Ok((
+                subscription(&self.endpoints.public_ws, &PERP_CHANNELS, &inst_ids),
+                parse_perp_message,
+            ))
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl SpotStream for OkxClient {
+    fn id(&self) -> ExchangeId {
+        ExchangeId::Okx
+    }
+
+    async fn stream_spots(&self, tx: mpsc::Sender<SpotTick>) -> Result<(), ExchangeError> {
+        stream_forever("OKX spot", tx, || async {
+            let inst_ids: Vec<String> = SpotExchange::fetch_all(self)
+                .await?
+                .into_iter()
+                .map(|s| s.instrument.native_symbol)
+                .collect();
+            Ok((
+                subscription(&self.endpoints.public_ws, &["tickers"], &inst_ids),
+                parse_spot_message,
+            ))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::Decimal;
+
+    #[test]
+    fn test_parse_okx_perp_stream() {
+        let mark = r#"{"arg":{"channel":"mark-price","instId":"BTC-USDT-SWAP"},"data":[{"instType":"SWAP","instId":"BTC-USDT-SWAP","markPx":"65000.1","ts":"1597026383085"}]}"#;
+        let ticks = parse_perp_message(mark);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].instrument.native_symbol, "BTC-USDT-SWAP");
+        assert_eq!(ticks[0].mark_price, Some(Decimal::new(650001, 1)));
+        assert_eq!(ticks[0].funding_rate, None);
+
+        let funding = r#"{"arg":{"channel":"funding-rate","instId":"BTC-USDT-SWAP"},"data":[{"fundingRate":"0.0001875","fundingTime":"1700726400000","instId":"BTC-USDT-SWAP","instType":"SWAP","nextFundingTime":"1700755200000"}]}"#;
+        let ticks = parse_perp_message(funding);
+        assert_eq!(ticks[0].funding_rate, Some(0.0001875));
+        assert_eq!(
+            ticks[0].next_funding_time.unwrap().timestamp_millis(),
+            1700755200000
+        );
+        assert_eq!(ticks[0].funding_interval_hours, Some(8));
+        assert_eq!(ticks[0].predicted_funding_rate, None);
+        assert_eq!(ticks[0].mark_price, None);
+
+        let tickers = r#"{"arg":{"channel":"tickers","instId":"ETH-USDT-SWAP"},"data":[{"instType":"SWAP","instId":"ETH-USDT-SWAP","last":"3000","volCcy24h":"2500000","vol24h":"250000"}]}"#;
+        let ticks = parse_perp_message(tickers);
+        assert_eq!(ticks[0].vol_24h_usd, Some(2_500_000.0));
+
+        assert!(parse_perp_message("pong").is_empty());
+        assert!(parse_perp_message(
+            r#"{"event":"subscribe","arg":{"channel":"tickers","instId":"BTC-USDT-SWAP"},"connId":"a4d3ae55"}"#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_parse_okx_spot_stream() {
+        let msg = r#"{"arg":{"channel":"tickers","instId":"BTC-USDT"},"data":[{"instType":"SPOT","instId":"BTC-USDT","last":"65000","volCcy24h":"12345678.9"}]}"#;
+        let ticks = parse_spot_message(msg);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].price, Some(Decimal::from(65000)));
+        assert_eq!(ticks[0].vol_24h_usd, Some(12_345_678.9));
+    }
+
+    #[test]
+    fn test_okx_subscription_chunks() {
+        let inst_ids: Vec<String> = (0..10).map(|i| format!("C{}-USDT-SWAP", i)).collect();
+        let sub = subscription(
+            "wss://ws.okx.com:8443/ws/v5/public",
+            &PERP_CHANNELS,
+            &inst_ids,
+        );
+        // 3채널 x 10상품 = 30개 인자 -> 20개씩 2개 메시지
+        assert_eq!(sub.messages.len(), 2);
+        assert!(sub.messages.iter().all(|m| m.len() < 4096));
+        assert_eq!(sub.ping.as_deref(), Some("ping"));
+    }
+}
@@ -0,0 +1,512 @@
+//! 거래소별 요청 한도(weight) 관리
+//!
+//! 같은 프로세스의 모든 REST 호출(oracle 수집기, trade의 주문/조회 모듈)이
+//! `RateLimiter::global()` 하나를 공유한다. 요청 전에 예상 weight를 예약하고,
+//! 응답 헤더(`X-MBX-USED-WEIGHT-1M`, `X-MBX-ORDER-COUNT-10S`, `X-MBX-ORDER-COUNT-1M` 등)로 서버 기준 사용량을
+//! 맞추며, 429/418을 받으면 Retry-After 동안 해당 한도의 요청을 모두 멈춘다.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{header::HeaderMap, RequestBuilder, Response, StatusCode};
+use tracing::{debug, warn};
+
+use interface::{ExchangeError, ExchangeId};
+
+use crate::error::retry_after;
+
+/// 한도의 이 비율까지만 사용 (다른 프로세스와 같은 IP를 쓰는 여유분)
+const HEADROOM_PERCENT: u64 = 90;
+/// Retry-After가 없는 429의 기본 대기 시간
+const DEFAULT_RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(30);
+/// Retry-After가 없는 418(IP 밴)의 기본 대기 시간
+const DEFAULT_BAN_BACKOFF: Duration = Duration::from_secs(120);
+/// 차단이 이보다 길면 기다리지 않고 RateLimited 에러로 돌려준다
+const MAX_BLOCKED_WAIT: Duration = Duration::from_secs(30);
+
+static GLOBAL: OnceLock<RateLimiter> = OnceLock::new();
+
+/// 요청 종류 (Binance는 호스트와 주문 여부에 따라 한도가 따로 있다)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointClass {
+    /// 현물 REST (api.binance.com /api)
+    Spot,
+    /// 선물 REST (fapi.binance.com)
+    Futures,
+    /// 지갑/수수료 등 /sapi (IP weight 한도가 따로 있다)
+    Wallet,
+    /// 현물 주문 (weight와 별도로 10초당 주문 수 한도)
+    SpotOrder,
+    /// 선물 주문 (10초당 주문 수 한도)
+    FuturesOrder,
+    /// 선물 1분당 주문 수 한도 (FuturesOrder 요청이 함께 차감한다)
+    FuturesOrderMinute,
+}
+
+/// window 동안 허용되는 weight 합
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub limit: u32,
+    pub window: Duration,
+}
+
+impl Budget {
+    pub const fn new(limit: u32, window: Duration) -> Self {
+        Self { limit, window }
+    }
+}
+
+type BudgetKey = (ExchangeId, EndpointClass);
+
+#[derive(Debug)]
+struct BudgetState {
+    budget: Budget,
+    /// 시각을 window로 나눈 몫 (거래소 한도는 벽시계 기준 분/초 단위로 초기화된다)
+    window_id: u64,
+    used: u32,
+    blocked_until: Option<Instant>,
+}
+
+impl BudgetState {
+    fn new(budget: Budget) -> Self {
+        Self {
+            budget,
+            window_id: 0,
+            used: 0,
+            blocked_until: None,
+        }
+    }
+
+    fn window_ms(&self) -> u64 {
+        (self.budget.window.as_millis() as u64).max(1)
+    }
+
+    fn roll(&mut self, now_ms: u64) {
+        let window_id = now_ms / self.window_ms();
+        if window_id != self.window_id {
+            self.window_id = window_id;
+            self.used = 0;
+        }
+    }
+
+    /// weight를 예약할 수 있으면 None, 아니면 기다려야 할 시간
+    fn wait_time(&mut self, weight: u32, now: Instant, now_ms: u64) -> Option<Duration> {
+        if let Some(until) = self.blocked_until {
+            if until > now {
+                return Some(until - now);
+            }
+            self.blocked_until = None;
+        }
+        self.roll(now_ms);
+
+        let cap = (self.budget.limit as u64 * HEADROOM_PERCENT / 100).max(1) as u32;
+        // 새 window의 첫 요청은 한도보다 무거워도 보낸다
+        if self.used > 0 && self.used + weight > cap {
+            let window_ms = self.window_ms();
+            return Some(Duration::from_millis(window_ms - now_ms % window_ms));
+        }
+        None
+    }
+}
+
+/// 거래소/요청 종류별 weight 한도 관리자
+#[derive(Debug)]
+pub struct RateLimiter {
+    budgets: Mutex<HashMap<BudgetKey, BudgetState>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimiter {
+    /// 거래소 문서 기준 기본 한도
+    pub fn new() -> Self {
+        let defaults = [
+            (
+                (ExchangeId::Binance, EndpointClass::Spot),
+                Budget::new(6000, Duration::from_secs(60)),
+            ),
+            (
+                (ExchangeId::Binance, EndpointClass::Futures),
+                Budget::new(2400, Duration::from_secs(60)),
+            ),
+            (
+                (ExchangeId::Binance, EndpointClass::Wallet),
+                Budget::new(12000, Duration::from_secs(60)),
+            ),
+            (
+                (ExchangeId::Binance, EndpointClass::SpotOrder),
+                Budget::new(100, Duration::from_secs(10)),
+            ),
+            (
+                (ExchangeId::Binance, EndpointClass::FuturesOrder),
+                Budget::new(300, Duration::from_secs(10)),
+            ),
+            (
+                (ExchangeId::Binance, EndpointClass::FuturesOrderMinute),
+                Budget::new(1200, Duration::from_secs(60)),
+            ),
+            // Binance 외 거래소는 IP 단위 한도 하나로 관리 (Spot 키에 모은다)
+            (
+                (ExchangeId::Bybit, EndpointClass::Spot),
+                Budget::new(600, Duration::from_secs(5)),
+            ),
+            (
+                (ExchangeId::Okx, EndpointClass::Spot),
+                Budget::new(20, Duration::from_secs(2)),
+            ),
+            (
+                (ExchangeId::Bitget, EndpointClass::Spot),
+                Budget::new(20, Duration::from_secs(1)),
+            ),
+            (
+                (ExchangeId::Bithumb, EndpointClass::Spot),
+                Budget::new(135, Duration::from_secs(1)),
+            ),
+        ];
+        Self {
+            budgets: Mutex::new(
+                defaults
+                    .into_iter()
+                    .map(|(key, budget)| (key, BudgetState::new(budget)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// 프로세스 전체가 공유하는 인스턴스
+    pub fn global() -> &'static RateLimiter {
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// 한도 변경 (VIP 등급 등으로 한도가 다른 계정)
+    pub fn set_budget(&self, exchange: ExchangeId, class: EndpointClass, budget: Budget) {
+        let mut budgets = self.budgets.lock().unwrap();
+        budgets
+            .entry(budget_keys(exchange, class)[0])
+            .and_modify(|state| state.budget = budget)
+            .or_insert_with(|| BudgetState::new(budget));
+    }
+
+    /// weight를 예약할 때까지 대기 (차단이 길면 RateLimited 에러)
+    pub async fn acquire(
+        &self,
+        exchange: ExchangeId,
+        class: EndpointClass,
+        weight: u32,
+    ) -> Result<(), ExchangeError> {
+        loop {
+            let Some(wait) = self.try_acquire(exchange, class, weight) else {
+                return Ok(());
+            };
+            // window가 바뀌길 기다리는 건 괜찮지만, 긴 차단(밴)은 호출자에게 넘긴다
+            if wait > MAX_BLOCKED_WAIT && self.is_blocked(exchange, class, MAX_BLOCKED_WAIT) {
+                return Err(ExchangeError::RateLimited {
+                    retry_after: Some(wait),
+                });
+            }
+            debug!(
+                "{:?} {:?} 요청 한도 대기: {:?} (weight {})",
+                exchange, class, wait, weight
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// 관련 한도 중 하나라도 `min` 이상 차단되어 있는지
+    fn is_blocked(&self, exchange: ExchangeId, class: EndpointClass, min: Duration) -> bool {
+        let now = Instant::now();
+        let budgets = self.budgets.lock().unwrap();
+        budget_keys(exchange, class).iter().any(|key| {
+            budgets
+                .get(key)
+                .and_then(|state| state.blocked_until)
+                .is_some_and(|until| until > now + min)
+        })
+    }
+
+    /// 예약에 성공하면 None, 아니면 기다려야 할 시간 (관련 한도를 모두 통과해야 예약한다)
+    fn try_acquire(
+        &self,
+        exchange: ExchangeId,
+        class: EndpointClass,
+        weight: u32,
+    ) -> Option<Duration> {
+        let now = Instant::now();
+        let now_ms = unix_millis();
+        let mut budgets = self.budgets.lock().unwrap();
+        let keys = budget_keys(exchange, class);
+
+        let mut wait = None;
+        for (i, key) in keys.iter().enumerate() {
+            let cost = key_weight(i, weight);
+            if let Some(state) = budgets.get_mut(key) {
+                wait = wait.max(state.wait_time(cost, now, now_ms));
+            }
+        }
+        if wait.is_some() {
+            return wait;
+        }
+
+        for (i, key) in keys.iter().enumerate() {
+            if let Some(state) = budgets.get_mut(key) {
+                state.used += key_weight(i, weight);
+            }
+        }
+        None
+    }
+
+    /// 응답의 사용량 헤더와 429/418 반영
+    pub fn record_response(
+        &self,
+        exchange: ExchangeId,
+        class: EndpointClass,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) {
+        let now = Instant::now();
+        let now_ms = unix_millis();
+        let mut budgets = self.budgets.lock().unwrap();
+        let keys = budget_keys(exchange, class);
+
+        for (key, header) in used_weight_headers(exchange, class) {
+            let used = headers
+                .get(header)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u32>().ok());
+            if let (Some(used), Some(state)) = (used, budgets.get_mut(&key)) {
+                // 같은 IP를 쓰는 다른 프로세스 사용량까지 포함된 서버 값이 더 크면 따른다
+                state.roll(now_ms);
+                state.used = state.used.max(used);
+            }
+        }
+
+        let backoff = match status.as_u16() {
+            429 => retry_after(headers).unwrap_or(DEFAULT_RATE_LIMIT_BACKOFF),
+            418 => retry_after(headers).unwrap_or(DEFAULT_BAN_BACKOFF),
+            _ => return,
+        };
+        warn!(
+            "{:?} {:?} 요청 한도 초과 (HTTP {}), {:?} 동안 요청 중지",
+            exchange, class, status, backoff
+        );
+        for key in keys {
+            if let Some(state) = budgets.get_mut(&key) {
+                let until = now + backoff;
+                state.blocked_until = Some(state.blocked_until.map_or(until, |u| u.max(until)));
+            }
+        }
+    }
+
+    /// 한도를 예약하고 요청을 보낸 뒤 응답 헤더를 반영
+    pub async fn send(
+        &self,
+        exchange: ExchangeId,
+        class: EndpointClass,
+        weight: u32,
+        request: RequestBuilder,
+    ) -> Result<Response, ExchangeError> {
+        self.acquire(exchange, class, weight).await?;
+        let response = request.send().await?;
+        self.record_response(exchange, class, response.status(), response.headers());
+        Ok(response)
+    }
+}
+
+/// 요청 하나가 차감되는 한도 목록 (주문은 호스트 weight와 주문 수 한도를 함께 쓴다)
+fn budget_keys(exchange: ExchangeId, class: EndpointClass) -> Vec<BudgetKey> {
+    match (exchange, class) {
+        (ExchangeId::Binance, EndpointClass::SpotOrder) => vec![
+            (exchange, EndpointClass::Spot),
+            (exchange, EndpointClass::SpotOrder),
+        ],
+        (ExchangeId::Binance, EndpointClass::FuturesOrder) => vec![
+            (exchange, EndpointClass::Futures),
+            (exchange, EndpointClass::FuturesOrder),
+            (exchange, EndpointClass::FuturesOrderMinute),
+        ],
+        (ExchangeId::Binance, class) => vec![(exchange, class)],
+        _ => vec![(exchange, EndpointClass::Spot)],
+    }
+}
+
+/// budget_keys의 i번째 한도에서 차감할 weight (주문 수 한도는 요청당 1)
+fn key_weight(index: usize, weight: u32) -> u32 {
+    if index == 0 {
+        weight
+    } else {
+        1
+    }
+}
+
+/// 서버가 알려주는 사용량 헤더 (Binance만)
+fn used_weight_headers(
+    exchange: ExchangeId,
+    class: EndpointClass,
+) -> Vec<(BudgetKey, &'static str)> {
+    if exchange != ExchangeId::Binance {
+        return Vec::new();
+    }
+    let mut headers = Vec::new();
+    match class {
+        EndpointClass::Wallet => {
+            headers.push((
+                (exchange, EndpointClass::Wallet),
+                "x-sapi-used-ip-weight-1m",
+            ));
+        }
+        EndpointClass::Spot | EndpointClass::SpotOrder => {
+            headers.push(((exchange, EndpointClass::Spot), "x-mbx-used-weight-1m"));
+        }
+        EndpointClass::Futures
+        | EndpointClass::FuturesOrder
+        | EndpointClass::FuturesOrderMinute => {
+            headers.push(((exchange, EndpointClass::Futures), "x-mbx-used-weight-1m"));
+        }
+    }
+    match class {
+        EndpointClass::SpotOrder => {
+            headers.push((
+                (exchange, EndpointClass::SpotOrder),
+                "x-mbx-order-count-10s",
+            ));
+        }
+        EndpointClass::FuturesOrder => {
+            headers.push((
+                (exchange, EndpointClass::FuturesOrder),
+                "x-mbx-order-count-10s",
+            ));
+            headers.push((
+                (exchange, EndpointClass::FuturesOrderMinute),
+                "x-mbx-order-count-1m",
+            ));
+        }
+        _ => {}
+    }
+    headers
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+    #[test]
+    fn test_budget_reservation() {
+        let limiter = RateLimiter::new();
+        // 10분 window라 테스트 중 초기화되지 않는다 (한도 100, 여유분 제외 90)
+        limiter.set_budget(
+            ExchangeId::Binance,
+            EndpointClass::Spot,
+            Budget::new(100, Duration::from_secs(600)),
+        );
+
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::Spot, 80)
+            .is_none());
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::Spot, 10)
+            .is_none());
+        let wait = limiter.try_acquire(ExchangeId::Binance, EndpointClass::Spot, 1);
+        assert!(wait.is_some_and(|w| w <= Duration::from_secs(600)));
+
+        // 선물은 별도 한도
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::Futures, 1)
+            .is_none());
+        // 현물 주문은 현물 weight 한도도 함께 쓴다
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::SpotOrder, 1)
+            .is_some());
+    }
+
+    #[test]
+    fn test_used_weight_header_and_ban() {
+        let limiter = RateLimiter::new();
+        limiter.set_budget(
+            ExchangeId::Binance,
+            EndpointClass::Futures,
+            Budget::new(100, Duration::from_secs(600)),
+        );
+
+        // 서버가 알려준 사용량이 로컬 예약보다 크면 서버 값을 따른다
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("95"));
+        limiter.record_response(
+            ExchangeId::Binance,
+            EndpointClass::Futures,
+            StatusCode::OK,
+            &headers,
+        );
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::Futures, 1)
+            .is_some());
+
+        // 418은 Retry-After 동안 같은 호스트 요청을 모두 막는다
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("300"));
+        limiter.record_response(
+            ExchangeId::Binance,
+            EndpointClass::SpotOrder,
+            StatusCode::IM_A_TEAPOT,
+            &headers,
+        );
+        let wait = limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::Spot, 1)
+            .unwrap();
+        assert!(wait > MAX_BLOCKED_WAIT);
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let result = rt.block_on(limiter.acquire(ExchangeId::Binance, EndpointClass::Spot, 1));
+        assert!(matches!(result, Err(ExchangeError::RateLimited { .. })));
+
+        // 다른 거래소에는 영향이 없다
+        assert!(limiter
+            .try_acquire(ExchangeId::Bybit, EndpointClass::Futures, 1)
+            .is_none());
+    }
+
+    #[test]
+    fn test_futures_order_count_minute() {
+        let limiter = RateLimiter::new();
+        limiter.set_budget(
+            ExchangeId::Binance,
+            EndpointClass::FuturesOrderMinute,
+            Budget::new(100, Duration::from_secs(600)),
+        );
+
+        // 10초 한도가 남아 있어도 1분 주문 수 헤더가 한도에 닿으면 주문을 막는다
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-order-count-10s", HeaderValue::from_static("1"));
+        headers.insert("x-mbx-order-count-1m", HeaderValue::from_static("95"));
+        limiter.record_response(
+            ExchangeId::Binance,
+            EndpointClass::FuturesOrder,
+            StatusCode::OK,
+            &headers,
+        );
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::FuturesOrder, 1)
+            .is_some());
+        // 주문이 아닌 선물 요청은 영향 없음
+        assert!(limiter
+            .try_acquire(ExchangeId::Binance, EndpointClass::Futures, 1)
+            .is_none());
+    }
+}
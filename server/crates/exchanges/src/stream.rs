@@ -0,0 +1,167 @@
+//! WebSocket 시세 스트림 공통 처리
+//!
+//! 거래소별 구현은 접속 정보(`WsSubscription`)와 메시지 파서만 만들고,
+//! 연결/구독/ping/재연결은 `stream_forever`가 맡는다.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use interface::ExchangeError;
+
+/// ping 전송 간격 (가장 짧은 OKX 30초 제한보다 짧게)
+const PING_INTERVAL: Duration = Duration::from_secs(20);
+/// 이 시간 동안 아무 메시지도 없으면 끊긴 연결로 보고 재연결
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+/// 구독 메시지 사이 간격 (거래소별 초당 요청 제한 회피)
+const SUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+/// 재연결 대기 시간 상한
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// 한 번의 WebSocket 연결에 필요한 정보
+#[derive(Debug, Clone)]
+pub struct WsSubscription {
+    pub url: String,
+    /// 연결 직후 순서대로 보낼 구독 메시지
+    pub messages: Vec<String>,
+    /// 거래소가 요구하는 텍스트 ping (예: OKX "ping"), None이면 WebSocket Ping 프레임
+    pub ping: Option<String>,
+}
+
+/// 구독 인자를 chunk_size개씩 나눠 구독 메시지 목록 생성
+/// (거래소마다 메시지 하나에 넣을 수 있는 인자 수 제한이 있다)
+pub(crate) fn subscribe_messages<T: Serialize>(
+    args: &[T],
+    chunk_size: usize,
+    wrap: impl Fn(&[T]) -> serde_json::Value,
+) -> Vec<String> {
+    args.chunks(chunk_size)
+        .map(|chunk| wrap(chunk).to_string())
+        .collect()
+}
+
+enum SessionEnd {
+    Disconnected,
+    ReceiverClosed,
+}
+
+fn ws_error(e: tokio_tungstenite::tungstenite::Error) -> ExchangeError {
+    ExchangeError::Other(format!("WebSocket error: {}", e))
+}
+
+/// 연결 하나를 끊길 때까지 처리
+async fn run_session<T, P>(
+    subscription: &WsSubscription,
+    tx: &mpsc::Sender<T>,
+    parse: &mut P,
+) -> Result<SessionEnd, ExchangeError>
+where
+    T: Send,
+    P: FnMut(&str) -> Vec<T> + Send,
+{
+    let (ws_stream, _) = connect_async(subscription.url.as_str())
+        .await
+        .map_err(ws_error)?;
+    let (mut write, mut read) = ws_stream.split();
+
+    for msg in &subscription.messages {
+        write
+            .send(Message::Text(msg.clone()))
+            .await
+            .map_err(ws_error)?;
+        tokio::time::sleep(SUBSCRIBE_DELAY).await;
+    }
+
+    let mut ping = tokio::time::interval(PING_INTERVAL);
+    let mut last_message = Instant::now();
+
+    loop {
+        tokio::select! {
+            _ = ping.tick() => {
+                if last_message.elapsed() > IDLE_TIMEOUT {
+                    return Err(ExchangeError::Timeout(format!(
+                        "no WebSocket message for {:?}",
+                        IDLE_TIMEOUT
+                    )));
+                }
+                let msg = match &subscription.ping {
+                    Some(text) => Message::Text(text.clone()),
+                    None => Message::Ping(Vec::new()),
+                };
+                write.send(msg).await.map_err(ws_error)?;
+            }
+            msg = read.next() => {
+                let Some(msg) = msg else {
+                    return Ok(SessionEnd::Disconnected);
+                };
+                last_message = Instant::now();
+                match msg.map_err(ws_error)? {
+                    Message::Text(text) => {
+                        for item in parse(&text) {
+                            if tx.send(item).await.is_err() {
+                                return Ok(SessionEnd::ReceiverClosed);
+                            }
+                        }
+                    }
+                    Message::Close(_) => return Ok(SessionEnd::Disconnected),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// 연결이 끊기면 다시 접속/구독하며 tx의 수신 측이 닫힐 때까지 스트림을 유지한다.
+/// connect는 접속할 때마다 호출되어 구독 정보와 메시지 파서를 만든다
+/// (상장 심볼 목록이 바뀌어도 재연결 시 반영된다).
+pub async fn stream_forever<T, F, Fut, P>(
+    name: &str,
+    tx: mpsc::Sender<T>,
+    mut connect: F,
+) -> Result<(), ExchangeError>
+where
+    T: Send,
+    F: FnMut() -> Fut + Send,
+    Fut: Future<Output = Result<(WsSubscription, P), ExchangeError>> + Send,
+    P: FnMut(&str) -> Vec<T> + Send,
+{
+    let mut backoff = Duration::from_secs(1);
+
+    while !tx.is_closed() {
+        let started = Instant::now();
+        let result = match connect().await {
+            Ok((subscription, mut parse)) => {
+                tracing::info!(
+                    "{} WebSocket 연결: {}개 구독 메시지",
+                    name,
+                    subscription.messages.len()
+                );
+                run_session(&subscription, &tx, &mut parse).await
+            }
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(SessionEnd::ReceiverClosed) => break,
+            Ok(SessionEnd::Disconnected) => {
+                tracing::warn!("{} WebSocket 연결이 종료되었습니다. 재연결 시도...", name);
+            }
+            Err(e) => {
+                tracing::error!("{} WebSocket 오류: {:?}. 재연결 시도...", name, e);
+            }
+        }
+
+        // 한동안 잘 유지된 연결이었으면 대기 시간을 초기화
+        if started.elapsed() > MAX_BACKOFF {
+            backoff = Duration::from_secs(1);
+        }
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+
+    Ok(())
+}
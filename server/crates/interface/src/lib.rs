@@ -1,4 +1,8 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
+pub use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -11,6 +15,22 @@ pub enum ExchangeId {
     Bithumb,
 }
 
+impl std::str::FromStr for ExchangeId {
+    type Err = String;
+
+    /// 대소문자 구분 없이 거래소 이름 파싱 (예: "binance", "OKX")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "binance" => Ok(Self::Binance),
+            "bybit" => Ok(Self::Bybit),
+            "okx" => Ok(Self::Okx),
+            "bitget" => Ok(Self::Bitget),
+            "bithumb" => Ok(Self::Bithumb),
+            _ => Err(format!("Unknown exchange: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Currency {
     USD,
@@ -18,33 +38,334 @@ pub enum Currency {
     USDT,
 }
 
+/// 상품 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InstrumentKind {
+    Spot,     // 현물
+    Perp,     // 무기한 선물
+    Delivery, // 만기 선물
+}
+
+/// 거래소 공통 상품 식별자
+///
+/// 거래소마다 다른 심볼 표기("BTCUSDT", "BTC-USDT-SWAP", "BTC_KRW" 등)를
+/// base/quote/kind 로 정규화한다. 거래소별 변환은 `exchanges::instrument` 참고.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Instrument {
+    pub base: String,  // 정규화된 베이스 자산 (예: "BTC", "PEPE")
+    pub quote: String, // 견적 자산 (예: "USDT", "KRW")
+    pub kind: InstrumentKind,
+    pub exchange: ExchangeId,
+    pub native_symbol: String, // 거래소 원본 심볼 (예: "1000PEPEUSDT")
+    pub multiplier: u32,       // native 수량 1 = base 몇 개인지 (예: 1000PEPE -> 1000)
+}
+
+impl Instrument {
+    /// base 앞의 수량 배수 접두어("1000PEPE", "1000000MOG")를 분리해 Instrument 생성
+    pub fn new(
+        exchange: ExchangeId,
+        kind: InstrumentKind,
+        base: &str,
+        quote: &str,
+        native_symbol: &str,
+    ) -> Self {
+        let (base, multiplier) = split_multiplier(&base.to_uppercase());
+        Self {
+            base,
+            quote: quote.to_uppercase(),
+            kind,
+            exchange,
+            native_symbol: native_symbol.to_string(),
+            multiplier,
+        }
+    }
+
+    /// 거래소 간 공통 심볼 (예: "BTCUSDT", "PEPEUSDT", "BTCKRW")
+    pub fn symbol(&self) -> String {
+        format!("{}{}", self.base, self.quote)
+    }
+
+    /// native 가격(배수 단위)을 base 1개 기준 가격으로 변환
+    pub fn unit_price(&self, native_price: Decimal) -> Decimal {
+        native_price / Decimal::from(self.multiplier)
+    }
+}
+
+impl std::fmt::Display for Instrument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?}:{}/{}:{:?}",
+            self.exchange, self.base, self.quote, self.kind
+        )
+    }
+}
+
+/// "1000PEPE" -> ("PEPE", 1000), "1MBABYDOGE" -> ("BABYDOGE", 1000000).
+/// 10의 거듭제곱(1000 이상) 접두어와 100만 배를 뜻하는 "1M" 접두어만 배수로 본다.
+/// "1INCH" 처럼 숫자로 시작하는 실제 토큰명은 그대로 둔다.
+fn split_multiplier(base: &str) -> (String, u32) {
+    if let Some(rest) = base.strip_prefix("1M") {
+        if rest.len() >= 3 && rest.chars().all(|c| c.is_ascii_alphabetic()) {
+            return (rest.to_string(), 1_000_000);
+        }
+    }
+    let digits = base.chars().take_while(|c| c.is_ascii_digit()).count();
+    let (prefix, rest) = base.split_at(digits);
+    if digits < 4 || rest.is_empty() || !prefix.starts_with('1') {
+        return (base.to_string(), 1);
+    }
+    if !prefix[1..].chars().all(|c| c == '0') {
+        return (base.to_string(), 1);
+    }
+    match prefix.parse::<u32>() {
+        Ok(multiplier) => (rest.to_string(), multiplier),
+        Err(_) => (base.to_string(), 1),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerpSnapshot {
     pub exchange: ExchangeId,
-    pub symbol: String,
+    pub symbol: String, // 공통 심볼 (instrument.symbol())
+    pub instrument: Instrument,
     pub currency: Currency,
-    pub mark_price: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub mark_price: Decimal,
     pub oi_usd: f64,
     pub vol_24h_usd: f64,
     pub funding_rate: f64, // 0.01 == 1%
     pub next_funding_time: Option<DateTime<Utc>>,
+    // 펀딩 주기 (시간, 모르면 None)
+    #[serde(default)]
+    pub funding_interval_hours: Option<u32>,
+    // 다음 주기 예상 펀딩비 (거래소가 따로 주는 경우만)
+    #[serde(default)]
+    pub predicted_funding_rate: Option<f64>,
+    // 인덱스 가격 (native 단위, 모르면 None)
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub index_price: Option<Decimal>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl PerpSnapshot {
+    /// 첫 변경분으로 스냅샷 생성 (mark price가 아직 없으면 None)
+    pub fn from_tick(tick: &PerpTick) -> Option<Self> {
+        Some(Self {
+            exchange: tick.exchange,
+            symbol: tick.instrument.symbol(),
+            instrument: tick.instrument.clone(),
+            currency: tick.currency,
+            mark_price: tick.mark_price?,
+            oi_usd: tick.oi_usd.unwrap_or(0.0),
+            vol_24h_usd: tick.vol_24h_usd.unwrap_or(0.0),
+            funding_rate: tick.funding_rate.unwrap_or(0.0),
+            next_funding_time: tick.next_funding_time,
+            funding_interval_hours: tick.funding_interval_hours,
+            predicted_funding_rate: tick.predicted_funding_rate,
+            index_price: tick.index_price,
+            updated_at: tick.updated_at,
+        })
+    }
+
+    /// 같은 상품의 변경분 반영 (tick에 없는 필드는 기존 값 유지)
+    pub fn apply(&mut self, tick: &PerpTick) {
+        if let Some(v) = tick.mark_price {
+            self.mark_price = v;
+        }
+        if let Some(v) = tick.oi_usd {
+            self.oi_usd = v;
+        }
+        if let Some(v) = tick.vol_24h_usd {
+            self.vol_24h_usd = v;
+        }
+        if let Some(v) = tick.funding_rate {
+            self.funding_rate = v;
+        }
+        if tick.next_funding_time.is_some() {
+            self.next_funding_time = tick.next_funding_time;
+        }
+        if tick.funding_interval_hours.is_some() {
+            self.funding_interval_hours = tick.funding_interval_hours;
+        }
+        if tick.predicted_funding_rate.is_some() {
+            self.predicted_funding_rate = tick.predicted_funding_rate;
+        }
+        if tick.index_price.is_some() {
+            self.index_price = tick.index_price;
+        }
+        self.updated_at = tick.updated_at;
+    }
+}
+
+/// WebSocket으로 들어오는 무기한 선물 시세 변경분 (None인 필드는 이번 메시지에 없음)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerpTick {
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+    pub currency: Currency,
+    pub mark_price: Option<Decimal>,
+    pub oi_usd: Option<f64>,
+    pub vol_24h_usd: Option<f64>,
+    pub funding_rate: Option<f64>,
+    pub next_funding_time: Option<DateTime<Utc>>,
+    pub funding_interval_hours: Option<u32>,
+    pub predicted_funding_rate: Option<f64>,
+    pub index_price: Option<Decimal>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PerpTick {
+    pub fn new(instrument: Instrument, currency: Currency) -> Self {
+        Self {
+            exchange: instrument.exchange,
+            instrument,
+            currency,
+            mark_price: None,
+            oi_usd: None,
+            vol_24h_usd: None,
+            funding_rate: None,
+            next_funding_time: None,
+            funding_interval_hours: None,
+            predicted_funding_rate: None,
+            index_price: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// 무기한 선물 펀딩비 정산 기록 (과거 히스토리)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingRecord {
+    pub exchange: ExchangeId,
+    pub symbol: String, // 거래소 원본 심볼 (예: "BTCUSDT", "BTC-USDT-SWAP")
+    pub funding_time: DateTime<Utc>,
+    pub funding_rate: f64,           // 실제 정산된 펀딩비, 0.01 == 1%
+    pub funding_interval_hours: u32, // 이 정산까지의 펀딩 주기 (시간)
+}
+
+/// 캔들 간격
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KlineInterval {
+    #[serde(rename = "1m")]
+    M1,
+    #[serde(rename = "5m")]
+    M5,
+    #[serde(rename = "15m")]
+    M15,
+    #[serde(rename = "30m")]
+    M30,
+    #[serde(rename = "1h")]
+    H1,
+    #[serde(rename = "4h")]
+    H4,
+    #[serde(rename = "1d")]
+    D1,
+}
+
+impl KlineInterval {
+    /// 캔들 하나의 길이
+    pub fn duration(&self) -> chrono::Duration {
+        match self {
+            Self::M1 => chrono::Duration::minutes(1),
+            Self::M5 => chrono::Duration::minutes(5),
+            Self::M15 => chrono::Duration::minutes(15),
+            Self::M30 => chrono::Duration::minutes(30),
+            Self::H1 => chrono::Duration::hours(1),
+            Self::H4 => chrono::Duration::hours(4),
+            Self::D1 => chrono::Duration::days(1),
+        }
+    }
+}
+
+/// 거래소 공통 OHLCV 캔들 (volume은 base 수량)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Candle {
+    pub exchange: ExchangeId,
+    pub symbol: String, // 거래소 원본 심볼
+    pub kind: InstrumentKind,
+    pub interval: KlineInterval,
+    pub open_time: DateTime<Utc>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub open: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub high: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub low: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub close: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub volume: Decimal,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotSnapshot {
     pub exchange: ExchangeId,
-    pub symbol: String,
+    pub symbol: String, // 공통 심볼 (instrument.symbol())
+    pub instrument: Instrument,
     pub currency: Currency,
-    pub price: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub price: Decimal,
     pub vol_24h_usd: f64,
     pub updated_at: DateTime<Utc>,
 }
 
+impl SpotSnapshot {
+    /// 첫 변경분으로 스냅샷 생성 (가격이 아직 없으면 None)
+    pub fn from_tick(tick: &SpotTick) -> Option<Self> {
+        Some(Self {
+            exchange: tick.exchange,
+            symbol: tick.instrument.symbol(),
+            instrument: tick.instrument.clone(),
+            currency: tick.currency,
+            price: tick.price?,
+            vol_24h_usd: tick.vol_24h_usd.unwrap_or(0.0),
+            updated_at: tick.updated_at,
+        })
+    }
+
+    /// 같은 상품의 변경분 반영 (tick에 없는 필드는 기존 값 유지)
+    pub fn apply(&mut self, tick: &SpotTick) {
+        if let Some(v) = tick.price {
+            self.price = v;
+        }
+        if let Some(v) = tick.vol_24h_usd {
+            self.vol_24h_usd = v;
+        }
+        self.updated_at = tick.updated_at;
+    }
+}
+
+/// WebSocket으로 들어오는 현물 시세 변경분 (None인 필드는 이번 메시지에 없음)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpotTick {
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+    pub currency: Currency,
+    pub price: Option<Decimal>,
+    pub vol_24h_usd: Option<f64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SpotTick {
+    pub fn new(instrument: Instrument, currency: Currency) -> Self {
+        Self {
+            exchange: instrument.exchange,
+            instrument,
+            currency,
+            price: None,
+            vol_24h_usd: None,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnifiedSnapshot {
     pub exchange: ExchangeId,
-    pub symbol: String,
+    pub symbol: String, // 공통 심볼 (base + quote)
+    pub base: String,
+    pub quote: String,
     pub currency: Currency,
     // 선물 데이터
     pub perp: Option<PerpData>,
@@ -57,42 +378,124 @@ pub struct UnifiedSnapshot {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeRates {
-    pub usd_krw: f64,  // 1 USD = ? KRW (예: 1300.0)
-    pub usdt_usd: f64, // 1 USDT = ? USD (보통 1.0)
-    pub usdt_krw: f64, // 1 USDT = ? KRW (예: 1300.0)
-    pub updated_at: DateTime<Utc>,
+    pub usd_krw: f64,              // 1 USD = ? KRW (예: 1300.0)
+    pub usdt_usd: f64,             // 1 USDT = ? USD (보통 1.0)
+    pub usdt_krw: f64,             // 1 USDT = ? KRW (예: 1300.0)
+    pub updated_at: DateTime<Utc>, // 세 환율 중 가장 오래된 집계 시각
+    // 환율별로 집계에 쓰인 시세 수 (0이면 이전 값 또는 기본값)
+    #[serde(default)]
+    pub sources: ExchangeRateSources,
+    // 하나라도 실제 시세 없이 기본값/오래된 값을 쓰고 있으면 true (이 환율로 주문 수량을 잡으면 안 된다)
+    #[serde(default)]
+    pub is_fallback: bool,
+    // 소스끼리 크게 어긋나 중앙값 대신 마지막 값에 가장 가까운 시세 하나를 쓴 환율이 있으면 true
+    #[serde(default)]
+    pub degraded: bool,
+    // updated_at 이후 지난 시간 (ms, 집계/응답 시점 기준으로 refresh_age가 채운다)
+    #[serde(default)]
+    pub age_ms: i64,
+}
+
+impl ExchangeRates {
+    /// age_ms를 현재 시각 기준으로 다시 계산
+    pub fn refresh_age(&mut self, now: DateTime<Utc>) {
+        self.age_ms = (now - self.updated_at).num_milliseconds().max(0);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExchangeRateSources {
+    pub usd_krw: usize,
+    pub usdt_usd: usize,
+    pub usdt_krw: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerpData {
+    pub instrument: Instrument,
     pub currency: Currency,
-    pub mark_price: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub mark_price: Decimal, // base 1개 기준 (instrument.multiplier 반영)
     pub oi_usd: f64,
     pub vol_24h_usd: f64,
     pub funding_rate: f64, // 0.01 == 1%
     pub next_funding_time: Option<DateTime<Utc>>,
+    // 펀딩 주기 (시간, 모르면 None)
+    #[serde(default)]
+    pub funding_interval_hours: Option<u32>,
+    // 다음 주기 예상 펀딩비 (거래소가 따로 주는 경우만)
+    #[serde(default)]
+    pub predicted_funding_rate: Option<f64>,
+    // 인덱스 가격 (base 1개 기준)
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub index_price: Option<Decimal>,
+    // 프리미엄 인덱스: mark / index - 1 (인덱스 가격이 없으면 None)
+    #[serde(default)]
+    pub premium_index: Option<f64>,
+}
+
+impl PerpData {
+    /// 펀딩 주기를 모를 때 가정하는 주기 (대부분 거래소 8시간)
+    pub const DEFAULT_FUNDING_INTERVAL_HOURS: u32 = 8;
+
+    /// 실제 펀딩 주기 (모르면 8시간)
+    pub fn funding_interval_hours_or_default(&self) -> u32 {
+        self.funding_interval_hours
+            .filter(|h| *h > 0)
+            .unwrap_or(Self::DEFAULT_FUNDING_INTERVAL_HOURS)
+    }
+
+    /// 시간당 펀딩비 (주기가 다른 거래소끼리 비교할 때)
+    pub fn hourly_funding_rate(&self) -> f64 {
+        self.funding_rate / self.funding_interval_hours_or_default() as f64
+    }
+
+    /// 연율 환산 펀딩비 (단리, 0.1 == 연 10%)
+    pub fn annualized_funding_rate(&self) -> f64 {
+        self.hourly_funding_rate() * 24.0 * 365.0
+    }
+}
+
+/// mark / index - 1 (인덱스 가격이 0이거나 없으면 None)
+pub fn premium_index(mark_price: Decimal, index_price: Option<Decimal>) -> Option<f64> {
+    let index = index_price.filter(|p| !p.is_zero())?;
+    (mark_price / index - Decimal::ONE).try_into().ok()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotData {
+    pub instrument: Instrument,
     pub currency: Currency,
-    pub price: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub price: Decimal,
     pub vol_24h_usd: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotAsset {
     pub currency: String,
-    pub total: f64,     // 총 보유량
-    pub available: f64, // 사용 가능한 잔액
-    pub in_use: f64,    // 주문에 사용 중인 잔액 (locked)
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total: Decimal, // 총 보유량
+    #[serde(with = "rust_decimal::serde::float")]
+    pub available: Decimal, // 사용 가능한 잔액
+    #[serde(with = "rust_decimal::serde::float")]
+    pub in_use: Decimal, // 주문에 사용 중인 잔액 (locked)
     pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FutureAsset {
     pub symbol: String,
-    pub position_amt: f64, // 양수면 롱, 음수면 숏
+    #[serde(with = "rust_decimal::serde::float")]
+    pub position_amt: Decimal, // 양수면 롱, 음수면 숏 (base 수량)
+    #[serde(with = "rust_decimal::serde::float")]
+    pub entry_price: Decimal, // 평균 진입가
+    #[serde(with = "rust_decimal::serde::float")]
+    pub unrealized_pnl: Decimal, // 미실현 손익 (증거금 통화 기준)
+    #[serde(with = "rust_decimal::serde::float")]
+    pub leverage: Decimal,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub liquidation_price: Option<Decimal>, // 청산 위험이 없으면 None
     pub updated_at: DateTime<Utc>,
 }
 
@@ -104,6 +507,7 @@ pub type Asset = SpotAsset;
 pub struct OrderBook {
     pub exchange: ExchangeId,
     pub symbol: String,
+    pub kind: InstrumentKind,      // 현물/무기한 호가 구분
     pub bids: Vec<OrderBookEntry>, // 매수 주문 (가격 높은 순)
     pub asks: Vec<OrderBookEntry>, // 매도 주문 (가격 낮은 순)
     pub updated_at: DateTime<Utc>,
@@ -111,8 +515,10 @@ pub struct OrderBook {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBookEntry {
-    pub price: f64,
-    pub quantity: f64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub price: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub quantity: Decimal,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -123,38 +529,609 @@ pub enum MarketType {
     Other(String), // 기타 마켓
 }
 
+impl MarketType {
+    /// quote 통화로 마켓 타입 결정 (예: "KRW" -> KRW)
+    pub fn from_quote(quote: &str) -> Self {
+        match quote.to_uppercase().as_str() {
+            "KRW" => MarketType::KRW,
+            "USDT" => MarketType::USDT,
+            "BTC" => MarketType::BTC,
+            other => MarketType::Other(other.to_string()),
+        }
+    }
+}
+
+/// 거래 수수료 할인 (예: Binance BNB 차감)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeDiscount {
+    pub asset: String, // 수수료를 대신 지불하는 자산 (예: "BNB")
+    pub rate: f64,     // 할인율 (예: 0.25 = 25% 할인)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeeInfo {
-    pub maker: f64, // 메이커 수수료 (예: 0.0004 = 0.04%)
+    pub maker: f64, // 메이커 수수료 (예: 0.0004 = 0.04%, 음수면 리베이트)
     pub taker: f64, // 테이커 수수료 (예: 0.0004 = 0.04%)
+    #[serde(default)]
+    pub tier: Option<String>, // VIP 등급 (거래소가 알려주는 경우만, 예: "Lv1")
+    #[serde(default)]
+    pub discount: Option<FeeDiscount>, // 계정에서 켜져 있는 할인
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DepositWithdrawalFee {
     pub currency: String,
-    pub deposit_fee: f64,    // 입금 수수료
-    pub withdrawal_fee: f64, // 출금 수수료
+    #[serde(with = "rust_decimal::serde::float")]
+    pub deposit_fee: Decimal, // 입금 수수료
+    #[serde(with = "rust_decimal::serde::float")]
+    pub withdrawal_fee: Decimal, // 출금 수수료
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 코인 하나의 네트워크별 입출금 상태
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletStatus {
+    pub exchange: ExchangeId,
+    pub currency: String,
+    pub networks: Vec<NetworkStatus>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkStatus {
+    pub network: String, // 거래소 표기 네트워크 이름 (예: "ETH", "TRX")
+    pub deposit_enabled: bool,
+    pub withdraw_enabled: bool,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub deposit_fee: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub withdraw_fee: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub deposit_min: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub withdraw_min: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub withdraw_max: Option<Decimal>,
+}
+
+impl WalletStatus {
+    /// 입금 가능한 네트워크가 하나라도 있는지
+    pub fn can_deposit(&self) -> bool {
+        self.networks.iter().any(|n| n.deposit_enabled)
+    }
+
+    /// 출금 가능한 네트워크가 하나라도 있는지
+    pub fn can_withdraw(&self) -> bool {
+        self.networks.iter().any(|n| n.withdraw_enabled)
+    }
+}
+
+/// 출금 요청 (주소는 화이트리스트에서 가져온 값만 사용할 것)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WithdrawRequest {
+    pub currency: String,
+    pub network: String, // 거래소 표기 네트워크 이름 (WalletStatus의 network와 같은 값)
+    pub address: String,
+    pub memo: Option<String>, // XRP destination tag, EOS memo 등 2차 주소
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    /// 거래소별 추가 파라미터 (예: Bithumb 트래블룰 수취인 정보 exchange_name, receiver_type)
+    #[serde(default)]
+    pub extra: BTreeMap<String, String>,
+    /// 클라이언트가 정한 출금 ID (Binance withdrawOrderId). 응답을 못 받았을 때 이 값으로 접수 여부를 확인한다
+    #[serde(default)]
+    pub client_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransferDirection {
+    Deposit,
+    Withdrawal,
+}
+
+/// 입출금 진행 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransferStatus {
+    Pending,    // 접수됨 (이메일 확인, 심사 대기 등)
+    Processing, // 블록체인 전송 중
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl TransferDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransferDirection::Deposit => "DEPOSIT",
+            TransferDirection::Withdrawal => "WITHDRAWAL",
+        }
+    }
+}
+
+impl std::str::FromStr for TransferDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "DEPOSIT" => Ok(Self::Deposit),
+            "WITHDRAWAL" => Ok(Self::Withdrawal),
+            _ => Err(format!("Invalid TransferDirection: {}", s)),
+        }
+    }
+}
+
+impl TransferStatus {
+    /// 더 이상 상태가 바뀌지 않는 입출금인지
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            TransferStatus::Completed | TransferStatus::Failed | TransferStatus::Cancelled
+        )
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransferStatus::Pending => "PENDING",
+            TransferStatus::Processing => "PROCESSING",
+            TransferStatus::Completed => "COMPLETED",
+            TransferStatus::Failed => "FAILED",
+            TransferStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+impl std::str::FromStr for TransferStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PENDING" => Ok(Self::Pending),
+            "PROCESSING" => Ok(Self::Processing),
+            "COMPLETED" => Ok(Self::Completed),
+            "FAILED" => Ok(Self::Failed),
+            "CANCELLED" => Ok(Self::Cancelled),
+            _ => Err(format!("Invalid TransferStatus: {}", s)),
+        }
+    }
+}
+
+/// 입금 또는 출금 한 건
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferRecord {
+    pub exchange: ExchangeId,
+    pub direction: TransferDirection,
+    pub id: String, // 거래소가 부여한 입출금 ID
+    pub currency: String,
+    pub network: String,
+    pub address: String,
+    pub memo: Option<String>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    #[serde(with = "rust_decimal::serde::float_option")]
+    pub fee: Option<Decimal>,
+    pub txid: Option<String>,
+    pub status: TransferStatus,
+    pub created_at: DateTime<Utc>,
+    /// 출금 신청 때 보낸 클라이언트 출금 ID (WithdrawRequest::client_id)
+    #[serde(default)]
+    pub client_id: Option<String>,
+}
+
 impl FeeInfo {
     pub fn new(maker: f64, taker: f64) -> Self {
-        Self { maker, taker }
+        Self {
+            maker,
+            taker,
+            tier: None,
+            discount: None,
+        }
     }
 
     /// 수수료 무료
     pub fn free() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    pub fn with_tier(mut self, tier: impl Into<String>) -> Self {
+        self.tier = Some(tier.into());
+        self
+    }
+
+    pub fn with_discount(mut self, discount: FeeDiscount) -> Self {
+        self.discount = Some(discount);
+        self
+    }
+
+    /// 할인을 반영한 실효 수수료율 (리베이트에는 할인이 적용되지 않음)
+    pub fn effective(&self, is_maker: bool) -> f64 {
+        let rate = if is_maker { self.maker } else { self.taker };
+        match &self.discount {
+            Some(discount) if rate > 0.0 => rate * (1.0 - discount.rate),
+            _ => rate,
+        }
+    }
+
+    /// 체결된 자산에서 직접 차감되는 수수료율
+    /// 할인 자산(BNB 등)으로 수수료를 내면 체결 수량은 줄지 않으므로 0.
+    /// (할인 자산 잔고가 부족하면 거래소가 체결 자산에서 차감하므로 잔고 관리가 필요하다)
+    pub fn traded_asset_rate(&self, is_maker: bool) -> f64 {
+        if self.discount.is_some() {
+            0.0
+        } else {
+            self.effective(is_maker)
+        }
+    }
+}
+
+/// 주문 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl OrderSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        }
+    }
+
+    /// 반대 방향 (포지션 청산용)
+    pub fn opposite(&self) -> Self {
+        match self {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        }
+    }
+}
+
+impl std::fmt::Display for OrderSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 주문 유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OrderType {
+    Market,
+    Limit,
+}
+
+/// 지정가 주문 유효 기간
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeInForce {
+    Gtc,      // 취소 전까지 유효
+    Ioc,      // 즉시 체결 후 잔량 취소
+    Fok,      // 전량 즉시 체결 아니면 취소
+    PostOnly, // 메이커로만 체결 (테이커가 되면 거부)
+}
+
+/// 거래소 공통 주문 요청
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderRequest {
+    pub symbol: String, // 거래소 고유 심볼 (Instrument::native_symbol)
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>, // 지정가 주문만
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: bool, // 선물 전용
+    pub client_order_id: Option<String>,
+}
+
+impl OrderRequest {
+    /// 시장가 주문
+    pub fn market(symbol: impl Into<String>, side: OrderSide, quantity: Decimal) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type: OrderType::Market,
+            quantity,
+            price: None,
+            time_in_force: None,
+            reduce_only: false,
+            client_order_id: None,
+        }
+    }
+
+    /// 지정가 주문
+    pub fn limit(
+        symbol: impl Into<String>,
+        side: OrderSide,
+        quantity: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+    ) -> Self {
         Self {
-            maker: 0.0,
-            taker: 0.0,
+            order_type: OrderType::Limit,
+            price: Some(price),
+            time_in_force: Some(time_in_force),
+            ..Self::market(symbol, side, quantity)
+        }
+    }
+
+    /// 포지션 축소 전용으로 설정 (선물)
+    pub fn reduce_only(mut self) -> Self {
+        self.reduce_only = true;
+        self
+    }
+
+    /// 클라이언트 주문 ID 지정 (응답을 받지 못했을 때 이 ID로 주문을 조회한다)
+    pub fn with_client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+}
+
+/// 주문 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Rejected,
+    Expired,
+}
+
+impl OrderStatus {
+    /// 더 이상 상태가 바뀌지 않는 주문인지
+    pub fn is_final(&self) -> bool {
+        !matches!(self, OrderStatus::New | OrderStatus::PartiallyFilled)
+    }
+}
+
+/// 개별 체결
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fill {
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub fee: Decimal,
+    pub fee_asset: String, // 수수료 차감 자산 (예: "USDT", "BNB")
+    pub is_maker: bool,
+}
+
+/// 거래소 공통 주문 응답
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderAck {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    pub order_id: String,
+    pub client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub status: OrderStatus,
+    pub executed_qty: Decimal,
+    pub avg_price: Option<Decimal>, // 거래소가 직접 주는 평균 체결가 (fills가 없을 때 사용)
+    pub fills: Vec<Fill>,
+    pub raw: serde_json::Value, // 거래소 원본 응답
+}
+
+impl OrderAck {
+    /// 평균 체결가. fills가 있으면 수량 가중 평균, 없으면 거래소가 준 평균가
+    pub fn average_fill_price(&self) -> Option<Decimal> {
+        let filled: Decimal = self.fills.iter().map(|f| f.qty).sum();
+        if filled.is_zero() {
+            return self.avg_price.filter(|p| !p.is_zero());
         }
+        let notional: Decimal = self.fills.iter().map(|f| f.price * f.qty).sum();
+        Some(notional / filled)
+    }
+
+    /// 수수료 자산별 합계
+    pub fn fees(&self) -> BTreeMap<String, Decimal> {
+        let mut fees = BTreeMap::new();
+        for fill in &self.fills {
+            *fees.entry(fill.fee_asset.clone()).or_insert(Decimal::ZERO) += fill.fee;
+        }
+        fees
     }
 }
 
 #[derive(Error, Debug)]
 pub enum ExchangeError {
     #[error("http error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(reqwest::Error),
+    /// 요청 한도 초과 (HTTP 429/418 또는 거래소 고유 코드)
+    #[error("rate limited (retry after {retry_after:?})")]
+    RateLimited { retry_after: Option<Duration> },
+    /// 잔고/증거금 부족
+    #[error("insufficient balance: {0}")]
+    InsufficientBalance(String),
+    /// 수량/가격이 LOT_SIZE, 최소 주문금액 등 필터를 만족하지 않음
+    #[error("invalid quantity: {0}")]
+    InvalidQuantity(String),
+    /// API 키 누락, 서명 오류, 권한 없음
+    #[error("auth error: {0}")]
+    Auth(String),
+    /// 요청 타임아웃 또는 recvWindow를 벗어난 타임스탬프
+    #[error("timeout: {0}")]
+    Timeout(String),
+    /// 요청이 거래소에 접수·실행됐는지 알 수 없음 (주문/출금 전송 후 응답 없음, Binance -1007 등)
+    /// 다시 보내면 두 번 실행될 수 있으므로 재시도하지 않고 client id로 조회해 판단한다.
+    #[error("unknown execution status: {0}")]
+    UnknownStatus(String),
+    /// 위 분류에 속하지 않는 거래소 거부 응답 (code는 거래소 원본 코드)
+    #[error("exchange reject: {code} - {msg}")]
+    ExchangeReject { code: String, msg: String },
+    #[error("not implemented: {0}")]
+    NotImplemented(String),
+    /// dry-run 모드라 실제 주문을 보내지 않음
+    #[error("dry run mode")]
+    DryRun,
     #[error("other error: {0}")]
     Other(String),
 }
+
+impl ExchangeError {
+    /// 같은 요청을 잠시 후 다시 보내면 성공할 가능성이 있는 에러인지 여부
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ExchangeError::Http(e) => {
+                e.is_timeout()
+                    || e.is_connect()
+                    || e.status().map(|s| s.is_server_error()).unwrap_or(false)
+            }
+            ExchangeError::RateLimited { .. } | ExchangeError::Timeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// 재시도 전에 기다려야 하는 시간 (거래소가 알려준 경우)
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ExchangeError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ExchangeError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            ExchangeError::Timeout(e.to_string())
+        } else {
+            ExchangeError::Http(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instrument_multiplier_prefix() {
+        let inst = Instrument::new(
+            ExchangeId::Binance,
+            InstrumentKind::Perp,
+            "1000PEPE",
+            "USDT",
+            "1000PEPEUSDT",
+        );
+        assert_eq!(inst.base, "PEPE");
+        assert_eq!(inst.multiplier, 1000);
+        assert_eq!(inst.symbol(), "PEPEUSDT");
+        assert_eq!(inst.unit_price(Decimal::new(12, 0)), Decimal::new(12, 3));
+
+        // 숫자로 시작하는 실제 토큰명은 배수로 보지 않는다
+        let inst = Instrument::new(
+            ExchangeId::Binance,
+            InstrumentKind::Spot,
+            "1INCH",
+            "USDT",
+            "1INCHUSDT",
+        );
+        assert_eq!(inst.base, "1INCH");
+        assert_eq!(inst.multiplier, 1);
+    }
+
+    #[test]
+    fn test_funding_rate_normalization() {
+        let instrument = Instrument::new(
+            ExchangeId::Bitget,
+            InstrumentKind::Perp,
+            "BTC",
+            "USDT",
+            "BTCUSDT",
+        );
+        let mut perp = PerpData {
+            instrument,
+            currency: Currency::USDT,
+            mark_price: Decimal::from(100_100),
+            oi_usd: 0.0,
+            vol_24h_usd: 0.0,
+            funding_rate: 0.0001,
+            next_funding_time: None,
+            funding_interval_hours: Some(4),
+            predicted_funding_rate: None,
+            index_price: Some(Decimal::from(100_000)),
+            premium_index: None,
+        };
+        // 4시간 주기 0.01%는 하루 6번 정산: 연 0.0001 * 6 * 365
+        assert!((perp.annualized_funding_rate() - 0.219).abs() < 1e-9);
+        perp.funding_interval_hours = None;
+        assert!((perp.annualized_funding_rate() - 0.1095).abs() < 1e-9);
+
+        assert_eq!(
+            premium_index(perp.mark_price, perp.index_price),
+            Some(0.001)
+        );
+        assert_eq!(premium_index(perp.mark_price, Some(Decimal::ZERO)), None);
+        assert_eq!(premium_index(perp.mark_price, None), None);
+    }
+
+    #[test]
+    fn test_order_ack_average_fill_price() {
+        let fill = |price: i64, qty: i64, fee: i64, asset: &str| Fill {
+            price: Decimal::new(price, 0),
+            qty: Decimal::new(qty, 1),
+            fee: Decimal::new(fee, 4),
+            fee_asset: asset.to_string(),
+            is_maker: false,
+        };
+        let mut ack = OrderAck {
+            exchange: ExchangeId::Binance,
+            symbol: "BTCUSDT".to_string(),
+            order_id: "1".to_string(),
+            client_order_id: None,
+            side: OrderSide::Buy,
+            status: OrderStatus::Filled,
+            executed_qty: Decimal::new(4, 1),
+            avg_price: None,
+            fills: vec![
+                fill(100, 1, 1, "BNB"),
+                fill(110, 3, 2, "BNB"),
+                fill(110, 0, 5, "USDT"),
+            ],
+            raw: serde_json::Value::Null,
+        };
+        // (100 * 0.1 + 110 * 0.3) / 0.4 = 107.5
+        assert_eq!(ack.average_fill_price(), Some(Decimal::new(1075, 1)));
+        assert_eq!(ack.fees().get("BNB"), Some(&Decimal::new(3, 4)));
+        assert_eq!(ack.fees().get("USDT"), Some(&Decimal::new(5, 4)));
+
+        // fills가 없으면 거래소가 준 평균가 사용
+        ack.fills.clear();
+        ack.avg_price = Some(Decimal::new(105, 0));
+        assert_eq!(ack.average_fill_price(), Some(Decimal::new(105, 0)));
+        assert!(OrderStatus::Filled.is_final());
+        assert!(!OrderStatus::PartiallyFilled.is_final());
+    }
+
+    #[test]
+    fn test_fee_info_discount() {
+        let fee = FeeInfo::new(0.001, 0.001).with_discount(FeeDiscount {
+            asset: "BNB".to_string(),
+            rate: 0.25,
+        });
+        assert!((fee.effective(false) - 0.00075).abs() < 1e-12);
+        assert_eq!(fee.traded_asset_rate(false), 0.0);
+
+        // 리베이트(음수 메이커)는 할인 대상이 아니다
+        let rebate = FeeInfo::new(-0.0001, 0.0005);
+        assert_eq!(rebate.effective(true), -0.0001);
+        assert_eq!(rebate.traded_asset_rate(false), 0.0005);
+        assert_eq!(MarketType::from_quote("krw"), MarketType::KRW);
+    }
+
+    #[test]
+    fn test_exchange_error_retryable() {
+        assert!(ExchangeError::RateLimited { retry_after: None }.is_retryable());
+        assert!(ExchangeError::Timeout("recvWindow".into()).is_retryable());
+        assert!(!ExchangeError::InsufficientBalance("USDT".into()).is_retryable());
+        assert!(!ExchangeError::UnknownStatus("-1007".into()).is_retryable());
+        assert!(!ExchangeError::Auth("bad key".into()).is_retryable());
+        assert!(!ExchangeError::DryRun.is_retryable());
+
+        let e = ExchangeError::RateLimited {
+            retry_after: Some(Duration::from_secs(3)),
+        };
+        assert_eq!(e.retry_after(), Some(Duration::from_secs(3)));
+    }
+}
@@ -0,0 +1,298 @@
+//! base 자산별 거래소 통합 보기 (/assets)
+//!
+//! 통합 스냅샷은 (거래소, base, quote) 단위라 같은 BTC라도 Binance BTCUSDT, OKX BTC-USDT-SWAP,
+//! Bithumb BTC_KRW가 따로 있다. 여기서는 base 기준으로 모든 거래소의 선물/현물 가격을
+//! ExchangeRates로 USD 환산해 한데 모으고, 거래소 간 가격 차이와 펀딩비 범위, OI 합계를 계산한다.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use interface::{Currency, ExchangeId, ExchangeRates, InstrumentKind, UnifiedSnapshot};
+
+use crate::units::{to_f64, BPS};
+
+/// 한 거래소의 선물 또는 현물 시세
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetVenue {
+    pub exchange: ExchangeId,
+    pub kind: InstrumentKind,
+    /// 거래소 원본 심볼
+    pub symbol: String,
+    pub quote: String,
+    pub currency: Currency,
+    /// 거래소 표시 통화 기준 가격 (base 1개)
+    pub price: f64,
+    /// USD 환산 가격
+    pub price_usd: f64,
+    pub vol_24h_usd: f64,
+    /// 선물만
+    pub oi_usd: Option<f64>,
+    /// 한 주기 펀딩비
+    pub funding_rate: Option<f64>,
+    /// 펀딩 주기 (시간, 거래소가 알려주지 않으면 8시간으로 가정)
+    pub funding_interval_hours: Option<u32>,
+    /// 주기로 환산한 단리 연율 펀딩비 (거래소 간 비교는 이 값으로 한다)
+    pub annualized_funding_rate: Option<f64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 최소/최대 값과 그 거래소
+#[derive(Debug, Clone, Serialize)]
+pub struct VenueValue {
+    pub exchange: ExchangeId,
+    pub kind: InstrumentKind,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetView {
+    pub base: String,
+    /// 가격이 가장 싼/비싼 거래소 (USD 환산)
+    pub price_low: Option<VenueValue>,
+    pub price_high: Option<VenueValue>,
+    /// 최고가 - 최저가 (USD)
+    pub spread_usd: Option<f64>,
+    /// 최고가 / 최저가 - 1 (bps)
+    pub spread_bps: Option<f64>,
+    /// 선물 연율 펀딩비 범위 (거래소마다 펀딩 주기가 달라 연율로 비교, 선물이 없으면 None)
+    pub annualized_funding_low: Option<VenueValue>,
+    pub annualized_funding_high: Option<VenueValue>,
+    pub total_oi_usd: f64,
+    pub total_vol_24h_usd: f64,
+    /// 가격 환산에 쓴 환율 (is_fallback이면 USD 환산 값을 믿으면 안 된다)
+    pub exchange_rates: ExchangeRates,
+    /// USD 환산 가격 오름차순
+    pub venues: Vec<AssetVenue>,
+}
+
+/// 표시 통화 가격을 USD로 환산
+fn to_usd(price: f64, currency: Currency, rates: &ExchangeRates) -> Option<f64> {
+    let converted = match currency {
+        Currency::USD => price,
+        Currency::USDT => price * rates.usdt_usd,
+        Currency::KRW => price / rates.usd_krw,
+    };
+    (converted.is_finite() && converted > 0.0).then_some(converted)
+}
+
+/// 통합 스냅샷 하나에서 선물/현물 시세 추출 (환산할 수 없는 가격은 뺀다)
+fn venues(snapshot: &UnifiedSnapshot, rates: &ExchangeRates) -> Vec<AssetVenue> {
+    let mut venues = Vec::new();
+    if let Some(perp) = &snapshot.perp {
+        let price = to_f64(perp.mark_price);
+        if let Some(price_usd) = to_usd(price, perp.currency, rates) {
+            venues.push(AssetVenue {
+                exchange: snapshot.exchange,
+                kind: InstrumentKind::Perp,
+                symbol: perp.instrument.native_symbol.clone(),
+                quote: snapshot.quote.clone(),
+                currency: perp.currency,
+                price,
+                price_usd,
+                vol_24h_usd: perp.vol_24h_usd,
+                oi_usd: Some(perp.oi_usd),
+                funding_rate: Some(perp.funding_rate),
+                funding_interval_hours: Some(perp.funding_interval_hours_or_default()),
+                annualized_funding_rate: Some(perp.annualized_funding_rate()),
+                updated_at: snapshot.updated_at,
+            });
+        }
+    }
+    if let Some(spot) = &snapshot.spot {
+        let price = to_f64(spot.price);
+        if let Some(price_usd) = to_usd(price, spot.currency, rates) {
+            venues.push(AssetVenue {
+                exchange: snapshot.exchange,
+                kind: InstrumentKind::Spot,
+                symbol: spot.instrument.native_symbol.clone(),
+                quote: snapshot.quote.clone(),
+                currency: spot.currency,
+                price,
+                price_usd,
+                vol_24h_usd: spot.vol_24h_usd,
+                oi_usd: None,
+                funding_rate: None,
+                funding_interval_hours: None,
+                annualized_funding_rate: None,
+                updated_at: snapshot.updated_at,
+            });
+        }
+    }
+    venues
+}
+
+fn venue_value(venue: &AssetVenue, value: f64) -> VenueValue {
+    VenueValue {
+        exchange: venue.exchange,
+        kind: venue.kind,
+        value,
+    }
+}
+
+/// 한 base 자산의 거래소별 시세로 요약 계산
+fn summarize(base: String, mut venues: Vec<AssetVenue>, rates: &ExchangeRates) -> AssetView {
+    venues.sort_by(|a, b| a.price_usd.total_cmp(&b.price_usd));
+
+    let price_low = venues.first().map(|v| venue_value(v, v.price_usd));
+    let price_high = venues.last().map(|v| venue_value(v, v.price_usd));
+    let (spread_usd, spread_bps) = match (&price_low, &price_high) {
+        (Some(low), Some(high)) => (
+            Some(high.value - low.value),
+            Some((high.value / low.value - 1.0) * BPS),
+        ),
+        _ => (None, None),
+    };
+
+    let funding: Vec<_> = venues
+        .iter()
+        .filter_map(|v| v.annualized_funding_rate.map(|rate| venue_value(v, rate)))
+        .filter(|v| v.value.is_finite())
+        .collect();
+    let annualized_funding_low = funding
+        .iter()
+        .min_by(|a, b| a.value.total_cmp(&b.value))
+        .cloned();
+    let annualized_funding_high = funding
+        .iter()
+        .max_by(|a, b| a.value.total_cmp(&b.value))
+        .cloned();
+
+    AssetView {
+        base,
+        price_low,
+        price_high,
+        spread_usd,
+        spread_bps,
+        annualized_funding_low,
+        annualized_funding_high,
+        total_oi_usd: venues.iter().filter_map(|v| v.oi_usd).sum(),
+        total_vol_24h_usd: venues.iter().map(|v| v.vol_24h_usd).sum(),
+        exchange_rates: rates.clone(),
+        venues,
+    }
+}
+
+/// 통합 스냅샷을 base 자산별로 묶어 요약 (거래량 합계 내림차순)
+pub fn aggregate_assets(snapshots: &[UnifiedSnapshot], rates: &ExchangeRates) -> Vec<AssetView> {
+    let mut by_base: BTreeMap<&str, Vec<AssetVenue>> = BTreeMap::new();
+    for snapshot in snapshots {
+        let venues = venues(snapshot, rates);
+        if !venues.is_empty() {
+            by_base.entry(&snapshot.base).or_default().extend(venues);
+        }
+    }
+    let mut assets: Vec<_> = by_base
+        .into_iter()
+        .map(|(base, venues)| summarize(base.to_string(), venues, rates))
+        .collect();
+    assets.sort_by(|a, b| b.total_vol_24h_usd.total_cmp(&a.total_vol_24h_usd));
+    assets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn rates() -> ExchangeRates {
+        test_support::rates_with(1400.0, 0.999)
+    }
+
+    /// 통합 스냅샷 (선물은 (가격, 펀딩비, 펀딩 주기))
+    fn snapshot(
+        exchange: ExchangeId,
+        base: &str,
+        quote: &str,
+        native: &str,
+        perp: Option<(i64, f64, Option<u32>)>,
+        spot: Option<i64>,
+    ) -> UnifiedSnapshot {
+        let mut snapshot = test_support::snapshot(
+            exchange,
+            base,
+            quote,
+            native,
+            perp.map(|(price, _, _)| price),
+            spot,
+        );
+        if let (Some(data), Some((_, funding_rate, interval))) = (snapshot.perp.as_mut(), perp) {
+            data.funding_rate = funding_rate;
+            data.funding_interval_hours = interval;
+        }
+        snapshot
+    }
+
+    #[test]
+    fn test_aggregate_by_base() {
+        let snapshots = vec![
+            snapshot(
+                ExchangeId::Binance,
+                "BTC",
+                "USDT",
+                "BTCUSDT",
+                Some((100_000, 0.0001, Some(8))),
+                Some(99_900),
+            ),
+            snapshot(
+                ExchangeId::Okx,
+                "BTC",
+                "USDT",
+                "BTC-USDT-SWAP",
+                Some((100_100, 0.00006, Some(4))),
+                None,
+            ),
+            snapshot(
+                ExchangeId::Bithumb,
+                "BTC",
+                "KRW",
+                "BTC_KRW",
+                None,
+                Some(141_400_000),
+            ),
+            snapshot(
+                ExchangeId::Bybit,
+                "ETH",
+                "USDT",
+                "ETHUSDT",
+                None,
+                Some(3_000),
+            ),
+        ];
+
+        let assets = aggregate_assets(&snapshots, &rates());
+        assert_eq!(assets.len(), 2);
+        let btc = &assets[0];
+        assert_eq!(btc.base, "BTC");
+        assert_eq!(btc.venues.len(), 4);
+
+        // 원화 가격은 USD/KRW로 환산: 141,400,000 / 1400 = 101,000 USD
+        let low = btc.price_low.as_ref().unwrap();
+        let high = btc.price_high.as_ref().unwrap();
+        assert_eq!(
+            (low.exchange, low.kind),
+            (ExchangeId::Binance, InstrumentKind::Spot)
+        );
+        assert!((low.value - 99_900.0 * 0.999).abs() < 1e-6);
+        assert_eq!(high.exchange, ExchangeId::Bithumb);
+        assert!((high.value - 101_000.0).abs() < 1e-6);
+        assert!((btc.spread_usd.unwrap() - (101_000.0 - 99_900.0 * 0.999)).abs() < 1e-6);
+        assert!(btc.spread_bps.unwrap() > 100.0);
+
+        // 한 주기 값은 OKX가 작아도 4시간 주기라 연율은 더 높다
+        // Binance 0.01% x 3 x 365 == 10.95%, OKX 0.006% x 6 x 365 == 13.14%
+        let funding_low = btc.annualized_funding_low.as_ref().unwrap();
+        let funding_high = btc.annualized_funding_high.as_ref().unwrap();
+        assert_eq!(funding_low.exchange, ExchangeId::Binance);
+        assert!((funding_low.value - 0.1095).abs() < 1e-9);
+        assert_eq!(funding_high.exchange, ExchangeId::Okx);
+        assert!((funding_high.value - 0.1314).abs() < 1e-9);
+        assert_eq!(btc.total_oi_usd, 2_000_000.0);
+
+        // 선물이 없는 자산은 펀딩비 범위가 없다
+        let eth = &assets[1];
+        assert!(eth.annualized_funding_low.is_none() && eth.total_oi_usd == 0.0);
+        assert_eq!(eth.spread_bps, Some(0.0));
+    }
+}
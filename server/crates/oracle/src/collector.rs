@@ -1,150 +1,845 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use tokio::time::sleep;
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use tokio::{sync::mpsc, time::sleep};
 use tracing::{info, warn};
 
-use crate::server::AppState;
-use exchanges::{exchange_rate::fetch_all_exchange_rates, PerpExchange, SpotExchange};
-use interface::{ExchangeId, PerpData, PerpSnapshot, SpotData, SpotSnapshot, UnifiedSnapshot};
+use crate::config::OracleConfig;
+use crate::push::UnifiedEvent;
+use crate::server::{AppState, FeedKind, SnapshotKey, UnifiedKey, STALE_AFTER};
+use exchanges::{
+    exchange_rate::fetch_all_exchange_rates, PerpExchange, PerpStream, SpotExchange, SpotStream,
+    WalletStatusExchange,
+};
+use interface::{
+    premium_index, Currency, ExchangeError, ExchangeId, ExchangeRates, Instrument, PerpData,
+    PerpSnapshot, PerpTick, SpotData, SpotSnapshot, SpotTick, UnifiedSnapshot,
+};
 
+/// 스트림과 REST 모두에서 이 시간 동안 갱신되지 않은 종목은 제거 (상장 폐지 등)
+const PRUNE_AFTER: chrono::Duration = chrono::Duration::minutes(5);
+/// 스트림 변경분 채널 크기와 한 번에 반영할 최대 개수
+const TICK_CHANNEL_CAPACITY: usize = 4096;
+const TICK_BATCH: usize = 256;
+
+/// 거래소별 WebSocket 스트림을 띄우고, 들어오는 변경분을 바로 AppState에 반영한다.
+/// 재연결은 각 스트림 구현이 알아서 하므로 여기서는 태스크만 띄운다.
+/// 설정에서 뺀 종목의 변경분은 버린다 (거래소 목록은 재시작해야 바뀐다).
+pub fn start_stream_tasks(
+    perp_streams: Vec<Arc<dyn PerpStream>>,
+    spot_streams: Vec<Arc<dyn SpotStream>>,
+    state: Arc<AppState>,
+) {
+    info!(
+        "시세 스트림 시작: {}개 선물 거래소, {}개 현물 거래소",
+        perp_streams.len(),
+        spot_streams.len()
+    );
+
+    let (perp_tx, mut perp_rx) = mpsc::channel::<PerpTick>(TICK_CHANNEL_CAPACITY);
+    for stream in perp_streams {
+        let tx = perp_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = stream.stream_perps(tx).await {
+                warn!("perp stream error from {:?}: {:?}", stream.id(), e);
+            }
+        });
+    }
+    drop(perp_tx);
+
+    let (spot_tx, mut spot_rx) = mpsc::channel::<SpotTick>(TICK_CHANNEL_CAPACITY);
+    for stream in spot_streams {
+        let tx = spot_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = stream.stream_spots(tx).await {
+                warn!("spot stream error from {:?}: {:?}", stream.id(), e);
+            }
+        });
+    }
+    drop(spot_tx);
+
+    let perp_state = state.clone();
+    tokio::spawn(async move {
+        let mut buf = Vec::with_capacity(TICK_BATCH);
+        while perp_rx.recv_many(&mut buf, TICK_BATCH).await > 0 {
+            apply_perp_ticks(&perp_state, buf.drain(..)).await;
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut buf = Vec::with_capacity(TICK_BATCH);
+        while spot_rx.recv_many(&mut buf, TICK_BATCH).await > 0 {
+            apply_spot_ticks(&state, buf.drain(..)).await;
+        }
+    });
+}
+
+/// 선물 변경분 반영 (새 종목은 mark price가 들어온 시점부터 추가)
+pub async fn apply_perp_ticks(state: &AppState, ticks: impl IntoIterator<Item = PerpTick>) {
+    let config = state.config();
+    let rates = state.exchange_rates.read().await.clone();
+    let mut perps = state.perp_snapshots.write().await;
+    let mut unified = state.unified_snapshots.write().await;
+    let mut changed = HashSet::new();
+
+    for tick in ticks {
+        if !config.perp_enabled(tick.exchange) || !config.allows_instrument(&tick.instrument) {
+            continue;
+        }
+        let key = (tick.exchange, tick.instrument.native_symbol.clone());
+        let snapshot = match perps.entry(key) {
+            Entry::Occupied(entry) => {
+                let snapshot = entry.into_mut();
+                snapshot.apply(&tick);
+                snapshot
+            }
+            Entry::Vacant(entry) => match PerpSnapshot::from_tick(&tick) {
+                Some(snapshot) if keep_perp(&config, &snapshot) => entry.insert(snapshot),
+                _ => continue,
+            },
+        };
+        // 환율을 받기 전에는 통합 스냅샷을 만들지 않는다 (첫 REST 수집 때 전체 재구성)
+        if let Some(rates) = &rates {
+            merge_perp(&mut unified, snapshot, rates);
+            changed.insert(unified_key(snapshot.exchange, &snapshot.instrument));
+        }
+    }
+    publish_changed(state, &unified, changed);
+}
+
+/// 현물 변경분 반영 (새 종목은 가격이 들어온 시점부터 추가)
+pub async fn apply_spot_ticks(state: &AppState, ticks: impl IntoIterator<Item = SpotTick>) {
+    let config = state.config();
+    let rates = state.exchange_rates.read().await.clone();
+    let mut spots = state.spot_snapshots.write().await;
+    let mut unified = state.unified_snapshots.write().await;
+    let mut changed = HashSet::new();
+
+    for tick in ticks {
+        if !config.spot_enabled(tick.exchange) || !config.allows_instrument(&tick.instrument) {
+            continue;
+        }
+        let key = (tick.exchange, tick.instrument.native_symbol.clone());
+        let snapshot = match spots.entry(key) {
+            Entry::Occupied(entry) => {
+                let snapshot = entry.into_mut();
+                snapshot.apply(&tick);
+                snapshot
+            }
+            Entry::Vacant(entry) => match SpotSnapshot::from_tick(&tick) {
+                Some(snapshot) if keep_spot(&config, &snapshot) => entry.insert(snapshot),
+                _ => continue,
+            },
+        };
+        if let Some(rates) = &rates {
+            merge_spot(&mut unified, snapshot, rates);
+            changed.insert(unified_key(snapshot.exchange, &snapshot.instrument));
+        }
+    }
+    publish_changed(state, &unified, changed);
+}
+
+/// 설정(거래소, 종목 목록, 최소 거래량)상 보관할 선물 스냅샷인지
+fn keep_perp(config: &OracleConfig, snapshot: &PerpSnapshot) -> bool {
+    config.perp_enabled(snapshot.exchange)
+        && config.accepts(&snapshot.instrument, snapshot.vol_24h_usd)
+}
+
+/// 설정상 보관할 현물 스냅샷인지
+fn keep_spot(config: &OracleConfig, snapshot: &SpotSnapshot) -> bool {
+    config.spot_enabled(snapshot.exchange)
+        && config.accepts(&snapshot.instrument, snapshot.vol_24h_usd)
+}
+
+/// 변경된 통합 스냅샷을 /ws, /sse 구독자에게 방송
+fn publish_changed(
+    state: &AppState,
+    unified: &HashMap<UnifiedKey, UnifiedSnapshot>,
+    changed: HashSet<UnifiedKey>,
+) {
+    if changed.is_empty() || !state.has_subscribers() {
+        return;
+    }
+    let list: Vec<_> = changed
+        .iter()
+        .filter_map(|key| unified.get(key).cloned())
+        .collect();
+    state.publish(UnifiedEvent::Updated(Arc::new(list)));
+}
+
+/// 재구성 전후 통합 스냅샷을 비교해 바뀐 항목과 사라진 항목을 방송
+fn publish_diff(
+    state: &AppState,
+    before: &HashMap<UnifiedKey, UnifiedSnapshot>,
+    after: &HashMap<UnifiedKey, UnifiedSnapshot>,
+) {
+    if !state.has_subscribers() {
+        return;
+    }
+    // UnifiedSnapshot에는 PartialEq가 없으므로 직렬화 결과로 비교 (30초에 한 번이라 충분)
+    let updated: Vec<_> = after
+        .iter()
+        .filter(|(key, snapshot)| {
+            before.get(*key).is_none_or(|prev| {
+                serde_json::to_value(prev).ok() != serde_json::to_value(snapshot).ok()
+            })
+        })
+        .map(|(_, snapshot)| snapshot.clone())
+        .collect();
+    let removed: Vec<_> = before
+        .keys()
+        .filter(|key| !after.contains_key(*key))
+        .cloned()
+        .collect();
+
+    if !updated.is_empty() {
+        state.publish(UnifiedEvent::Updated(Arc::new(updated)));
+    }
+    if !removed.is_empty() {
+        state.publish(UnifiedEvent::Removed(Arc::new(removed)));
+    }
+}
+
+/// REST 선물 결과 반영: 스트림이 최근에 갱신한 종목은 OI/거래량만 보완하고
+/// (Binance, OKX 스트림에는 OI가 없다), 없거나 오래된 종목은 통째로 교체
+fn apply_rest_perps(perps: &mut HashMap<SnapshotKey, PerpSnapshot>, fetched: Vec<PerpSnapshot>) {
+    let now = Utc::now();
+    for snapshot in fetched {
+        let key = (snapshot.exchange, snapshot.instrument.native_symbol.clone());
+        match perps.entry(key) {
+            Entry::Occupied(mut entry) if now - entry.get().updated_at < STALE_AFTER => {
+                let current = entry.get_mut();
+                current.oi_usd = snapshot.oi_usd;
+                current.vol_24h_usd = snapshot.vol_24h_usd;
+            }
+            Entry::Occupied(mut entry) => {
+                entry.insert(snapshot);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(snapshot);
+            }
+        }
+    }
+}
+
+/// REST 현물 결과 반영 (apply_rest_perps와 같은 규칙, 스트림이 살아 있으면 거래량만 보완)
+fn apply_rest_spots(spots: &mut HashMap<SnapshotKey, SpotSnapshot>, fetched: Vec<SpotSnapshot>) {
+    let now = Utc::now();
+    for snapshot in fetched {
+        let key = (snapshot.exchange, snapshot.instrument.native_symbol.clone());
+        match spots.entry(key) {
+            Entry::Occupied(mut entry) if now - entry.get().updated_at < STALE_AFTER => {
+                entry.get_mut().vol_24h_usd = snapshot.vol_24h_usd;
+            }
+            Entry::Occupied(mut entry) => {
+                entry.insert(snapshot);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(snapshot);
+            }
+        }
+    }
+}
+
+/// 한 거래소의 REST 수집을 타임아웃 안에서 실행하고 (결과, 걸린 시간 ms)를 반환
+async fn timed_fetch<T>(
+    fetch: impl Future<Output = Result<Vec<T>, ExchangeError>>,
+    timeout: Duration,
+) -> (Result<Vec<T>, String>, u64) {
+    let started = Instant::now();
+    let result = match tokio::time::timeout(timeout, fetch).await {
+        Ok(Ok(v)) => Ok(v),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("timed out after {}s", timeout.as_secs())),
+    };
+    (result, started.elapsed().as_millis() as u64)
+}
+
+/// 수집 결과를 거래소 상태에 기록하고, 성공한 데이터만 돌려준다
+async fn record_fetch<T>(
+    state: &AppState,
+    exchange: ExchangeId,
+    kind: FeedKind,
+    (result, latency_ms): (Result<Vec<T>, String>, u64),
+) -> Option<Vec<T>> {
+    match result {
+        Ok(v) => {
+            state
+                .record_fetch_success(exchange, kind, latency_ms, v.len())
+                .await;
+            Some(v)
+        }
+        Err(e) => {
+            warn!("{:?} fetch error from {:?}: {}", kind, exchange, e);
+            state
+                .record_fetch_error(exchange, kind, latency_ms, e)
+                .await;
+            None
+        }
+    }
+}
+
+/// REST 보완 수집 루프
+/// 스트림이 끊겼거나 아직 붙지 않은 종목을 채우고, 스트림에 없는 값(OI 등)과 환율을 갱신한다.
+/// 거래소마다 동시에 요청하고 fetch_timeout을 넘긴 거래소는 이번 회차에서 실패로 처리한다.
+/// 실패한 거래소의 스냅샷은 지우지 않고 마지막 성공 값을 유지한다 (경과 시간은 /health 참고).
+/// 간격과 대상 거래소는 매 회차 현재 설정에서 읽고, 설정이 바뀌면 기다리지 않고 바로 다시 수집한다.
 pub fn start_collect_loop(
     perp_exchanges: Vec<Arc<dyn PerpExchange>>,
     spot_exchanges: Vec<Arc<dyn SpotExchange>>,
     state: Arc<AppState>,
-    interval: Duration,
 ) {
     tokio::spawn(async move {
         info!(
-            "데이터 수집 루프 시작: {}개 선물 거래소, {}개 현물 거래소, {}초 간격",
+            "REST 보완 수집 루프 시작: {}개 선물 거래소, {}개 현물 거래소, {}초 간격",
             perp_exchanges.len(),
             spot_exchanges.len(),
-            interval.as_secs()
+            state.config().collect_interval_secs
         );
+        let mut config_rx = state.config.subscribe();
         loop {
-            // 선물 데이터 수집
-            let mut all_perp: Vec<PerpSnapshot> = Vec::new();
-            for ex in &perp_exchanges {
-                match ex.fetch_all().await {
-                    Ok(mut v) => all_perp.append(&mut v),
-                    Err(e) => {
-                        warn!("perp fetch error from {:?}: {:?}", ex.id(), e);
-                    }
-                }
+            collect_once(&perp_exchanges, &spot_exchanges, &state).await;
+            let interval = state.config().collect_interval();
+            tokio::select! {
+                _ = sleep(interval) => {}
+                Ok(()) = config_rx.changed() => info!("설정이 바뀌어 바로 다시 수집합니다"),
             }
+        }
+    });
+}
+
+/// 선물/현물/환율을 한 번 동시에 수집해 AppState에 반영 (시계열 저장소가 있으면 이번 회차도 저장)
+/// 설정에서 빠진 거래소는 요청하지 않는다.
+pub async fn collect_once(
+    perp_exchanges: &[Arc<dyn PerpExchange>],
+    spot_exchanges: &[Arc<dyn SpotExchange>],
+    state: &AppState,
+) {
+    let config = state.config();
+    let fetch_timeout = config.fetch_timeout();
+    let perp_exchanges = perp_exchanges
+        .iter()
+        .filter(|ex| config.perp_enabled(ex.id()));
+    let spot_exchanges = spot_exchanges
+        .iter()
+        .filter(|ex| config.spot_enabled(ex.id()));
+    let perp_fetches = perp_exchanges.map(|ex| async move {
+        let result = timed_fetch(ex.fetch_all(), fetch_timeout).await;
+        (
+            ex.id(),
+            record_fetch(state, ex.id(), FeedKind::Perp, result).await,
+        )
+    });
+    let spot_fetches = spot_exchanges.map(|ex| async move {
+        let result = timed_fetch(ex.fetch_all(), fetch_timeout).await;
+        (
+            ex.id(),
+            record_fetch(state, ex.id(), FeedKind::Spot, result).await,
+        )
+    });
+    let (perp_results, spot_results, exchange_rates) = tokio::join!(
+        join_all(perp_fetches),
+        join_all(spot_fetches),
+        fetch_all_exchange_rates()
+    );
+    apply_collected(state, perp_results, spot_results, exchange_rates.clone()).await;
+
+    if let Some(history) = &state.history {
+        let snapshots = state.unified_list().await;
+        if let Err(e) = history
+            .save_cycle(Utc::now(), &snapshots, &exchange_rates)
+            .await
+        {
+            warn!("시계열 저장 실패: {}", e);
+        }
+    }
+}
+
+/// 거래소별 수집 결과(실패면 None)와 환율을 반영하고 통합 스냅샷을 다시 구성
+/// 현재 설정에 맞지 않는 종목(꺼진 거래소, 차단 종목, 최소 거래량 미달)은 이때 정리된다.
+async fn apply_collected(
+    state: &AppState,
+    perp_results: Vec<(ExchangeId, Option<Vec<PerpSnapshot>>)>,
+    spot_results: Vec<(ExchangeId, Option<Vec<SpotSnapshot>>)>,
+    exchange_rates: ExchangeRates,
+) {
+    let config = state.config();
+    *state.exchange_rates.write().await = Some(exchange_rates.clone());
 
-            // 정렬: OI 기준 내림차순
-            all_perp.sort_by(|a, b| {
-                b.oi_usd
-                    .partial_cmp(&a.oi_usd)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-            let perp_count = all_perp.len();
-            let perp_clone = all_perp.clone();
-            {
-                let mut guard = state.perp_snapshots.write().await;
-                *guard = all_perp;
+    // 이번 회차에 실패한 거래소는 정리 대상에서 뺀다
+    let mut failed_perps = HashSet::new();
+    let mut failed_spots = HashSet::new();
+    let (perp_count, spot_count, unified_count) = {
+        let mut perps = state.perp_snapshots.write().await;
+        let mut spots = state.spot_snapshots.write().await;
+        for (exchange, fetched) in perp_results {
+            match fetched {
+                Some(v) => apply_rest_perps(
+                    &mut perps,
+                    v.into_iter().filter(|s| keep_perp(&config, s)).collect(),
+                ),
+                None => {
+                    failed_perps.insert(exchange);
+                }
+            }
+        }
+        for (exchange, fetched) in spot_results {
+            match fetched {
+                Some(v) => apply_rest_spots(
+                    &mut spots,
+                    v.into_iter().filter(|s| keep_spot(&config, s)).collect(),
+                ),
+                None => {
+                    failed_spots.insert(exchange);
+                }
             }
+        }
+
+        // 오래되거나 설정에 맞지 않는 종목을 정리하고 통합 스냅샷을 새 환율로 다시 구성
+        let cutoff = Utc::now() - PRUNE_AFTER;
+        perps.retain(|(exchange, _), s| {
+            keep_perp(&config, s) && (s.updated_at > cutoff || failed_perps.contains(exchange))
+        });
+        spots.retain(|(exchange, _), s| {
+            keep_spot(&config, s) && (s.updated_at > cutoff || failed_spots.contains(exchange))
+        });
+
+        let mut unified = state.unified_snapshots.write().await;
+        let rebuilt = merge_into_map(perps.values(), spots.values(), &exchange_rates);
+        publish_diff(state, &unified, &rebuilt);
+        *unified = rebuilt;
+        (perps.len(), spots.len(), unified.len())
+    };
+
+    info!(
+        "REST 보완 수집 완료: {}개 선물 스냅샷, {}개 현물 스냅샷, {}개 통합 스냅샷 (실패: 선물 {:?}, 현물 {:?})",
+        perp_count, spot_count, unified_count, failed_perps, failed_spots
+    );
+}
+
+fn unified_key(exchange: ExchangeId, instrument: &Instrument) -> UnifiedKey {
+    (exchange, instrument.base.clone(), instrument.quote.clone())
+}
+
+/// (거래소, base, quote) 통합 스냅샷을 가져오거나 새로 만든다 (환율은 항상 최신 값으로)
+fn unified_entry<'a>(
+    unified_map: &'a mut HashMap<UnifiedKey, UnifiedSnapshot>,
+    exchange: ExchangeId,
+    instrument: &Instrument,
+    currency: Currency,
+    exchange_rates: &ExchangeRates,
+    updated_at: DateTime<Utc>,
+) -> &'a mut UnifiedSnapshot {
+    let key = unified_key(exchange, instrument);
+    let unified = unified_map.entry(key).or_insert_with(|| UnifiedSnapshot {
+        exchange,
+        symbol: instrument.symbol(),
+        base: instrument.base.clone(),
+        quote: instrument.quote.clone(),
+        currency,
+        perp: None,
+        spot: None,
+        exchange_rates: exchange_rates.clone(),
+        updated_at,
+    });
+    unified.exchange_rates = exchange_rates.clone();
+    unified
+}
+
+/// 선물 스냅샷 하나를 통합 스냅샷에 반영
+fn merge_perp(
+    unified_map: &mut HashMap<UnifiedKey, UnifiedSnapshot>,
+    perp: &PerpSnapshot,
+    exchange_rates: &ExchangeRates,
+) {
+    let instrument = &perp.instrument;
+    let unified = unified_entry(
+        unified_map,
+        perp.exchange,
+        instrument,
+        perp.currency,
+        exchange_rates,
+        perp.updated_at,
+    );
+    unified.perp = Some(PerpData {
+        currency: perp.currency,
+        mark_price: instrument.unit_price(perp.mark_price),
+        oi_usd: perp.oi_usd,
+        vol_24h_usd: perp.vol_24h_usd,
+        funding_rate: perp.funding_rate,
+        next_funding_time: perp.next_funding_time,
+        funding_interval_hours: perp.funding_interval_hours,
+        predicted_funding_rate: perp.predicted_funding_rate,
+        index_price: perp.index_price.map(|price| instrument.unit_price(price)),
+        premium_index: premium_index(perp.mark_price, perp.index_price),
+        instrument: instrument.clone(),
+    });
+    // currency와 updated_at은 더 최신 것으로 업데이트
+    unified.currency = perp.currency;
+    if perp.updated_at > unified.updated_at {
+        unified.updated_at = perp.updated_at;
+    }
+}
+
+/// 현물 스냅샷 하나를 통합 스냅샷에 반영
+fn merge_spot(
+    unified_map: &mut HashMap<UnifiedKey, UnifiedSnapshot>,
+    spot: &SpotSnapshot,
+    exchange_rates: &ExchangeRates,
+) {
+    let instrument = &spot.instrument;
+    let unified = unified_entry(
+        unified_map,
+        spot.exchange,
+        instrument,
+        spot.currency,
+        exchange_rates,
+        spot.updated_at,
+    );
+    unified.spot = Some(SpotData {
+        currency: spot.currency,
+        price: instrument.unit_price(spot.price),
+        vol_24h_usd: spot.vol_24h_usd,
+        instrument: instrument.clone(),
+    });
+    // currency와 updated_at은 더 최신 것으로 업데이트 (현물이 없으면 현물 currency 사용)
+    if unified.perp.is_none() {
+        unified.currency = spot.currency;
+    }
+    if spot.updated_at > unified.updated_at {
+        unified.updated_at = spot.updated_at;
+    }
+}
+
+/// 선물/현물 스냅샷 전체로 통합 스냅샷 맵 구성
+fn merge_into_map<'a>(
+    perps: impl IntoIterator<Item = &'a PerpSnapshot>,
+    spots: impl IntoIterator<Item = &'a SpotSnapshot>,
+    exchange_rates: &ExchangeRates,
+) -> HashMap<UnifiedKey, UnifiedSnapshot> {
+    let mut unified_map = HashMap::new();
+    for perp in perps {
+        merge_perp(&mut unified_map, perp, exchange_rates);
+    }
+    for spot in spots {
+        merge_spot(&mut unified_map, spot, exchange_rates);
+    }
+    unified_map
+}
+
+/// 선물/현물 스냅샷을 (거래소, base, quote) 단위로 병합
+/// 거래소마다 심볼 표기가 달라도 Instrument 기준으로 같은 종목끼리 묶이며,
+/// "1000PEPEUSDT" 같은 배수 선물은 base 1개 기준 가격으로 환산해 현물과 맞춘다.
+pub fn merge_snapshots(
+    perps: Vec<PerpSnapshot>,
+    spots: Vec<SpotSnapshot>,
+    exchange_rates: &ExchangeRates,
+) -> Vec<UnifiedSnapshot> {
+    merge_into_map(&perps, &spots, exchange_rates)
+        .into_values()
+        .collect()
+}
 
-            // 현물 데이터 수집
-            let mut all_spot: Vec<SpotSnapshot> = Vec::new();
-            for ex in &spot_exchanges {
-                match ex.fetch_all().await {
-                    Ok(mut v) => all_spot.append(&mut v),
+/// 입출금 현황 수집 루프 (입출금 중단은 자주 바뀌지 않으므로 긴 간격으로 조회)
+/// 조회에 실패한 거래소는 이전 현황을 유지한다. 간격은 매 회차 현재 설정에서 읽는다.
+pub fn start_wallet_status_loop(
+    exchanges: Vec<Arc<dyn WalletStatusExchange>>,
+    state: Arc<AppState>,
+) {
+    tokio::spawn(async move {
+        info!(
+            "입출금 현황 수집 루프 시작: {}개 거래소, {}초 간격",
+            exchanges.len(),
+            state.config().wallet_status_interval_secs
+        );
+        loop {
+            for ex in &exchanges {
+                match ex.fetch_wallet_status().await {
+                    Ok(list) => {
+                        let closed = list
+                            .iter()
+                            .filter(|s| !s.can_deposit() || !s.can_withdraw())
+                            .count();
+                        info!(
+                            "{:?} 입출금 현황: {}개 코인 중 {}개 입출금 중단",
+                            ex.id(),
+                            list.len(),
+                            closed
+                        );
+                        state.wallet_status.write().await.insert(ex.id(), list);
+                    }
                     Err(e) => {
-                        warn!("spot fetch error from {:?}: {:?}", ex.id(), e);
+                        warn!("wallet status fetch error from {:?}: {:?}", ex.id(), e);
                     }
                 }
             }
+            sleep(state.config().wallet_status_interval()).await;
+        }
+    });
+}
 
-            // 정렬: 거래량 기준 내림차순
-            all_spot.sort_by(|a, b| {
-                b.vol_24h_usd
-                    .partial_cmp(&a.vol_24h_usd)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-            let spot_count = all_spot.len();
-            let spot_clone = all_spot.clone();
-            {
-                let mut guard = state.spot_snapshots.write().await;
-                *guard = all_spot;
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{perp, rates, spot};
+    use async_trait::async_trait;
+    use interface::{Decimal, InstrumentKind};
 
-            // 환율 정보 가져오기
-            let exchange_rates = fetch_all_exchange_rates().await;
-
-            // 통합 스냅샷 생성
-            let mut unified_map: HashMap<(ExchangeId, String), UnifiedSnapshot> = HashMap::new();
-
-            // 선물 데이터 추가
-            for perp in perp_clone {
-                let key = (perp.exchange, perp.symbol.clone());
-                let unified = unified_map.entry(key).or_insert_with(|| UnifiedSnapshot {
-                    exchange: perp.exchange,
-                    symbol: perp.symbol.clone(),
-                    currency: perp.currency,
-                    perp: None,
-                    spot: None,
-                    exchange_rates: exchange_rates.clone(),
-                    updated_at: perp.updated_at,
-                });
-                unified.perp = Some(PerpData {
-                    currency: perp.currency,
-                    mark_price: perp.mark_price,
-                    oi_usd: perp.oi_usd,
-                    vol_24h_usd: perp.vol_24h_usd,
-                    funding_rate: perp.funding_rate,
-                    next_funding_time: perp.next_funding_time,
-                });
-                // currency와 updated_at은 더 최신 것으로 업데이트
-                unified.currency = perp.currency;
-                unified.exchange_rates = exchange_rates.clone();
-                if perp.updated_at > unified.updated_at {
-                    unified.updated_at = perp.updated_at;
-                }
-            }
+    #[test]
+    fn test_merge_by_instrument() {
+        let perps = vec![
+            perp(
+                ExchangeId::Okx,
+                "BTC-USDT-SWAP",
+                "BTC",
+                Decimal::new(100_010, 0),
+            ),
+            perp(
+                ExchangeId::Binance,
+                "1000PEPEUSDT",
+                "1000PEPE",
+                Decimal::new(12, 3),
+            ),
+        ];
+        let spots = vec![
+            spot(ExchangeId::Okx, "BTC-USDT", "BTC", Decimal::new(100_000, 0)),
+            spot(ExchangeId::Binance, "PEPEUSDT", "PEPE", Decimal::new(12, 6)),
+        ];
 
-            // 현물 데이터 추가
-            for spot in spot_clone {
-                let key = (spot.exchange, spot.symbol.clone());
-                let unified = unified_map.entry(key).or_insert_with(|| UnifiedSnapshot {
-                    exchange: spot.exchange,
-                    symbol: spot.symbol.clone(),
-                    currency: spot.currency,
-                    perp: None,
-                    spot: None,
-                    exchange_rates: exchange_rates.clone(),
-                    updated_at: spot.updated_at,
-                });
-                unified.spot = Some(SpotData {
-                    currency: spot.currency,
-                    price: spot.price,
-                    vol_24h_usd: spot.vol_24h_usd,
-                });
-                // currency와 updated_at은 더 최신 것으로 업데이트 (현물이 없으면 현물 currency 사용)
-                if unified.perp.is_none() {
-                    unified.currency = spot.currency;
-                }
-                unified.exchange_rates = exchange_rates.clone();
-                if spot.updated_at > unified.updated_at {
-                    unified.updated_at = spot.updated_at;
-                }
-            }
+        let merged = merge_snapshots(perps, spots, &rates());
+        assert_eq!(merged.len(), 2);
 
-            let unified_snapshots: Vec<UnifiedSnapshot> = unified_map.into_values().collect();
-            let unified_count = unified_snapshots.len();
-            {
-                let mut guard = state.unified_snapshots.write().await;
-                *guard = unified_snapshots;
-            }
+        let okx = merged
+            .iter()
+            .find(|u| u.exchange == ExchangeId::Okx)
+            .unwrap();
+        assert_eq!(okx.symbol, "BTCUSDT");
+        assert!(okx.perp.is_some() && okx.spot.is_some());
+
+        let pepe = merged
+            .iter()
+            .find(|u| u.exchange == ExchangeId::Binance)
+            .unwrap();
+        assert_eq!(pepe.symbol, "PEPEUSDT");
+        let perp = pepe.perp.as_ref().unwrap();
+        assert_eq!(perp.instrument.native_symbol, "1000PEPEUSDT");
+        // 1000PEPE 가격을 1000으로 나눈 값이 오차 없이 PEPE 현물 가격과 일치해야 한다
+        assert_eq!(perp.mark_price, Decimal::new(12, 6));
+        assert!(pepe.spot.is_some());
+    }
 
-            info!(
-                "데이터 수집 완료: {}개 선물 스냅샷, {}개 현물 스냅샷, {}개 통합 스냅샷",
-                perp_count, spot_count, unified_count
-            );
+    #[tokio::test]
+    async fn test_apply_ticks_and_rest_fallback() {
+        let state = AppState::new();
+        *state.exchange_rates.write().await = Some(rates());
+        let instrument = Instrument::new(
+            ExchangeId::Bybit,
+            InstrumentKind::Perp,
+            "BTC",
+            "USDT",
+            "BTCUSDT",
+        );
+
+        // mark price 없는 첫 변경분은 스냅샷을 만들지 않는다
+        let mut tick = PerpTick::new(instrument.clone(), Currency::USDT);
+        tick.funding_rate = Some(0.0001);
+        apply_perp_ticks(&state, [tick.clone()]).await;
+        assert!(state.perp_snapshots.read().await.is_empty());
+
+        tick.mark_price = Some(Decimal::from(65000));
+        apply_perp_ticks(&state, [tick]).await;
+        let mut delta = PerpTick::new(instrument, Currency::USDT);
+        delta.mark_price = Some(Decimal::from(65100));
+        apply_perp_ticks(&state, [delta]).await;
 
-            sleep(interval).await;
+        let key = (ExchangeId::Bybit, "BTCUSDT".to_string());
+        {
+            let perps = state.perp_snapshots.read().await;
+            let snapshot = &perps[&key];
+            assert_eq!(snapshot.mark_price, Decimal::from(65100));
+            assert_eq!(snapshot.funding_rate, 0.0001);
+            let unified = state.unified_snapshots.read().await;
+            assert_eq!(unified.len(), 1);
         }
-    });
+
+        // 스트림이 살아 있으면 REST는 OI/거래량만 보완
+        let mut rest = perp(ExchangeId::Bybit, "BTCUSDT", "BTC", Decimal::from(64000));
+        rest.oi_usd = 1_000_000.0;
+        apply_rest_perps(&mut *state.perp_snapshots.write().await, vec![rest.clone()]);
+        {
+            let perps = state.perp_snapshots.read().await;
+            assert_eq!(perps[&key].mark_price, Decimal::from(65100));
+            assert_eq!(perps[&key].oi_usd, 1_000_000.0);
+        }
+
+        // 스트림이 끊겨 오래된 값은 REST 결과로 교체
+        state
+            .perp_snapshots
+            .write()
+            .await
+            .get_mut(&key)
+            .unwrap()
+            .updated_at = Utc::now() - STALE_AFTER - chrono::Duration::seconds(1);
+        apply_rest_perps(&mut *state.perp_snapshots.write().await, vec![rest]);
+        assert_eq!(
+            state.perp_snapshots.read().await[&key].mark_price,
+            Decimal::from(64000)
+        );
+    }
+
+    /// 정해진 응답을 delay 뒤에 돌려주는 가짜 선물 거래소
+    struct MockPerps {
+        id: ExchangeId,
+        delay: Duration,
+        result: Result<Vec<PerpSnapshot>, String>,
+    }
+
+    #[async_trait]
+    impl PerpExchange for MockPerps {
+        fn id(&self) -> ExchangeId {
+            self.id
+        }
+
+        async fn fetch_all(&self) -> Result<Vec<PerpSnapshot>, ExchangeError> {
+            sleep(self.delay).await;
+            self.result.clone().map_err(ExchangeError::Other)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collect_keeps_last_good_data() {
+        let state = AppState::new();
+        let old = Utc::now() - PRUNE_AFTER - chrono::Duration::seconds(1);
+        for exchange in [ExchangeId::Binance, ExchangeId::Okx, ExchangeId::Bitget] {
+            let mut snapshot = perp(exchange, "ETHUSDT", "ETH", Decimal::from(3000));
+            snapshot.updated_at = old;
+            state
+                .perp_snapshots
+                .write()
+                .await
+                .insert((exchange, "ETHUSDT".to_string()), snapshot);
+        }
+
+        let exchanges = [
+            // 타임아웃
+            MockPerps {
+                id: ExchangeId::Binance,
+                delay: Duration::from_secs(5),
+                result: Ok(Vec::new()),
+            },
+            // 오류
+            MockPerps {
+                id: ExchangeId::Okx,
+                delay: Duration::ZERO,
+                result: Err("bad gateway".to_string()),
+            },
+            // 성공했지만 ETHUSDT가 빠짐 (상장 폐지)
+            MockPerps {
+                id: ExchangeId::Bitget,
+                delay: Duration::ZERO,
+                result: Ok(vec![perp(
+                    ExchangeId::Bitget,
+                    "BTCUSDT",
+                    "BTC",
+                    Decimal::from(65000),
+                )]),
+            },
+        ];
+
+        let started = Instant::now();
+        let fetches = exchanges.iter().map(|ex| async {
+            let result = timed_fetch(ex.fetch_all(), Duration::from_millis(100)).await;
+            (
+                ex.id(),
+                record_fetch(&state, ex.id(), FeedKind::Perp, result).await,
+            )
+        });
+        let results = join_all(fetches).await;
+        // 느린 거래소를 기다리지 않는다
+        assert!(started.elapsed() < Duration::from_secs(1));
+        apply_collected(&state, results, Vec::new(), rates()).await;
+
+        {
+            let perps = state.perp_snapshots.read().await;
+            assert!(perps.contains_key(&(ExchangeId::Binance, "ETHUSDT".to_string())));
+            assert!(perps.contains_key(&(ExchangeId::Okx, "ETHUSDT".to_string())));
+            assert!(!perps.contains_key(&(ExchangeId::Bitget, "ETHUSDT".to_string())));
+            assert!(perps.contains_key(&(ExchangeId::Bitget, "BTCUSDT".to_string())));
+            assert_eq!(state.unified_snapshots.read().await.len(), 3);
+        }
+
+        let now = Utc::now();
+        let statuses = state.exchange_status.read().await;
+        let binance = &statuses[&(ExchangeId::Binance, FeedKind::Perp)];
+        assert!(binance.last_error.as_deref().unwrap().contains("timed out"));
+        assert!(binance.is_stale(now));
+        let okx = &statuses[&(ExchangeId::Okx, FeedKind::Perp)];
+        assert!(okx.last_error.as_deref().unwrap().contains("bad gateway"));
+        let bitget = &statuses[&(ExchangeId::Bitget, FeedKind::Perp)];
+        assert_eq!(bitget.record_count, 1);
+        assert!(bitget.last_error.is_none() && !bitget.is_stale(now));
+    }
+
+    #[tokio::test]
+    async fn test_reloaded_config_filters_snapshots() {
+        let state = AppState::new();
+        *state.exchange_rates.write().await = Some(rates());
+        let mut btc = perp(ExchangeId::Bybit, "BTCUSDT", "BTC", Decimal::from(65000));
+        btc.vol_24h_usd = 5_000_000.0;
+        let eth = perp(ExchangeId::Bybit, "ETHUSDT", "ETH", Decimal::from(3000));
+        let okx = perp(
+            ExchangeId::Okx,
+            "BTC-USDT-SWAP",
+            "BTC",
+            Decimal::from(65000),
+        );
+        apply_collected(
+            &state,
+            vec![
+                (ExchangeId::Bybit, Some(vec![btc, eth])),
+                (ExchangeId::Okx, Some(vec![okx])),
+            ],
+            Vec::new(),
+            rates(),
+        )
+        .await;
+        assert_eq!(state.perp_snapshots.read().await.len(), 3);
+
+        // OKX를 끄고 ETH를 차단, 최소 거래량 1M
+        let mut config = OracleConfig::default();
+        config.perp_exchanges.retain(|ex| *ex != ExchangeId::Okx);
+        config.symbols.deny = vec!["eth".to_string()];
+        config.min_volume_usd = 1_000_000.0;
+        state.set_config(config);
+
+        // 실패한 거래소라도 설정에서 빠진 종목은 정리된다
+        apply_collected(&state, vec![(ExchangeId::Bybit, None)], Vec::new(), rates()).await;
+        {
+            let perps = state.perp_snapshots.read().await;
+            assert_eq!(perps.len(), 1);
+            assert!(perps.contains_key(&(ExchangeId::Bybit, "BTCUSDT".to_string())));
+            assert_eq!(state.unified_snapshots.read().await.len(), 1);
+        }
+
+        // 차단 종목과 꺼진 거래소의 스트림 변경분은 버린다
+        let mut tick = PerpTick::new(
+            Instrument::new(
+                ExchangeId::Okx,
+                InstrumentKind::Perp,
+                "SOL",
+                "USDT",
+                "SOL-USDT-SWAP",
+            ),
+            Currency::USDT,
+        );
+        tick.mark_price = Some(Decimal::from(150));
+        apply_perp_ticks(&state, [tick]).await;
+        assert_eq!(state.perp_snapshots.read().await.len(), 1);
+    }
 }
@@ -0,0 +1,425 @@
+//! Oracle 설정 (TOML 파일 + CLI 플래그)
+//!
+//! 설정 파일을 읽은 뒤 CLI에 준 값으로 덮어쓴다. SIGHUP을 받으면 같은 순서로 다시 읽어
+//! AppState::config에 반영하고, 수집 루프와 CORS 검사는 다음 조회부터 새 값을 쓴다.
+//! 바인드 주소/포트와 history 설정은 재시작해야 바뀌고, 거래소 목록을 바꾼 설정은
+//! WebSocket 스트림과 어긋나므로 다시 읽을 때 거부한다.
+
+use std::{
+    net::{IpAddr, SocketAddr},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Deserializer};
+use structopt::StructOpt;
+use thiserror::Error;
+
+use interface::{ExchangeId, Instrument};
+
+/// --config를 주지 않았을 때 찾는 설정 파일 (없으면 기본값)
+pub const DEFAULT_CONFIG_PATH: &str = "oracle.toml";
+
+const ALL_PERP_EXCHANGES: [ExchangeId; 4] = [
+    ExchangeId::Binance,
+    ExchangeId::Bybit,
+    ExchangeId::Okx,
+    ExchangeId::Bitget,
+];
+const ALL_SPOT_EXCHANGES: [ExchangeId; 5] = [
+    ExchangeId::Binance,
+    ExchangeId::Bybit,
+    ExchangeId::Okx,
+    ExchangeId::Bitget,
+    ExchangeId::Bithumb,
+];
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+
+    #[error("Invalid TOML: {0}")]
+    Toml(String),
+
+    #[error("Invalid config: {0}")]
+    Invalid(String),
+}
+
+/// 종목 허용/차단 목록. base("BTC")나 공통 심볼("BTCUSDT")로 적는다 (대소문자 무관).
+/// allow가 비어 있으면 deny에 없는 전부를 허용한다.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SymbolFilter {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl SymbolFilter {
+    fn contains(list: &[String], base: &str, symbol: &str) -> bool {
+        list.iter()
+            .any(|s| s.eq_ignore_ascii_case(base) || s.eq_ignore_ascii_case(symbol))
+    }
+
+    pub fn allows(&self, base: &str, symbol: &str) -> bool {
+        (self.allow.is_empty() || Self::contains(&self.allow, base, symbol))
+            && !Self::contains(&self.deny, base, symbol)
+    }
+}
+
+/// 시계열 저장 설정
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HistoryConfig {
+    /// 빈 값(기본)이면 저장하지 않는다
+    pub db_path: String,
+    pub retention_days: i64,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            db_path: String::new(),
+            retention_days: 7,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OracleConfig {
+    pub bind: IpAddr,
+    pub port: u16,
+    /// 수집할 선물/현물 거래소 (소문자 이름)
+    #[serde(deserialize_with = "de_exchanges")]
+    pub perp_exchanges: Vec<ExchangeId>,
+    #[serde(deserialize_with = "de_exchanges")]
+    pub spot_exchanges: Vec<ExchangeId>,
+    pub symbols: SymbolFilter,
+    /// 24시간 거래량이 이보다 작은 종목은 보관하지 않음 (USD, 0이면 전부)
+    pub min_volume_usd: f64,
+    /// REST 보완 수집 간격과 거래소별 타임아웃 (초)
+    pub collect_interval_secs: u64,
+    pub fetch_timeout_secs: u64,
+    /// 입출금 현황 수집 간격 (초)
+    pub wallet_status_interval_secs: u64,
+    /// 허용할 CORS origin (비어 있거나 "*"가 있으면 전부 허용)
+    pub cors_origins: Vec<String>,
+    pub history: HistoryConfig,
+}
+
+impl Default for OracleConfig {
+    fn default() -> Self {
+        Self {
+            bind: IpAddr::from([0, 0, 0, 0]),
+            port: 12090,
+            perp_exchanges: ALL_PERP_EXCHANGES.to_vec(),
+            spot_exchanges: ALL_SPOT_EXCHANGES.to_vec(),
+            symbols: SymbolFilter::default(),
+            min_volume_usd: 0.0,
+            collect_interval_secs: 10,
+            fetch_timeout_secs: 20,
+            wallet_status_interval_secs: 300,
+            cors_origins: Vec::new(),
+            history: HistoryConfig::default(),
+        }
+    }
+}
+
+fn de_exchanges<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<ExchangeId>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|s| s.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
+
+impl OracleConfig {
+    pub fn from_toml_str(text: &str) -> Result<Self, ConfigError> {
+        let config: Self = toml::from_str(text).map_err(|e| ConfigError::Toml(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text =
+            std::fs::read_to_string(path).map_err(|e| ConfigError::Io(path.to_path_buf(), e))?;
+        Self::from_toml_str(&text)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(ex) = self
+            .perp_exchanges
+            .iter()
+            .find(|ex| !ALL_PERP_EXCHANGES.contains(ex))
+        {
+            return Err(ConfigError::Invalid(format!(
+                "{:?} has no perpetual market",
+                ex
+            )));
+        }
+        if self.collect_interval_secs == 0 || self.wallet_status_interval_secs == 0 {
+            return Err(ConfigError::Invalid(
+                "intervals must be positive".to_string(),
+            ));
+        }
+        if self.fetch_timeout_secs == 0 {
+            return Err(ConfigError::Invalid(
+                "fetch_timeout_secs must be positive".to_string(),
+            ));
+        }
+        if self.min_volume_usd.is_nan() || self.min_volume_usd < 0.0 {
+            return Err(ConfigError::Invalid(
+                "min_volume_usd must be >= 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        SocketAddr::new(self.bind, self.port)
+    }
+
+    pub fn collect_interval(&self) -> Duration {
+        Duration::from_secs(self.collect_interval_secs)
+    }
+
+    pub fn fetch_timeout(&self) -> Duration {
+        Duration::from_secs(self.fetch_timeout_secs)
+    }
+
+    pub fn wallet_status_interval(&self) -> Duration {
+        Duration::from_secs(self.wallet_status_interval_secs)
+    }
+
+    /// 다시 읽은 설정을 적용할 수 있는지 (스트림은 시작 시 거래소로만 떠 있으므로 거래소 목록이 같아야 한다)
+    pub fn check_reload(&self, running: &OracleConfig) -> Result<(), ConfigError> {
+        fn same(a: &[ExchangeId], b: &[ExchangeId]) -> bool {
+            a.iter().all(|ex| b.contains(ex)) && b.iter().all(|ex| a.contains(ex))
+        }
+        if !same(&self.perp_exchanges, &running.perp_exchanges)
+            || !same(&self.spot_exchanges, &running.spot_exchanges)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "exchange list changed (perp {:?} -> {:?}, spot {:?} -> {:?}); restart to apply",
+                running.perp_exchanges,
+                self.perp_exchanges,
+                running.spot_exchanges,
+                self.spot_exchanges
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn perp_enabled(&self, exchange: ExchangeId) -> bool {
+        self.perp_exchanges.contains(&exchange)
+    }
+
+    pub fn spot_enabled(&self, exchange: ExchangeId) -> bool {
+        self.spot_exchanges.contains(&exchange)
+    }
+
+    /// 종목 허용 목록 확인 (거래량은 보지 않음)
+    pub fn allows_instrument(&self, instrument: &Instrument) -> bool {
+        self.symbols.allows(&instrument.base, &instrument.symbol())
+    }
+
+    /// 종목 허용 목록과 최소 거래량을 함께 확인
+    pub fn accepts(&self, instrument: &Instrument, vol_24h_usd: f64) -> bool {
+        self.allows_instrument(instrument) && vol_24h_usd >= self.min_volume_usd
+    }
+
+    /// CORS origin 허용 여부
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.cors_origins.is_empty()
+            || self
+                .cors_origins
+                .iter()
+                .any(|o| o == "*" || o.trim_end_matches('/') == origin)
+    }
+}
+
+/// oracle 실행 옵션. 지정한 값은 설정 파일보다 우선하며 SIGHUP으로 다시 읽을 때도 유지된다.
+#[derive(Debug, Clone, Default, StructOpt)]
+#[structopt(name = "oracle", about = "거래소 시세 수집 및 HTTP 제공 서버")]
+pub struct CliArgs {
+    /// 설정 파일 경로 (기본: oracle.toml, 없으면 기본값 사용)
+    #[structopt(long, parse(from_os_str))]
+    pub config: Option<PathBuf>,
+    /// 바인드 주소
+    #[structopt(long)]
+    pub bind: Option<IpAddr>,
+    #[structopt(long)]
+    pub port: Option<u16>,
+    /// 수집할 선물 거래소 (쉼표 구분, 예: binance,bybit)
+    #[structopt(long, use_delimiter = true)]
+    pub perp_exchanges: Option<Vec<ExchangeId>>,
+    /// 수집할 현물 거래소 (쉼표 구분)
+    #[structopt(long, use_delimiter = true)]
+    pub spot_exchanges: Option<Vec<ExchangeId>>,
+    /// 허용 종목 (쉼표 구분, base 또는 공통 심볼)
+    #[structopt(long, use_delimiter = true)]
+    pub allow_symbols: Option<Vec<String>>,
+    /// 차단 종목 (쉼표 구분)
+    #[structopt(long, use_delimiter = true)]
+    pub deny_symbols: Option<Vec<String>>,
+    /// 최소 24시간 거래량 (USD)
+    #[structopt(long)]
+    pub min_volume_usd: Option<f64>,
+    /// REST 보완 수집 간격 (초)
+    #[structopt(long)]
+    pub interval: Option<u64>,
+    /// 허용할 CORS origin (쉼표 구분)
+    #[structopt(long, use_delimiter = true)]
+    pub cors_origins: Option<Vec<String>>,
+}
+
+impl CliArgs {
+    /// 설정 파일을 읽고 CLI 값을 덮어쓴 최종 설정
+    /// --config로 지정한 파일이 없으면 오류, 기본 경로 파일이 없으면 기본값에서 시작한다.
+    pub fn load_config(&self) -> Result<OracleConfig, ConfigError> {
+        let mut config = match &self.config {
+            Some(path) => OracleConfig::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                OracleConfig::from_file(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            None => OracleConfig::default(),
+        };
+        self.apply(&mut config);
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn apply(&self, config: &mut OracleConfig) {
+        if let Some(bind) = self.bind {
+            config.bind = bind;
+        }
+        if let Some(port) = self.port {
+            config.port = port;
+        }
+        if let Some(list) = &self.perp_exchanges {
+            config.perp_exchanges = list.clone();
+        }
+        if let Some(list) = &self.spot_exchanges {
+            config.spot_exchanges = list.clone();
+        }
+        if let Some(list) = &self.allow_symbols {
+            config.symbols.allow = list.clone();
+        }
+        if let Some(list) = &self.deny_symbols {
+            config.symbols.deny = list.clone();
+        }
+        if let Some(min_volume_usd) = self.min_volume_usd {
+            config.min_volume_usd = min_volume_usd;
+        }
+        if let Some(interval) = self.interval {
+            config.collect_interval_secs = interval;
+        }
+        if let Some(list) = &self.cors_origins {
+            config.cors_origins = list.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::InstrumentKind;
+
+    #[test]
+    fn test_parse_config() {
+        let config = OracleConfig::from_toml_str(
+            r#"
+            bind = "127.0.0.1"
+            port = 18080
+            perp_exchanges = ["binance", "OKX"]
+            min_volume_usd = 1_000_000
+            cors_origins = ["https://example.com/"]
+
+            [symbols]
+            deny = ["LUNA", "ethusdt"]
+
+            [history]
+            db_path = ""
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.addr(), "127.0.0.1:18080".parse().unwrap());
+        assert_eq!(
+            config.perp_exchanges,
+            vec![ExchangeId::Binance, ExchangeId::Okx]
+        );
+        // 적지 않은 값은 기본값
+        assert_eq!(config.spot_exchanges.len(), 5);
+        assert_eq!(config.collect_interval_secs, 10);
+        assert_eq!(config.history.retention_days, 7);
+        assert!(config.history.db_path.is_empty());
+
+        let eth = Instrument::new(
+            ExchangeId::Bybit,
+            InstrumentKind::Spot,
+            "ETH",
+            "USDT",
+            "ETHUSDT",
+        );
+        let eth_krw = Instrument::new(
+            ExchangeId::Bithumb,
+            InstrumentKind::Spot,
+            "ETH",
+            "KRW",
+            "ETH_KRW",
+        );
+        assert!(!config.allows_instrument(&eth));
+        assert!(config.allows_instrument(&eth_krw));
+        assert!(!config.accepts(&eth_krw, 10.0));
+        assert!(config.allows_origin("https://example.com"));
+        assert!(!config.allows_origin("https://evil.example"));
+
+        // 알 수 없는 키, 선물이 없는 거래소는 거부
+        assert!(OracleConfig::from_toml_str("intervall = 10").is_err());
+        assert!(OracleConfig::from_toml_str(r#"perp_exchanges = ["bithumb"]"#).is_err());
+        assert!(OracleConfig::from_toml_str("port = ").is_err());
+
+        // 시계열 저장은 기본으로 꺼져 있다
+        assert!(OracleConfig::default().history.db_path.is_empty());
+    }
+
+    #[test]
+    fn test_check_reload() {
+        let running = OracleConfig::default();
+        let mut reloaded = running.clone();
+        reloaded.collect_interval_secs = 60;
+        reloaded.perp_exchanges.reverse();
+        assert!(reloaded.check_reload(&running).is_ok());
+
+        // 거래소를 켜거나 끄는 설정은 거부
+        reloaded
+            .spot_exchanges
+            .retain(|ex| *ex != ExchangeId::Bithumb);
+        assert!(reloaded.check_reload(&running).is_err());
+        let narrowed = OracleConfig {
+            perp_exchanges: vec![ExchangeId::Binance],
+            ..OracleConfig::default()
+        };
+        assert!(running.check_reload(&narrowed).is_err());
+    }
+
+    #[test]
+    fn test_cli_overrides() {
+        let args = CliArgs::from_iter_safe([
+            "oracle",
+            "--port",
+            "9000",
+            "--allow-symbols",
+            "btc,ETH",
+            "--spot-exchanges",
+            "bithumb",
+        ])
+        .unwrap();
+        let mut config = OracleConfig::default();
+        args.apply(&mut config);
+        assert_eq!(config.port, 9000);
+        assert_eq!(config.symbols.allow, vec!["btc", "ETH"]);
+        assert_eq!(config.spot_exchanges, vec![ExchangeId::Bithumb]);
+        assert_eq!(config.perp_exchanges.len(), 4);
+    }
+}
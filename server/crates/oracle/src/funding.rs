@@ -0,0 +1,215 @@
+//! base 자산별 펀딩비 비교 (/funding)
+//!
+//! 거래소마다 펀딩 주기가 달라(Binance/Bybit/OKX 대부분 8시간, 일부 계약과 Bitget은 1/2/4시간)
+//! 한 주기 펀딩비를 그대로 비교하면 틀린다. 여기서는 선물 스냅샷을 시간당/연율로 환산하고,
+//! base 자산별로 연율 펀딩비가 가장 낮은/높은 거래소와 그 차이(펀딩 차익 폭)를 계산한다.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use interface::{ExchangeId, PerpData, UnifiedSnapshot};
+
+use crate::units::to_f64;
+
+/// 한 거래소 선물의 펀딩 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingVenue {
+    pub exchange: ExchangeId,
+    /// 거래소 원본 심볼
+    pub symbol: String,
+    pub quote: String,
+    /// 한 주기 펀딩비 (0.0001 == 0.01%)
+    pub funding_rate: f64,
+    /// 펀딩 주기 (시간, 거래소가 알려주지 않으면 8시간으로 가정)
+    pub funding_interval_hours: u32,
+    /// 거래소가 주기를 알려주지 않아 기본값을 썼는지
+    pub interval_assumed: bool,
+    pub hourly_rate: f64,
+    /// 단리 연율 (0.1 == 연 10%)
+    pub annualized_rate: f64,
+    /// 다음 주기 예상 펀딩비 (OKX처럼 따로 주는 거래소만)
+    pub predicted_funding_rate: Option<f64>,
+    pub next_funding_time: Option<DateTime<Utc>>,
+    pub mark_price: f64,
+    pub index_price: Option<f64>,
+    /// mark / index - 1
+    pub premium_index: Option<f64>,
+    pub oi_usd: f64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 연율 펀딩비가 가장 낮은/높은 거래소
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingSide {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    pub annualized_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingView {
+    pub base: String,
+    pub annualized_low: Option<FundingSide>,
+    pub annualized_high: Option<FundingSide>,
+    /// 최고 - 최저 연율 펀딩비 (낮은 쪽 롱 + 높은 쪽 숏으로 받을 수 있는 펀딩 폭, 거래소가 하나면 0)
+    pub differential: Option<f64>,
+    /// OI 가중 평균 연율 펀딩비 (OI를 모르면 단순 평균)
+    pub annualized_mean: Option<f64>,
+    /// 연율 펀딩비 오름차순
+    pub venues: Vec<FundingVenue>,
+}
+
+fn funding_venue(snapshot: &UnifiedSnapshot, perp: &PerpData) -> FundingVenue {
+    FundingVenue {
+        exchange: snapshot.exchange,
+        symbol: perp.instrument.native_symbol.clone(),
+        quote: snapshot.quote.clone(),
+        funding_rate: perp.funding_rate,
+        funding_interval_hours: perp.funding_interval_hours_or_default(),
+        interval_assumed: perp.funding_interval_hours.is_none_or(|h| h == 0),
+        hourly_rate: perp.hourly_funding_rate(),
+        annualized_rate: perp.annualized_funding_rate(),
+        predicted_funding_rate: perp.predicted_funding_rate,
+        next_funding_time: perp.next_funding_time,
+        mark_price: to_f64(perp.mark_price),
+        index_price: perp.index_price.map(to_f64),
+        premium_index: perp.premium_index,
+        oi_usd: perp.oi_usd,
+        updated_at: snapshot.updated_at,
+    }
+}
+
+fn funding_side(venue: &FundingVenue) -> FundingSide {
+    FundingSide {
+        exchange: venue.exchange,
+        symbol: venue.symbol.clone(),
+        annualized_rate: venue.annualized_rate,
+    }
+}
+
+fn weighted_mean(venues: &[FundingVenue]) -> Option<f64> {
+    if venues.is_empty() {
+        return None;
+    }
+    let total_oi: f64 = venues.iter().map(|v| v.oi_usd).sum();
+    if total_oi > 0.0 {
+        Some(
+            venues
+                .iter()
+                .map(|v| v.annualized_rate * v.oi_usd)
+                .sum::<f64>()
+                / total_oi,
+        )
+    } else {
+        Some(venues.iter().map(|v| v.annualized_rate).sum::<f64>() / venues.len() as f64)
+    }
+}
+
+/// 한 base 자산의 거래소별 펀딩 정보로 요약 계산
+fn summarize(base: String, mut venues: Vec<FundingVenue>) -> FundingView {
+    venues.retain(|v| v.annualized_rate.is_finite());
+    venues.sort_by(|a, b| a.annualized_rate.total_cmp(&b.annualized_rate));
+
+    let annualized_low = venues.first().map(funding_side);
+    let annualized_high = venues.last().map(funding_side);
+    let differential = match (&annualized_low, &annualized_high) {
+        (Some(low), Some(high)) => Some(high.annualized_rate - low.annualized_rate),
+        _ => None,
+    };
+
+    FundingView {
+        base,
+        annualized_low,
+        annualized_high,
+        differential,
+        annualized_mean: weighted_mean(&venues),
+        venues,
+    }
+}
+
+/// 통합 스냅샷의 선물을 base 자산별로 묶어 펀딩비 요약 (거래소 간 연율 차이 내림차순)
+pub fn aggregate_funding(snapshots: &[UnifiedSnapshot]) -> Vec<FundingView> {
+    let mut by_base: BTreeMap<&str, Vec<FundingVenue>> = BTreeMap::new();
+    for snapshot in snapshots {
+        if let Some(perp) = &snapshot.perp {
+            by_base
+                .entry(&snapshot.base)
+                .or_default()
+                .push(funding_venue(snapshot, perp));
+        }
+    }
+    let mut views: Vec<_> = by_base
+        .into_iter()
+        .map(|(base, venues)| summarize(base.to_string(), venues))
+        .filter(|view| !view.venues.is_empty())
+        .collect();
+    views.sort_by(|a, b| {
+        b.differential
+            .unwrap_or(0.0)
+            .total_cmp(&a.differential.unwrap_or(0.0))
+    });
+    views
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    /// USDT 무기한 선물만 있는 통합 스냅샷
+    fn snapshot(
+        exchange: ExchangeId,
+        base: &str,
+        funding_rate: f64,
+        funding_interval_hours: Option<u32>,
+        oi_usd: f64,
+    ) -> UnifiedSnapshot {
+        let native = format!("{}USDT", base);
+        let mut snapshot = test_support::snapshot(exchange, base, "USDT", &native, Some(100), None);
+        if let Some(data) = snapshot.perp.as_mut() {
+            data.funding_rate = funding_rate;
+            data.funding_interval_hours = funding_interval_hours;
+            data.oi_usd = oi_usd;
+        }
+        snapshot
+    }
+
+    #[test]
+    fn test_aggregate_funding() {
+        let snapshots = vec![
+            // 8시간 0.01% == 연 10.95%
+            snapshot(ExchangeId::Binance, "BTC", 0.0001, Some(8), 3_000_000.0),
+            // 4시간 0.01% == 연 21.9% (한 주기 값은 같아도 두 배)
+            snapshot(ExchangeId::Bitget, "BTC", 0.0001, Some(4), 1_000_000.0),
+            // 주기를 모르면 8시간으로 가정
+            snapshot(ExchangeId::Okx, "BTC", -0.0001, None, 0.0),
+            snapshot(ExchangeId::Bybit, "ETH", 0.0002, Some(8), 0.0),
+        ];
+
+        let views = aggregate_funding(&snapshots);
+        assert_eq!(views.len(), 2);
+        let btc = &views[0];
+        assert_eq!(btc.base, "BTC");
+        assert_eq!(btc.venues.len(), 3);
+
+        let low = btc.annualized_low.as_ref().unwrap();
+        let high = btc.annualized_high.as_ref().unwrap();
+        assert_eq!(low.exchange, ExchangeId::Okx);
+        assert_eq!(high.exchange, ExchangeId::Bitget);
+        assert!((high.annualized_rate - 0.219).abs() < 1e-9);
+        assert!((btc.differential.unwrap() - (0.219 + 0.1095)).abs() < 1e-9);
+        assert!(btc.venues[0].interval_assumed);
+        assert_eq!(btc.venues[0].funding_interval_hours, 8);
+
+        // OI 가중 평균: (0.1095 * 3 + 0.219 * 1) / 4
+        let mean = btc.annualized_mean.unwrap();
+        assert!((mean - (0.1095 * 3.0 + 0.219) / 4.0).abs() < 1e-9);
+
+        // 거래소가 하나뿐이면 차이 0, OI가 없으면 단순 평균
+        let eth = &views[1];
+        assert_eq!(eth.differential, Some(0.0));
+        assert!((eth.annualized_mean.unwrap() - 0.219).abs() < 1e-9);
+    }
+}
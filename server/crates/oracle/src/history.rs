@@ -0,0 +1,505 @@
+//! 수집 회차별 스냅샷 시계열 저장소 (SQLite)
+//!
+//! REST 보완 수집이 끝날 때마다 통합 스냅샷(선물/현물)과 환율을 한 줄씩 쌓고,
+//! `/history`에서 거래소·심볼·필드·기간으로 골라 구간 평균으로 다운샘플링해 돌려준다.
+//! 시각은 정렬과 구간 계산이 쉽도록 ms 타임스탬프 정수로 저장한다.
+
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    sea_query::Index, ColumnTrait, ConnectionTrait, Database, DatabaseConnection, DbBackend, DbErr,
+    EntityTrait, FromQueryResult, QueryFilter, Schema, Set, Statement, TransactionTrait,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+use interface::{Decimal, ExchangeId, ExchangeRates, UnifiedSnapshot};
+
+/// 한 번에 insert할 최대 행 수 (SQLite 바인딩 변수 한도 안쪽)
+const INSERT_CHUNK: usize = 500;
+
+/// 통합 스냅샷 시계열 엔티티
+pub mod snapshot_history {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "snapshot_history")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 수집 시각 (UTC ms)
+        pub ts: i64,
+
+        /// 거래소 이름 (소문자)
+        #[sea_orm(column_type = "Text")]
+        pub exchange: String,
+
+        /// 공통 심볼 (base + quote, 예: BTCUSDT)
+        #[sea_orm(column_type = "Text")]
+        pub symbol: String,
+
+        #[sea_orm(column_type = "Double", nullable)]
+        pub mark_price: Option<f64>,
+
+        #[sea_orm(column_type = "Double", nullable)]
+        pub spot_price: Option<f64>,
+
+        /// 선물 mark / 현물 - 1 (둘 다 있을 때만)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub basis: Option<f64>,
+
+        #[sea_orm(column_type = "Double", nullable)]
+        pub funding_rate: Option<f64>,
+
+        #[sea_orm(column_type = "Double", nullable)]
+        pub oi_usd: Option<f64>,
+
+        #[sea_orm(column_type = "Double", nullable)]
+        pub perp_volume: Option<f64>,
+
+        #[sea_orm(column_type = "Double", nullable)]
+        pub spot_volume: Option<f64>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+/// 환율 시계열 엔티티
+pub mod rate_history {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "rate_history")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 수집 시각 (UTC ms)
+        pub ts: i64,
+
+        #[sea_orm(column_type = "Double")]
+        pub usd_krw: f64,
+
+        #[sea_orm(column_type = "Double")]
+        pub usdt_usd: f64,
+
+        #[sea_orm(column_type = "Double")]
+        pub usdt_krw: f64,
+
+        /// 기본값/오래된 환율이 섞였는지
+        pub is_fallback: bool,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[derive(Error, Debug)]
+pub enum HistoryError {
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+
+    #[error("{0}")]
+    InvalidQuery(String),
+
+    #[error("History storage error: {0}")]
+    Other(String),
+}
+
+/// 조회할 수 있는 필드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryField {
+    MarkPrice,
+    SpotPrice,
+    Basis,
+    FundingRate,
+    OiUsd,
+    PerpVolume,
+    SpotVolume,
+    UsdKrw,
+    UsdtUsd,
+    UsdtKrw,
+}
+
+impl HistoryField {
+    /// 컬럼 이름 (SQL에 그대로 들어가므로 여기 나열한 값만 쓴다)
+    fn column(&self) -> &'static str {
+        match self {
+            HistoryField::MarkPrice => "mark_price",
+            HistoryField::SpotPrice => "spot_price",
+            HistoryField::Basis => "basis",
+            HistoryField::FundingRate => "funding_rate",
+            HistoryField::OiUsd => "oi_usd",
+            HistoryField::PerpVolume => "perp_volume",
+            HistoryField::SpotVolume => "spot_volume",
+            HistoryField::UsdKrw => "usd_krw",
+            HistoryField::UsdtUsd => "usdt_usd",
+            HistoryField::UsdtKrw => "usdt_krw",
+        }
+    }
+
+    /// 환율 필드면 거래소/심볼 없이 rate_history에서 조회
+    pub fn is_rate(&self) -> bool {
+        matches!(
+            self,
+            HistoryField::UsdKrw | HistoryField::UsdtUsd | HistoryField::UsdtKrw
+        )
+    }
+}
+
+/// 시계열 조회 조건
+#[derive(Debug, Clone)]
+pub struct HistoryQuery {
+    /// 환율 필드가 아니면 필수
+    pub exchange: Option<ExchangeId>,
+    pub symbol: Option<String>,
+    pub field: HistoryField,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// 다운샘플링 구간 (ms, 1이면 원본 그대로)
+    pub bucket_ms: i64,
+}
+
+/// 다운샘플링 구간 하나 (time은 구간 시작 시각)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryPoint {
+    pub time: DateTime<Utc>,
+    /// 구간 평균
+    pub value: f64,
+    pub min: f64,
+    pub max: f64,
+    /// 구간에 들어간 수집 회차 수
+    pub count: i64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct BucketRow {
+    bucket: i64,
+    avg: f64,
+    min: f64,
+    max: f64,
+    count: i64,
+}
+
+fn exchange_name(exchange: ExchangeId) -> String {
+    format!("{:?}", exchange).to_lowercase()
+}
+
+fn to_f64(value: Decimal) -> Option<f64> {
+    f64::try_from(value).ok()
+}
+
+fn snapshot_row(ts: i64, snapshot: &UnifiedSnapshot) -> snapshot_history::ActiveModel {
+    let perp = snapshot.perp.as_ref();
+    let spot = snapshot.spot.as_ref();
+    let basis = match (perp, spot) {
+        (Some(perp), Some(spot)) if !spot.price.is_zero() => {
+            to_f64(perp.mark_price / spot.price - Decimal::ONE)
+        }
+        _ => None,
+    };
+
+    snapshot_history::ActiveModel {
+        ts: Set(ts),
+        exchange: Set(exchange_name(snapshot.exchange)),
+        symbol: Set(snapshot.symbol.clone()),
+        mark_price: Set(perp.and_then(|p| to_f64(p.mark_price))),
+        spot_price: Set(spot.and_then(|s| to_f64(s.price))),
+        basis: Set(basis),
+        funding_rate: Set(perp.map(|p| p.funding_rate)),
+        oi_usd: Set(perp.map(|p| p.oi_usd)),
+        perp_volume: Set(perp.map(|p| p.vol_24h_usd)),
+        spot_volume: Set(spot.map(|s| s.vol_24h_usd)),
+        ..Default::default()
+    }
+}
+
+/// SQLite 기반 시계열 저장소
+pub struct HistoryStore {
+    db: DatabaseConnection,
+}
+
+impl HistoryStore {
+    /// DB 파일을 열거나 만든다 (상대 경로는 현재 디렉토리 기준)
+    pub async fn open(db_path: &str) -> Result<Self, HistoryError> {
+        let mut path = PathBuf::from(db_path);
+        if !path.is_absolute() {
+            if let Ok(current_dir) = std::env::current_dir() {
+                path = current_dir.join(db_path);
+            }
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                HistoryError::Other(format!("Failed to create DB directory: {}", e))
+            })?;
+        }
+
+        let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
+        info!("시계열 DB 연결: {}", db_url);
+        Self::connect(&db_url).await
+    }
+
+    /// DB URL로 연결하고 테이블/인덱스가 없으면 만든다 (테스트는 sqlite::memory:)
+    pub async fn connect(db_url: &str) -> Result<Self, HistoryError> {
+        let db = Database::connect(db_url).await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut snapshot_table = schema.create_table_from_entity(snapshot_history::Entity);
+        snapshot_table.if_not_exists();
+        db.execute(backend.build(&snapshot_table)).await?;
+
+        let mut rate_table = schema.create_table_from_entity(rate_history::Entity);
+        rate_table.if_not_exists();
+        db.execute(backend.build(&rate_table)).await?;
+
+        let mut series_idx = Index::create()
+            .name("idx_snapshot_history_series")
+            .table(snapshot_history::Entity)
+            .col(snapshot_history::Column::Exchange)
+            .col(snapshot_history::Column::Symbol)
+            .col(snapshot_history::Column::Ts)
+            .to_owned();
+        series_idx.if_not_exists();
+        db.execute(backend.build(&series_idx)).await?;
+
+        // 보관 기간 정리용
+        let mut ts_idx = Index::create()
+            .name("idx_snapshot_history_ts")
+            .table(snapshot_history::Entity)
+            .col(snapshot_history::Column::Ts)
+            .to_owned();
+        ts_idx.if_not_exists();
+        db.execute(backend.build(&ts_idx)).await?;
+
+        let mut rate_ts_idx = Index::create()
+            .name("idx_rate_history_ts")
+            .table(rate_history::Entity)
+            .col(rate_history::Column::Ts)
+            .to_owned();
+        rate_ts_idx.if_not_exists();
+        db.execute(backend.build(&rate_ts_idx)).await?;
+
+        Ok(Self { db })
+    }
+
+    /// 수집 한 회차 저장 (통합 스냅샷 전체 + 환율), 저장한 스냅샷 행 수를 반환
+    pub async fn save_cycle(
+        &self,
+        at: DateTime<Utc>,
+        snapshots: &[UnifiedSnapshot],
+        rates: &ExchangeRates,
+    ) -> Result<usize, HistoryError> {
+        let ts = at.timestamp_millis();
+        let txn = self.db.begin().await?;
+
+        for chunk in snapshots.chunks(INSERT_CHUNK) {
+            snapshot_history::Entity::insert_many(chunk.iter().map(|s| snapshot_row(ts, s)))
+                .exec_without_returning(&txn)
+                .await?;
+        }
+        rate_history::Entity::insert(rate_history::ActiveModel {
+            ts: Set(ts),
+            usd_krw: Set(rates.usd_krw),
+            usdt_usd: Set(rates.usdt_usd),
+            usdt_krw: Set(rates.usdt_krw),
+            is_fallback: Set(rates.is_fallback),
+            ..Default::default()
+        })
+        .exec_without_returning(&txn)
+        .await?;
+
+        txn.commit().await?;
+        Ok(snapshots.len())
+    }
+
+    /// before 이전 기록 삭제, 지운 행 수를 반환
+    pub async fn prune(&self, before: DateTime<Utc>) -> Result<u64, HistoryError> {
+        let ts = before.timestamp_millis();
+        let snapshots = snapshot_history::Entity::delete_many()
+            .filter(snapshot_history::Column::Ts.lt(ts))
+            .exec(&self.db)
+            .await?;
+        let rates = rate_history::Entity::delete_many()
+            .filter(rate_history::Column::Ts.lt(ts))
+            .exec(&self.db)
+            .await?;
+        Ok(snapshots.rows_affected + rates.rows_affected)
+    }
+
+    /// 조건에 맞는 값을 bucket_ms 구간별 평균/최소/최대로 묶어 시간 오름차순으로 반환
+    /// (값이 없는 회차는 건너뛴다)
+    pub async fn query(&self, query: &HistoryQuery) -> Result<Vec<HistoryPoint>, HistoryError> {
+        let bucket = query.bucket_ms.max(1);
+        let column = query.field.column();
+        let mut values = vec![
+            bucket.into(),
+            bucket.into(),
+            query.start.timestamp_millis().into(),
+            query.end.timestamp_millis().into(),
+        ];
+
+        let (table, series_filter) = if query.field.is_rate() {
+            ("rate_history", "")
+        } else {
+            let (Some(exchange), Some(symbol)) = (query.exchange, query.symbol.as_ref()) else {
+                return Err(HistoryError::InvalidQuery(format!(
+                    "exchange and symbol are required for {}",
+                    column
+                )));
+            };
+            values.push(exchange_name(exchange).into());
+            values.push(symbol.to_uppercase().into());
+            ("snapshot_history", " AND exchange = ? AND symbol = ?")
+        };
+
+        let sql = format!(
+            "SELECT (ts / ?) * ? AS bucket, AVG({col}) AS avg, MIN({col}) AS min, \
+             MAX({col}) AS max, COUNT({col}) AS count FROM {table} \
+             WHERE ts >= ? AND ts <= ?{series_filter} AND {col} IS NOT NULL \
+             GROUP BY bucket ORDER BY bucket",
+            col = column,
+            table = table,
+            series_filter = series_filter,
+        );
+        let rows = BucketRow::find_by_statement(Statement::from_sql_and_values(
+            DbBackend::Sqlite,
+            sql,
+            values,
+        ))
+        .all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                Some(HistoryPoint {
+                    time: DateTime::from_timestamp_millis(row.bucket)?,
+                    value: row.avg,
+                    min: row.min,
+                    max: row.max,
+                    count: row.count,
+                })
+            })
+            .collect())
+    }
+}
+
+/// 보관 기간이 지난 기록을 한 시간마다 지운다
+pub fn start_retention_loop(store: Arc<HistoryStore>, retention: chrono::Duration) {
+    tokio::spawn(async move {
+        info!("시계열 보관 기간: {}일", retention.num_days());
+        loop {
+            match store.prune(Utc::now() - retention).await {
+                Ok(0) => {}
+                Ok(n) => info!("보관 기간이 지난 시계열 {}행 삭제", n),
+                Err(e) => warn!("시계열 정리 실패: {}", e),
+            }
+            sleep(Duration::from_secs(3600)).await;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn snapshot(exchange: ExchangeId, mark: i64, spot: Option<i64>) -> UnifiedSnapshot {
+        test_support::snapshot(exchange, "BTC", "USDT", "BTCUSDT", Some(mark), spot)
+    }
+
+    fn rates(usd_krw: f64) -> ExchangeRates {
+        test_support::rates_with(usd_krw, 1.0)
+    }
+
+    #[tokio::test]
+    async fn test_save_query_and_prune() {
+        let store = HistoryStore::connect("sqlite::memory:").await.unwrap();
+        // 구간은 epoch 기준으로 나뉘므로 2분 경계에 맞춘 시각에서 시작
+        let t0 = DateTime::from_timestamp_millis(1_700_000_040_000).unwrap();
+        let minute = chrono::Duration::minutes(1);
+
+        // 1분 간격 4회: Bybit 베이시스 1%, 2%, 3%, 4% (마지막 회차는 현물 없음)
+        for i in 0..4 {
+            let spot = (i < 3).then_some(100);
+            let snapshots = vec![
+                snapshot(ExchangeId::Bybit, 101 + i, spot),
+                snapshot(ExchangeId::Okx, 200, Some(200)),
+            ];
+            let saved = store
+                .save_cycle(
+                    t0 + minute * i as i32,
+                    &snapshots,
+                    &rates(1300.0 + i as f64),
+                )
+                .await
+                .unwrap();
+            assert_eq!(saved, 2);
+        }
+
+        let query = |field, bucket_ms| HistoryQuery {
+            exchange: Some(ExchangeId::Bybit),
+            symbol: Some("btcusdt".to_string()),
+            field,
+            start: t0,
+            end: t0 + minute * 10,
+            bucket_ms,
+        };
+
+        let raw = store.query(&query(HistoryField::Basis, 1)).await.unwrap();
+        assert_eq!(raw.len(), 3);
+        assert_eq!(raw[0].time, t0);
+        assert!((raw[2].value - 0.03).abs() < 1e-9);
+
+        // 2분 구간: [1%, 2%], [3%]
+        let downsampled = store
+            .query(&query(HistoryField::Basis, 2 * 60_000))
+            .await
+            .unwrap();
+        assert_eq!(downsampled.len(), 2);
+        assert!((downsampled[0].value - 0.015).abs() < 1e-9);
+        assert_eq!(downsampled[0].count, 2);
+        assert!((downsampled[0].max - 0.02).abs() < 1e-9);
+
+        let marks = store
+            .query(&query(HistoryField::MarkPrice, 1))
+            .await
+            .unwrap();
+        assert_eq!(marks.len(), 4);
+
+        let usd_krw = store
+            .query(&HistoryQuery {
+                exchange: None,
+                symbol: None,
+                ..query(HistoryField::UsdKrw, 1)
+            })
+            .await
+            .unwrap();
+        assert_eq!(usd_krw.last().unwrap().value, 1303.0);
+
+        let err = store
+            .query(&HistoryQuery {
+                exchange: None,
+                ..query(HistoryField::OiUsd, 1)
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HistoryError::InvalidQuery(_)));
+
+        // 앞 2회차 삭제: 스냅샷 2행 x 2회 + 환율 2행
+        assert_eq!(store.prune(t0 + minute * 2).await.unwrap(), 6);
+        let raw = store.query(&query(HistoryField::Basis, 1)).await.unwrap();
+        assert_eq!(raw.len(), 1);
+    }
+}
@@ -1,2 +1,12 @@
+pub mod asset;
 pub mod collector;
+pub mod config;
+pub mod funding;
+pub mod history;
+pub mod opportunity;
+pub mod push;
+pub mod query;
 pub mod server;
+#[cfg(test)]
+mod test_support;
+mod units;
@@ -1,14 +1,20 @@
-use std::{sync::Arc, time::Duration};
+use std::sync::Arc;
 
 use color_eyre::eyre;
-use tracing::info;
+use structopt::StructOpt;
+use tracing::{info, warn};
 use tracing_subscriber::{fmt, EnvFilter};
 
 use exchanges::{
-    bithumb::BithumbClient, BinanceClient, BitgetClient, BybitClient, OkxClient, PerpExchange,
-    SpotExchange,
+    bithumb::BithumbClient, BinanceClient, BitgetClient, BybitClient, FeeExchange,
+    FundingHistoryExchange, OkxClient, PerpExchange, PerpStream, SpotExchange, SpotStream,
+    WalletStatusExchange,
+};
+use oracle::{
+    config::{CliArgs, OracleConfig},
+    history::HistoryStore,
+    server::AppState,
 };
-use oracle::server::AppState;
 
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
@@ -20,35 +26,146 @@ async fn main() -> eyre::Result<()> {
 
     info!("서버 시작 중...");
 
-    let state = Arc::new(AppState::new());
+    // config file first, CLI flags on top (re-applied on every SIGHUP reload)
+    let args = CliArgs::from_args();
+    let config = args.load_config()?;
+    info!(
+        "설정: 선물 {:?}, 현물 {:?}, {}초 간격, {}",
+        config.perp_exchanges,
+        config.spot_exchanges,
+        config.collect_interval_secs,
+        config.addr()
+    );
+    let mut state = AppState::with_config(config.clone());
+
+    // one client per exchange, shared by REST, stream and funding history
+    let binance = Arc::new(BinanceClient::new());
+    let bybit = Arc::new(BybitClient::new());
+    let okx = Arc::new(OkxClient::new());
+    let bitget = Arc::new(BitgetClient::new());
+    let bithumb = Arc::new(BithumbClient::new());
+
+    // every client is handed to the REST loop, which skips venues disabled in the config
+    let perp_exchanges: Vec<Arc<dyn PerpExchange>> =
+        vec![binance.clone(), bybit.clone(), okx.clone(), bitget.clone()];
+    let perp_streams: Vec<Arc<dyn PerpStream>> =
+        vec![binance.clone(), bybit.clone(), okx.clone(), bitget.clone()];
+
+    // funding history is fetched on request from the same perp clients
+    let funding_exchanges: Vec<Arc<dyn FundingHistoryExchange>> =
+        vec![binance.clone(), bybit.clone(), okx.clone(), bitget.clone()];
+    state.funding_exchanges = funding_exchanges;
 
-    // set up perp exchanges
-    let perp_exchanges: Vec<Arc<dyn PerpExchange>> = vec![
-        Arc::new(BinanceClient::new()),
-        Arc::new(BybitClient::new()),
-        Arc::new(OkxClient::new()),
-        Arc::new(BitgetClient::new()),
+    // published taker fees for the /opportunities scanner
+    let fee_exchanges: Vec<Arc<dyn FeeExchange>> = vec![
+        binance.clone(),
+        bybit.clone(),
+        okx.clone(),
+        bitget.clone(),
+        bithumb.clone(),
     ];
+    state.fee_exchanges = fee_exchanges;
+
+    // time-series history of every REST collection cycle (empty history.db_path disables it)
+    if !config.history.db_path.is_empty() {
+        match HistoryStore::open(&config.history.db_path).await {
+            Ok(store) => {
+                let store = Arc::new(store);
+                oracle::history::start_retention_loop(
+                    store.clone(),
+                    chrono::Duration::days(config.history.retention_days),
+                );
+                state.history = Some(store);
+            }
+            Err(e) => warn!("시계열 저장소를 열 수 없어 저장하지 않습니다: {}", e),
+        }
+    }
+    let state = Arc::new(state);
+
+    // wallet status: Bithumb is public, Binance needs an API key
+    let mut wallet_exchanges: Vec<Arc<dyn WalletStatusExchange>> = vec![bithumb.clone()];
+    match BinanceClient::with_credentials() {
+        Ok(client) => wallet_exchanges.push(Arc::new(client)),
+        Err(e) => warn!("Binance 입출금 현황은 수집하지 않습니다: {}", e),
+    }
 
     // set up spot exchanges
     let spot_exchanges: Vec<Arc<dyn SpotExchange>> = vec![
-        Arc::new(BinanceClient::new()),
-        Arc::new(BybitClient::new()),
-        Arc::new(OkxClient::new()),
-        Arc::new(BitgetClient::new()),
-        Arc::new(BithumbClient::new()),
+        binance.clone(),
+        bybit.clone(),
+        okx.clone(),
+        bitget.clone(),
+        bithumb.clone(),
     ];
+    let spot_streams: Vec<Arc<dyn SpotStream>> = vec![binance, bybit, okx, bitget, bithumb];
 
-    // start background collector
-    oracle::collector::start_collect_loop(
-        perp_exchanges,
-        spot_exchanges,
-        state.clone(),
-        Duration::from_secs(10),
-    );
+    // streams are only opened for venues enabled at startup
+    let perp_streams = perp_streams
+        .into_iter()
+        .filter(|s| config.perp_enabled(s.id()))
+        .collect();
+    let spot_streams = spot_streams
+        .into_iter()
+        .filter(|s| config.spot_enabled(s.id()))
+        .collect();
+
+    // market data arrives over WebSocket; REST polling only fills gaps
+    // (all venues in parallel, each one given up to fetch_timeout_secs)
+    oracle::collector::start_stream_tasks(perp_streams, spot_streams, state.clone());
+    oracle::collector::start_collect_loop(perp_exchanges, spot_exchanges, state.clone());
+    oracle::collector::start_wallet_status_loop(wallet_exchanges, state.clone());
 
-    // start HTTP server on 8080
-    oracle::server::serve(state, 12090).await?;
+    #[cfg(unix)]
+    spawn_reload_on_sighup(args, config, state.clone())?;
 
+    // start HTTP server on the configured bind address and port
+    oracle::server::serve(state).await?;
+
+    Ok(())
+}
+
+/// SIGHUP을 받으면 설정 파일을 다시 읽어 수집 루프와 CORS에 반영한다
+/// (읽기에 실패하거나 거래소 목록이 바뀌었으면 기존 설정 유지)
+/// startup은 시작 시 설정으로, 스트림을 띄운 거래소와 바인드 주소를 비교하는 데 쓴다.
+#[cfg(unix)]
+fn spawn_reload_on_sighup(
+    args: CliArgs,
+    startup: OracleConfig,
+    state: Arc<AppState>,
+) -> eyre::Result<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangup = signal(SignalKind::hangup())?;
+    tokio::spawn(async move {
+        while hangup.recv().await.is_some() {
+            match args.load_config() {
+                Ok(config) => apply_reloaded(&state, &startup, config),
+                Err(e) => warn!("설정을 다시 읽지 못해 기존 설정을 유지합니다: {}", e),
+            }
+        }
+    });
     Ok(())
 }
+
+#[cfg(unix)]
+fn apply_reloaded(state: &AppState, startup: &OracleConfig, config: OracleConfig) {
+    if config.addr() != startup.addr() {
+        warn!(
+            "바인드 주소 변경({} -> {})은 재시작해야 반영됩니다",
+            startup.addr(),
+            config.addr()
+        );
+    }
+    if config.history != startup.history {
+        warn!("history 설정 변경은 재시작해야 반영됩니다");
+    }
+    if let Err(e) = config.check_reload(startup) {
+        warn!("거래소 목록이 바뀐 설정은 적용하지 않습니다: {}", e);
+        return;
+    }
+    info!(
+        "설정을 다시 읽었습니다: 선물 {:?}, 현물 {:?}, {}초 간격",
+        config.perp_exchanges, config.spot_exchanges, config.collect_interval_secs
+    );
+    state.set_config(config);
+}
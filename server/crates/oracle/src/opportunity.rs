@@ -0,0 +1,410 @@
+//! 수수료 차감 재정거래 기회 계산 (/opportunities)
+//!
+//! 통합 스냅샷에서 base 자산별로 네 가지를 계산한다.
+//! - intra_basis: 같은 거래소 현물-선물 베이시스 (진입+청산 4번 테이커)
+//! - perp_spread: 거래소 간 선물 가격 차이 (진입+청산 4번 테이커)
+//! - spot_spread: USDT/USD 현물 거래소 간 가격 차이 (사고 파는 2번 테이커, 이체 비용 제외)
+//! - krw_premium: 원화 현물과 해외 현물의 가격 차이 (ExchangeRates의 USDT/KRW로 환산, 환율이 대체값이면 계산하지 않음)
+//!
+//! 가격은 모두 USDT 기준으로 환산해 비교하고, 선물 레그는 horizon 동안의 펀딩비를 더하거나 뺀다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use interface::{Currency, ExchangeId, ExchangeRates, InstrumentKind, UnifiedSnapshot};
+
+use crate::units::{to_f64, BPS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpportunityKind {
+    IntraBasis,
+    PerpSpread,
+    SpotSpread,
+    KrwPremium,
+}
+
+/// 한쪽 레그 (buy는 싼 쪽, sell은 비싼 쪽)
+#[derive(Debug, Clone, Serialize)]
+pub struct OpportunityLeg {
+    pub exchange: ExchangeId,
+    pub kind: InstrumentKind,
+    /// 거래소 원본 심볼
+    pub symbol: String,
+    pub currency: Currency,
+    /// 거래소 표시 통화 기준 가격 (base 1개)
+    pub price: f64,
+    /// USDT 환산 가격
+    pub price_usdt: f64,
+    pub vol_24h_usd: f64,
+    /// 선물 레그만
+    pub oi_usd: Option<f64>,
+    pub funding_rate: Option<f64>,
+    /// 펀딩 주기 (시간)
+    pub funding_interval_hours: Option<u32>,
+    /// 적용한 테이커 수수료율 (선물 레그는 선물 요율)
+    pub taker_fee: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Opportunity {
+    pub kind: OpportunityKind,
+    pub base: String,
+    pub buy: OpportunityLeg,
+    pub sell: OpportunityLeg,
+    /// 비싼 쪽 / 싼 쪽 - 1 (bps)
+    pub gross_bps: f64,
+    /// 테이커 수수료 합계 (bps)
+    pub fee_bps: f64,
+    /// horizon 동안 선물 레그에서 받는(+) 또는 내는(-) 펀딩비 (bps)
+    pub funding_carry_bps: f64,
+    /// gross - fee + funding carry
+    pub edge_bps: f64,
+}
+
+/// 계산 조건
+#[derive(Debug, Clone)]
+pub struct ScanParams {
+    /// 펀딩비를 계산할 보유 기간 (시간)
+    pub horizon_hours: f64,
+    /// 두 레그 모두 24시간 거래량이 이 이상 (USD)
+    pub min_volume_usd: f64,
+    /// 선물 레그 미결제약정이 이 이상 (USD)
+    pub min_oi_usd: f64,
+}
+
+impl Default for ScanParams {
+    fn default() -> Self {
+        Self {
+            horizon_hours: 24.0,
+            min_volume_usd: 0.0,
+            min_oi_usd: 0.0,
+        }
+    }
+}
+
+/// 표시 통화 가격을 USDT로 환산
+fn to_usdt(price: f64, currency: Currency, rates: &ExchangeRates) -> Option<f64> {
+    let converted = match currency {
+        Currency::USDT => price,
+        Currency::USD => price / rates.usdt_usd,
+        Currency::KRW => price / rates.usdt_krw,
+    };
+    (converted.is_finite() && converted > 0.0).then_some(converted)
+}
+
+/// 통합 스냅샷에서 선물/현물 레그 추출 (가격을 환산할 수 없으면 None)
+fn perp_leg(
+    snapshot: &UnifiedSnapshot,
+    rates: &ExchangeRates,
+    taker_fee: &impl Fn(ExchangeId, &str, InstrumentKind) -> f64,
+) -> Option<OpportunityLeg> {
+    let perp = snapshot.perp.as_ref()?;
+    let price = to_f64(perp.mark_price);
+    Some(OpportunityLeg {
+        exchange: snapshot.exchange,
+        kind: InstrumentKind::Perp,
+        symbol: perp.instrument.native_symbol.clone(),
+        currency: perp.currency,
+        price,
+        price_usdt: to_usdt(price, perp.currency, rates)?,
+        vol_24h_usd: perp.vol_24h_usd,
+        oi_usd: Some(perp.oi_usd),
+        funding_rate: Some(perp.funding_rate),
+        funding_interval_hours: Some(perp.funding_interval_hours_or_default()),
+        taker_fee: taker_fee(snapshot.exchange, &snapshot.quote, InstrumentKind::Perp),
+    })
+}
+
+fn spot_leg(
+    snapshot: &UnifiedSnapshot,
+    rates: &ExchangeRates,
+    taker_fee: &impl Fn(ExchangeId, &str, InstrumentKind) -> f64,
+) -> Option<OpportunityLeg> {
+    let spot = snapshot.spot.as_ref()?;
+    let price = to_f64(spot.price);
+    Some(OpportunityLeg {
+        exchange: snapshot.exchange,
+        kind: InstrumentKind::Spot,
+        symbol: spot.instrument.native_symbol.clone(),
+        currency: spot.currency,
+        price,
+        price_usdt: to_usdt(price, spot.currency, rates)?,
+        vol_24h_usd: spot.vol_24h_usd,
+        oi_usd: None,
+        funding_rate: None,
+        funding_interval_hours: None,
+        taker_fee: taker_fee(snapshot.exchange, &snapshot.quote, InstrumentKind::Spot),
+    })
+}
+
+/// 레그 한 쌍으로 기회 하나를 만든다. 싼 쪽이 buy, 비싼 쪽이 sell이 된다.
+/// round_trips: 수수료를 몇 번 왕복으로 계산할지 (포지션 진입+청산이면 2, 한 번 사고 팔면 1)
+fn evaluate(
+    kind: OpportunityKind,
+    base: &str,
+    a: &OpportunityLeg,
+    b: &OpportunityLeg,
+    round_trips: f64,
+    params: &ScanParams,
+) -> Opportunity {
+    let (buy, sell) = if a.price_usdt <= b.price_usdt {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let gross_bps = (sell.price_usdt / buy.price_usdt - 1.0) * BPS;
+    let fee_bps = (buy.taker_fee + sell.taker_fee) * round_trips * BPS;
+
+    // 선물을 사면 양수 펀딩비를 내고, 팔면 받는다. 거래소마다 펀딩 주기가 달라 시간당으로 환산
+    let funding_carry_bps =
+        (sell.hourly_funding_rate() - buy.hourly_funding_rate()) * params.horizon_hours * BPS;
+
+    Opportunity {
+        kind,
+        base: base.to_string(),
+        buy: buy.clone(),
+        sell: sell.clone(),
+        gross_bps,
+        fee_bps,
+        funding_carry_bps,
+        edge_bps: gross_bps - fee_bps + funding_carry_bps,
+    }
+}
+
+impl OpportunityLeg {
+    /// 시간당 펀딩비 (현물 레그는 0)
+    fn hourly_funding_rate(&self) -> f64 {
+        match (self.funding_rate, self.funding_interval_hours) {
+            (Some(rate), Some(hours)) if hours > 0 => rate / hours as f64,
+            _ => 0.0,
+        }
+    }
+}
+
+fn passes_filters(leg: &OpportunityLeg, params: &ScanParams) -> bool {
+    leg.vol_24h_usd >= params.min_volume_usd && leg.oi_usd.is_none_or(|oi| oi >= params.min_oi_usd)
+}
+
+/// 레그 목록에서 거래소가 다른 모든 쌍을 평가
+fn cross_pairs(
+    kind: OpportunityKind,
+    base: &str,
+    legs: &[OpportunityLeg],
+    round_trips: f64,
+    params: &ScanParams,
+    out: &mut Vec<Opportunity>,
+) {
+    for (i, a) in legs.iter().enumerate() {
+        for b in &legs[i + 1..] {
+            if a.exchange != b.exchange {
+                out.push(evaluate(kind, base, a, b, round_trips, params));
+            }
+        }
+    }
+}
+
+/// 모든 기회를 계산해 기대 수익(edge_bps) 내림차순으로 반환
+/// taker_fee: (거래소, quote, 현물/선물) -> 테이커 수수료율
+/// 환율이 대체값(is_fallback)이면 원화 프리미엄은 믿을 수 없으므로 계산하지 않는다.
+pub fn scan_opportunities(
+    snapshots: &[UnifiedSnapshot],
+    rates: &ExchangeRates,
+    taker_fee: impl Fn(ExchangeId, &str, InstrumentKind) -> f64,
+    params: &ScanParams,
+) -> Vec<Opportunity> {
+    // base 자산별 레그 (거래량/OI 필터를 통과한 것만)
+    let mut perps: HashMap<&str, Vec<OpportunityLeg>> = HashMap::new();
+    let mut spots: HashMap<&str, Vec<OpportunityLeg>> = HashMap::new();
+    let mut krw_spots: HashMap<&str, Vec<OpportunityLeg>> = HashMap::new();
+    let mut opportunities = Vec::new();
+
+    for snapshot in snapshots {
+        let base = snapshot.base.as_str();
+        let perp = perp_leg(snapshot, rates, &taker_fee).filter(|l| passes_filters(l, params));
+        let spot = spot_leg(snapshot, rates, &taker_fee).filter(|l| passes_filters(l, params));
+
+        if let (Some(perp), Some(spot)) = (&perp, &spot) {
+            opportunities.push(evaluate(
+                OpportunityKind::IntraBasis,
+                base,
+                spot,
+                perp,
+                2.0,
+                params,
+            ));
+        }
+        if let Some(perp) = perp {
+            perps.entry(base).or_default().push(perp);
+        }
+        if let Some(spot) = spot {
+            let target = if spot.currency == Currency::KRW {
+                &mut krw_spots
+            } else {
+                &mut spots
+            };
+            target.entry(base).or_default().push(spot);
+        }
+    }
+
+    for (base, legs) in &perps {
+        cross_pairs(
+            OpportunityKind::PerpSpread,
+            base,
+            legs,
+            2.0,
+            params,
+            &mut opportunities,
+        );
+    }
+    for (base, legs) in &spots {
+        cross_pairs(
+            OpportunityKind::SpotSpread,
+            base,
+            legs,
+            1.0,
+            params,
+            &mut opportunities,
+        );
+    }
+    for (base, krw_legs) in krw_spots.iter().filter(|_| !rates.is_fallback) {
+        for krw in krw_legs {
+            for foreign in spots.get(base).into_iter().flatten() {
+                opportunities.push(evaluate(
+                    OpportunityKind::KrwPremium,
+                    base,
+                    krw,
+                    foreign,
+                    1.0,
+                    params,
+                ));
+            }
+        }
+    }
+
+    opportunities.sort_by(|a, b| {
+        b.edge_bps
+            .partial_cmp(&a.edge_bps)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    opportunities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{self, rates};
+
+    /// BTC 통합 스냅샷 (선물은 (가격, 펀딩비))
+    fn snapshot(
+        exchange: ExchangeId,
+        quote: &str,
+        perp: Option<(i64, f64)>,
+        spot: Option<i64>,
+    ) -> UnifiedSnapshot {
+        let native = format!("BTC{}", quote);
+        let mut snapshot = test_support::snapshot(
+            exchange,
+            "BTC",
+            quote,
+            &native,
+            perp.map(|(price, _)| price),
+            spot,
+        );
+        if let (Some(data), Some((_, funding_rate))) = (snapshot.perp.as_mut(), perp) {
+            data.funding_rate = funding_rate;
+            data.oi_usd = 50_000_000.0;
+            data.vol_24h_usd = 100_000_000.0;
+        }
+        if let Some(data) = snapshot.spot.as_mut() {
+            data.vol_24h_usd = 10_000_000.0;
+        }
+        snapshot
+    }
+
+    fn find(list: &[Opportunity], kind: OpportunityKind) -> Vec<&Opportunity> {
+        list.iter().filter(|o| o.kind == kind).collect()
+    }
+
+    #[test]
+    fn test_scan_opportunities() {
+        let snapshots = vec![
+            // 베이시스 +1%, 펀딩 0.01%/8h
+            snapshot(
+                ExchangeId::Binance,
+                "USDT",
+                Some((101_000, 0.0001)),
+                Some(100_000),
+            ),
+            snapshot(ExchangeId::Bybit, "USDT", Some((100_500, -0.0001)), None),
+            // 원화 프리미엄 +2% (1억 4280만원 / 1400 = 102,000 USDT)
+            snapshot(ExchangeId::Bithumb, "KRW", None, Some(142_800_000)),
+        ];
+        let fee = |exchange: ExchangeId, _quote: &str, kind: InstrumentKind| match (exchange, kind)
+        {
+            (ExchangeId::Bithumb, _) => 0.0004,
+            (_, InstrumentKind::Spot) => 0.001,
+            _ => 0.0005,
+        };
+        let params = ScanParams::default();
+        let list = scan_opportunities(&snapshots, &rates(), fee, &params);
+
+        // 기대 수익 내림차순
+        assert!(list.windows(2).all(|w| w[0].edge_bps >= w[1].edge_bps));
+
+        let basis = find(&list, OpportunityKind::IntraBasis);
+        assert_eq!(basis.len(), 1);
+        let basis = basis[0];
+        assert_eq!(basis.buy.kind, InstrumentKind::Spot);
+        assert!((basis.gross_bps - 100.0).abs() < 1e-6);
+        // 현물 0.1%, 선물 0.05% 테이커를 진입+청산 두 번씩
+        assert!((basis.buy.taker_fee - 0.001).abs() < 1e-12);
+        assert!((basis.sell.taker_fee - 0.0005).abs() < 1e-12);
+        assert!((basis.fee_bps - 30.0).abs() < 1e-6);
+        // 선물 매도로 24시간(3회) 펀딩 수취
+        assert!((basis.funding_carry_bps - 3.0).abs() < 1e-6);
+        assert!((basis.edge_bps - 73.0).abs() < 1e-6);
+
+        // Bybit 선물 매수(음수 펀딩 수취) + Binance 선물 매도(양수 펀딩 수취)
+        let spread = find(&list, OpportunityKind::PerpSpread);
+        assert_eq!(spread.len(), 1);
+        assert_eq!(spread[0].buy.exchange, ExchangeId::Bybit);
+        assert!((spread[0].funding_carry_bps - 6.0).abs() < 1e-6);
+
+        let premium = find(&list, OpportunityKind::KrwPremium);
+        assert_eq!(premium.len(), 1);
+        assert_eq!(premium[0].sell.exchange, ExchangeId::Bithumb);
+        assert!((premium[0].gross_bps - 200.0).abs() < 1e-6);
+        assert!((premium[0].fee_bps - 14.0).abs() < 1e-6);
+
+        // 환율이 대체값이면 원화 프리미엄은 빠진다
+        let fallback = ExchangeRates {
+            is_fallback: true,
+            ..rates()
+        };
+        let list = scan_opportunities(&snapshots, &fallback, fee, &ScanParams::default());
+        assert!(find(&list, OpportunityKind::KrwPremium).is_empty());
+        assert_eq!(find(&list, OpportunityKind::PerpSpread).len(), 1);
+
+        // 현물이 하나뿐인 USDT 거래소끼리는 spot_spread 없음
+        assert!(find(&list, OpportunityKind::SpotSpread).is_empty());
+
+        // 현물 거래량 필터에 걸리면 현물이 들어간 기회는 빠진다
+        let params = ScanParams {
+            min_volume_usd: 50_000_000.0,
+            ..Default::default()
+        };
+        let list = scan_opportunities(&snapshots, &rates(), fee, &params);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].kind, OpportunityKind::PerpSpread);
+
+        // 펀딩 주기가 4시간이면 24시간에 6회: 0.01% * 6 + 0.01% * 3 = 9bps
+        let mut snapshots = snapshots;
+        snapshots[1].perp.as_mut().unwrap().funding_interval_hours = Some(4);
+        let list = scan_opportunities(&snapshots, &rates(), fee, &ScanParams::default());
+        let spread = find(&list, OpportunityKind::PerpSpread);
+        assert_eq!(spread[0].buy.funding_interval_hours, Some(4));
+        assert!((spread[0].funding_carry_bps - 9.0).abs() < 1e-6);
+    }
+}
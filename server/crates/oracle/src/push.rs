@@ -0,0 +1,320 @@
+//! 통합 스냅샷 푸시 (/ws, /sse)
+//!
+//! 접속하면 필터에 맞는 전체 스냅샷을 한 번 보내고, 이후에는 수집기가 방송하는
+//! 변경분(UnifiedEvent)만 보낸다. 구독자가 밀려 변경분을 놓치면 전체 스냅샷을 다시 보낸다.
+
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Response,
+    },
+};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use tracing::warn;
+
+use crate::query::split_list;
+use crate::server::{api_error, ApiError, AppState, UnifiedKey};
+use interface::{ExchangeId, UnifiedSnapshot};
+
+/// 수집기가 방송하는 통합 스냅샷 변경분
+#[derive(Debug, Clone)]
+pub enum UnifiedEvent {
+    /// 새로 생기거나 값이 바뀐 항목
+    Updated(Arc<Vec<UnifiedSnapshot>>),
+    /// 정리되어 사라진 항목
+    Removed(Arc<Vec<UnifiedKey>>),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RemovedEntry {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    pub base: String,
+    pub quote: String,
+}
+
+/// 구독자에게 보내는 메시지 ({"type": "...", "data": [...]})
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "lowercase")]
+pub enum PushMessage {
+    /// 접속 직후(또는 변경분을 놓친 뒤) 필터에 맞는 전체 목록
+    Snapshot(Vec<UnifiedSnapshot>),
+    Update(Vec<UnifiedSnapshot>),
+    Remove(Vec<RemovedEntry>),
+}
+
+impl PushMessage {
+    fn event_name(&self) -> &'static str {
+        match self {
+            PushMessage::Snapshot(_) => "snapshot",
+            PushMessage::Update(_) => "update",
+            PushMessage::Remove(_) => "remove",
+        }
+    }
+
+    /// SSE 이벤트 (event 이름은 type, data는 목록 JSON)
+    fn to_sse_event(&self) -> Result<Event, axum::Error> {
+        let event = Event::default().event(self.event_name());
+        match self {
+            PushMessage::Snapshot(data) | PushMessage::Update(data) => event.json_data(data),
+            PushMessage::Remove(data) => event.json_data(data),
+        }
+    }
+}
+
+/// 구독 파라미터 (쉼표로 여러 개, 없으면 전체)
+#[derive(Debug, Default, Deserialize)]
+pub struct SubscribeParams {
+    pub exchange: Option<String>,
+    pub symbol: Option<String>,
+}
+
+/// 거래소/공통 심볼 구독 필터
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionFilter {
+    exchanges: Option<HashSet<ExchangeId>>,
+    symbols: Option<HashSet<String>>,
+}
+
+impl SubscriptionFilter {
+    pub fn from_params(params: &SubscribeParams) -> Result<Self, String> {
+        let exchanges = split_list(params.exchange.as_deref())
+            .map(|list| list.map(|e| e.parse::<ExchangeId>()).collect())
+            .transpose()?;
+        let symbols =
+            split_list(params.symbol.as_deref()).map(|list| list.map(str::to_uppercase).collect());
+        Ok(Self { exchanges, symbols })
+    }
+
+    pub fn matches(&self, exchange: ExchangeId, symbol: &str) -> bool {
+        self.exchanges
+            .as_ref()
+            .is_none_or(|e| e.contains(&exchange))
+            && self.symbols.as_ref().is_none_or(|s| s.contains(symbol))
+    }
+
+    /// 방송된 변경분 중 필터에 맞는 것만 메시지로 (남는 게 없으면 None)
+    pub fn apply(&self, event: &UnifiedEvent) -> Option<PushMessage> {
+        match event {
+            UnifiedEvent::Updated(list) => {
+                let list: Vec<_> = list
+                    .iter()
+                    .filter(|u| self.matches(u.exchange, &u.symbol))
+                    .cloned()
+                    .collect();
+                (!list.is_empty()).then_some(PushMessage::Update(list))
+            }
+            UnifiedEvent::Removed(keys) => {
+                let list: Vec<_> = keys
+                    .iter()
+                    .map(|(exchange, base, quote)| RemovedEntry {
+                        exchange: *exchange,
+                        symbol: format!("{}{}", base, quote),
+                        base: base.clone(),
+                        quote: quote.clone(),
+                    })
+                    .filter(|r| self.matches(r.exchange, &r.symbol))
+                    .collect();
+                (!list.is_empty()).then_some(PushMessage::Remove(list))
+            }
+        }
+    }
+
+    /// 현재 통합 스냅샷 중 필터에 맞는 전체 목록
+    pub async fn snapshot(&self, state: &AppState) -> PushMessage {
+        let mut list = state.unified_list().await;
+        list.retain(|u| self.matches(u.exchange, &u.symbol));
+        PushMessage::Snapshot(list)
+    }
+}
+
+/// 다음에 보낼 메시지를 기다린다 (방송이 끝나면 None)
+async fn next_message(
+    rx: &mut Receiver<UnifiedEvent>,
+    state: &AppState,
+    filter: &SubscriptionFilter,
+) -> Option<PushMessage> {
+    loop {
+        match rx.recv().await {
+            Ok(event) => {
+                if let Some(message) = filter.apply(&event) {
+                    return Some(message);
+                }
+            }
+            Err(RecvError::Lagged(skipped)) => {
+                warn!(
+                    "push subscriber lagged by {} events, resending snapshot",
+                    skipped
+                );
+                return Some(filter.snapshot(state).await);
+            }
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
+fn parse_filter(params: &SubscribeParams) -> Result<SubscriptionFilter, ApiError> {
+    SubscriptionFilter::from_params(params).map_err(|e| api_error(StatusCode::BAD_REQUEST, e))
+}
+
+/// GET /ws?exchange=&symbol= (WebSocket, 텍스트 메시지 하나에 PushMessage 하나)
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SubscribeParams>,
+) -> Result<Response, ApiError> {
+    let filter = parse_filter(&params)?;
+    Ok(ws.on_upgrade(move |socket| ws_session(socket, state, filter)))
+}
+
+async fn send_ws(socket: &mut WebSocket, message: &PushMessage) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(message).map_err(axum::Error::new)?;
+    socket.send(Message::Text(text)).await
+}
+
+async fn ws_session(mut socket: WebSocket, state: Arc<AppState>, filter: SubscriptionFilter) {
+    // 스냅샷을 읽기 전에 구독해야 그 사이 변경분을 놓치지 않는다
+    let mut rx = state.updates.subscribe();
+    if send_ws(&mut socket, &filter.snapshot(&state).await)
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            message = next_message(&mut rx, &state, &filter) => {
+                let Some(message) = message else { break };
+                if send_ws(&mut socket, &message).await.is_err() {
+                    break;
+                }
+            }
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+/// GET /sse?exchange=&symbol= (Server-Sent Events, event 이름은 snapshot/update/remove)
+pub async fn sse_handler(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SubscribeParams>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ApiError> {
+    let filter = parse_filter(&params)?;
+    let rx = state.updates.subscribe();
+    let initial = filter.snapshot(&state).await;
+
+    let stream = futures::stream::unfold(
+        (Some(initial), rx, state, filter),
+        |(pending, mut rx, state, filter)| async move {
+            let message = match pending {
+                Some(message) => message,
+                None => next_message(&mut rx, &state, &filter).await?,
+            };
+            Some((message.to_sse_event(), (None, rx, state, filter)))
+        },
+    );
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::apply_perp_ticks;
+    use crate::server::router;
+    use crate::test_support::rates;
+    use futures::StreamExt;
+    use interface::{Currency, Decimal, Instrument, InstrumentKind, PerpTick};
+    use tokio_tungstenite::tungstenite;
+
+    fn tick(exchange: ExchangeId, base: &str, price: i64) -> PerpTick {
+        let native = format!("{}USDT", base);
+        let instrument = Instrument::new(exchange, InstrumentKind::Perp, base, "USDT", &native);
+        let mut tick = PerpTick::new(instrument, Currency::USDT);
+        tick.mark_price = Some(Decimal::from(price));
+        tick
+    }
+
+    /// 다음 WebSocket 텍스트 메시지를 JSON으로
+    async fn next_json<S>(ws: &mut S) -> serde_json::Value
+    where
+        S: Stream<Item = Result<tungstenite::Message, tungstenite::Error>> + Unpin,
+    {
+        let message = ws.next().await.unwrap().unwrap();
+        let tungstenite::Message::Text(text) = message else {
+            panic!("unexpected message: {:?}", message);
+        };
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn test_subscription_filter() {
+        let filter = SubscriptionFilter::from_params(&SubscribeParams {
+            exchange: Some("bybit, OKX".to_string()),
+            symbol: Some("btcusdt".to_string()),
+        })
+        .unwrap();
+        assert!(filter.matches(ExchangeId::Okx, "BTCUSDT"));
+        assert!(!filter.matches(ExchangeId::Binance, "BTCUSDT"));
+        assert!(!filter.matches(ExchangeId::Bybit, "ETHUSDT"));
+
+        let removed = UnifiedEvent::Removed(Arc::new(vec![
+            (ExchangeId::Bybit, "BTC".to_string(), "USDT".to_string()),
+            (ExchangeId::Bybit, "ETH".to_string(), "USDT".to_string()),
+        ]));
+        match filter.apply(&removed) {
+            Some(PushMessage::Remove(list)) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!(list[0].symbol, "BTCUSDT");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        assert!(SubscriptionFilter::from_params(&SubscribeParams {
+            exchange: Some("kraken".to_string()),
+            symbol: None,
+        })
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_ws_pushes_snapshot_then_filtered_updates() {
+        let state = Arc::new(AppState::new());
+        *state.exchange_rates.write().await = Some(rates());
+        apply_perp_ticks(&state, [tick(ExchangeId::Bybit, "ETH", 3000)]).await;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = router(state.clone());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let url = format!("ws://{}/ws?exchange=bybit&symbol=BTCUSDT,ETHUSDT", addr);
+        let (mut ws, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let snapshot = next_json(&mut ws).await;
+        assert_eq!(snapshot["type"], "snapshot");
+        assert_eq!(snapshot["data"].as_array().unwrap().len(), 1);
+
+        // 필터 밖(OKX) 변경분은 보내지 않는다
+        apply_perp_ticks(&state, [tick(ExchangeId::Okx, "BTC", 65000)]).await;
+        apply_perp_ticks(&state, [tick(ExchangeId::Bybit, "BTC", 65100)]).await;
+        let update = next_json(&mut ws).await;
+        assert_eq!(update["type"], "update");
+        let data = update["data"].as_array().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["exchange"], "Bybit");
+        assert_eq!(data[0]["symbol"], "BTCUSDT");
+    }
+}
@@ -0,0 +1,369 @@
+//! 스냅샷 목록 조회 필터·정렬·페이지 (/snapshots, /spot-snapshots, /unified-snapshots)
+//!
+//! 파라미터를 주지 않으면 예전과 같이 전체 목록을 엔드포인트별 기본 순서로 돌려준다.
+
+use std::{cmp::Ordering, collections::HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use interface::{ExchangeId, PerpSnapshot, SpotSnapshot, UnifiedSnapshot};
+
+/// 정렬 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortField {
+    Symbol,
+    Exchange,
+    VolUsd,
+    OiUsd,
+    FundingRate,
+    UpdatedAt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// 목록 조회 파라미터 (exchange, symbol, base는 쉼표로 여러 개)
+#[derive(Debug, Default, Deserialize)]
+pub struct SnapshotParams {
+    pub exchange: Option<String>,
+    /// 공통 심볼 (예: BTCUSDT)
+    pub symbol: Option<String>,
+    pub base: Option<String>,
+    pub min_vol_usd: Option<f64>,
+    /// OI가 없는 항목(현물)은 제외된다
+    pub min_oi_usd: Option<f64>,
+    pub has_perp: Option<bool>,
+    pub has_spot: Option<bool>,
+    pub sort: Option<SortField>,
+    /// 기본값: symbol/exchange는 asc, 나머지는 desc
+    pub order: Option<SortOrder>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// 쉼표로 구분된 목록 (빈 항목은 무시, 값이 없으면 None)
+pub(crate) fn split_list(value: Option<&str>) -> Option<impl Iterator<Item = &str>> {
+    value.map(|v| v.split(',').map(str::trim).filter(|s| !s.is_empty()))
+}
+
+/// 필터·정렬에 쓰는 스냅샷 공통 값
+pub trait SnapshotView {
+    fn exchange(&self) -> ExchangeId;
+    fn symbol(&self) -> &str;
+    fn base(&self) -> &str;
+    fn vol_usd(&self) -> f64;
+    fn oi_usd(&self) -> Option<f64>;
+    fn funding_rate(&self) -> Option<f64>;
+    fn updated_at(&self) -> DateTime<Utc>;
+    fn has_perp(&self) -> bool;
+    fn has_spot(&self) -> bool;
+}
+
+impl SnapshotView for PerpSnapshot {
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn base(&self) -> &str {
+        &self.instrument.base
+    }
+    fn vol_usd(&self) -> f64 {
+        self.vol_24h_usd
+    }
+    fn oi_usd(&self) -> Option<f64> {
+        Some(self.oi_usd)
+    }
+    fn funding_rate(&self) -> Option<f64> {
+        Some(self.funding_rate)
+    }
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+    fn has_perp(&self) -> bool {
+        true
+    }
+    fn has_spot(&self) -> bool {
+        false
+    }
+}
+
+impl SnapshotView for SpotSnapshot {
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn base(&self) -> &str {
+        &self.instrument.base
+    }
+    fn vol_usd(&self) -> f64 {
+        self.vol_24h_usd
+    }
+    fn oi_usd(&self) -> Option<f64> {
+        None
+    }
+    fn funding_rate(&self) -> Option<f64> {
+        None
+    }
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+    fn has_perp(&self) -> bool {
+        false
+    }
+    fn has_spot(&self) -> bool {
+        true
+    }
+}
+
+impl SnapshotView for UnifiedSnapshot {
+    fn exchange(&self) -> ExchangeId {
+        self.exchange
+    }
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    fn base(&self) -> &str {
+        &self.base
+    }
+    /// 선물/현물 중 큰 쪽 거래량
+    fn vol_usd(&self) -> f64 {
+        let perp = self.perp.as_ref().map_or(0.0, |p| p.vol_24h_usd);
+        let spot = self.spot.as_ref().map_or(0.0, |s| s.vol_24h_usd);
+        perp.max(spot)
+    }
+    fn oi_usd(&self) -> Option<f64> {
+        self.perp.as_ref().map(|p| p.oi_usd)
+    }
+    fn funding_rate(&self) -> Option<f64> {
+        self.perp.as_ref().map(|p| p.funding_rate)
+    }
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+    fn has_perp(&self) -> bool {
+        self.perp.is_some()
+    }
+    fn has_spot(&self) -> bool {
+        self.spot.is_some()
+    }
+}
+
+/// 파싱한 조회 조건
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotQuery {
+    exchanges: Option<HashSet<ExchangeId>>,
+    symbols: Option<HashSet<String>>,
+    bases: Option<HashSet<String>>,
+    min_vol_usd: Option<f64>,
+    min_oi_usd: Option<f64>,
+    has_perp: Option<bool>,
+    has_spot: Option<bool>,
+    sort: Option<(SortField, SortOrder)>,
+    offset: usize,
+    limit: Option<usize>,
+}
+
+/// 한 페이지와 필터에 맞은 전체 개수
+#[derive(Debug)]
+pub struct Page<T> {
+    pub total: usize,
+    pub items: Vec<T>,
+}
+
+/// 값이 없는 항목은 정렬 방향과 관계없이 뒤로
+fn cmp_optional(a: Option<f64>, b: Option<f64>, order: SortOrder) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let ord = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            match order {
+                SortOrder::Asc => ord,
+                SortOrder::Desc => ord.reverse(),
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl SnapshotQuery {
+    pub fn from_params(params: &SnapshotParams) -> Result<Self, String> {
+        let exchanges = split_list(params.exchange.as_deref())
+            .map(|list| list.map(|e| e.parse::<ExchangeId>()).collect())
+            .transpose()?;
+        let upper = |value: Option<&str>| {
+            split_list(value).map(|list| list.map(str::to_uppercase).collect())
+        };
+        for (name, value) in [
+            ("min_vol_usd", params.min_vol_usd),
+            ("min_oi_usd", params.min_oi_usd),
+        ] {
+            if value.is_some_and(f64::is_nan) {
+                return Err(format!("invalid {}", name));
+            }
+        }
+        let sort = params.sort.map(|field| {
+            let order = params.order.unwrap_or(match field {
+                SortField::Symbol | SortField::Exchange => SortOrder::Asc,
+                _ => SortOrder::Desc,
+            });
+            (field, order)
+        });
+        Ok(Self {
+            exchanges,
+            symbols: upper(params.symbol.as_deref()),
+            bases: upper(params.base.as_deref()),
+            min_vol_usd: params.min_vol_usd,
+            min_oi_usd: params.min_oi_usd,
+            has_perp: params.has_perp,
+            has_spot: params.has_spot,
+            sort,
+            offset: params.offset.unwrap_or(0),
+            limit: params.limit,
+        })
+    }
+
+    pub fn matches<T: SnapshotView>(&self, item: &T) -> bool {
+        self.exchanges
+            .as_ref()
+            .is_none_or(|e| e.contains(&item.exchange()))
+            && self
+                .symbols
+                .as_ref()
+                .is_none_or(|s| s.contains(item.symbol()))
+            && self.bases.as_ref().is_none_or(|b| b.contains(item.base()))
+            && self.min_vol_usd.is_none_or(|min| item.vol_usd() >= min)
+            && self
+                .min_oi_usd
+                .is_none_or(|min| item.oi_usd().is_some_and(|oi| oi >= min))
+            && self.has_perp.is_none_or(|has| item.has_perp() == has)
+            && self.has_spot.is_none_or(|has| item.has_spot() == has)
+    }
+
+    /// 기본 순서로 정렬된 목록에 필터·정렬·페이지 적용 (sort가 없으면 순서 유지)
+    pub fn apply<T: SnapshotView>(&self, mut list: Vec<T>) -> Page<T> {
+        list.retain(|item| self.matches(item));
+        if let Some((field, order)) = self.sort {
+            list.sort_by(|a, b| {
+                let ord = match field {
+                    SortField::Symbol => a.symbol().cmp(b.symbol()),
+                    SortField::Exchange => (a.exchange() as u8).cmp(&(b.exchange() as u8)),
+                    SortField::UpdatedAt => a.updated_at().cmp(&b.updated_at()),
+                    SortField::VolUsd => {
+                        return cmp_optional(Some(a.vol_usd()), Some(b.vol_usd()), order)
+                    }
+                    SortField::OiUsd => return cmp_optional(a.oi_usd(), b.oi_usd(), order),
+                    SortField::FundingRate => {
+                        return cmp_optional(a.funding_rate(), b.funding_rate(), order)
+                    }
+                };
+                match order {
+                    SortOrder::Asc => ord,
+                    SortOrder::Desc => ord.reverse(),
+                }
+            });
+        }
+        let total = list.len();
+        let items = list
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect();
+        Page { total, items }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    /// USDT 통합 스냅샷 (OI를 주면 선물 시세를 채운다)
+    fn unified(exchange: ExchangeId, base: &str, oi_usd: Option<f64>, vol: f64) -> UnifiedSnapshot {
+        let mut snapshot =
+            test_support::snapshot(exchange, base, "USDT", base, oi_usd.map(|_| 1), None);
+        if let (Some(data), Some(oi_usd)) = (snapshot.perp.as_mut(), oi_usd) {
+            data.oi_usd = oi_usd;
+            data.vol_24h_usd = vol;
+        }
+        snapshot
+    }
+
+    fn query(params: SnapshotParams) -> SnapshotQuery {
+        SnapshotQuery::from_params(&params).unwrap()
+    }
+
+    #[test]
+    fn test_filter_sort_and_paginate() {
+        let list = vec![
+            unified(ExchangeId::Binance, "BTC", Some(5e9), 1e10),
+            unified(ExchangeId::Binance, "ETH", Some(2e9), 5e9),
+            unified(ExchangeId::Bybit, "BTC", Some(3e9), 4e9),
+            unified(ExchangeId::Okx, "DOGE", Some(1e8), 1e8),
+            unified(ExchangeId::Bithumb, "BTC", None, 0.0),
+        ];
+
+        let page = query(SnapshotParams {
+            exchange: Some("binance, BYBIT".to_string()),
+            base: Some("btc".to_string()),
+            ..Default::default()
+        })
+        .apply(list.clone());
+        assert_eq!(page.total, 2);
+
+        // OI 내림차순, OI 없는 항목은 맨 뒤
+        let page = query(SnapshotParams {
+            sort: Some(SortField::OiUsd),
+            order: Some(SortOrder::Asc),
+            offset: Some(1),
+            limit: Some(2),
+            ..Default::default()
+        })
+        .apply(list.clone());
+        assert_eq!(page.total, 5);
+        let oi: Vec<_> = page.items.iter().map(|u| u.oi_usd()).collect();
+        assert_eq!(oi, vec![Some(2e9), Some(3e9)]);
+        let last = query(SnapshotParams {
+            sort: Some(SortField::OiUsd),
+            ..Default::default()
+        })
+        .apply(list.clone());
+        assert_eq!(last.items[0].oi_usd(), Some(5e9));
+        assert_eq!(last.items[4].exchange, ExchangeId::Bithumb);
+
+        let page = query(SnapshotParams {
+            min_vol_usd: Some(1e9),
+            has_perp: Some(true),
+            min_oi_usd: Some(2.5e9),
+            ..Default::default()
+        })
+        .apply(list.clone());
+        assert_eq!(page.total, 2);
+        assert!(page.items.iter().all(|u| u.base == "BTC"));
+
+        let page = query(SnapshotParams {
+            has_perp: Some(false),
+            offset: Some(10),
+            ..Default::default()
+        })
+        .apply(list);
+        assert_eq!(page.total, 1);
+        assert!(page.items.is_empty());
+
+        assert!(SnapshotQuery::from_params(&SnapshotParams {
+            exchange: Some("kraken".to_string()),
+            ..Default::default()
+        })
+        .is_err());
+    }
+}
@@ -1,61 +1,976 @@
-use std::{net::SocketAddr, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
-use axum::{extract::State, response::IntoResponse, routing::get, Json, Router};
-use tokio::sync::RwLock;
-use tower_http::cors::CorsLayer;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, watch, RwLock};
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::info;
 
-use interface::{PerpSnapshot, SpotSnapshot, UnifiedSnapshot};
+use crate::asset::{aggregate_assets, AssetView};
+use crate::config::OracleConfig;
+use crate::funding::{aggregate_funding, FundingView};
+use crate::history::{HistoryError, HistoryField, HistoryPoint, HistoryQuery, HistoryStore};
+use crate::opportunity::{scan_opportunities, Opportunity, OpportunityKind, ScanParams};
+use crate::push::{sse_handler, ws_handler, UnifiedEvent};
+use crate::query::{SnapshotParams, SnapshotQuery, SnapshotView};
+use exchanges::{FeeExchange, FundingHistoryExchange};
+use interface::{
+    ExchangeId, ExchangeRates, FundingRecord, InstrumentKind, MarketType, NetworkStatus,
+    PerpSnapshot, SpotSnapshot, UnifiedSnapshot, WalletStatus,
+};
 
+/// 펀딩 히스토리 기본/최대 조회 개수
+const DEFAULT_FUNDING_LIMIT: usize = 100;
+const MAX_FUNDING_LIMIT: usize = 1000;
+/// /history 응답 최대 구간 수 (넘으면 구간을 넓힌다)
+const MAX_HISTORY_POINTS: i64 = 2000;
+/// /opportunities 기본/최대 조회 개수
+const DEFAULT_OPPORTUNITY_LIMIT: usize = 100;
+const MAX_OPPORTUNITY_LIMIT: usize = 1000;
+/// 수수료 정보가 없는 거래소에 적용할 테이커 수수료 (0.1%)
+const DEFAULT_TAKER_FEE: f64 = 0.001;
+/// /ws, /sse 구독자에게 보낼 변경분 버퍼 (넘치면 밀린 구독자는 전체 스냅샷을 다시 받는다)
+const UPDATE_CHANNEL_CAPACITY: usize = 1024;
+
+/// 선물/현물 스냅샷 키: (거래소, 거래소 원본 심볼)
+pub type SnapshotKey = (ExchangeId, String);
+/// 통합 스냅샷 키: (거래소, base, quote)
+pub type UnifiedKey = (ExchangeId, String, String);
+
+/// 데이터가 이보다 오래되면 stale: 거래소 REST 수집(/health), 응답하는 스냅샷,
+/// REST 결과로 교체할 스트림 시세 모두 이 기준을 쓴다
+pub const STALE_AFTER: Duration = Duration::seconds(60);
+
+/// REST 수집 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedKind {
+    Perp,
+    Spot,
+}
+
+/// 거래소·수집 종류별 마지막 REST 수집 결과
+/// 수집에 실패해도 스냅샷은 마지막 성공 값을 유지하므로, 데이터가 얼마나 오래됐는지는 여기서 본다.
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeStatus {
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    /// 마지막 요청의 응답 시간 (타임아웃이면 타임아웃 시간)
+    pub latency_ms: u64,
+    /// 마지막으로 성공한 수집의 종목 수
+    pub record_count: usize,
+}
+
+impl ExchangeStatus {
+    /// 마지막 성공 이후 경과 시간 (성공한 적이 없으면 None)
+    pub fn age(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.last_success.map(|t| now - t)
+    }
+
+    /// 아직 성공한 적이 없거나 마지막 성공이 STALE_AFTER보다 오래됨
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        self.age(now).is_none_or(|age| age > STALE_AFTER)
+    }
+}
+
+/// 응답용 스냅샷: 거래소별 시세에 응답 시점 기준 경과 시간과 stale 여부를 붙인다
+#[derive(Debug, Serialize)]
+pub struct ServedSnapshot<T> {
+    #[serde(flatten)]
+    pub snapshot: T,
+    /// updated_at 이후 경과 ms
+    pub age_ms: i64,
+    /// 경과 시간이 STALE_AFTER보다 김
+    pub stale: bool,
+}
+
+impl<T: SnapshotView> ServedSnapshot<T> {
+    pub fn new(snapshot: T, now: DateTime<Utc>) -> Self {
+        let age = now - snapshot.updated_at();
+        Self {
+            age_ms: age.num_milliseconds().max(0),
+            stale: age > STALE_AFTER,
+            snapshot,
+        }
+    }
+}
+
+/// 스트림 메시지마다 한 종목씩 갱신되므로 키 단위 맵으로 보관하고,
+/// 정렬은 조회 시점에 한다.
 #[derive(Clone)]
 pub struct AppState {
-    pub perp_snapshots: Arc<RwLock<Vec<PerpSnapshot>>>,
-    pub spot_snapshots: Arc<RwLock<Vec<SpotSnapshot>>>,
-    pub unified_snapshots: Arc<RwLock<Vec<UnifiedSnapshot>>>,
+    pub perp_snapshots: Arc<RwLock<HashMap<SnapshotKey, PerpSnapshot>>>,
+    pub spot_snapshots: Arc<RwLock<HashMap<SnapshotKey, SpotSnapshot>>>,
+    pub unified_snapshots: Arc<RwLock<HashMap<UnifiedKey, UnifiedSnapshot>>>,
+    /// 마지막으로 조회한 환율 (첫 조회 전에는 None)
+    pub exchange_rates: Arc<RwLock<Option<ExchangeRates>>>,
+    /// 펀딩 히스토리를 요청 시점에 조회할 거래소들
+    pub funding_exchanges: Vec<Arc<dyn FundingHistoryExchange>>,
+    /// 재정거래 기회 계산에 쓸 거래소별 테이커 수수료 제공자
+    pub fee_exchanges: Vec<Arc<dyn FeeExchange>>,
+    /// 거래소별 마지막 입출금 현황 (코인 이름순)
+    pub wallet_status: Arc<RwLock<HashMap<ExchangeId, Vec<WalletStatus>>>>,
+    /// 거래소·수집 종류별 마지막 REST 수집 결과
+    pub exchange_status: Arc<RwLock<HashMap<(ExchangeId, FeedKind), ExchangeStatus>>>,
+    /// 수집 회차별 시계열 저장소 (None이면 저장하지 않고 /history는 503)
+    pub history: Option<Arc<HistoryStore>>,
+    /// 통합 스냅샷 변경분 방송 (/ws, /sse)
+    pub updates: broadcast::Sender<UnifiedEvent>,
+    /// 현재 설정 (SIGHUP으로 다시 읽으면 교체되고, 수집 루프는 변경을 구독한다)
+    pub config: watch::Sender<Arc<OracleConfig>>,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl AppState {
     pub fn new() -> Self {
+        Self::with_config(OracleConfig::default())
+    }
+
+    pub fn with_config(config: OracleConfig) -> Self {
         Self {
-            perp_snapshots: Arc::new(RwLock::new(Vec::new())),
-            spot_snapshots: Arc::new(RwLock::new(Vec::new())),
-            unified_snapshots: Arc::new(RwLock::new(Vec::new())),
+            perp_snapshots: Arc::new(RwLock::new(HashMap::new())),
+            spot_snapshots: Arc::new(RwLock::new(HashMap::new())),
+            unified_snapshots: Arc::new(RwLock::new(HashMap::new())),
+            exchange_rates: Arc::new(RwLock::new(None)),
+            funding_exchanges: Vec::new(),
+            fee_exchanges: Vec::new(),
+            wallet_status: Arc::new(RwLock::new(HashMap::new())),
+            exchange_status: Arc::new(RwLock::new(HashMap::new())),
+            history: None,
+            updates: broadcast::channel(UPDATE_CHANNEL_CAPACITY).0,
+            config: watch::channel(Arc::new(config)).0,
         }
     }
+
+    /// 현재 설정
+    pub fn config(&self) -> Arc<OracleConfig> {
+        self.config.borrow().clone()
+    }
+
+    /// 설정 교체 (다시 읽은 설정 적용)
+    pub fn set_config(&self, config: OracleConfig) {
+        self.config.send_replace(Arc::new(config));
+    }
+
+    /// /ws, /sse 구독자가 있는지 (없으면 변경분을 만들 필요가 없다)
+    pub fn has_subscribers(&self) -> bool {
+        self.updates.receiver_count() > 0
+    }
+
+    /// 통합 스냅샷 변경분 방송 (구독자가 없으면 버린다)
+    pub fn publish(&self, event: UnifiedEvent) {
+        let _ = self.updates.send(event);
+    }
+
+    /// REST 수집 성공 기록
+    pub async fn record_fetch_success(
+        &self,
+        exchange: ExchangeId,
+        kind: FeedKind,
+        latency_ms: u64,
+        record_count: usize,
+    ) {
+        let mut statuses = self.exchange_status.write().await;
+        let status = statuses.entry((exchange, kind)).or_default();
+        status.last_success = Some(Utc::now());
+        status.latency_ms = latency_ms;
+        status.record_count = record_count;
+    }
+
+    /// REST 수집 실패 기록 (마지막 성공 시각과 종목 수는 그대로 둔다)
+    pub async fn record_fetch_error(
+        &self,
+        exchange: ExchangeId,
+        kind: FeedKind,
+        latency_ms: u64,
+        error: String,
+    ) {
+        let mut statuses = self.exchange_status.write().await;
+        let status = statuses.entry((exchange, kind)).or_default();
+        status.last_error = Some(error);
+        status.last_error_at = Some(Utc::now());
+        status.latency_ms = latency_ms;
+    }
+
+    /// 선물 스냅샷 목록 (OI 기준 내림차순)
+    pub async fn perp_list(&self) -> Vec<PerpSnapshot> {
+        let mut list: Vec<PerpSnapshot> =
+            self.perp_snapshots.read().await.values().cloned().collect();
+        list.sort_by(|a, b| {
+            b.oi_usd
+                .partial_cmp(&a.oi_usd)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        list
+    }
+
+    /// 현물 스냅샷 목록 (거래량 기준 내림차순)
+    pub async fn spot_list(&self) -> Vec<SpotSnapshot> {
+        let mut list: Vec<SpotSnapshot> =
+            self.spot_snapshots.read().await.values().cloned().collect();
+        list.sort_by(|a, b| {
+            b.vol_24h_usd
+                .partial_cmp(&a.vol_24h_usd)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        list
+    }
+
+    /// 통합 스냅샷 목록 (심볼, 거래소 순, 환율 age_ms는 조회 시점 기준)
+    pub async fn unified_list(&self) -> Vec<UnifiedSnapshot> {
+        let now = Utc::now();
+        let mut list: Vec<UnifiedSnapshot> = self
+            .unified_snapshots
+            .read()
+            .await
+            .values()
+            .cloned()
+            .map(|mut snapshot| {
+                snapshot.exchange_rates.refresh_age(now);
+                snapshot
+            })
+            .collect();
+        list.sort_by(|a, b| {
+            a.symbol
+                .cmp(&b.symbol)
+                .then((a.exchange as u8).cmp(&(b.exchange as u8)))
+        });
+        list
+    }
+}
+
+/// 필터·정렬·페이지를 적용한 목록 (필터에 맞은 전체 개수는 X-Total-Count 헤더로)
+fn snapshot_page<T: SnapshotView + Serialize>(
+    params: &SnapshotParams,
+    list: Vec<T>,
+) -> Result<impl IntoResponse, ApiError> {
+    let query =
+        SnapshotQuery::from_params(params).map_err(|e| api_error(StatusCode::BAD_REQUEST, e))?;
+    let page = query.apply(list);
+    let now = Utc::now();
+    let items: Vec<ServedSnapshot<T>> = page
+        .items
+        .into_iter()
+        .map(|snapshot| ServedSnapshot::new(snapshot, now))
+        .collect();
+    Ok(([("x-total-count", page.total.to_string())], Json(items)))
+}
+
+/// GET /snapshots?exchange=&symbol=&base=&min_vol_usd=&min_oi_usd=&sort=&order=&limit=&offset=
+async fn snapshots_handler(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SnapshotParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    snapshot_page(&params, state.perp_list().await)
+}
+
+/// GET /spot-snapshots (파라미터는 /snapshots와 같음)
+async fn spot_snapshots_handler(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SnapshotParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    snapshot_page(&params, state.spot_list().await)
 }
 
-async fn snapshots_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let data = state.perp_snapshots.read().await.clone();
-    Json(data)
+/// GET /unified-snapshots (파라미터는 /snapshots와 같고 has_perp, has_spot 추가)
+async fn unified_snapshots_handler(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SnapshotParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    snapshot_page(&params, state.unified_list().await)
 }
 
-async fn spot_snapshots_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let data = state.spot_snapshots.read().await.clone();
-    Json(data)
+/// GET /unified-snapshots/:exchange/:symbol (공통 심볼, 대소문자 무관)
+async fn unified_snapshot_handler(
+    State(state): State<Arc<AppState>>,
+    Path((exchange, symbol)): Path<(String, String)>,
+) -> Result<Json<ServedSnapshot<UnifiedSnapshot>>, ApiError> {
+    let exchange_id: ExchangeId = exchange
+        .parse()
+        .map_err(|e: String| api_error(StatusCode::NOT_FOUND, e))?;
+    let now = Utc::now();
+    state
+        .unified_snapshots
+        .read()
+        .await
+        .values()
+        .find(|u| u.exchange == exchange_id && u.symbol.eq_ignore_ascii_case(&symbol))
+        .cloned()
+        .map(|mut snapshot| {
+            snapshot.exchange_rates.refresh_age(now);
+            Json(ServedSnapshot::new(snapshot, now))
+        })
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::NOT_FOUND,
+                format!("no snapshot for {:?} {}", exchange_id, symbol),
+            )
+        })
 }
 
-async fn unified_snapshots_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
-    let data = state.unified_snapshots.read().await.clone();
-    Json(data)
+/// 펀딩 히스토리 조회 파라미터 (시각은 ms 타임스탬프)
+#[derive(Debug, Deserialize)]
+pub struct FundingHistoryQuery {
+    pub start: Option<i64>, // 기본값: end - 30일
+    pub end: Option<i64>,   // 기본값: 현재
+    pub limit: Option<usize>,
 }
 
-async fn health_handler() -> impl IntoResponse {
-    Json(serde_json::json!({ "status": "ok" }))
+#[derive(Debug, Serialize)]
+pub struct FundingHistoryResponse {
+    pub exchange: ExchangeId,
+    pub symbol: String,
+    pub records: Vec<FundingRecord>,
+    /// 더 과거 기록을 받을 때 end로 넘길 값 (ms), 마지막 페이지면 None
+    pub next_end: Option<i64>,
 }
 
-pub async fn serve(state: Arc<AppState>, port: u16) -> eyre::Result<()> {
-    let app = Router::new()
+pub(crate) type ApiError = (StatusCode, Json<serde_json::Value>);
+
+pub(crate) fn api_error(status: StatusCode, msg: impl Into<String>) -> ApiError {
+    (status, Json(serde_json::json!({ "error": msg.into() })))
+}
+
+fn parse_time(ms: Option<i64>, name: &str) -> Result<Option<DateTime<Utc>>, ApiError> {
+    ms.map(|ms| {
+        DateTime::from_timestamp_millis(ms)
+            .ok_or_else(|| api_error(StatusCode::BAD_REQUEST, format!("invalid {}: {}", name, ms)))
+    })
+    .transpose()
+}
+
+/// GET /funding-history/:exchange/:symbol?start=&end=&limit=
+/// [start, end] 범위의 최신 limit개를 시간 오름차순으로 반환하고,
+/// 더 과거 기록이 있을 수 있으면 next_end를 함께 준다.
+async fn funding_history_handler(
+    State(state): State<Arc<AppState>>,
+    Path((exchange, symbol)): Path<(String, String)>,
+    Query(query): Query<FundingHistoryQuery>,
+) -> Result<Json<FundingHistoryResponse>, ApiError> {
+    let exchange_id: ExchangeId = exchange
+        .parse()
+        .map_err(|e: String| api_error(StatusCode::NOT_FOUND, e))?;
+    let client = state
+        .funding_exchanges
+        .iter()
+        .find(|ex| ex.id() == exchange_id)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::NOT_FOUND,
+                format!("funding history not supported for {:?}", exchange_id),
+            )
+        })?;
+
+    let end = parse_time(query.end, "end")?.unwrap_or_else(Utc::now);
+    let start = parse_time(query.start, "start")?.unwrap_or(end - Duration::days(30));
+    if start > end {
+        return Err(api_error(StatusCode::BAD_REQUEST, "start is after end"));
+    }
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_FUNDING_LIMIT)
+        .clamp(1, MAX_FUNDING_LIMIT);
+
+    let records = client
+        .fetch_funding_history(&symbol, start, end, limit)
+        .await
+        .map_err(|e| api_error(StatusCode::BAD_GATEWAY, e.to_string()))?;
+
+    let next_end = match records.first() {
+        Some(first) if records.len() == limit && first.funding_time > start => {
+            Some(first.funding_time.timestamp_millis() - 1)
+        }
+        _ => None,
+    };
+
+    Ok(Json(FundingHistoryResponse {
+        exchange: exchange_id,
+        symbol,
+        records,
+        next_end,
+    }))
+}
+
+/// 입출금 현황 조회 파라미터
+#[derive(Debug, Default, Deserialize)]
+pub struct WalletStatusQuery {
+    pub exchange: Option<String>,
+    pub currency: Option<String>,
+    /// true면 입금이나 출금이 막힌 코인만
+    #[serde(default)]
+    pub closed_only: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WalletStatusEntry {
+    pub exchange: ExchangeId,
+    pub currency: String,
+    pub deposit_enabled: bool,  // 입금 가능한 네트워크가 하나라도 있는지
+    pub withdraw_enabled: bool, // 출금 가능한 네트워크가 하나라도 있는지
+    /// 입금이나 출금 중 하나라도 전부 막혀 이 거래소를 거치는 이체가 불가능
+    pub transfer_closed: bool,
+    pub networks: Vec<NetworkStatus>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&WalletStatus> for WalletStatusEntry {
+    fn from(status: &WalletStatus) -> Self {
+        let deposit_enabled = status.can_deposit();
+        let withdraw_enabled = status.can_withdraw();
+        Self {
+            exchange: status.exchange,
+            currency: status.currency.clone(),
+            deposit_enabled,
+            withdraw_enabled,
+            transfer_closed: !deposit_enabled || !withdraw_enabled,
+            networks: status.networks.clone(),
+            updated_at: status.updated_at,
+        }
+    }
+}
+
+/// GET /wallet-status?exchange=&currency=&closed_only=
+/// 입출금이 막힌 코인은 프리미엄이 있어도 재정거래할 수 없으므로 transfer_closed로 표시한다.
+async fn wallet_status_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<WalletStatusQuery>,
+) -> Result<Json<Vec<WalletStatusEntry>>, ApiError> {
+    let exchange = query
+        .exchange
+        .as_deref()
+        .map(|e| e.parse::<ExchangeId>())
+        .transpose()
+        .map_err(|e| api_error(StatusCode::BAD_REQUEST, e))?;
+    let currency = query.currency.as_deref().map(str::to_uppercase);
+
+    let statuses = state.wallet_status.read().await;
+    let mut entries: Vec<WalletStatusEntry> = statuses
+        .iter()
+        .filter(|(id, _)| exchange.is_none_or(|e| e == **id))
+        .flat_map(|(_, list)| list.iter())
+        .filter(|s| currency.as_ref().is_none_or(|c| *c == s.currency))
+        .map(WalletStatusEntry::from)
+        .filter(|e| !query.closed_only || e.transfer_closed)
+        .collect();
+    entries.sort_by(|a, b| {
+        a.currency
+            .cmp(&b.currency)
+            .then((a.exchange as u8).cmp(&(b.exchange as u8)))
+    });
+    Ok(Json(entries))
+}
+
+/// 시계열 조회 파라미터 (시각은 ms 타임스탬프, interval은 초)
+#[derive(Debug, Deserialize)]
+pub struct HistoryParams {
+    pub exchange: Option<String>,
+    pub symbol: Option<String>,
+    pub field: HistoryField,
+    pub start: Option<i64>, // 기본값: end - 24시간
+    pub end: Option<i64>,   // 기본값: 현재
+    /// 다운샘플링 구간 (초). 없으면 MAX_HISTORY_POINTS개 안쪽이 되도록 자동, 0이면 원본
+    pub interval: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistoryResponse {
+    pub exchange: Option<ExchangeId>,
+    pub symbol: Option<String>,
+    pub field: HistoryField,
+    /// 실제로 적용한 구간 (ms)
+    pub interval_ms: i64,
+    pub points: Vec<HistoryPoint>,
+}
+
+/// GET /history?exchange=&symbol=&field=&start=&end=&interval=
+/// 환율 필드(usd_krw, usdt_usd, usdt_krw)는 exchange/symbol 없이 조회한다.
+async fn history_handler(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<HistoryParams>,
+) -> Result<Json<HistoryResponse>, ApiError> {
+    let store = state.history.as_ref().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "history storage is disabled",
+        )
+    })?;
+    let exchange = params
+        .exchange
+        .as_deref()
+        .map(|e| e.parse::<ExchangeId>())
+        .transpose()
+        .map_err(|e| api_error(StatusCode::BAD_REQUEST, e))?;
+    let symbol = params.symbol.as_deref().map(str::to_uppercase);
+
+    let end = parse_time(params.end, "end")?.unwrap_or_else(Utc::now);
+    let start = parse_time(params.start, "start")?.unwrap_or(end - Duration::hours(24));
+    if start > end {
+        return Err(api_error(StatusCode::BAD_REQUEST, "start is after end"));
+    }
+    let range_ms = (end - start).num_milliseconds();
+    let min_bucket_ms = (range_ms / MAX_HISTORY_POINTS).max(1);
+    let bucket_ms = match params.interval {
+        Some(secs) if secs < 0 => {
+            return Err(api_error(StatusCode::BAD_REQUEST, "interval must be >= 0"))
+        }
+        Some(secs) => (secs * 1000).max(min_bucket_ms),
+        None => min_bucket_ms,
+    };
+
+    let query = HistoryQuery {
+        exchange,
+        symbol: symbol.clone(),
+        field: params.field,
+        start,
+        end,
+        bucket_ms,
+    };
+    let points = store.query(&query).await.map_err(|e| match e {
+        HistoryError::InvalidQuery(msg) => api_error(StatusCode::BAD_REQUEST, msg),
+        e => api_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    })?;
+
+    Ok(Json(HistoryResponse {
+        exchange,
+        symbol,
+        field: params.field,
+        interval_ms: bucket_ms,
+        points,
+    }))
+}
+
+/// 재정거래 기회 조회 파라미터
+#[derive(Debug, Default, Deserialize)]
+pub struct OpportunityParams {
+    /// 펀딩비를 계산할 보유 기간 (시간, 기본 24)
+    pub horizon_hours: Option<f64>,
+    /// 두 레그 모두 24시간 거래량이 이 이상 (USD)
+    pub min_volume_usd: Option<f64>,
+    /// 선물 레그 미결제약정이 이 이상 (USD)
+    pub min_oi_usd: Option<f64>,
+    pub kind: Option<OpportunityKind>,
+    pub base: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// 자산 보기 계산에 쓸 환율 (첫 수집 전이면 503, age_ms는 응답 시점 기준)
+async fn current_rates(state: &AppState) -> Result<ExchangeRates, ApiError> {
+    let mut rates = state.exchange_rates.read().await.clone().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "exchange rates not collected yet",
+        )
+    })?;
+    rates.refresh_age(Utc::now());
+    Ok(rates)
+}
+
+/// GET /assets
+/// base 자산별로 모든 거래소 시세를 USD 환산해 묶은 목록 (거래량 합계 내림차순)
+async fn assets_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<AssetView>>, ApiError> {
+    let rates = current_rates(&state).await?;
+    Ok(Json(aggregate_assets(&state.unified_list().await, &rates)))
+}
+
+/// GET /assets/:base (대소문자 무관)
+async fn asset_handler(
+    State(state): State<Arc<AppState>>,
+    Path(base): Path<String>,
+) -> Result<Json<AssetView>, ApiError> {
+    let rates = current_rates(&state).await?;
+    let base = base.to_uppercase();
+    let snapshots: Vec<UnifiedSnapshot> = state
+        .unified_list()
+        .await
+        .into_iter()
+        .filter(|u| u.base == base)
+        .collect();
+    aggregate_assets(&snapshots, &rates)
+        .pop()
+        .map(Json)
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("no venues for {}", base)))
+}
+
+/// GET /funding
+/// base 자산별 거래소 펀딩비를 연율로 환산해 비교 (거래소 간 차이 내림차순)
+async fn funding_handler(State(state): State<Arc<AppState>>) -> Json<Vec<FundingView>> {
+    Json(aggregate_funding(&state.unified_list().await))
+}
+
+/// GET /funding/:base (대소문자 무관)
+async fn funding_base_handler(
+    State(state): State<Arc<AppState>>,
+    Path(base): Path<String>,
+) -> Result<Json<FundingView>, ApiError> {
+    let base = base.to_uppercase();
+    let snapshots: Vec<UnifiedSnapshot> = state
+        .unified_list()
+        .await
+        .into_iter()
+        .filter(|u| u.base == base)
+        .collect();
+    aggregate_funding(&snapshots)
+        .pop()
+        .map(Json)
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("no perps for {}", base)))
+}
+
+/// GET /opportunities?horizon_hours=&min_volume_usd=&min_oi_usd=&kind=&base=&limit=
+/// 테이커 수수료는 FeeExchange::get_fee/get_perp_fee의 공시 요율 (계정 등급/할인 미반영)
+async fn opportunities_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<OpportunityParams>,
+) -> Result<Json<Vec<Opportunity>>, ApiError> {
+    let rates = current_rates(&state).await?;
+    let defaults = ScanParams::default();
+    let params = ScanParams {
+        horizon_hours: query.horizon_hours.unwrap_or(defaults.horizon_hours),
+        min_volume_usd: query.min_volume_usd.unwrap_or(defaults.min_volume_usd),
+        min_oi_usd: query.min_oi_usd.unwrap_or(defaults.min_oi_usd),
+    };
+    if params.horizon_hours.is_nan() || params.horizon_hours < 0.0 {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "horizon_hours must be >= 0",
+        ));
+    }
+    let base = query.base.as_deref().map(str::to_uppercase);
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_OPPORTUNITY_LIMIT)
+        .clamp(1, MAX_OPPORTUNITY_LIMIT);
+
+    let taker_fee = |exchange: ExchangeId, quote: &str, kind: InstrumentKind| {
+        state
+            .fee_exchanges
+            .iter()
+            .find(|ex| ex.id() == exchange)
+            .map(|ex| match kind {
+                InstrumentKind::Spot => ex.get_fee(MarketType::from_quote(quote)).taker,
+                _ => ex.get_perp_fee().taker,
+            })
+            .unwrap_or(DEFAULT_TAKER_FEE)
+    };
+    let snapshots: Vec<UnifiedSnapshot> = state
+        .unified_list()
+        .await
+        .into_iter()
+        .filter(|u| base.as_ref().is_none_or(|b| *b == u.base))
+        .collect();
+
+    let opportunities = scan_opportunities(&snapshots, &rates, taker_fee, &params)
+        .into_iter()
+        .filter(|o| query.kind.is_none_or(|k| k == o.kind))
+        .take(limit)
+        .collect();
+    Ok(Json(opportunities))
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeHealth {
+    pub exchange: ExchangeId,
+    pub kind: FeedKind,
+    pub last_success: Option<DateTime<Utc>>,
+    /// 마지막 성공 이후 경과 초 (성공한 적이 없으면 None)
+    pub age_secs: Option<i64>,
+    pub stale: bool,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    pub latency_ms: u64,
+    pub record_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    /// 모든 거래소가 최신이면 "ok", 하나라도 stale이면 "degraded"
+    pub status: &'static str,
+    pub exchanges: Vec<ExchangeHealth>,
+}
+
+/// GET /health
+/// 거래소별 마지막 수집 성공 시각, 마지막 오류, 응답 시간, 종목 수를 함께 준다.
+async fn health_handler(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
+    let now = Utc::now();
+    let mut exchanges: Vec<ExchangeHealth> = state
+        .exchange_status
+        .read()
+        .await
+        .iter()
+        .map(|(&(exchange, kind), status)| ExchangeHealth {
+            exchange,
+            kind,
+            last_success: status.last_success,
+            age_secs: status.age(now).map(|age| age.num_seconds()),
+            stale: status.is_stale(now),
+            last_error: status.last_error.clone(),
+            last_error_at: status.last_error_at,
+            latency_ms: status.latency_ms,
+            record_count: status.record_count,
+        })
+        .collect();
+    exchanges.sort_by_key(|e| (e.exchange as u8, e.kind as u8));
+
+    let status = if exchanges.iter().any(|e| e.stale) {
+        "degraded"
+    } else {
+        "ok"
+    };
+    Json(HealthResponse { status, exchanges })
+}
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
         .route("/health", get(health_handler))
         .route("/snapshots", get(snapshots_handler))
         .route("/spot-snapshots", get(spot_snapshots_handler))
         .route("/unified-snapshots", get(unified_snapshots_handler))
-        .layer(CorsLayer::permissive())
-        .with_state(state);
+        .route(
+            "/unified-snapshots/:exchange/:symbol",
+            get(unified_snapshot_handler),
+        )
+        .route(
+            "/funding-history/:exchange/:symbol",
+            get(funding_history_handler),
+        )
+        .route("/wallet-status", get(wallet_status_handler))
+        .route("/history", get(history_handler))
+        .route("/opportunities", get(opportunities_handler))
+        .route("/assets", get(assets_handler))
+        .route("/assets/:base", get(asset_handler))
+        .route("/funding", get(funding_handler))
+        .route("/funding/:base", get(funding_base_handler))
+        .route("/ws", get(ws_handler))
+        .route("/sse", get(sse_handler))
+        .layer(cors_layer(state.clone()))
+        .with_state(state)
+}
+
+/// 요청마다 현재 설정의 cors_origins로 origin을 검사한다 (재시작 없이 반영)
+fn cors_layer(state: Arc<AppState>) -> CorsLayer {
+    CorsLayer::permissive().allow_origin(AllowOrigin::predicate(move |origin, _| {
+        origin
+            .to_str()
+            .is_ok_and(|origin| state.config().allows_origin(origin))
+    }))
+}
+
+/// 설정의 bind:port로 HTTP 서버 시작 (주소 변경은 재시작해야 반영)
+pub async fn serve(state: Arc<AppState>) -> eyre::Result<()> {
+    let addr = state.config().addr();
+    let app = router(state);
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], port));
     info!("listening on http://{}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
     axum::serve(listener, app).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::spot;
+    use async_trait::async_trait;
+    use interface::{Decimal, ExchangeError};
+
+    /// 0시부터 8시간 간격으로 10개의 정산 기록을 가진 가짜 거래소
+    struct MockFunding;
+
+    #[async_trait]
+    impl FundingHistoryExchange for MockFunding {
+        fn id(&self) -> ExchangeId {
+            ExchangeId::Binance
+        }
+
+        async fn fetch_funding_history(
+            &self,
+            symbol: &str,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+            limit: usize,
+        ) -> Result<Vec<FundingRecord>, ExchangeError> {
+            let mut records: Vec<_> = (0..10)
+                .map(|i| FundingRecord {
+                    exchange: ExchangeId::Binance,
+                    symbol: symbol.to_string(),
+                    funding_time: DateTime::UNIX_EPOCH + Duration::hours(8 * i),
+                    funding_rate: 0.0001,
+                    funding_interval_hours: 8,
+                })
+                .filter(|r| r.funding_time >= start && r.funding_time <= end)
+                .collect();
+            let skip = records.len().saturating_sub(limit);
+            Ok(records.split_off(skip))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_funding_history_paging() {
+        let mut state = AppState::new();
+        state.funding_exchanges = vec![Arc::new(MockFunding)];
+        let state = Arc::new(state);
+
+        let query = |end: Option<i64>| FundingHistoryQuery {
+            start: Some(0),
+            end,
+            limit: Some(4),
+        };
+        let path = || Path(("binance".to_string(), "BTCUSDT".to_string()));
+
+        let Json(page) = funding_history_handler(
+            State(state.clone()),
+            path(),
+            Query(query(Some(Duration::hours(72).num_milliseconds()))),
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.records.len(), 4);
+        assert_eq!(
+            page.records[0].funding_time,
+            DateTime::UNIX_EPOCH + Duration::hours(48)
+        );
+
+        // next_end로 이어 받다 보면 start에 닿아 next_end가 사라진다
+        let mut next_end = page.next_end;
+        let mut total = page.records.len();
+        while let Some(end) = next_end {
+            let Json(page) =
+                funding_history_handler(State(state.clone()), path(), Query(query(Some(end))))
+                    .await
+                    .unwrap();
+            total += page.records.len();
+            next_end = page.next_end;
+        }
+        assert_eq!(total, 10);
+
+        let err = funding_history_handler(
+            State(state),
+            Path(("bithumb".to_string(), "BTCUSDT".to_string())),
+            Query(query(None)),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.0, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_wallet_status_closed_only() {
+        let network = |name: &str, deposit: bool, withdraw: bool| NetworkStatus {
+            network: name.to_string(),
+            deposit_enabled: deposit,
+            withdraw_enabled: withdraw,
+            deposit_fee: None,
+            withdraw_fee: None,
+            deposit_min: None,
+            withdraw_min: None,
+            withdraw_max: None,
+        };
+        let status = |currency: &str, networks| WalletStatus {
+            exchange: ExchangeId::Bithumb,
+            currency: currency.to_string(),
+            networks,
+            updated_at: Utc::now(),
+        };
+
+        let state = AppState::new();
+        state.wallet_status.write().await.insert(
+            ExchangeId::Bithumb,
+            vec![
+                // 한 네트워크라도 열려 있으면 이체 가능
+                status(
+                    "USDT",
+                    vec![network("ETH", true, false), network("TRX", true, true)],
+                ),
+                status("XYZ", vec![network("XYZ", true, false)]),
+            ],
+        );
+        let state = Arc::new(state);
+
+        let query = WalletStatusQuery {
+            closed_only: true,
+            ..Default::default()
+        };
+        let Json(closed) = wallet_status_handler(State(state.clone()), Query(query))
+            .await
+            .unwrap();
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].currency, "XYZ");
+        assert!(closed[0].deposit_enabled && !closed[0].withdraw_enabled);
+
+        let query = WalletStatusQuery {
+            exchange: Some("binance".to_string()),
+            ..Default::default()
+        };
+        let Json(none) = wallet_status_handler(State(state), Query(query))
+            .await
+            .unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_served_snapshot_age() {
+        let now = Utc::now();
+        let mut snapshot = spot(ExchangeId::Bybit, "BTCUSDT", "BTC", Decimal::from(65000));
+        snapshot.updated_at = now - Duration::seconds(5);
+
+        let served = ServedSnapshot::new(snapshot.clone(), now);
+        assert_eq!(served.age_ms, 5000);
+        assert!(!served.stale);
+        // 스냅샷 필드는 그대로 두고 age_ms, stale만 덧붙는다
+        let json = serde_json::to_value(&served).unwrap();
+        assert_eq!(json["symbol"], "BTCUSDT");
+        assert_eq!(json["age_ms"], 5000);
+        assert_eq!(json["stale"], false);
+
+        let served = ServedSnapshot::new(snapshot, now + STALE_AFTER);
+        assert!(served.stale);
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_stale_exchanges() {
+        let state = Arc::new(AppState::new());
+        let Json(health) = health_handler(State(state.clone())).await;
+        assert_eq!(health.status, "ok");
+        assert!(health.exchanges.is_empty());
+
+        state
+            .record_fetch_success(ExchangeId::Bybit, FeedKind::Spot, 120, 300)
+            .await;
+        state
+            .record_fetch_error(
+                ExchangeId::Bithumb,
+                FeedKind::Spot,
+                20_000,
+                "timed out".into(),
+            )
+            .await;
+        let Json(health) = health_handler(State(state)).await;
+        assert_eq!(health.status, "degraded");
+        assert_eq!(health.exchanges.len(), 2);
+
+        let bybit = &health.exchanges[0];
+        assert_eq!(bybit.exchange, ExchangeId::Bybit);
+        assert!(!bybit.stale && bybit.age_secs == Some(0));
+        assert_eq!((bybit.latency_ms, bybit.record_count), (120, 300));
+
+        // 한 번도 성공하지 못한 거래소는 stale
+        let bithumb = &health.exchanges[1];
+        assert!(bithumb.stale && bithumb.last_success.is_none());
+        assert_eq!(bithumb.last_error.as_deref(), Some("timed out"));
+    }
+}
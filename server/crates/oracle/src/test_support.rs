@@ -0,0 +1,111 @@
+//! 테스트 공통 픽스처 (환율, 선물/현물/통합 스냅샷)
+
+use chrono::Utc;
+use interface::{
+    Currency, Decimal, ExchangeId, ExchangeRates, Instrument, InstrumentKind, PerpData,
+    PerpSnapshot, SpotData, SpotSnapshot, UnifiedSnapshot,
+};
+
+/// 1 USD = usd_krw KRW, 1 USDT = usdt_usd USD (USDT/KRW는 둘의 곱)
+pub(crate) fn rates_with(usd_krw: f64, usdt_usd: f64) -> ExchangeRates {
+    ExchangeRates {
+        usd_krw,
+        usdt_usd,
+        usdt_krw: usd_krw * usdt_usd,
+        updated_at: Utc::now(),
+        sources: Default::default(),
+        is_fallback: false,
+        degraded: false,
+        age_ms: 0,
+    }
+}
+
+/// 1 USD = 1400 KRW, 1 USDT = 1 USD
+pub(crate) fn rates() -> ExchangeRates {
+    rates_with(1400.0, 1.0)
+}
+
+/// USDT 선물 스냅샷 (OI, 거래량, 펀딩비 0)
+pub(crate) fn perp(
+    exchange: ExchangeId,
+    native: &str,
+    base: &str,
+    mark_price: Decimal,
+) -> PerpSnapshot {
+    let instrument = Instrument::new(exchange, InstrumentKind::Perp, base, "USDT", native);
+    PerpSnapshot {
+        exchange,
+        symbol: instrument.symbol(),
+        instrument,
+        currency: Currency::USDT,
+        mark_price,
+        oi_usd: 0.0,
+        vol_24h_usd: 0.0,
+        funding_rate: 0.0,
+        next_funding_time: None,
+        funding_interval_hours: None,
+        predicted_funding_rate: None,
+        index_price: None,
+        updated_at: Utc::now(),
+    }
+}
+
+/// USDT 현물 스냅샷 (거래량 0)
+pub(crate) fn spot(exchange: ExchangeId, native: &str, base: &str, price: Decimal) -> SpotSnapshot {
+    let instrument = Instrument::new(exchange, InstrumentKind::Spot, base, "USDT", native);
+    SpotSnapshot {
+        exchange,
+        symbol: instrument.symbol(),
+        instrument,
+        currency: Currency::USDT,
+        price,
+        vol_24h_usd: 0.0,
+        updated_at: Utc::now(),
+    }
+}
+
+/// 통합 스냅샷 (환율은 rates())
+/// 선물 가격을 주면 OI 100만, 거래량 500만 USD, 펀딩 0.01%(주기 모름) 선물 시세를,
+/// 현물 가격을 주면 거래량 200만 USD 현물 시세를 채운다. 다른 값은 테스트에서 고친다.
+pub(crate) fn snapshot(
+    exchange: ExchangeId,
+    base: &str,
+    quote: &str,
+    native: &str,
+    perp: Option<i64>,
+    spot: Option<i64>,
+) -> UnifiedSnapshot {
+    let currency = if quote == "KRW" {
+        Currency::KRW
+    } else {
+        Currency::USDT
+    };
+    UnifiedSnapshot {
+        exchange,
+        symbol: format!("{}{}", base, quote),
+        base: base.to_string(),
+        quote: quote.to_string(),
+        currency,
+        perp: perp.map(|price| PerpData {
+            instrument: Instrument::new(exchange, InstrumentKind::Perp, base, quote, native),
+            currency,
+            mark_price: Decimal::from(price),
+            oi_usd: 1_000_000.0,
+            vol_24h_usd: 5_000_000.0,
+            funding_rate: 0.0001,
+            next_funding_time: None,
+            funding_interval_hours: None,
+            predicted_funding_rate: None,
+            index_price: None,
+            premium_index: None,
+        }),
+        spot: spot.map(|price| SpotData {
+            instrument: Instrument::new(exchange, InstrumentKind::Spot, base, quote, native),
+            currency,
+            price: Decimal::from(price),
+            vol_24h_usd: 2_000_000.0,
+        }),
+        exchange_rates: rates(),
+        updated_at: Utc::now(),
+    }
+}
@@ -0,0 +1,11 @@
+//! 응답 계산에 쓰는 공통 단위 변환
+
+use interface::Decimal;
+
+/// 비율 -> bps 배수 (0.0001 == 1bp)
+pub(crate) const BPS: f64 = 10_000.0;
+
+/// 응답용 f64 변환 (범위를 벗어나면 0)
+pub(crate) fn to_f64(value: Decimal) -> f64 {
+    f64::try_from(value).unwrap_or(0.0)
+}
@@ -42,8 +42,18 @@ pub enum LegExecutionPolicy {
     PostOnlyMaker,
 }
 
-use interface::ExchangeId;
+impl LegExecutionPolicy {
+    /// maker 수수료가 적용되는 정책인지 여부
+    pub fn is_maker(&self) -> bool {
+        matches!(self, Self::PassiveMaker | Self::PostOnlyMaker)
+    }
+}
+
+use crate::trader::binance::MarginAllocatorParams;
+use interface::{ExchangeError, ExchangeId};
 use std::fmt;
+use std::time::Duration;
+use tracing::{error, trace, warn};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StrategyMode {
@@ -93,6 +103,8 @@ pub struct StrategyParams {
     pub spot_leg: LegExecutionPolicy,
     /// 선물 레그의 개별 실행 정책 (MarketTaker, AggressiveLimitTaker, PassiveMaker, PostOnlyMaker)
     pub futures_leg: LegExecutionPolicy,
+    /// 선물 지갑 마진 자동 배분 설정 (None이면 지갑 간 이동을 하지 않음, dry-run에서는 동작하지 않음)
+    pub margin_allocator: Option<MarginAllocatorParams>,
 }
 
 impl Default for StrategyParams {
@@ -109,6 +121,7 @@ impl Default for StrategyParams {
             policy: ExecutionPolicy::TakerTaker,
             spot_leg: LegExecutionPolicy::MarketTaker,
             futures_leg: LegExecutionPolicy::MarketTaker,
+            margin_allocator: None,
         }
     }
 }
@@ -177,3 +190,44 @@ impl Default for CrossStrategyParams {
         }
     }
 }
+
+/// 전략 루프에서 주문 실패 처리 (intra/cross 공통)
+/// - 재시도 가능한 에러(RateLimited, Timeout 등)는 잠시 대기 후 다음 루프에서 다시 시도
+/// - 잔고 부족/수량 오류는 설정을 고쳐야 하므로 경고만 남기고 계속 가격을 감시
+/// - 인증 오류는 루프를 계속해도 의미가 없으므로 에러를 반환해 루프를 종료
+/// - 실행 여부를 알 수 없는 주문(UnknownStatus)은 다시 넣으면 두 배로 체결될 수 있으므로
+///   에러를 반환해 루프를 종료한다 (주문 클라이언트가 client order id로 이미 조회한 뒤다)
+pub(crate) async fn handle_order_error(
+    action: &str,
+    e: ExchangeError,
+) -> Result<(), ExchangeError> {
+    match e {
+        ExchangeError::DryRun => {
+            trace!("DRY RUN: {} skipped", action);
+        }
+        ExchangeError::Auth(_) | ExchangeError::UnknownStatus(_) => {
+            error!("Failed to {}: {}", action, e);
+            return Err(e);
+        }
+        ExchangeError::InsufficientBalance(_) | ExchangeError::InvalidQuantity(_) => {
+            warn!(
+                "Failed to {}: {} (check notional / balance settings)",
+                action, e
+            );
+        }
+        _ if e.is_retryable() => {
+            warn!("Failed to {} (retryable): {}", action, e);
+            backoff(&e).await;
+        }
+        _ => {
+            warn!("Failed to {}: {}", action, e);
+        }
+    }
+    Ok(())
+}
+
+/// 재시도 전 대기 (RateLimited면 거래소가 알려준 시간, 없으면 1초)
+pub(crate) async fn backoff(e: &ExchangeError) {
+    let wait = e.retry_after().unwrap_or(Duration::from_secs(1));
+    tokio::time::sleep(wait).await;
+}
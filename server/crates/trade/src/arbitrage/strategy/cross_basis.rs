@@ -4,11 +4,13 @@
 use serde_json;
 use tracing::{info, warn};
 
-use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse, SpotExchangeTrader};
-use interface::ExchangeError;
+use crate::trader::binance::HedgedPair;
+use crate::trader::{BinanceTrader, FuturesExchangeTrader, SpotExchangeTrader};
+use interface::{Decimal, ExchangeError, OrderAck};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 
 use super::super::state::ArbitrageState;
-use super::{CrossStrategyParams, StrategyMode};
+use super::{CrossStrategyParams, StrategyMode, backoff, handle_order_error};
 
 /// 두 개의 서로 다른 거래소 간 베이시스(가격 격차)를 이용해
 /// **크로스 거래소 델타-뉴트럴 포지션**을 자동으로 관리하는 전략 엔진.
@@ -67,7 +69,7 @@ use super::{CrossStrategyParams, StrategyMode};
 ///   - 현재 포지션 유무(open)
 ///   - 포지션 방향(dir = "carry" / "reverse")
 ///   - 진입 시 베이시스, 수량, 주문 내역(actions)
-///   을 복원할 수 있다.
+///     을 복원할 수 있다.
 /// - 포지션이 열린 상태에서는 **청산 조건만** 감시하고,
 ///   포지션이 없을 때만 **진입 조건**을 평가한다.
 ///
@@ -189,7 +191,17 @@ where
         )
     }
 
-    fn clamp_cross_quantity(&self, qty: f64) -> f64 {
+    /// 양쪽 레그가 같은 수량으로 체결된 것으로 보고 상태 저장용 HedgedPair 생성
+    fn filled_pair(filled_qty: Decimal) -> HedgedPair {
+        HedgedPair {
+            spot_order_qty: filled_qty,
+            fut_order_qty: filled_qty,
+            spot_net_qty_est: filled_qty,
+            delta_est: Decimal::ZERO,
+        }
+    }
+
+    fn clamp_cross_quantity(&self, qty: Decimal) -> Decimal {
         let spot_qty = self
             .spot_trader
             .clamp_spot_quantity(&self.params.primary_symbol, qty);
@@ -199,17 +211,20 @@ where
         spot_qty.min(fut_qty)
     }
 
-    fn target_quantity(&self, primary_price: f64, hedge_price: f64) -> f64 {
-        let primary_qty = if primary_price > 0.0 {
-            self.params.primary_notional / primary_price
+    fn target_quantity(&self, primary_price: Decimal, hedge_price: Decimal) -> Decimal {
+        let primary_notional = Decimal::from_f64(self.params.primary_notional).unwrap_or_default();
+        let hedge_notional = Decimal::from_f64(self.params.hedge_notional).unwrap_or_default();
+
+        let primary_qty = if primary_price > Decimal::ZERO {
+            primary_notional / primary_price
         } else {
-            0.0
+            Decimal::ZERO
         };
 
-        let hedge_qty = if hedge_price > 0.0 {
-            self.params.hedge_notional / hedge_price
+        let hedge_qty = if hedge_price > Decimal::ZERO {
+            hedge_notional / hedge_price
         } else {
-            0.0
+            Decimal::ZERO
         };
 
         primary_qty.min(hedge_qty)
@@ -248,7 +263,7 @@ where
     ///        * reverse → close_reverse(state.qty):
     ///            - 선물 레그: reduce-only SELL
     ///            - spot 레그: BUY
-    ///        * 두 레그의 OrderResponse 를 JSON(actions) 으로 기록하고,
+    ///        * 두 레그의 OrderAck 를 JSON(actions) 으로 기록하고,
     ///          ArbitrageState 를 닫힌 상태(open=false, dir=None, qty=0)로 갱신 후 디스크에 저장한다.
     ///
     /// 4. 포지션이 없을 때(state.open == false)
@@ -316,26 +331,41 @@ where
         loop {
             tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
 
-            let primary_price = self
+            let primary_price = match self
                 .spot_trader
                 .get_spot_price(&self.params.primary_symbol)
                 .await
-                .map_err(|e| {
+            {
+                Ok(price) => price,
+                Err(e) => {
                     warn!("Failed to get primary spot price: {}", e);
-                    e
-                })?;
+                    if !e.is_retryable() {
+                        return Err(e);
+                    }
+                    backoff(&e).await;
+                    continue;
+                }
+            };
 
-            let hedge_mark = self
+            let hedge_mark = match self
                 .hedge_trader
                 .get_mark_price(&self.params.hedge_symbol)
                 .await
-                .map_err(|e| {
+            {
+                Ok(mark) => mark,
+                Err(e) => {
                     warn!("Failed to get hedge mark price: {}", e);
-                    e
-                })?;
+                    if !e.is_retryable() {
+                        return Err(e);
+                    }
+                    backoff(&e).await;
+                    continue;
+                }
+            };
 
-            let adjusted_primary = primary_price * self.params.fx_adjustment;
-            if adjusted_primary <= 0.0 {
+            let fx_adjustment = Decimal::from_f64(self.params.fx_adjustment).unwrap_or_default();
+            let adjusted_primary = primary_price * fx_adjustment;
+            if adjusted_primary <= Decimal::ZERO {
                 warn!(
                     "Adjusted primary price invalid ({}). Skipping iteration.",
                     adjusted_primary
@@ -343,7 +373,10 @@ where
                 continue;
             }
 
-            let basis_bps = (hedge_mark - adjusted_primary) / adjusted_primary * 10_000.0;
+            let basis_bps = ((hedge_mark - adjusted_primary) / adjusted_primary
+                * Decimal::from(10_000))
+            .to_f64()
+            .unwrap_or(0.0);
 
             info!(
                 "Primary: {:.8}, Hedge: {:.8}, Adjusted Basis: {:.8} bps",
@@ -361,8 +394,8 @@ where
                 if should_close {
                     info!("Exit condition met. Closing position...");
                     let result = match state.dir.as_deref() {
-                        Some("carry") => self.close_carry(todo!()).await,
-                        Some("reverse") => self.close_reverse(todo!()).await,
+                        Some("carry") => self.close_carry(state.pair.fut_order_qty).await,
+                        Some("reverse") => self.close_reverse(state.pair.fut_order_qty).await,
                         _ => {
                             warn!("Unknown position direction: {:?}", state.dir);
                             continue;
@@ -386,7 +419,7 @@ where
                             info!("Position closed successfully");
                         }
                         Err(e) => {
-                            warn!("Failed to close position: {}", e);
+                            handle_order_error("close position", e).await?;
                         }
                     }
                 }
@@ -401,7 +434,7 @@ where
                         && basis_bps < -self.params.entry_bps;
 
                 let qty = self.target_quantity(primary_price, hedge_mark);
-                if qty <= 0.0 {
+                if qty <= Decimal::ZERO {
                     warn!(
                         "Target quantity too small. primary/hedge prices: {}/{}",
                         primary_price, hedge_mark
@@ -420,7 +453,7 @@ where
                             state.update_position(
                                 true,
                                 Some("carry".to_string()),
-                                todo!(),
+                                Self::filled_pair(filled_qty),
                                 Some(basis_bps),
                                 Some(actions),
                             );
@@ -428,7 +461,7 @@ where
                             info!("Cross-exchange CARRY position opened successfully");
                         }
                         Err(e) => {
-                            warn!("Failed to open CARRY position: {}", e);
+                            handle_order_error("open CARRY position", e).await?;
                         }
                     }
                 } else if should_open_reverse {
@@ -442,7 +475,7 @@ where
                             state.update_position(
                                 true,
                                 Some("reverse".to_string()),
-                                todo!(),
+                                Self::filled_pair(filled_qty),
                                 Some(basis_bps),
                                 Some(actions),
                             );
@@ -450,7 +483,7 @@ where
                             info!("Cross-exchange REVERSE position opened successfully");
                         }
                         Err(e) => {
-                            warn!("Failed to open REVERSE position: {}", e);
+                            handle_order_error("open REVERSE position", e).await?;
                         }
                     }
                 }
@@ -460,8 +493,8 @@ where
 
     async fn open_carry(
         &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse, f64), ExchangeError> {
+        qty: Decimal,
+    ) -> Result<(OrderAck, OrderAck, Decimal), ExchangeError> {
         info!(
             "Opening cross CARRY: buy {} {} on {:?}, sell futures {} {} on {:?}",
             qty,
@@ -477,12 +510,12 @@ where
                 "DRY RUN: Would BUY spot {} {}, SELL futures {} {}",
                 qty, self.params.primary_symbol, qty, self.params.hedge_symbol
             );
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            return Err(ExchangeError::DryRun);
         }
 
         let trade_qty = self.clamp_cross_quantity(qty);
-        if trade_qty <= 0.0 {
-            return Err(ExchangeError::Other(format!(
+        if trade_qty <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(format!(
                 "Quantity too small after clamping. Requested={}",
                 qty
             )));
@@ -500,7 +533,7 @@ where
         Ok((spot_order, hedge_order, trade_qty))
     }
 
-    async fn close_carry(&self, qty: f64) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+    async fn close_carry(&self, qty: Decimal) -> Result<(OrderAck, OrderAck), ExchangeError> {
         info!("Closing cross CARRY position (reduce-only) qty {}", qty);
 
         if self.params.dry_run {
@@ -508,12 +541,12 @@ where
                 "DRY RUN: Would BUY futures {} {} (reduce only) and SELL spot {} {}",
                 qty, self.params.hedge_symbol, qty, self.params.primary_symbol
             );
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            return Err(ExchangeError::DryRun);
         }
 
         let trade_qty = self.clamp_cross_quantity(qty);
-        if trade_qty <= 0.0 {
-            return Err(ExchangeError::Other(
+        if trade_qty <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(
                 "Quantity too small after clamping".to_string(),
             ));
         }
@@ -532,8 +565,8 @@ where
 
     async fn open_reverse(
         &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse, f64), ExchangeError> {
+        qty: Decimal,
+    ) -> Result<(OrderAck, OrderAck, Decimal), ExchangeError> {
         info!(
             "Opening cross REVERSE: sell {} {} on {:?}, buy futures {} {} on {:?}",
             qty,
@@ -549,15 +582,15 @@ where
                 "DRY RUN: Would SELL spot {} {}, BUY futures {} {}",
                 qty, self.params.primary_symbol, qty, self.params.hedge_symbol
             );
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            return Err(ExchangeError::DryRun);
         }
 
         let spot_balance = self
             .spot_trader
             .get_spot_balance(&self.params.primary_base_asset)
             .await?;
-        if spot_balance <= 0.0 {
-            return Err(ExchangeError::Other(format!(
+        if spot_balance <= Decimal::ZERO {
+            return Err(ExchangeError::InsufficientBalance(format!(
                 "Insufficient spot inventory on {:?}. balance={}",
                 self.params.primary_exchange, spot_balance
             )));
@@ -565,8 +598,8 @@ where
 
         let max_qty = spot_balance.min(qty);
         let trade_qty = self.clamp_cross_quantity(max_qty);
-        if trade_qty <= 0.0 {
-            return Err(ExchangeError::Other(
+        if trade_qty <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(
                 "Quantity too small after inventory clamp".to_string(),
             ));
         }
@@ -583,10 +616,7 @@ where
         Ok((spot_order, hedge_order, trade_qty))
     }
 
-    async fn close_reverse(
-        &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+    async fn close_reverse(&self, qty: Decimal) -> Result<(OrderAck, OrderAck), ExchangeError> {
         info!("Closing cross REVERSE position qty {}", qty);
 
         if self.params.dry_run {
@@ -594,12 +624,12 @@ where
                 "DRY RUN: Would SELL futures {} {} (reduce only), BUY spot {} {}",
                 qty, self.params.hedge_symbol, qty, self.params.primary_symbol
             );
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            return Err(ExchangeError::DryRun);
         }
 
         let trade_qty = self.clamp_cross_quantity(qty);
-        if trade_qty <= 0.0 {
-            return Err(ExchangeError::Other(
+        if trade_qty <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(
                 "Quantity too small after clamping".to_string(),
             ));
         }
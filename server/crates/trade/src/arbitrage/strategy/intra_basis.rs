@@ -1,11 +1,22 @@
-use interface::ExchangeError;
+use interface::{Decimal, ExchangeError, Instrument, InstrumentKind, OrderAck, OrderSide};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use serde_json;
+use tokio::task::JoinHandle;
 use tracing::{info, trace, warn};
 
 use super::super::state::ArbitrageState;
-use super::{StrategyMode, StrategyParams};
+use super::{StrategyMode, StrategyParams, backoff, handle_order_error};
 use crate::trader::binance::HedgedPair;
-use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
+use crate::trader::{BinanceTrader, FuturesExchangeTrader};
+
+/// drop될 때 백그라운드 태스크를 중단시키는 핸들
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
 
 /// 단일 거래소(Binance) 안에서 스팟/선물 간 베이시스(가격 격차)를 이용해
 /// 델타-뉴트럴 포지션을 자동으로 관리하는 인트라(intra) 베이시스 아비트라지 전략.
@@ -29,7 +40,7 @@ use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
 /// - 한 거래소 안에서 **spot vs futures** 베이시스를 보고,
 ///   - 선물이 스팟보다 충분히 비싸면 → 캐리(CARRY) 포지션
 ///   - 선물이 스팟보다 충분히 싸면 → 리버스(REVERSE) 포지션
-///   에 진입한 뒤, 베이시스가 다시 좁혀지면 청산하는 **mean-reversion 전략**.
+///     에 진입한 뒤, 베이시스가 다시 좁혀지면 청산하는 **mean-reversion 전략**.
 /// - 모든 것이 같은 기축(USDT) 기준이기 때문에,
 ///   CrossBasis 와 달리 fx_adjustment, cross 계정 재고, 출금/전송 리스크를
 ///   따로 고려할 필요가 없다.
@@ -81,7 +92,7 @@ use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
 ///   - 수량(qty),
 ///   - 진입/청산 시점의 basis_bps,
 ///   - 마지막 주문 응답(actions: {spot, futures})
-///   를 지속적으로 기록한다.
+///     를 지속적으로 기록한다.
 /// - 포지션이 없을 때:
 ///   - StrategyMode::Carry / Reverse / Auto 에 따라
 ///     entry_bps 초과/미만 조건을 만족하면 `open_carry` 또는 `open_reverse` 호출.
@@ -91,34 +102,66 @@ use crate::trader::{BinanceTrader, FuturesExchangeTrader, OrderResponse};
 pub struct IntraBasisArbitrageStrategy {
     trader: BinanceTrader,
     params: StrategyParams,
+    /// params.symbol 을 해석한 스팟/선물 상품
+    /// (예: "1000PEPEUSDT" -> 스팟 PEPEUSDT, 선물 1000PEPEUSDT x1000)
+    spot: Instrument,
+    futures: Instrument,
 }
 
 impl IntraBasisArbitrageStrategy {
-    pub fn new(params: StrategyParams) -> Result<Self, ExchangeError> {
+    /// exchangeInfo(스팟/선물 LOT_SIZE 필터와 상장 목록)를 로드한 뒤 심볼을 해석한다.
+    pub async fn new(params: StrategyParams) -> Result<Self, ExchangeError> {
         let trader = BinanceTrader::new()?;
-        Ok(Self { trader, params })
+
+        info!("Loading spot exchangeInfo...");
+        trader.load_spot_exchange_info().await.map_err(|e| {
+            ExchangeError::Other(format!("Failed to load spot exchangeInfo: {}", e))
+        })?;
+
+        info!("Loading futures exchangeInfo...");
+        trader.load_futures_exchange_info().await.map_err(|e| {
+            ExchangeError::Other(format!("Failed to load futures exchangeInfo: {}", e))
+        })?;
+
+        let (spot, futures) = trader.resolve_instruments(&params.symbol)?;
+        Ok(Self {
+            trader,
+            params,
+            spot,
+            futures,
+        })
     }
 
     /// 베이시스 계산 (bps 단위)
     /// basis_bps = (futures_mark - spot_price) / spot_price * 10000
-    pub fn compute_basis_bps(&self, spot_price: f64, futures_mark: f64) -> f64 {
-        if spot_price <= 0.0 {
+    pub fn compute_basis_bps(&self, spot_price: Decimal, futures_mark: Decimal) -> f64 {
+        if spot_price <= Decimal::ZERO {
             return 0.0;
         }
-        (futures_mark - spot_price) / spot_price * 10000.0
+        ((futures_mark - spot_price) / spot_price * Decimal::from(10000))
+            .to_f64()
+            .unwrap_or(0.0)
     }
 
     /// 포지션 청산 시 PnL 계산 및 로깅
     fn log_position_pnl(
         &self,
         state: &ArbitrageState,
-        spot_price: f64,
-        futures_mark: f64,
+        spot_price: Decimal,
+        futures_mark: Decimal,
         basis_bps: f64,
     ) {
         let open_basis = state.last_open_basis_bps.unwrap_or(0.0);
         let close_basis = basis_bps;
         let pair = &state.pair;
+        // 로그용 추정치이므로 f64로 계산
+        let spot_price = spot_price.to_f64().unwrap_or(0.0);
+        let futures_mark = futures_mark.to_f64().unwrap_or(0.0);
+        let spot_qty = pair.spot_order_qty.to_f64().unwrap_or(0.0);
+        // 가격은 base 1개 기준이므로 선물 수량도 base 단위로 환산
+        let fut_base_qty = (pair.fut_order_qty * Decimal::from(self.futures.multiplier))
+            .to_f64()
+            .unwrap_or(0.0);
 
         // 베이시스 변화로부터 이득 추정
         // CARRY: 진입 시 basis > exit, 청산 시 basis <= exit
@@ -133,7 +176,7 @@ impl IntraBasisArbitrageStrategy {
 
         // 베이시스 변화를 USDT 이득으로 환산
         // basis_change (bps) = (basis_change / 10000) * spot_price * 수량
-        let basis_pnl_usdt = (basis_change / 10000.0) * spot_price * pair.spot_order_qty;
+        let basis_pnl_usdt = (basis_change / 10000.0) * spot_price * spot_qty;
 
         // 더 정확한 계산: 스팟과 선물 각각의 가격 변화
         // 진입 시점 가격 추정 (현재 가격과 베이시스로 역산)
@@ -148,21 +191,21 @@ impl IntraBasisArbitrageStrategy {
         //   선물 이득 = (현재_fut - 진입_fut) * fut_qty
         let (spot_pnl, futures_pnl) = match state.dir.as_deref() {
             Some("carry") => {
-                let spot_pnl = (spot_price - open_spot_price) * pair.spot_order_qty;
-                let futures_pnl = (open_futures_price - futures_mark) * pair.fut_order_qty;
+                let spot_pnl = (spot_price - open_spot_price) * spot_qty;
+                let futures_pnl = (open_futures_price - futures_mark) * fut_base_qty;
                 (spot_pnl, futures_pnl)
             }
             Some("reverse") => {
-                let spot_pnl = (open_spot_price - spot_price) * pair.spot_order_qty;
-                let futures_pnl = (futures_mark - open_futures_price) * pair.fut_order_qty;
+                let spot_pnl = (open_spot_price - spot_price) * spot_qty;
+                let futures_pnl = (futures_mark - open_futures_price) * fut_base_qty;
                 (spot_pnl, futures_pnl)
             }
             _ => (0.0, 0.0),
         };
 
         let total_pnl = spot_pnl + futures_pnl;
-        let total_pnl_bps = if pair.spot_order_qty > 0.0 {
-            (total_pnl / (spot_price * pair.spot_order_qty)) * 10000.0
+        let total_pnl_bps = if spot_qty > 0.0 {
+            (total_pnl / (spot_price * spot_qty)) * 10000.0
         } else {
             0.0
         };
@@ -197,46 +240,55 @@ impl IntraBasisArbitrageStrategy {
     }
 
     /// 명목가에서 수량 계산 (스팟 기준)
-    pub fn size_from_notional(&self, spot_price: f64) -> f64 {
-        let qty = self.params.notional / spot_price;
-        self.trader.clamp_spot_quantity(&self.params.symbol, qty)
+    pub fn size_from_notional(&self, spot_price: Decimal) -> Decimal {
+        let notional = Decimal::from_f64(self.params.notional).unwrap_or_default();
+        if spot_price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let qty = notional / spot_price;
+        self.trader
+            .clamp_spot_quantity(&self.spot.native_symbol, qty)
     }
 
     /// Carry 포지션 오픈: 스팟 롱 + 선물 숏
     pub async fn open_carry(
         &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse, HedgedPair), ExchangeError> {
+        qty: Decimal,
+    ) -> Result<(OrderAck, OrderAck, HedgedPair), ExchangeError> {
         info!(
             "Opening CARRY position: spot BUY {} {}, futures SELL {} {}",
-            qty, self.params.symbol, qty, self.params.symbol
+            qty, self.spot.native_symbol, qty, self.futures.native_symbol
         );
 
         let fee = self
             .trader
-            .get_trade_fee_for_symbol(&self.params.symbol)
+            .get_trade_fee(&self.spot.native_symbol, InstrumentKind::Spot)
             .await?;
 
-        let spot_fee_rate = match self.params.mode {
-            StrategyMode::Carry => fee.taker,
-            _ => fee.maker,
-        };
+        // BNB로 수수료를 내면 매수한 base 수량은 줄지 않는다
+        let spot_fee_rate = fee.traded_asset_rate(self.params.spot_leg.is_maker());
 
         // 스팟과 선물의 수량을 각각 clamp하고, 더 작은 쪽 사용
         let pair = self
             .trader
-            .find_hedged_pair(&self.params.symbol, qty, spot_fee_rate)
-            .ok_or_else(|| ExchangeError::Other("Failed to find hedged pair".into()))?;
+            .find_hedged_pair(&self.spot, &self.futures, qty, spot_fee_rate)
+            .ok_or_else(|| ExchangeError::InvalidQuantity("Failed to find hedged pair".into()))?;
 
         if self.params.dry_run {
             info!("DRY RUN: pair: {:?}", pair);
-            info!("DRY RUN: spot BUY {} {}", qty, self.params.symbol);
-            info!("DRY RUN: futures SELL {} {}", qty, self.params.symbol);
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            info!(
+                "DRY RUN: spot BUY {} {}",
+                pair.spot_order_qty, self.spot.native_symbol
+            );
+            info!(
+                "DRY RUN: futures SELL {} {}",
+                pair.fut_order_qty, self.futures.native_symbol
+            );
+            return Err(ExchangeError::DryRun);
         }
 
-        if pair.spot_net_qty_est <= 0.0 {
-            return Err(ExchangeError::Other(format!(
+        if pair.spot_net_qty_est <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(format!(
                 "Quantity too small after clamping. Increase notional. spot_qty={}, fut_qty={}",
                 pair.spot_order_qty, pair.fut_order_qty
             )));
@@ -247,13 +299,23 @@ impl IntraBasisArbitrageStrategy {
         // 스팟 매수
         let spot_order = self
             .trader
-            .place_spot_order(&self.params.symbol, "BUY", pair.spot_order_qty, false)
+            .market_spot_order(
+                &self.spot.native_symbol,
+                OrderSide::Buy,
+                pair.spot_order_qty,
+                false,
+            )
             .await?;
 
         // 선물 숏
         let futures_order = self
             .trader
-            .place_futures_order(&self.params.symbol, "SELL", pair.fut_order_qty, false)
+            .market_futures_order(
+                &self.futures.native_symbol,
+                OrderSide::Sell,
+                pair.fut_order_qty,
+                false,
+            )
             .await?;
 
         // TODO: 선물 실패 처리, 트랜잭션
@@ -267,38 +329,51 @@ impl IntraBasisArbitrageStrategy {
     pub async fn close_carry(
         &self,
         pair: HedgedPair,
-    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+    ) -> Result<(OrderAck, OrderAck), ExchangeError> {
         info!(
             "Closing CARRY position: spot SELL {} {}, futures BUY {} {} (reduceOnly)",
-            pair.spot_order_qty, self.params.symbol, pair.fut_order_qty, self.params.symbol
+            pair.spot_order_qty,
+            self.spot.native_symbol,
+            pair.fut_order_qty,
+            self.futures.native_symbol
         );
 
         if self.params.dry_run {
             info!(
                 "DRY RUN: futures BUY {} {} (reduceOnly)",
-                pair.fut_order_qty, self.params.symbol
+                pair.fut_order_qty, self.futures.native_symbol
             );
             info!(
                 "DRY RUN: spot SELL {} {}",
-                pair.spot_order_qty, self.params.symbol
+                pair.spot_order_qty, self.spot.native_symbol
             );
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            return Err(ExchangeError::DryRun);
         }
 
         let spot_sell_qty = self
             .trader
-            .clamp_spot_quantity(&self.params.symbol, pair.spot_net_qty_est);
+            .clamp_spot_quantity(&self.spot.native_symbol, pair.spot_net_qty_est);
 
         // 스팟 매도
         let spot_order = self
             .trader
-            .place_spot_order(&self.params.symbol, "SELL", spot_sell_qty, false)
+            .market_spot_order(
+                &self.spot.native_symbol,
+                OrderSide::Sell,
+                spot_sell_qty,
+                false,
+            )
             .await?;
 
         // 선물 청산 (reduceOnly)
         let futures_order = self
             .trader
-            .place_futures_order(&self.params.symbol, "BUY", pair.fut_order_qty, true)
+            .market_futures_order(
+                &self.futures.native_symbol,
+                OrderSide::Buy,
+                pair.fut_order_qty,
+                true,
+            )
             .await?;
 
         Ok((futures_order, spot_order))
@@ -307,40 +382,47 @@ impl IntraBasisArbitrageStrategy {
     /// Reverse 포지션 오픈: 스팟 숏(보유분만) + 선물 롱
     pub async fn open_reverse(
         &self,
-        qty: f64,
-    ) -> Result<(OrderResponse, OrderResponse, HedgedPair), ExchangeError> {
+        qty: Decimal,
+    ) -> Result<(OrderAck, OrderAck, HedgedPair), ExchangeError> {
         info!(
             "Opening REVERSE position: spot SELL {} {}, futures BUY {} {}",
-            qty, self.params.symbol, qty, self.params.symbol
+            qty, self.spot.native_symbol, qty, self.futures.native_symbol
         );
 
         if self.params.dry_run {
-            info!("DRY RUN: spot SELL {} {}", qty, self.params.symbol);
-            info!("DRY RUN: futures BUY {} {}", qty, self.params.symbol);
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            info!("DRY RUN: spot SELL {} {}", qty, self.spot.native_symbol);
+            info!(
+                "DRY RUN: futures BUY {} {}",
+                qty, self.futures.native_symbol
+            );
+            return Err(ExchangeError::DryRun);
         }
 
         // 스팟 잔고 확인
-        let base_asset = BinanceTrader::base_asset_from_symbol(&self.params.symbol);
-        let free = self.trader.get_spot_balance(&base_asset).await?;
+        let free = self.trader.get_spot_balance(&self.spot.base).await?;
         let available_qty = qty.min(free);
         let use_qty = self
             .trader
-            .clamp_spot_quantity(&self.params.symbol, available_qty);
+            .clamp_spot_quantity(&self.spot.native_symbol, available_qty);
 
-        if use_qty <= 0.0 {
-            return Err(ExchangeError::Other(format!(
+        if use_qty <= Decimal::ZERO {
+            return Err(ExchangeError::InsufficientBalance(format!(
                 "Insufficient spot inventory to sell. free={}, requested={}",
                 free, qty
             )));
         }
 
-        // 선물 수량도 clamp
-        let fut_qty = self.trader.clamp_futures_quantity(&self.params.symbol, qty);
-        let final_qty = use_qty.min(fut_qty);
+        // 선물 수량도 clamp (선물 심볼 단위), 스팟은 선물이 덮는 base 수량에 맞춘다
+        let multiplier = Decimal::from(self.futures.multiplier);
+        let fut_qty = self
+            .trader
+            .clamp_futures_quantity(&self.futures.native_symbol, use_qty / multiplier);
+        let final_qty = self
+            .trader
+            .clamp_spot_quantity(&self.spot.native_symbol, fut_qty * multiplier);
 
-        if final_qty <= 0.0 {
-            return Err(ExchangeError::Other(format!(
+        if final_qty <= Decimal::ZERO || fut_qty <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(format!(
                 "Quantity too small after clamping. use_qty={}, fut_qty={}",
                 use_qty, fut_qty
             )));
@@ -349,37 +431,35 @@ impl IntraBasisArbitrageStrategy {
         // 수수료 정보 가져오기
         let fee = self
             .trader
-            .get_trade_fee_for_symbol(&self.params.symbol)
+            .get_trade_fee(&self.spot.native_symbol, InstrumentKind::Spot)
             .await?;
 
-        let spot_fee_rate = match self.params.mode {
-            StrategyMode::Reverse => fee.taker,
-            _ => fee.maker,
-        };
+        let spot_fee_rate = fee.traded_asset_rate(self.params.spot_leg.is_maker());
+        let spot_fee_rate = Decimal::from_f64(spot_fee_rate).unwrap_or_default();
 
         // 스팟 매도
         let spot_order = self
             .trader
-            .place_spot_order(&self.params.symbol, "SELL", final_qty, false)
+            .market_spot_order(&self.spot.native_symbol, OrderSide::Sell, final_qty, false)
             .await?;
 
         // 선물 롱
         let futures_order = self
             .trader
-            .place_futures_order(&self.params.symbol, "BUY", final_qty, false)
+            .market_futures_order(&self.futures.native_symbol, OrderSide::Buy, fut_qty, false)
             .await?;
 
         // HedgedPair 생성
         // 스팟 매도 시: 매도 수량 * (1 - fee_rate) = 실제 받는 USDT 수량
-        // 선물 롱: final_qty
+        // 선물 롱: fut_qty (base 단위로는 fut_qty * multiplier)
         // delta_est = (매도 후 받는 USDT를 base로 환산) - 선물 수량
         // 간단히: spot_net_qty_est = final_qty * (1 - fee_rate) (매도 후 받는 base 수량)
-        let spot_net_qty_est = final_qty * (1.0 - spot_fee_rate);
-        let delta_est = spot_net_qty_est - final_qty;
+        let spot_net_qty_est = final_qty * (Decimal::ONE - spot_fee_rate);
+        let delta_est = spot_net_qty_est - fut_qty * multiplier;
 
         let pair = HedgedPair {
             spot_order_qty: final_qty,
-            fut_order_qty: final_qty,
+            fut_order_qty: fut_qty,
             spot_net_qty_est,
             delta_est,
         };
@@ -391,34 +471,47 @@ impl IntraBasisArbitrageStrategy {
     pub async fn close_reverse(
         &self,
         pair: HedgedPair,
-    ) -> Result<(OrderResponse, OrderResponse), ExchangeError> {
+    ) -> Result<(OrderAck, OrderAck), ExchangeError> {
         info!(
             "Closing REVERSE position: spot BUY {} {}, futures SELL {} {} (reduceOnly)",
-            pair.spot_order_qty, self.params.symbol, pair.fut_order_qty, self.params.symbol
+            pair.spot_order_qty,
+            self.spot.native_symbol,
+            pair.fut_order_qty,
+            self.futures.native_symbol
         );
 
         if self.params.dry_run {
             info!(
                 "DRY RUN: futures SELL {} {} (reduceOnly)",
-                pair.fut_order_qty, self.params.symbol
+                pair.fut_order_qty, self.futures.native_symbol
             );
             info!(
                 "DRY RUN: spot BUY {} {}",
-                pair.spot_order_qty, self.params.symbol
+                pair.spot_order_qty, self.spot.native_symbol
             );
-            return Err(ExchangeError::Other("Dry run mode".to_string()));
+            return Err(ExchangeError::DryRun);
         }
 
         // 선물 청산 (reduceOnly)
         let futures_order = self
             .trader
-            .place_futures_order(&self.params.symbol, "SELL", pair.fut_order_qty, true)
+            .market_futures_order(
+                &self.futures.native_symbol,
+                OrderSide::Sell,
+                pair.fut_order_qty,
+                true,
+            )
             .await?;
 
         // 스팟 매수
         let spot_order = self
             .trader
-            .place_spot_order(&self.params.symbol, "BUY", pair.spot_order_qty, false)
+            .market_spot_order(
+                &self.spot.native_symbol,
+                OrderSide::Buy,
+                pair.spot_order_qty,
+                false,
+            )
             .await?;
 
         Ok((futures_order, spot_order))
@@ -482,41 +575,52 @@ impl IntraBasisArbitrageStrategy {
     /// - 어떤 주문 타입(시장가/지정가/post-only 등)으로 실제 주문을 집행할지는
     ///   StrategyParams.policy / spot_leg / futures_leg 및 BinanceTrader 구현에 위임한다.
     ///   (현재 open_carry/open_reverse는 전달받은 qty를 clamp 한 뒤
-    ///    trader.place_spot_order / place_futures_order를 호출하는 형태로 동작하며,
-    ///    dry_run 모드일 때는 실제 주문 대신 로그만 남기고 에러를 반환한다.)
+    ///   trader.market_spot_order / market_futures_order를 호출하는 형태로 동작하며,
+    ///   dry_run 모드일 때는 실제 주문 대신 로그만 남기고 ExchangeError::DryRun을 반환한다.)
+    /// - 주문 실패는 ExchangeError 종류에 따라 처리한다 (handle_order_error 참고).
     ///
     /// 주의사항:
     /// - 손절 조건(베이시스가 더 벌어질 때 강제 청산 등)은 포함되어 있지 않으며,
     ///   베이시스가 장기간 확장되는 경우 선물 측 마진 부족으로 청산 위험이 존재한다.
-    /// - 수수료, 슬리피지, 펀딩 비용은 별도로 추적하지 않고, entry_bps/exit_bps 설정에
+    /// - 시작 시 실제 레그 수수료로 왕복 비용을 계산해 entry/exit 간격이 이보다 좁으면 경고만 한다.
+    ///   슬리피지, 펀딩 비용은 별도로 추적하지 않으므로 entry_bps/exit_bps 설정에
     ///   간접적으로 녹여서 사용해야 한다.
     pub async fn run_loop(&self) -> Result<(), ExchangeError> {
-        // exchangeInfo 로드 (스팟 및 선물 LOT_SIZE 필터 캐싱)
-        info!("Loading spot exchangeInfo...");
-        self.trader.load_spot_exchange_info().await.map_err(|e| {
-            ExchangeError::Other(format!("Failed to load spot exchangeInfo: {}", e))
-        })?;
-
-        info!("Loading futures exchangeInfo...");
-        self.trader
-            .load_futures_exchange_info()
-            .await
-            .map_err(|e| {
-                ExchangeError::Other(format!("Failed to load futures exchangeInfo: {}", e))
-            })?;
-
         // 선물 설정 확인
         self.trader
             .ensure_account_setup(
-                &self.params.symbol,
+                &self.futures.native_symbol,
                 self.params.leverage,
                 self.params.isolated,
             )
             .await?;
 
+        // 선물 지갑 마진 자동 배분 (포지션이 열려 있을 때만 실제로 이동)
+        // 루프가 어떤 이유로든 끝나면 guard가 drop되면서 배분 태스크도 중단된다.
+        let _margin_allocator = match &self.params.margin_allocator {
+            Some(margin_params) if !self.params.dry_run => {
+                // 다음 진입의 현물 레그 주문 금액은 현물 지갑에 남겨둔다
+                let mut margin_params = margin_params.clone();
+                let notional = Decimal::from_f64(self.params.notional).unwrap_or_default();
+                margin_params.spot_reserve = margin_params.spot_reserve.max(notional);
+                info!(
+                    "Starting margin allocator (target ratio {}, top up above {}, release below {}, spot reserve {})",
+                    margin_params.target_ratio,
+                    margin_params.top_up_ratio,
+                    margin_params.release_ratio,
+                    margin_params.spot_reserve
+                );
+                Some(AbortOnDrop(
+                    self.trader.margin_allocator(margin_params).spawn(),
+                ))
+            }
+            _ => None,
+        };
+
         // WebSocket 리스너 시작 (백그라운드에서 실시간 가격 수신)
         info!("Starting WebSocket listeners for real-time price updates...");
-        self.trader.start_websocket_listener(&self.params.symbol);
+        self.trader
+            .start_websocket_listener(&self.spot.native_symbol, &self.futures.native_symbol);
 
         // WebSocket 연결이 안정화될 때까지 잠시 대기
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
@@ -529,10 +633,39 @@ impl IntraBasisArbitrageStrategy {
 
         info!("Starting basis arbitrage strategy");
         info!("Symbol: {}", self.params.symbol);
+        info!(
+            "Instruments: spot {}, futures {} (x{})",
+            self.spot.native_symbol, self.futures.native_symbol, self.futures.multiplier
+        );
         info!("Mode: {}", self.params.mode);
         info!("Entry BPS: {}", self.params.entry_bps);
         info!("Exit BPS: {}", self.params.exit_bps);
         info!("Notional: {} USDT", self.params.notional);
+
+        // 실제 거래할 레그 기준 왕복 수수료 (진입 + 청산, 양쪽 레그)
+        let spot_fee = self
+            .trader
+            .get_trade_fee(&self.spot.native_symbol, InstrumentKind::Spot)
+            .await?;
+        let futures_fee = self
+            .trader
+            .get_trade_fee(&self.futures.native_symbol, InstrumentKind::Perp)
+            .await?;
+        let round_trip_fee_bps = 2.0
+            * (spot_fee.effective(self.params.spot_leg.is_maker())
+                + futures_fee.effective(self.params.futures_leg.is_maker()))
+            * 10000.0;
+        info!(
+            "Fees: spot {:?}, futures {:?}, round trip {:.2} bps",
+            spot_fee, futures_fee, round_trip_fee_bps
+        );
+        if self.params.entry_bps - self.params.exit_bps < round_trip_fee_bps {
+            warn!(
+                "Entry/exit spread {:.2} bps is below round trip fee {:.2} bps",
+                self.params.entry_bps - self.params.exit_bps,
+                round_trip_fee_bps
+            );
+        }
         info!(
             "Current state: open={}, dir={:?}, pair={:?}",
             state.open, state.dir, state.pair
@@ -541,24 +674,35 @@ impl IntraBasisArbitrageStrategy {
         loop {
             tokio::time::sleep(tokio::time::Duration::from_micros(100)).await;
 
-            // 가격 조회
-            let spot_price = self
-                .trader
-                .get_spot_price(&self.params.symbol)
-                .await
-                .map_err(|e| {
+            // 가격 조회 (재시도 가능한 에러는 대기 후 다음 루프에서 다시 조회)
+            let spot_price = match self.trader.get_spot_price(&self.spot.native_symbol).await {
+                Ok(price) => price,
+                Err(e) => {
                     warn!("Failed to get spot price: {}", e);
-                    e
-                })?;
+                    if !e.is_retryable() {
+                        return Err(e);
+                    }
+                    backoff(&e).await;
+                    continue;
+                }
+            };
 
-            let futures_mark = self
+            // 배수 선물(1000PEPEUSDT 등)은 base 1개 기준 가격으로 환산
+            let futures_mark = match self
                 .trader
-                .get_futures_mark_price(&self.params.symbol)
+                .get_futures_mark_price(&self.futures.native_symbol)
                 .await
-                .map_err(|e| {
+            {
+                Ok(mark) => self.futures.unit_price(mark),
+                Err(e) => {
                     warn!("Failed to get futures mark price: {}", e);
-                    e
-                })?;
+                    if !e.is_retryable() {
+                        return Err(e);
+                    }
+                    backoff(&e).await;
+                    continue;
+                }
+            };
 
             let basis_bps = self.compute_basis_bps(spot_price, futures_mark);
 
@@ -592,23 +736,23 @@ impl IntraBasisArbitrageStrategy {
                             self.log_position_pnl(&state, spot_price, futures_mark, basis_bps);
 
                             // 포지션 닫기 기록 저장 (새로운 position_records 테이블)
-                            if let Some(dir) = state.dir.as_deref() {
-                                if let Some(carry_upper) = match dir {
+                            if let Some(dir) = state.dir.as_deref()
+                                && let Some(carry_upper) = match dir {
                                     "carry" => Some("CARRY"),
                                     "reverse" => Some("REVERSE"),
                                     _ => None,
-                                } {
-                                    crate::record::save_position_record(
-                                        "intra_basis",
-                                        carry_upper,
-                                        "CLOSE",
-                                        &self.params.symbol,
-                                        spot_price,
-                                        futures_mark,
-                                        self.trader.exchange_name(),
-                                    )
-                                    .await;
                                 }
+                            {
+                                crate::record::save_position_record(
+                                    "intra_basis",
+                                    carry_upper,
+                                    "CLOSE",
+                                    &self.params.symbol,
+                                    spot_price,
+                                    futures_mark,
+                                    self.trader.exchange_name(),
+                                )
+                                .await;
                             }
 
                             let actions = serde_json::json!({
@@ -627,7 +771,7 @@ impl IntraBasisArbitrageStrategy {
                             info!("Position closed successfully");
                         }
                         Err(e) => {
-                            warn!("Failed to close position: {}", e);
+                            handle_order_error("close position", e).await?;
                         }
                     }
                 }
@@ -674,7 +818,7 @@ impl IntraBasisArbitrageStrategy {
                             info!("CARRY position opened successfully");
                         }
                         Err(e) => {
-                            warn!("Failed to open CARRY position: {}", e);
+                            handle_order_error("open CARRY position", e).await?;
                         }
                     }
                 } else if should_open_reverse {
@@ -710,7 +854,7 @@ impl IntraBasisArbitrageStrategy {
                             info!("REVERSE position opened successfully");
                         }
                         Err(e) => {
-                            warn!("Failed to open REVERSE position: {}", e);
+                            handle_order_error("open REVERSE position", e).await?;
                         }
                     }
                 }
@@ -1,8 +1,12 @@
 use color_eyre::eyre;
-use exchanges::{AssetExchange, BinanceClient, BithumbClient};
+use exchanges::{
+    AssetExchange, BinanceClient, BitgetClient, BithumbClient, BybitClient, OkxClient,
+    instrument::to_native_symbol,
+};
+use interface::{Decimal, ExchangeId, InstrumentKind, OrderSide};
 use tracing::{error, info, warn};
 
-use crate::trader::{binance::BinanceTrader, bithumb::BithumbTrader, SpotExchangeTrader};
+use crate::trader::{SpotExchangeTrader, binance::BinanceTrader, bithumb::BithumbTrader};
 
 /// Binance의 모든 자산을 USDT로 강제 청산
 pub async fn liquidate_binance() -> eyre::Result<()> {
@@ -34,7 +38,7 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
         .iter()
         .filter(|a| {
             let currency = &a.currency;
-            currency != "USDT" && a.available > 0.0
+            currency != "USDT" && a.available > Decimal::ZERO
         })
         .collect();
 
@@ -51,13 +55,18 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
         let available = asset.available;
 
         // 심볼 생성 (예: BTC -> BTCUSDT)
-        let symbol = format!("{}USDT", currency);
+        let Some(symbol) =
+            to_native_symbol(ExchangeId::Binance, InstrumentKind::Spot, currency, "USDT")
+        else {
+            warn!("{}의 USDT 마켓 심볼을 만들 수 없습니다.", currency);
+            continue;
+        };
 
         info!("{} {} -> USDT 변환 시도...", available, currency);
 
         // 수량 클램프
         let qty = trader.clamp_spot_quantity(&symbol, available);
-        if qty <= 0.0 {
+        if qty <= Decimal::ZERO {
             warn!(
                 "{}의 수량이 너무 작아서 거래할 수 없습니다. (available: {})",
                 currency, available
@@ -78,9 +87,7 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
                 // 에러가 발생해도 다음 자산 계속 처리
             }
         }
-
-        // API 레이트 리밋 방지를 위한 짧은 대기
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        // 요청 간격은 공유 RateLimiter가 Binance weight 한도에 맞춰 조절한다
     }
 
     // 선물 포지션 청산
@@ -104,7 +111,7 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
             // 수량 클램프
             let abs_qty = position_amt.abs();
             let qty = trader.clamp_futures_quantity(symbol, abs_qty);
-            if qty <= 0.0 {
+            if qty <= Decimal::ZERO {
                 warn!(
                     "{}의 수량이 너무 작아서 거래할 수 없습니다. (position_amt: {})",
                     symbol, position_amt
@@ -115,9 +122,13 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
             // 포지션 방향에 따라 청산
             // position_amt가 양수면 롱 포지션 -> 매도로 청산
             // position_amt가 음수면 숏 포지션 -> 매수로 청산
-            let side = if position_amt > 0.0 { "SELL" } else { "BUY" };
+            let side = if position_amt > Decimal::ZERO {
+                OrderSide::Sell
+            } else {
+                OrderSide::Buy
+            };
 
-            match trader.place_futures_order(symbol, side, qty, true).await {
+            match trader.market_futures_order(symbol, side, qty, true).await {
                 Ok(order) => {
                     info!(
                         "{} {} {} 청산 성공: order_id={:?}, executed_qty={:?}",
@@ -129,9 +140,6 @@ pub async fn liquidate_binance() -> eyre::Result<()> {
                     // 에러가 발생해도 다음 포지션 계속 처리
                 }
             }
-
-            // API 레이트 리밋 방지를 위한 짧은 대기
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         }
     }
 
@@ -159,7 +167,7 @@ pub async fn liquidate_bithumb() -> eyre::Result<()> {
         .iter()
         .filter(|a| {
             let currency = &a.currency;
-            currency != "KRW" && a.available > 0.0
+            currency != "KRW" && a.available > Decimal::ZERO
         })
         .collect();
 
@@ -175,14 +183,19 @@ pub async fn liquidate_bithumb() -> eyre::Result<()> {
         let currency = &asset.currency;
         let available = asset.available;
 
-        // 심볼 생성 (예: BTC -> BTC-KRW)
-        let symbol = format!("{}-KRW", currency);
+        // 심볼 생성 (예: BTC -> BTC_KRW)
+        let Some(symbol) =
+            to_native_symbol(ExchangeId::Bithumb, InstrumentKind::Spot, currency, "KRW")
+        else {
+            warn!("{}의 KRW 마켓 심볼을 만들 수 없습니다.", currency);
+            continue;
+        };
 
         info!("{} {} -> KRW 변환 시도...", available, currency);
 
         // 수량 클램프
         let qty = trader.clamp_spot_quantity(&symbol, available);
-        if qty <= 0.0 {
+        if qty <= Decimal::ZERO {
             warn!(
                 "{}의 수량이 너무 작아서 거래할 수 없습니다. (available: {})",
                 currency, available
@@ -203,15 +216,41 @@ pub async fn liquidate_bithumb() -> eyre::Result<()> {
                 // 에러가 발생해도 다음 자산 계속 처리
             }
         }
-
-        // API 레이트 리밋 방지를 위한 짧은 대기
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
     }
 
     info!("=== Bithumb 강제 청산 완료 ===");
     Ok(())
 }
 
+/// 자동 청산을 지원하지 않는 거래소의 남은 잔고/포지션을 경고로 남긴다
+async fn report_remaining(client: &impl AssetExchange) {
+    let exchange = client.id();
+
+    match client.fetch_spots().await {
+        Ok(assets) => {
+            for asset in assets.iter().filter(|a| a.currency != "USDT") {
+                warn!(
+                    "{:?} {} {} 잔고는 수동 청산이 필요합니다.",
+                    exchange, asset.total, asset.currency
+                );
+            }
+        }
+        Err(e) => error!("{:?} 스팟 자산 조회 실패: {}", exchange, e),
+    }
+
+    match client.fetch_futures().await {
+        Ok(positions) => {
+            for position in positions {
+                warn!(
+                    "{:?} {} 포지션(수량: {}, 미실현 손익: {})은 수동 청산이 필요합니다.",
+                    exchange, position.symbol, position.position_amt, position.unrealized_pnl
+                );
+            }
+        }
+        Err(e) => error!("{:?} 선물 포지션 조회 실패: {}", exchange, e),
+    }
+}
+
 /// 모든 거래소의 자산을 강제 청산
 pub async fn liquidate_all() -> eyre::Result<()> {
     info!("=== 전체 강제 청산 시작 ===");
@@ -228,6 +267,17 @@ pub async fn liquidate_all() -> eyre::Result<()> {
         Err(e) => error!("Bithumb 청산 실패: {}", e),
     }
 
+    // 키가 설정된 나머지 거래소는 남은 자산만 알린다
+    if let Ok(client) = BybitClient::with_credentials() {
+        report_remaining(&client).await;
+    }
+    if let Ok(client) = OkxClient::with_credentials() {
+        report_remaining(&client).await;
+    }
+    if let Ok(client) = BitgetClient::with_credentials() {
+        report_remaining(&client).await;
+    }
+
     info!("=== 전체 강제 청산 완료 ===");
     Ok(())
 }
@@ -2,7 +2,7 @@ use color_eyre::eyre;
 use tracing::info;
 
 use exchanges::{AssetExchange, BinanceClient, BithumbClient};
-use interface::{SpotAsset, UnifiedSnapshot};
+use interface::{FutureAsset, SpotAsset, UnifiedSnapshot};
 
 const ORACLE_SERVER_URL: &str = "http://localhost:12090";
 
@@ -46,6 +46,9 @@ pub fn print_unified_snapshots(snapshots: &[UnifiedSnapshot]) {
         println!("    USD/KRW: {:.8}", snapshot.exchange_rates.usd_krw);
         println!("    USDT/USD: {:.6}", snapshot.exchange_rates.usdt_usd);
         println!("    USDT/KRW: {:.8}", snapshot.exchange_rates.usdt_krw);
+        if snapshot.exchange_rates.is_fallback {
+            println!("    (fallback 환율 - 실시간 시세 아님)");
+        }
 
         println!("  Updated At: {}", snapshot.updated_at);
     }
@@ -69,6 +72,21 @@ pub async fn fetch_binance_assets() -> eyre::Result<Vec<SpotAsset>> {
     Ok(assets)
 }
 
+/// 임의 거래소의 현물 잔고와 선물 포지션 조회 (Bybit, OKX, Bitget 등)
+pub async fn fetch_exchange_assets(
+    client: &impl AssetExchange,
+) -> eyre::Result<(Vec<SpotAsset>, Vec<FutureAsset>)> {
+    let assets = client
+        .fetch_spots()
+        .await
+        .map_err(|e| eyre::eyre!("{:?} 자산 조회 실패: {}", client.id(), e))?;
+    let positions = client
+        .fetch_futures()
+        .await
+        .map_err(|e| eyre::eyre!("{:?} 포지션 조회 실패: {}", client.id(), e))?;
+    Ok((assets, positions))
+}
+
 pub fn print_assets(assets: &[SpotAsset]) {
     info!("=== Assets (총 {}개) ===", assets.len());
 
@@ -80,3 +98,19 @@ pub fn print_assets(assets: &[SpotAsset]) {
         println!("  Updated At: {}", asset.updated_at);
     }
 }
+
+pub fn print_positions(positions: &[FutureAsset]) {
+    info!("=== Positions (총 {}개) ===", positions.len());
+
+    for position in positions {
+        println!("\n[{}]", position.symbol);
+        println!("  Position Amt: {:.8}", position.position_amt);
+        println!("  Entry Price: {:.8}", position.entry_price);
+        println!("  Unrealized PnL: {:.8}", position.unrealized_pnl);
+        println!("  Leverage: {}x", position.leverage);
+        if let Some(liquidation_price) = position.liquidation_price {
+            println!("  Liquidation Price: {:.8}", liquidation_price);
+        }
+        println!("  Updated At: {}", position.updated_at);
+    }
+}
@@ -1,11 +1,15 @@
 use color_eyre::eyre;
-use exchanges::BinanceClient;
+use exchanges::{
+    BinanceClient, BitgetClient, BithumbClient, BybitClient, OkxClient, endpoint::Endpoints,
+};
+use interface::{Decimal, ExchangeId};
 use structopt::StructOpt;
 use tracing::info;
 
 mod explore;
 
 use trade::arbitrage::{IntraBasisArbitrageStrategy, StrategyParams};
+use trade::trader::transfer::{TransferManager, WithdrawWhitelist};
 
 // lib.rs에서 자동으로 dotenv가 로드됨
 
@@ -20,6 +24,34 @@ enum Command {
     ArbitrageTest,
     /// 강제 청산 테스트 (모든 자산을 USDT/KRW로 변환)
     EmergencyTest,
+    /// 화이트리스트 주소로 출금 (WITHDRAW_WHITELIST 파일의 label 지정)
+    Withdraw {
+        /// 출금 거래소 (binance, bithumb)
+        from: ExchangeId,
+        /// 화이트리스트 항목 이름
+        label: String,
+        /// 출금 수량
+        amount: Decimal,
+        /// 완료될 때까지 상태를 추적
+        #[structopt(long)]
+        wait: bool,
+    },
+    /// 출금 상태 조회
+    WithdrawStatus {
+        from: ExchangeId,
+        id: String,
+        /// id를 클라이언트 출금 ID로 보고 접수 여부 확인 (신청 응답을 받지 못한 출금)
+        #[structopt(long)]
+        client_id: bool,
+    },
+    /// 최근 입금 내역 조회
+    Deposits {
+        exchange: ExchangeId,
+        currency: String,
+        /// 조회 기간 (일)
+        #[structopt(long, default_value = "7")]
+        days: i64,
+    },
 }
 
 #[tokio::main]
@@ -30,6 +62,12 @@ async fn main() -> eyre::Result<()> {
     // init logging
     let _guards = trade::logger::init_tracing();
 
+    // 거래소 주소 프로필 (잘못된 값이면 실계정 주소로 넘어가지 않도록 바로 종료)
+    let endpoints =
+        Endpoints::from_env().map_err(|e| eyre::eyre!("거래소 주소 설정 실패: {}", e))?;
+    Endpoints::init(endpoints).map_err(|e| eyre::eyre!("{}", e))?;
+    info!("거래소 주소 프로필: {}", Endpoints::current().profile);
+
     // init trade record repository
     trade::record::init_global_repository()
         .await
@@ -59,6 +97,22 @@ async fn main() -> eyre::Result<()> {
         Command::ExploreTest => run_explore_test().await,
         Command::ArbitrageTest => run_arbitrage_test().await,
         Command::EmergencyTest => run_emergency_test().await,
+        Command::Withdraw {
+            from,
+            label,
+            amount,
+            wait,
+        } => run_withdraw(from, &label, amount, wait).await,
+        Command::WithdrawStatus {
+            from,
+            id,
+            client_id,
+        } => run_withdraw_status(from, &id, client_id).await,
+        Command::Deposits {
+            exchange,
+            currency,
+            days,
+        } => run_deposits(exchange, &currency, days).await,
     };
 
     // 커맨드가 완료되어도 서버는 계속 실행되도록 대기
@@ -95,6 +149,26 @@ async fn run_explore_test() -> eyre::Result<()> {
     let assets = explore::fetch_binance_assets().await?;
     explore::print_assets(&assets);
 
+    // 키가 설정된 거래소만 조회
+    if let Ok(client) = BybitClient::with_credentials() {
+        info!("\n=== Bybit 자산 정보 조회 중... ===");
+        let (assets, positions) = explore::fetch_exchange_assets(&client).await?;
+        explore::print_assets(&assets);
+        explore::print_positions(&positions);
+    }
+    if let Ok(client) = OkxClient::with_credentials() {
+        info!("\n=== OKX 자산 정보 조회 중... ===");
+        let (assets, positions) = explore::fetch_exchange_assets(&client).await?;
+        explore::print_assets(&assets);
+        explore::print_positions(&positions);
+    }
+    if let Ok(client) = BitgetClient::with_credentials() {
+        info!("\n=== Bitget 자산 정보 조회 중... ===");
+        let (assets, positions) = explore::fetch_exchange_assets(&client).await?;
+        explore::print_assets(&assets);
+        explore::print_positions(&positions);
+    }
+
     info!("완료!");
 
     Ok(())
@@ -104,8 +178,10 @@ async fn run_explore_test() -> eyre::Result<()> {
 async fn run_arbitrage_test() -> eyre::Result<()> {
     info!("베이시스 아비트라지 전략 테스트 시작 (dry-run 모드)...");
 
-    let mut params = StrategyParams::default();
-    params.dry_run = false;
+    let params = StrategyParams {
+        dry_run: false,
+        ..Default::default()
+    };
 
     info!("테스트 파라미터:");
     info!("  Symbol: {}", params.symbol);
@@ -118,6 +194,7 @@ async fn run_arbitrage_test() -> eyre::Result<()> {
     info!("  Dry Run: {}", params.dry_run);
 
     let strategy = IntraBasisArbitrageStrategy::new(params)
+        .await
         .map_err(|e| eyre::eyre!("전략 초기화 실패: {}", e))?;
 
     info!("전략이 성공적으로 초기화되었습니다.");
@@ -141,3 +218,80 @@ async fn run_emergency_test() -> eyre::Result<()> {
 
     Ok(())
 }
+
+/// 키가 설정된 거래소로 출금 실행기 구성
+fn transfer_manager() -> eyre::Result<TransferManager> {
+    let whitelist = WithdrawWhitelist::load()?;
+    let mut manager = TransferManager::new(whitelist);
+    if let Ok(client) = BinanceClient::with_credentials() {
+        manager = manager.with_exchange(client);
+    }
+    if let Ok(client) = BithumbClient::with_credentials() {
+        manager = manager.with_exchange(client);
+    }
+    Ok(manager)
+}
+
+/// 화이트리스트 주소로 출금
+async fn run_withdraw(
+    from: ExchangeId,
+    label: &str,
+    amount: Decimal,
+    wait: bool,
+) -> eyre::Result<()> {
+    let manager = transfer_manager()?;
+    let record = manager.withdraw(from, label, amount).await?;
+    info!("출금 신청 완료: {:?}", record);
+
+    if wait {
+        let record = manager
+            .wait_withdrawal(
+                from,
+                &record.id,
+                std::time::Duration::from_secs(30),
+                std::time::Duration::from_secs(60 * 60),
+            )
+            .await?;
+        info!("출금 종료: {:?}", record);
+    }
+
+    Ok(())
+}
+
+/// 출금 상태 조회
+async fn run_withdraw_status(from: ExchangeId, id: &str, client_id: bool) -> eyre::Result<()> {
+    let manager = transfer_manager()?;
+    if client_id {
+        match manager.reconcile_withdrawal(from, id).await? {
+            Some(record) => info!("출금 상태: {:?}", record),
+            None => info!("클라이언트 출금 ID {}로 접수된 출금 없음", id),
+        }
+        return Ok(());
+    }
+    match manager.poll_withdrawal(from, id).await? {
+        Some(record) => info!("출금 상태: {:?}", record),
+        None => info!("출금 {}이 아직 거래소 내역에 없음", id),
+    }
+    Ok(())
+}
+
+/// 최근 입금 내역 조회
+async fn run_deposits(exchange: ExchangeId, currency: &str, days: i64) -> eyre::Result<()> {
+    let start = chrono::Utc::now() - chrono::Duration::days(days);
+    let records = transfer_manager()?
+        .fetch_deposits(exchange, currency, start)
+        .await?;
+    for record in &records {
+        println!(
+            "{} {} {} {} ({:?}) txid={}",
+            record.created_at,
+            record.currency,
+            record.amount,
+            record.network,
+            record.status,
+            record.txid.as_deref().unwrap_or("-")
+        );
+    }
+    info!("입금 {}건", records.len());
+    Ok(())
+}
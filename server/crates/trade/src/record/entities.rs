@@ -56,6 +56,14 @@ pub mod trade_record {
         /// 청산 실행 기록 여부
         #[sea_orm(column_type = "Boolean")]
         pub is_liquidation: bool,
+
+        /// 체결 수수료 합계 (NULL 가능)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub fee: Option<f64>,
+
+        /// 수수료 차감 자산 (NULL 가능, 예: "USDT", "BNB")
+        #[sea_orm(column_type = "Text", nullable)]
+        pub fee_asset: Option<String>,
     }
 
     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -117,3 +125,77 @@ pub mod position_record {
 
     impl ActiveModelBehavior for ActiveModel {}
 }
+
+/// 입출금 기록 엔티티 모듈
+pub mod transfer_record {
+    use sea_orm::entity::prelude::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+    #[sea_orm(table_name = "transfer_records")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = true)]
+        pub id: i64,
+
+        /// 거래소 접수 UTC 시간 (ISO 8601 형식)
+        #[sea_orm(column_type = "Text")]
+        pub created_at: String,
+
+        /// 마지막으로 상태를 갱신한 UTC 시간 (ISO 8601 형식)
+        #[sea_orm(column_type = "Text")]
+        pub updated_at: String,
+
+        /// 거래소 이름
+        #[sea_orm(column_type = "Text")]
+        pub exchange: String,
+
+        /// 입출금 방향 (DEPOSIT, WITHDRAWAL)
+        #[sea_orm(column_type = "Text")]
+        pub direction: String,
+
+        /// 거래소가 부여한 입출금 ID
+        #[sea_orm(column_type = "Text")]
+        pub transfer_id: String,
+
+        /// 코인 심볼
+        #[sea_orm(column_type = "Text")]
+        pub currency: String,
+
+        /// 네트워크 (거래소 표기)
+        #[sea_orm(column_type = "Text")]
+        pub network: String,
+
+        /// 주소 (조회 응답에 없으면 빈 문자열)
+        #[sea_orm(column_type = "Text")]
+        pub address: String,
+
+        /// 메모/태그 (NULL 가능)
+        #[sea_orm(column_type = "Text", nullable)]
+        pub memo: Option<String>,
+
+        /// 수량
+        #[sea_orm(column_type = "Double")]
+        pub amount: f64,
+
+        /// 수수료 (NULL 가능)
+        #[sea_orm(column_type = "Double", nullable)]
+        pub fee: Option<f64>,
+
+        /// 블록체인 트랜잭션 ID (NULL 가능)
+        #[sea_orm(column_type = "Text", nullable)]
+        pub txid: Option<String>,
+
+        /// 진행 상태 (PENDING, PROCESSING, COMPLETED, FAILED, CANCELLED)
+        #[sea_orm(column_type = "Text")]
+        pub status: String,
+
+        /// 출금 신청 때 보낸 클라이언트 출금 ID (NULL 가능)
+        #[sea_orm(column_type = "Text", nullable)]
+        pub client_id: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
@@ -3,7 +3,7 @@ use std::sync::OnceLock;
 
 use super::{
     PositionRecordRepository, SqlitePositionRecordRepository, SqliteTradeRecordRepository,
-    TradeRecordRepository,
+    SqliteTransferRecordRepository, TradeRecordRepository, TransferRecordRepository,
 };
 
 /// 전역 거래 기록 저장소
@@ -14,6 +14,10 @@ static GLOBAL_REPOSITORY: OnceLock<Arc<dyn TradeRecordRepository + Send + Sync>>
 static GLOBAL_POSITION_REPOSITORY: OnceLock<Arc<dyn PositionRecordRepository + Send + Sync>> =
     OnceLock::new();
 
+/// 전역 입출금 기록 저장소
+static GLOBAL_TRANSFER_REPOSITORY: OnceLock<Arc<dyn TransferRecordRepository + Send + Sync>> =
+    OnceLock::new();
+
 /// 전역 Repository 초기화
 pub async fn init_global_repository() -> Result<(), super::RecordError> {
     let repo = SqliteTradeRecordRepository::new().await?;
@@ -28,6 +32,13 @@ pub async fn init_global_repository() -> Result<(), super::RecordError> {
             super::RecordError::Other("Position repository already initialized".to_string())
         })?;
 
+    let transfer_repo = SqliteTransferRecordRepository::new().await?;
+    GLOBAL_TRANSFER_REPOSITORY
+        .set(Arc::new(transfer_repo))
+        .map_err(|_| {
+            super::RecordError::Other("Transfer repository already initialized".to_string())
+        })?;
+
     Ok(())
 }
 
@@ -41,18 +52,24 @@ pub fn get_position_repository() -> Option<Arc<dyn PositionRecordRepository + Se
     GLOBAL_POSITION_REPOSITORY.get().cloned()
 }
 
+/// 전역 입출금 Repository 가져오기
+pub fn get_transfer_repository() -> Option<Arc<dyn TransferRecordRepository + Send + Sync>> {
+    GLOBAL_TRANSFER_REPOSITORY.get().cloned()
+}
+
 /// 거래 기록 저장 (전역 Repository 사용)
 /// Repository가 초기화되지 않았으면 에러 없이 무시
 pub async fn save_trade_record_safe(record: &super::TradeRecord) {
-    if let Some(repo) = get_repository() {
-        if let Err(e) = repo.save(record).await {
-            tracing::warn!("Failed to save trade record: {}", e);
-        }
+    if let Some(repo) = get_repository()
+        && let Err(e) = repo.save(record).await
+    {
+        tracing::warn!("Failed to save trade record: {}", e);
     }
 }
 
 /// 포지션 기록 저장 (전역 Repository 사용)
 /// Repository가 초기화되지 않았으면 에러 없이 무시
+#[allow(clippy::too_many_arguments)]
 pub async fn save_position_record_safe(
     bot_name: &str,
     carry: &str,
@@ -63,8 +80,8 @@ pub async fn save_position_record_safe(
     buy_exchange: &str,
     sell_exchange: &str,
 ) {
-    if let Some(repo) = get_position_repository() {
-        if let Err(e) = repo
+    if let Some(repo) = get_position_repository()
+        && let Err(e) = repo
             .save(
                 bot_name,
                 carry,
@@ -76,8 +93,17 @@ pub async fn save_position_record_safe(
                 sell_exchange,
             )
             .await
-        {
-            tracing::warn!("Failed to save position record: {}", e);
-        }
+    {
+        tracing::warn!("Failed to save position record: {}", e);
+    }
+}
+
+/// 입출금 기록 저장 (전역 Repository 사용)
+/// Repository가 초기화되지 않았으면 에러 없이 무시
+pub async fn save_transfer_record_safe(record: &interface::TransferRecord) {
+    if let Some(repo) = get_transfer_repository()
+        && let Err(e) = repo.save(record).await
+    {
+        tracing::warn!("Failed to save transfer record: {}", e);
     }
 }
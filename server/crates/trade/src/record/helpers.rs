@@ -1,71 +1,98 @@
 use chrono::Utc;
+use interface::{Decimal, OrderAck, OrderRequest, OrderSide, OrderType};
+use rust_decimal::prelude::ToPrimitive;
 use serde_json;
-use std::str::FromStr;
 
 use super::{MarketType, TradeRecord, TradeSide, TradeType};
-use crate::trader::OrderResponse;
 
-/// OrderResponse를 TradeRecord로 변환하는 헬퍼 함수
+/// OrderAck를 TradeRecord로 변환하는 헬퍼 함수
 /// 거래 실행 후 호출하여 기록을 저장할 수 있습니다.
 pub fn create_trade_record_from_order(
     exchange: String,
-    symbol: String,
     market_type: MarketType,
-    side: TradeSide,
-    trade_type: TradeType,
-    quantity: f64,
+    request: &OrderRequest,
     request_query_string: Option<String>,
-    order_response: &OrderResponse,
+    order: &OrderAck,
     is_liquidation: bool,
 ) -> TradeRecord {
-    // OrderResponse에서 실행 가격 추출 시도
-    let executed_price = extract_price_from_order_response(order_response);
+    let side = match request.side {
+        OrderSide::Buy => TradeSide::Buy,
+        OrderSide::Sell => TradeSide::Sell,
+    };
+    let trade_type = match request.order_type {
+        OrderType::Market => TradeType::Market,
+        OrderType::Limit => TradeType::Limit,
+    };
+
+    // 체결 수량이 있으면 체결 기준, 아직 체결 전이면 요청 수량
+    let quantity = if order.executed_qty.is_zero() {
+        request.quantity
+    } else {
+        order.executed_qty
+    };
+
+    // OrderAck를 JSON 문자열로 변환
+    let api_response = serde_json::to_string(order).ok();
 
-    // OrderResponse를 JSON 문자열로 변환
-    let api_response = serde_json::to_string(order_response).ok();
+    // 수수료 컬럼은 첫 체결의 차감 자산 기준 (여러 자산으로 나뉘면 전체는 metadata에 기록)
+    let fees = order.fees();
+    let fee_asset = order.fills.first().map(|f| f.fee_asset.clone());
+    let fee = fee_asset
+        .as_ref()
+        .and_then(|asset| fees.get(asset))
+        .and_then(|fee| fee.to_f64());
 
-    TradeRecord {
+    let mut record = TradeRecord {
         executed_at: Utc::now(),
         exchange,
-        symbol,
+        symbol: request.symbol.clone(),
         market_type,
         side,
         trade_type,
-        executed_price,
-        quantity,
+        // DB 컬럼은 REAL이므로 기록 시점에만 f64로 변환
+        executed_price: order.average_fill_price().and_then(|p| p.to_f64()),
+        quantity: quantity.to_f64().unwrap_or(0.0),
         request_query_string,
         api_response,
         metadata: None,
         is_liquidation,
-    }
+        fee,
+        fee_asset,
+    };
+
+    // 수수료는 자산별로 metadata에도 기록
+    add_metadata(
+        &mut record,
+        serde_json::json!({
+            "order_id": order.order_id,
+            "status": order.status,
+            "fees": fees
+                .iter()
+                .map(|(asset, fee)| (asset.clone(), fee.to_f64().unwrap_or(0.0).into()))
+                .collect::<serde_json::Map<String, serde_json::Value>>(),
+            "reduce_only": request.reduce_only,
+        }),
+    );
+
+    record
 }
 
 /// Spot 주문 기록 저장 (편의 함수)
 pub async fn save_trade_record_spot_order(
     exchange: &str,
-    symbol: &str,
-    side: &str,
-    quantity: f64,
+    request: &OrderRequest,
     query_string: &str,
-    order_response: &OrderResponse,
+    order: &OrderAck,
     is_liquidation: bool,
 ) {
     use super::global::save_trade_record_safe;
 
-    let trade_side = match TradeSide::from_str(side) {
-        Ok(s) => s,
-        Err(_) => return, // 잘못된 side는 무시
-    };
-
     let record = create_trade_record_from_order(
         exchange.to_string(),
-        symbol.to_string(),
         MarketType::Spot,
-        trade_side,
-        TradeType::Market,
-        quantity,
+        request,
         Some(query_string.to_string()),
-        order_response,
+        order,
         is_liquidation,
     );
 
@@ -75,142 +102,25 @@ pub async fn save_trade_record_spot_order(
 /// Futures 주문 기록 저장 (편의 함수)
 pub async fn save_trade_record_futures_order(
     exchange: &str,
-    symbol: &str,
-    side: &str,
-    quantity: f64,
+    request: &OrderRequest,
     query_string: &str,
-    order_response: &OrderResponse,
-    _reduce_only: bool,
+    order: &OrderAck,
     is_liquidation: bool,
 ) {
     use super::global::save_trade_record_safe;
 
-    let trade_side = match TradeSide::from_str(side) {
-        Ok(s) => s,
-        Err(_) => return, // 잘못된 side는 무시
-    };
-
     let record = create_trade_record_from_order(
         exchange.to_string(),
-        symbol.to_string(),
         MarketType::Futures,
-        trade_side,
-        TradeType::Market,
-        quantity,
+        request,
         Some(query_string.to_string()),
-        order_response,
-        is_liquidation,
-    );
-
-    save_trade_record_safe(&record).await;
-}
-
-/// Bithumb 주문 기록 저장 (편의 함수)
-pub async fn save_trade_record_bithumb_order(
-    exchange: &str,
-    symbol: &str,
-    endpoint: &str,
-    quantity: f64,
-    params: &str,
-    order_response: &OrderResponse,
-    is_liquidation: bool,
-) {
-    use super::global::save_trade_record_safe;
-
-    let trade_side = if endpoint.contains("buy") {
-        TradeSide::Buy
-    } else if endpoint.contains("sell") {
-        TradeSide::Sell
-    } else {
-        return; // 잘못된 endpoint는 무시
-    };
-
-    let record = create_trade_record_from_order(
-        exchange.to_string(),
-        symbol.to_string(),
-        MarketType::Spot,
-        trade_side,
-        TradeType::Market,
-        quantity,
-        Some(params.to_string()),
-        order_response,
+        order,
         is_liquidation,
     );
 
     save_trade_record_safe(&record).await;
 }
 
-/// OrderResponse에서 가격 정보를 추출 (가능한 경우)
-fn extract_price_from_order_response(order_response: &OrderResponse) -> Option<f64> {
-    // 1. fills 배열에서 가격 추출 (Binance 시장가 주문의 경우)
-    if let Some(fills) = order_response.extra.get("fills").and_then(|v| v.as_array()) {
-        if !fills.is_empty() {
-            // 첫 번째 fill의 price 사용
-            if let Some(price_str) = fills[0].get("price").and_then(|v| v.as_str()) {
-                if let Ok(price) = price_str.parse::<f64>() {
-                    if price > 0.0 {
-                        return Some(price);
-                    }
-                }
-            }
-        }
-    }
-
-    // 2. cummulativeQuoteQty와 executedQty로 평균 가격 계산 (Binance)
-    if let Some(cum_qty_str) = order_response
-        .extra
-        .get("cummulativeQuoteQty")
-        .and_then(|v| v.as_str())
-        .or_else(|| {
-            // 누적 거래 금액 필드명 변형들 시도
-            order_response
-                .extra
-                .get("cumulativeQuoteQty")
-                .and_then(|v| v.as_str())
-        })
-        .or_else(|| {
-            order_response
-                .extra
-                .get("cumQuote")
-                .and_then(|v| v.as_str())
-        })
-    {
-        if let Some(executed_qty_str) = order_response.executed_qty.as_ref() {
-            if let (Ok(cum_quote), Ok(executed_qty)) =
-                (cum_qty_str.parse::<f64>(), executed_qty_str.parse::<f64>())
-            {
-                if executed_qty > 0.0 && cum_quote > 0.0 {
-                    return Some(cum_quote / executed_qty);
-                }
-            }
-        }
-    }
-
-    // 3. extra 필드에서 직접 가격 정보 찾기
-    if let Some(price_str) = order_response.extra.get("price").and_then(|v| v.as_str()) {
-        if let Ok(price) = price_str.parse::<f64>() {
-            if price > 0.0 {
-                return Some(price);
-            }
-        }
-    }
-
-    // 4. avgPrice 필드 확인
-    if let Some(avg_price_str) = order_response
-        .extra
-        .get("avgPrice")
-        .and_then(|v| v.as_str())
-    {
-        if let Ok(price) = avg_price_str.parse::<f64>() {
-            if price > 0.0 {
-                return Some(price);
-            }
-        }
-    }
-
-    None
-}
-
 /// 메타데이터를 JSON 문자열로 변환하여 추가
 pub fn add_metadata(record: &mut TradeRecord, metadata: serde_json::Value) {
     record.metadata = serde_json::to_string(&metadata).ok();
@@ -230,8 +140,8 @@ pub async fn save_position_record(
     carry: &str,  // "CARRY" or "REVERSE"
     action: &str, // "OPEN" or "CLOSE"
     symbol: &str,
-    spot_price: f64,
-    futures_mark: f64,
+    spot_price: Decimal,
+    futures_mark: Decimal,
     exchange_name: &str, // "binance", "bithumb", "bybit" 등
 ) {
     // carry를 소문자로 변환
@@ -244,8 +154,8 @@ pub async fn save_position_record(
         carry,
         action,
         symbol,
-        spot_price,
-        futures_mark,
+        spot_price.to_f64().unwrap_or(0.0),
+        futures_mark.to_f64().unwrap_or(0.0),
         &buy_exchange,
         &sell_exchange,
     )
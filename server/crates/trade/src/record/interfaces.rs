@@ -1,5 +1,7 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use interface::{Decimal, ExchangeId, TransferDirection, TransferRecord, TransferStatus};
+use rust_decimal::prelude::FromPrimitive;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt::Display;
@@ -126,6 +128,10 @@ pub struct TradeRecord {
     pub metadata: Option<String>,
     /// 청산 실행 기록 여부
     pub is_liquidation: bool,
+    /// 체결 수수료 합계 (fee_asset 기준)
+    pub fee: Option<f64>,
+    /// 수수료 차감 자산 (예: "USDT", "BNB")
+    pub fee_asset: Option<String>,
 }
 
 /// 거래 기록 저장소 인터페이스
@@ -186,13 +192,11 @@ impl TryFrom<super::entities::trade_record::Model> for StoredTradeRecord {
             .map_err(|e| RecordError::Other(format!("Failed to parse executed_at: {}", e)))?
             .with_timezone(&Utc);
 
-        let market_type =
-            MarketType::from_str(&model.market_type).map_err(|e| RecordError::Other(e))?;
+        let market_type = MarketType::from_str(&model.market_type).map_err(RecordError::Other)?;
 
-        let side = TradeSide::from_str(&model.side).map_err(|e| RecordError::Other(e))?;
+        let side = TradeSide::from_str(&model.side).map_err(RecordError::Other)?;
 
-        let trade_type =
-            TradeType::from_str(&model.trade_type).map_err(|e| RecordError::Other(e))?;
+        let trade_type = TradeType::from_str(&model.trade_type).map_err(RecordError::Other)?;
 
         let record = TradeRecord {
             executed_at,
@@ -207,6 +211,8 @@ impl TryFrom<super::entities::trade_record::Model> for StoredTradeRecord {
             api_response: model.api_response,
             metadata: model.metadata,
             is_liquidation: model.is_liquidation,
+            fee: model.fee,
+            fee_asset: model.fee_asset,
         };
 
         Ok(StoredTradeRecord {
@@ -282,6 +288,7 @@ impl TryFrom<super::entities::position_record::Model> for StoredPositionRecord {
 #[async_trait]
 pub trait PositionRecordRepository: Send + Sync {
     /// 포지션 기록 저장
+    #[allow(clippy::too_many_arguments)]
     async fn save(
         &self,
         bot_name: &str,
@@ -298,6 +305,72 @@ pub trait PositionRecordRepository: Send + Sync {
     async fn find_all(&self, limit: Option<u64>) -> Result<Vec<StoredPositionRecord>, RecordError>;
 }
 
+/// 저장소에 저장된 입출금 기록 (ID 포함)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredTransferRecord {
+    /// 데이터베이스 ID
+    pub id: i64,
+    /// 마지막 상태 갱신 시간
+    pub updated_at: DateTime<Utc>,
+    /// 입출금 기록 데이터
+    #[serde(flatten)]
+    pub record: TransferRecord,
+}
+
+/// SeaORM transfer_record::Model을 StoredTransferRecord로 변환
+impl TryFrom<super::entities::transfer_record::Model> for StoredTransferRecord {
+    type Error = RecordError;
+
+    fn try_from(model: super::entities::transfer_record::Model) -> Result<Self, Self::Error> {
+        let parse_time = |value: &str, field: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| RecordError::Other(format!("Failed to parse {}: {}", field, e)))
+        };
+
+        let record = TransferRecord {
+            exchange: ExchangeId::from_str(&model.exchange).map_err(RecordError::Other)?,
+            direction: TransferDirection::from_str(&model.direction).map_err(RecordError::Other)?,
+            id: model.transfer_id,
+            currency: model.currency,
+            network: model.network,
+            address: model.address,
+            memo: model.memo,
+            amount: Decimal::from_f64(model.amount).unwrap_or(Decimal::ZERO),
+            fee: model.fee.and_then(Decimal::from_f64),
+            txid: model.txid,
+            status: TransferStatus::from_str(&model.status).map_err(RecordError::Other)?,
+            created_at: parse_time(&model.created_at, "created_at")?,
+            client_id: model.client_id,
+        };
+
+        Ok(StoredTransferRecord {
+            id: model.id,
+            updated_at: parse_time(&model.updated_at, "updated_at")?,
+            record,
+        })
+    }
+}
+
+/// 입출금 기록 저장소 인터페이스
+#[async_trait]
+pub trait TransferRecordRepository: Send + Sync {
+    /// 입출금 기록 저장 (같은 거래소/방향/ID가 이미 있으면 상태, txid, 수수료만 갱신)
+    /// 클라이언트 출금 ID로 먼저 저장해 둔 기록은 거래소 ID로 바꿔 같은 기록으로 잇는다.
+    async fn save(&self, record: &TransferRecord) -> Result<(), RecordError>;
+
+    /// 거래소 입출금 ID로 조회
+    async fn find_by_transfer_id(
+        &self,
+        exchange: ExchangeId,
+        direction: TransferDirection,
+        transfer_id: &str,
+    ) -> Result<Option<StoredTransferRecord>, RecordError>;
+
+    /// 모든 입출금 기록 조회 (최신순)
+    async fn find_all(&self, limit: Option<u64>) -> Result<Vec<StoredTransferRecord>, RecordError>;
+}
+
 /// 기록 저장소 에러 타입
 #[derive(Debug, thiserror::Error)]
 pub enum RecordError {
@@ -8,6 +8,9 @@ pub use global::*;
 pub use helpers::*;
 pub use interfaces::{
     MarketType, PositionRecord, PositionRecordRepository, RecordError, StoredPositionRecord,
-    StoredTradeRecord, TradeRecord, TradeRecordRepository, TradeSide, TradeType,
+    StoredTradeRecord, StoredTransferRecord, TradeRecord, TradeRecordRepository, TradeSide,
+    TradeType, TransferRecordRepository,
+};
+pub use sqlite::{
+    SqlitePositionRecordRepository, SqliteTradeRecordRepository, SqliteTransferRecordRepository,
 };
-pub use sqlite::{SqlitePositionRecordRepository, SqliteTradeRecordRepository};
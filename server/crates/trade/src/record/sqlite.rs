@@ -11,10 +11,13 @@ use tracing::info;
 
 use super::entities::position_record;
 use super::entities::trade_record;
+use super::entities::transfer_record;
 use super::{
-    PositionRecordRepository, RecordError, StoredPositionRecord, StoredTradeRecord, TradeRecord,
-    TradeRecordRepository,
+    PositionRecordRepository, RecordError, StoredPositionRecord, StoredTradeRecord,
+    StoredTransferRecord, TradeRecord, TradeRecordRepository, TransferRecordRepository,
 };
+use interface::{ExchangeId, TransferDirection, TransferRecord};
+use rust_decimal::prelude::ToPrimitive;
 
 /// SQLite 기반 거래 기록 저장소
 pub struct SqliteTradeRecordRepository {
@@ -45,9 +48,14 @@ impl SqliteTradeRecordRepository {
         let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
         info!("Connecting to SQLite database: {}", db_url);
 
-        let db = Database::connect(&db_url)
+        Self::connect(&db_url).await
+    }
+
+    /// 지정한 URL의 DB에 연결 (테스트는 "sqlite::memory:")
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        let db = Database::connect(db_url)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         // SeaORM SchemaBuilder를 사용하여 테이블 및 인덱스 생성
         let backend = db.get_database_backend();
@@ -59,7 +67,10 @@ impl SqliteTradeRecordRepository {
 
         db.execute(backend.build(&create_table_stmt))
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
+
+        // 수수료 컬럼 이전에 만들어진 DB에 컬럼 추가 (이미 있으면 실패하므로 무시)
+        add_trade_fee_columns(&db).await;
 
         // 인덱스 생성 - SeaORM 1.1에서는 sea_orm::sea_query::Index 사용
         use sea_orm::sea_query::Index;
@@ -105,6 +116,31 @@ impl SqliteTradeRecordRepository {
     }
 }
 
+/// 기존 trade_records 테이블에 fee, fee_asset 컬럼 추가
+async fn add_trade_fee_columns(db: &DatabaseConnection) {
+    use sea_orm::sea_query::{ColumnDef, Table};
+
+    let backend = db.get_database_backend();
+    // SQLite의 ALTER TABLE은 한 번에 컬럼 하나만 추가할 수 있다
+    let columns = [
+        ColumnDef::new(trade_record::Column::Fee)
+            .double()
+            .to_owned(),
+        ColumnDef::new(trade_record::Column::FeeAsset)
+            .text()
+            .to_owned(),
+    ];
+    for column in columns {
+        let stmt = Table::alter()
+            .table(trade_record::Entity)
+            .add_column(column)
+            .to_owned();
+        if let Err(e) = db.execute(backend.build(&stmt)).await {
+            tracing::debug!("trade_records column migration skipped: {}", e);
+        }
+    }
+}
+
 #[async_trait]
 impl TradeRecordRepository for SqliteTradeRecordRepository {
     async fn save(&self, record: &TradeRecord) -> Result<(), RecordError> {
@@ -121,13 +157,15 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
             api_response: Set(record.api_response.clone()),
             metadata: Set(record.metadata.clone()),
             is_liquidation: Set(record.is_liquidation),
+            fee: Set(record.fee),
+            fee_asset: Set(record.fee_asset.clone()),
             ..Default::default()
         };
 
         trade_record::Entity::insert(model)
             .exec(&self.db)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         Ok(())
     }
@@ -152,6 +190,8 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
                 api_response: Set(record.api_response.clone()),
                 metadata: Set(record.metadata.clone()),
                 is_liquidation: Set(record.is_liquidation),
+                fee: Set(record.fee),
+                fee_asset: Set(record.fee_asset.clone()),
                 ..Default::default()
             })
             .collect();
@@ -159,7 +199,7 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
         trade_record::Entity::insert_many(models)
             .exec(&self.db)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         Ok(())
     }
@@ -168,7 +208,7 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
         let model = trade_record::Entity::find_by_id(id)
             .one(&self.db)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         match model {
             Some(m) => Ok(Some(m.try_into()?)),
@@ -189,10 +229,7 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
             query = query.limit(limit_val);
         }
 
-        let models = query
-            .all(&self.db)
-            .await
-            .map_err(|e| RecordError::Database(e))?;
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
 
         models.into_iter().map(|m| m.try_into()).collect()
     }
@@ -210,10 +247,7 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
             query = query.limit(limit_val);
         }
 
-        let models = query
-            .all(&self.db)
-            .await
-            .map_err(|e| RecordError::Database(e))?;
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
 
         models.into_iter().map(|m| m.try_into()).collect()
     }
@@ -236,10 +270,7 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
             query = query.limit(limit_val);
         }
 
-        let models = query
-            .all(&self.db)
-            .await
-            .map_err(|e| RecordError::Database(e))?;
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
 
         models.into_iter().map(|m| m.try_into()).collect()
     }
@@ -252,10 +283,7 @@ impl TradeRecordRepository for SqliteTradeRecordRepository {
             query = query.limit(limit_val);
         }
 
-        let models = query
-            .all(&self.db)
-            .await
-            .map_err(|e| RecordError::Database(e))?;
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
 
         models.into_iter().map(|m| m.try_into()).collect()
     }
@@ -277,10 +305,10 @@ impl SqlitePositionRecordRepository {
         let db_path = env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
 
         let mut path = PathBuf::from(&db_path);
-        if !path.is_absolute() {
-            if let Ok(current_dir) = env::current_dir() {
-                path = current_dir.join(&db_path);
-            }
+        if !path.is_absolute()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            path = current_dir.join(&db_path);
         }
 
         if let Some(parent) = path.parent() {
@@ -296,7 +324,7 @@ impl SqlitePositionRecordRepository {
 
         let db = Database::connect(&db_url)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         // SeaORM SchemaBuilder를 사용하여 테이블 생성
         let backend = db.get_database_backend();
@@ -308,7 +336,7 @@ impl SqlitePositionRecordRepository {
 
         db.execute(backend.build(&create_table_stmt))
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         info!("Position records table initialized");
 
@@ -346,7 +374,7 @@ impl PositionRecordRepository for SqlitePositionRecordRepository {
         position_record::Entity::insert(model)
             .exec(&self.db)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
 
         Ok(())
     }
@@ -359,11 +387,340 @@ impl PositionRecordRepository for SqlitePositionRecordRepository {
             query = query.limit(limit_val);
         }
 
-        let models = query
-            .all(&self.db)
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
+
+        models.into_iter().map(|m| m.try_into()).collect()
+    }
+}
+
+// ============================================================================
+// 입출금 기록 저장소
+// ============================================================================
+
+/// 거래소 이름을 trade_records와 같은 소문자 표기로 (예: "binance")
+fn exchange_name(exchange: ExchangeId) -> String {
+    format!("{:?}", exchange).to_lowercase()
+}
+
+/// SQLite 기반 입출금 기록 저장소
+pub struct SqliteTransferRecordRepository {
+    db: DatabaseConnection,
+}
+
+impl SqliteTransferRecordRepository {
+    /// 새로운 SQLite 저장소 인스턴스 생성
+    /// DB 파일 경로는 환경 변수 DB_PATH로 지정 가능 (기본값: "trade_records.db")
+    pub async fn new() -> Result<Self, RecordError> {
+        let db_path = env::var("DB_PATH").unwrap_or_else(|_| "trade_records.db".to_string());
+
+        let mut path = PathBuf::from(&db_path);
+        if !path.is_absolute()
+            && let Ok(current_dir) = env::current_dir()
+        {
+            path = current_dir.join(&db_path);
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| RecordError::Other(format!("Failed to create DB directory: {}", e)))?;
+        }
+
+        let db_url = format!("sqlite://{}?mode=rwc", path.to_string_lossy());
+        info!(
+            "Connecting to SQLite database for transfer records: {}",
+            db_url
+        );
+
+        Self::connect(&db_url).await
+    }
+
+    /// 지정한 URL의 DB에 연결 (테스트는 "sqlite::memory:")
+    pub async fn connect(db_url: &str) -> Result<Self, RecordError> {
+        let db = Database::connect(db_url)
+            .await
+            .map_err(RecordError::Database)?;
+
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+
+        let mut create_table_stmt = schema.create_table_from_entity(transfer_record::Entity);
+        create_table_stmt.if_not_exists();
+
+        db.execute(backend.build(&create_table_stmt))
+            .await
+            .map_err(RecordError::Database)?;
+
+        // 상태 갱신 시 같은 입출금을 찾기 위한 유니크 인덱스
+        use sea_orm::sea_query::Index;
+
+        let mut transfer_idx = Index::create()
+            .name("idx_transfer_records_transfer")
+            .table(transfer_record::Entity)
+            .col(transfer_record::Column::Exchange)
+            .col(transfer_record::Column::Direction)
+            .col(transfer_record::Column::TransferId)
+            .unique()
+            .to_owned();
+        transfer_idx.if_not_exists();
+
+        db.execute(backend.build(&transfer_idx))
+            .await
+            .map_err(RecordError::Database)?;
+
+        info!("Transfer records table initialized");
+
+        Ok(Self { db })
+    }
+}
+
+#[async_trait]
+impl TransferRecordRepository for SqliteTransferRecordRepository {
+    async fn save(&self, record: &TransferRecord) -> Result<(), RecordError> {
+        use sea_orm::sea_query::OnConflict;
+
+        let model = transfer_record::ActiveModel {
+            created_at: Set(record.created_at.to_rfc3339()),
+            updated_at: Set(Utc::now().to_rfc3339()),
+            exchange: Set(exchange_name(record.exchange)),
+            direction: Set(record.direction.as_str().to_string()),
+            transfer_id: Set(record.id.clone()),
+            currency: Set(record.currency.clone()),
+            network: Set(record.network.clone()),
+            address: Set(record.address.clone()),
+            memo: Set(record.memo.clone()),
+            amount: Set(record.amount.to_f64().unwrap_or(0.0)),
+            fee: Set(record.fee.and_then(|f| f.to_f64())),
+            txid: Set(record.txid.clone()),
+            status: Set(record.status.as_str().to_string()),
+            client_id: Set(record.client_id.clone()),
+            ..Default::default()
+        };
+
+        // 출금 신청 전에 클라이언트 출금 ID로 저장해 둔 기록을 거래소 ID로 바꾼다
+        if let Some(client_id) = &record.client_id
+            && record.id != *client_id
+        {
+            use sea_orm::sea_query::Expr;
+
+            transfer_record::Entity::update_many()
+                .col_expr(
+                    transfer_record::Column::TransferId,
+                    Expr::value(record.id.clone()),
+                )
+                .filter(transfer_record::Column::Exchange.eq(exchange_name(record.exchange)))
+                .filter(transfer_record::Column::Direction.eq(record.direction.as_str()))
+                .filter(transfer_record::Column::TransferId.eq(client_id.as_str()))
+                .filter(transfer_record::Column::ClientId.eq(client_id.as_str()))
+                .exec(&self.db)
+                .await
+                .map_err(RecordError::Database)?;
+        }
+
+        // 출금 신청 때 저장한 주소/메모는 조회 응답으로 덮어쓰지 않는다
+        transfer_record::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    transfer_record::Column::Exchange,
+                    transfer_record::Column::Direction,
+                    transfer_record::Column::TransferId,
+                ])
+                .update_columns([
+                    transfer_record::Column::UpdatedAt,
+                    transfer_record::Column::Fee,
+                    transfer_record::Column::Txid,
+                    transfer_record::Column::Status,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.db)
             .await
-            .map_err(|e| RecordError::Database(e))?;
+            .map_err(RecordError::Database)?;
+
+        Ok(())
+    }
+
+    async fn find_by_transfer_id(
+        &self,
+        exchange: ExchangeId,
+        direction: TransferDirection,
+        transfer_id: &str,
+    ) -> Result<Option<StoredTransferRecord>, RecordError> {
+        let model = transfer_record::Entity::find()
+            .filter(transfer_record::Column::Exchange.eq(exchange_name(exchange)))
+            .filter(transfer_record::Column::Direction.eq(direction.as_str()))
+            .filter(transfer_record::Column::TransferId.eq(transfer_id))
+            .one(&self.db)
+            .await
+            .map_err(RecordError::Database)?;
+
+        match model {
+            Some(m) => Ok(Some(m.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self, limit: Option<u64>) -> Result<Vec<StoredTransferRecord>, RecordError> {
+        let mut query =
+            transfer_record::Entity::find().order_by_desc(transfer_record::Column::CreatedAt);
+
+        if let Some(limit_val) = limit {
+            query = query.limit(limit_val);
+        }
+
+        let models = query.all(&self.db).await.map_err(RecordError::Database)?;
 
         models.into_iter().map(|m| m.try_into()).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{MarketType, create_trade_record_from_order};
+    use interface::{
+        Decimal, Fill, OrderAck, OrderRequest, OrderSide, OrderStatus, TransferStatus,
+    };
+
+    #[tokio::test]
+    async fn test_trade_record_fee_columns() {
+        let repo = SqliteTradeRecordRepository::connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        // 수수료 컬럼 추가 전 스키마에서도 컬럼이 추가되어야 한다
+        repo.db
+            .execute_unprepared("ALTER TABLE trade_records DROP COLUMN fee_asset")
+            .await
+            .unwrap();
+        repo.db
+            .execute_unprepared("ALTER TABLE trade_records DROP COLUMN fee")
+            .await
+            .unwrap();
+        add_trade_fee_columns(&repo.db).await;
+        // 이미 있으면 그대로 둔다
+        add_trade_fee_columns(&repo.db).await;
+
+        let request = OrderRequest::market("BTCUSDT", OrderSide::Buy, Decimal::new(2, 3));
+        let order = OrderAck {
+            exchange: ExchangeId::Binance,
+            symbol: "BTCUSDT".to_string(),
+            order_id: "1".to_string(),
+            client_order_id: None,
+            side: OrderSide::Buy,
+            status: OrderStatus::Filled,
+            executed_qty: Decimal::new(2, 3),
+            avg_price: None,
+            fills: vec![
+                Fill {
+                    price: Decimal::new(100_000, 0),
+                    qty: Decimal::new(1, 3),
+                    fee: Decimal::new(4, 2),
+                    fee_asset: "USDT".to_string(),
+                    is_maker: false,
+                },
+                Fill {
+                    price: Decimal::new(100_000, 0),
+                    qty: Decimal::new(1, 3),
+                    fee: Decimal::new(4, 2),
+                    fee_asset: "USDT".to_string(),
+                    is_maker: false,
+                },
+            ],
+            raw: serde_json::Value::Null,
+        };
+        let record = create_trade_record_from_order(
+            "binance".to_string(),
+            MarketType::Futures,
+            &request,
+            None,
+            &order,
+            false,
+        );
+        repo.save(&record).await.unwrap();
+
+        let stored = repo.find_all(None).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].record.fee, Some(0.08));
+        assert_eq!(stored[0].record.fee_asset.as_deref(), Some("USDT"));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_record_upsert() {
+        let repo = SqliteTransferRecordRepository::connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        let mut record = TransferRecord {
+            exchange: ExchangeId::Bithumb,
+            direction: TransferDirection::Withdrawal,
+            id: "9f432943-54e0-40b7-825f-b6fec8b42b79".to_string(),
+            currency: "XRP".to_string(),
+            network: "XRP".to_string(),
+            address: "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh".to_string(),
+            memo: Some("101286922".to_string()),
+            amount: Decimal::new(1005, 1),
+            fee: None,
+            txid: None,
+            status: TransferStatus::Pending,
+            created_at: Utc::now(),
+            client_id: None,
+        };
+        repo.save(&record).await.unwrap();
+
+        // 상태 조회 응답에는 주소가 없다
+        record.address = String::new();
+        record.memo = None;
+        record.fee = Some(Decimal::new(4, 1));
+        record.txid = Some("ABCDEF".to_string());
+        record.status = TransferStatus::Completed;
+        repo.save(&record).await.unwrap();
+
+        let stored = repo
+            .find_by_transfer_id(record.exchange, record.direction, &record.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.record.status, TransferStatus::Completed);
+        assert_eq!(stored.record.txid.as_deref(), Some("ABCDEF"));
+        assert_eq!(stored.record.fee, Some(Decimal::new(4, 1)));
+        assert_eq!(stored.record.amount, Decimal::new(1005, 1));
+        assert_eq!(stored.record.address, "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh");
+        assert_eq!(stored.record.memo.as_deref(), Some("101286922"));
+        assert_eq!(repo.find_all(None).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_record_links_client_id() {
+        let repo = SqliteTransferRecordRepository::connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        // 출금 신청 전: 거래소 ID가 없어 클라이언트 출금 ID로 저장
+        let mut record = TransferRecord {
+            exchange: ExchangeId::Binance,
+            direction: TransferDirection::Withdrawal,
+            id: "wd-1".to_string(),
+            currency: "XRP".to_string(),
+            network: "XRP".to_string(),
+            address: "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh".to_string(),
+            memo: None,
+            amount: Decimal::new(30, 0),
+            fee: None,
+            txid: None,
+            status: TransferStatus::Pending,
+            created_at: Utc::now(),
+            client_id: Some("wd-1".to_string()),
+        };
+        repo.save(&record).await.unwrap();
+
+        record.id = "7213fea8e94b4a5593d507237e5a555b".to_string();
+        record.status = TransferStatus::Processing;
+        repo.save(&record).await.unwrap();
+
+        let all = repo.find_all(None).await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].record.id, record.id);
+        assert_eq!(all[0].record.status, TransferStatus::Processing);
+        assert_eq!(all[0].record.client_id.as_deref(), Some("wd-1"));
+    }
+}
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 use std::sync::RwLock;
 
-use exchanges::binance::{generate_signature, get_timestamp};
+use exchanges::binance::{api_error, generate_signature, get_timestamp};
+use exchanges::decimal::parse_decimal;
+use exchanges::error::retry_after;
+use exchanges::rate_limit::{EndpointClass, RateLimiter};
 use exchanges::BinanceClient;
-use interface::ExchangeError;
+use interface::{Decimal, ExchangeError, ExchangeId};
 
-use super::types::{clamp_quantity_with_filter, LotSizeFilter};
-
-const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+use super::types::{clamp_quantity_with_filter, FuturesMarginSummary, LotSizeFilter};
 
 /// Binance Futures API: Futures 주문, exchangeInfo, LOT_SIZE 캐시 관리
 pub struct BinanceFuturesApi {
@@ -25,25 +26,23 @@ impl BinanceFuturesApi {
 
     /// 선물 exchangeInfo를 로드하여 LOT_SIZE 필터를 캐시에 저장
     pub async fn load_exchange_info(&self) -> Result<(), ExchangeError> {
-        let url = format!("{}/fapi/v1/exchangeInfo", FUTURES_BASE_URL);
+        let url = format!("{}/fapi/v1/exchangeInfo", self.client.endpoints.futures_rest);
 
-        let response = self
-            .client
-            .http
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Futures,
+                1,
+                self.client.http.get(&url),
+            )
+            .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Futures exchangeInfo API error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         let resp: serde_json::Value = serde_json::from_str(&response_text)
@@ -66,20 +65,20 @@ impl BinanceFuturesApi {
                             let min_qty = filter
                                 .get("minQty")
                                 .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<f64>().ok())
-                                .unwrap_or(0.0);
+                                .and_then(parse_decimal)
+                                .unwrap_or(Decimal::ZERO);
 
                             let max_qty = filter
                                 .get("maxQty")
                                 .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<f64>().ok())
-                                .unwrap_or(f64::MAX);
+                                .and_then(parse_decimal)
+                                .unwrap_or(Decimal::MAX);
 
                             let step_size = filter
                                 .get("stepSize")
                                 .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<f64>().ok())
-                                .unwrap_or(1.0);
+                                .and_then(parse_decimal)
+                                .unwrap_or(Decimal::ONE);
 
                             cache.insert(
                                 symbol.clone(),
@@ -106,7 +105,7 @@ impl BinanceFuturesApi {
     }
 
     /// 선물 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
-    pub fn clamp_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    pub fn clamp_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
         if let Some(filter) = self.get_lot_size(symbol) {
             clamp_quantity_with_filter(filter, qty)
         } else {
@@ -129,12 +128,12 @@ impl BinanceFuturesApi {
             .client
             .api_key
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+            .ok_or_else(|| ExchangeError::Auth("API key not set".to_string()))?;
         let api_secret = self
             .client
             .api_secret
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+            .ok_or_else(|| ExchangeError::Auth("API secret not set".to_string()))?;
 
         // 1. 마진 타입 설정
         let endpoint = "/fapi/v1/marginType";
@@ -148,25 +147,28 @@ impl BinanceFuturesApi {
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            self.client.endpoints.futures_rest, endpoint, query_string, signature
         );
 
-        let response = self
-            .client
-            .http
-            .post(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Futures,
+                1,
+                self.client.http.post(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
             .await;
 
         // 마진 타입이 이미 설정되어 있으면 에러가 날 수 있음 (무시)
-        if let Ok(resp) = response {
-            if !resp.status().is_success() {
-                let text = resp.text().await.unwrap_or_default();
-                if !text.contains("-4046") {
-                    // -4046은 "No need to change margin type" 에러
-                    tracing::warn!("Failed to set margin type: {}", text);
-                }
+        if let Ok(resp) = response
+            && !resp.status().is_success()
+        {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            match api_error(status, None, &text) {
+                // -4046은 "No need to change margin type" 에러
+                ExchangeError::ExchangeReject { code, .. } if code == "-4046" => {}
+                e => tracing::warn!("Failed to set margin type: {}", e),
             }
         }
 
@@ -181,17 +183,17 @@ impl BinanceFuturesApi {
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            self.client.endpoints.futures_rest, endpoint, query_string, signature
         );
 
-        let response = self
-            .client
-            .http
-            .post(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Futures,
+                1,
+                self.client.http.post(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
+            .await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -207,17 +209,17 @@ impl BinanceFuturesApi {
     }
 
     /// 선물 잔고 조회 (USDT 마진)
-    pub async fn get_balance(&self) -> Result<f64, ExchangeError> {
+    pub async fn get_balance(&self) -> Result<Decimal, ExchangeError> {
         let api_key = self
             .client
             .api_key
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+            .ok_or_else(|| ExchangeError::Auth("API key not set".to_string()))?;
         let api_secret = self
             .client
             .api_secret
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+            .ok_or_else(|| ExchangeError::Auth("API secret not set".to_string()))?;
 
         let endpoint = "/fapi/v2/balance";
         let timestamp = get_timestamp();
@@ -226,27 +228,24 @@ impl BinanceFuturesApi {
 
         let url = format!(
             "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+            self.client.endpoints.futures_rest, endpoint, query_string, signature
         );
 
-        let response = self
-            .client
-            .http
-            .get(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Futures,
+                5,
+                self.client.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
+            .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Futures balance API error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         #[derive(Debug, serde::Deserialize)]
@@ -262,14 +261,96 @@ impl BinanceFuturesApi {
         let usdt_balance = balances
             .iter()
             .find(|b| b.asset == "USDT")
-            .and_then(|b| b.balance.parse::<f64>().ok())
-            .unwrap_or(0.0);
+            .and_then(|b| parse_decimal(&b.balance))
+            .unwrap_or(Decimal::ZERO);
 
         Ok(usdt_balance)
     }
 
+    /// 선물 계정 마진 요약 조회 (마진 비율 계산용)
+    pub async fn get_margin_summary(&self) -> Result<FuturesMarginSummary, ExchangeError> {
+        let api_key = self
+            .client
+            .api_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Auth("API key not set".to_string()))?;
+        let api_secret = self
+            .client
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Auth("API secret not set".to_string()))?;
+
+        let endpoint = "/fapi/v2/account";
+        let timestamp = get_timestamp();
+        let query_string = format!("timestamp={}&recvWindow=50000", timestamp);
+        let signature = generate_signature(&query_string, api_secret);
+
+        let url = format!(
+            "{}{}?{}&signature={}",
+            self.client.endpoints.futures_rest, endpoint, query_string, signature
+        );
+
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Futures,
+                5,
+                self.client.http.get(&url).header("X-MBX-APIKEY", api_key.as_str()),
+            )
+            .await?;
+
+        let status = response.status();
+        let retry_after = retry_after(response.headers());
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(api_error(status, retry_after, &response_text));
+        }
+
+        parse_margin_summary(&response_text)
+    }
+
     pub fn client(&self) -> &BinanceClient {
         &self.client
     }
 }
 
+/// GET /fapi/v2/account 응답에서 마진 요약 추출
+fn parse_margin_summary(body: &str) -> Result<FuturesMarginSummary, ExchangeError> {
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FuturesAccount {
+        total_wallet_balance: String,
+        total_margin_balance: String,
+        total_maint_margin: String,
+        total_initial_margin: String,
+        max_withdraw_amount: String,
+    }
+
+    let account: FuturesAccount = serde_json::from_str(body)
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse futures account: {}", e)))?;
+    let field = |v: &str| parse_decimal(v).unwrap_or(Decimal::ZERO);
+
+    Ok(FuturesMarginSummary {
+        wallet_balance: field(&account.total_wallet_balance),
+        margin_balance: field(&account.total_margin_balance),
+        maint_margin: field(&account.total_maint_margin),
+        initial_margin: field(&account.total_initial_margin),
+        max_withdraw_amount: field(&account.max_withdraw_amount),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_margin_summary() {
+        let body = r#"{"feeTier":0,"canTrade":true,"totalInitialMargin":"120.5","totalMaintMargin":"12.05","totalWalletBalance":"200","totalUnrealizedProfit":"-10","totalMarginBalance":"190","availableBalance":"69.5","maxWithdrawAmount":"69.5","assets":[],"positions":[]}"#;
+        let summary = parse_margin_summary(body).unwrap();
+        assert_eq!(summary.margin_balance, Decimal::new(190, 0));
+        assert_eq!(summary.max_withdraw_amount, Decimal::new(695, 1));
+        assert_eq!(summary.margin_ratio(), Some(Decimal::new(1205, 2) / Decimal::new(190, 0)));
+        assert_eq!(FuturesMarginSummary::default().margin_ratio(), None);
+    }
+}
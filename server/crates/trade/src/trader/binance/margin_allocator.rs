@@ -0,0 +1,301 @@
+//! 선물 지갑 마진 자동 배분
+//!
+//! 열린 포지션의 Binance 마진 비율(유지 증거금 / 마진 잔고)을 목표 근처로 유지한다.
+//! 비율이 top_up_ratio를 넘으면 현물(부족하면 펀딩) 지갑에서 선물 지갑으로 채워 넣고,
+//! release_ratio 아래로 내려가면 남는 마진을 현물 지갑으로 돌려보낸다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use interface::{Decimal, ExchangeError};
+use tokio::task::JoinHandle;
+
+use super::futures_api::BinanceFuturesApi;
+use super::spot_api::BinanceSpotApi;
+use super::types::{FuturesMarginSummary, floor_to_step};
+use super::wallet_api::{BinanceWalletApi, WalletType};
+
+#[derive(Debug, Clone)]
+pub struct MarginAllocatorParams {
+    /// 마진 자산 (USDⓈ-M은 "USDT")
+    pub asset: String,
+    /// 채우거나 돌려보낼 때 맞출 마진 비율 (예: 0.05 = 마진 잔고가 유지 증거금의 20배)
+    pub target_ratio: Decimal,
+    /// 이 비율을 넘으면 선물 지갑으로 채워 넣음
+    pub top_up_ratio: Decimal,
+    /// 이 비율 아래면 남는 마진을 현물 지갑으로 돌려보냄
+    pub release_ratio: Decimal,
+    /// 이보다 작은 이동은 하지 않음 (자산 단위)
+    pub min_transfer: Decimal,
+    /// 현물 지갑에 항상 남겨둘 금액 (현물 레그 주문용, 0이면 현물 잔고를 전부 옮길 수 있음)
+    pub spot_reserve: Decimal,
+    /// 현물 지갑이 부족할 때 펀딩 지갑에서도 가져올지
+    pub use_funding: bool,
+    /// 점검 주기
+    pub interval: Duration,
+}
+
+impl Default for MarginAllocatorParams {
+    fn default() -> Self {
+        Self {
+            asset: "USDT".to_string(),
+            target_ratio: Decimal::new(5, 2),
+            top_up_ratio: Decimal::new(10, 2),
+            release_ratio: Decimal::new(2, 2),
+            min_transfer: Decimal::new(10, 0),
+            spot_reserve: Decimal::new(100, 0),
+            use_funding: true,
+            interval: Duration::from_secs(30),
+        }
+    }
+}
+
+/// 실행할 지갑 이동 한 건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlannedTransfer {
+    pub from: WalletType,
+    pub to: WalletType,
+    pub amount: Decimal,
+}
+
+/// 이동 수량은 0.01 단위로 내림 (USDT 기준 충분한 정밀도)
+fn transfer_amount(amount: Decimal) -> Decimal {
+    floor_to_step(amount.max(Decimal::ZERO), Decimal::new(1, 2))
+}
+
+/// 현재 마진 상태와 지갑 잔고로 필요한 이동 계획을 세운다 (포지션이 없으면 빈 계획)
+pub fn plan_rebalance(
+    params: &MarginAllocatorParams,
+    summary: &FuturesMarginSummary,
+    spot_free: Decimal,
+    funding_free: Decimal,
+) -> Vec<PlannedTransfer> {
+    let Some(ratio) = summary.margin_ratio() else {
+        return Vec::new();
+    };
+    if params.target_ratio <= Decimal::ZERO {
+        return Vec::new();
+    }
+    let desired_margin = summary.maint_margin / params.target_ratio;
+    let mut plan = Vec::new();
+
+    if ratio > params.top_up_ratio {
+        let mut need = desired_margin - summary.margin_balance;
+
+        let from_spot = transfer_amount(need.min(spot_free - params.spot_reserve));
+        if from_spot >= params.min_transfer {
+            plan.push(PlannedTransfer {
+                from: WalletType::Spot,
+                to: WalletType::UsdmFutures,
+                amount: from_spot,
+            });
+            need -= from_spot;
+        }
+
+        if params.use_funding {
+            let from_funding = transfer_amount(need.min(funding_free));
+            if from_funding >= params.min_transfer {
+                plan.push(PlannedTransfer {
+                    from: WalletType::Funding,
+                    to: WalletType::UsdmFutures,
+                    amount: from_funding,
+                });
+            }
+        }
+    } else if ratio < params.release_ratio {
+        let excess = summary.margin_balance - desired_margin;
+        let amount = transfer_amount(excess.min(summary.max_withdraw_amount));
+        if amount >= params.min_transfer {
+            plan.push(PlannedTransfer {
+                from: WalletType::UsdmFutures,
+                to: WalletType::Spot,
+                amount,
+            });
+        }
+    }
+
+    plan
+}
+
+/// 선물 지갑 마진 자동 배분기 (BinanceTrader::margin_allocator로 생성)
+pub struct MarginAllocator {
+    spot: Arc<BinanceSpotApi>,
+    futures: Arc<BinanceFuturesApi>,
+    wallet: Arc<BinanceWalletApi>,
+    params: MarginAllocatorParams,
+}
+
+impl MarginAllocator {
+    pub fn new(
+        spot: Arc<BinanceSpotApi>,
+        futures: Arc<BinanceFuturesApi>,
+        wallet: Arc<BinanceWalletApi>,
+        params: MarginAllocatorParams,
+    ) -> Self {
+        Self {
+            spot,
+            futures,
+            wallet,
+            params,
+        }
+    }
+
+    pub fn params(&self) -> &MarginAllocatorParams {
+        &self.params
+    }
+
+    /// 한 번 점검하고 필요한 이동을 실행. 실행한 이동 목록을 반환
+    pub async fn rebalance_once(&self) -> Result<Vec<PlannedTransfer>, ExchangeError> {
+        let summary = self.futures.get_margin_summary().await?;
+        let Some(ratio) = summary.margin_ratio() else {
+            return Ok(Vec::new());
+        };
+
+        let (spot_free, funding_free) = if ratio > self.params.top_up_ratio {
+            let spot_free = self.spot.get_balance(&self.params.asset).await?;
+            let funding_free = if self.params.use_funding {
+                self.wallet.get_funding_balance(&self.params.asset).await?
+            } else {
+                Decimal::ZERO
+            };
+            (spot_free, funding_free)
+        } else {
+            (Decimal::ZERO, Decimal::ZERO)
+        };
+
+        let plan = plan_rebalance(&self.params, &summary, spot_free, funding_free);
+        if plan.is_empty() && ratio > self.params.top_up_ratio {
+            tracing::warn!(
+                "선물 마진 비율 {:.4}가 한도 {}를 넘었지만 옮길 {} 잔고가 없습니다",
+                ratio,
+                self.params.top_up_ratio,
+                self.params.asset
+            );
+        }
+
+        for transfer in &plan {
+            tracing::info!(
+                "선물 마진 비율 {:.4} (목표 {}): {} {} {} -> {}",
+                ratio,
+                self.params.target_ratio,
+                transfer.amount,
+                self.params.asset,
+                transfer.from,
+                transfer.to
+            );
+            self.wallet
+                .transfer(
+                    transfer.from,
+                    transfer.to,
+                    &self.params.asset,
+                    transfer.amount,
+                )
+                .await?;
+        }
+
+        Ok(plan)
+    }
+
+    /// 백그라운드에서 interval마다 rebalance_once 실행
+    /// 인증 오류는 계속해도 의미가 없으므로 루프를 끝낸다.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.params.interval);
+            loop {
+                ticker.tick().await;
+                match self.rebalance_once().await {
+                    Ok(_) => {}
+                    Err(ExchangeError::Auth(msg)) => {
+                        tracing::error!("마진 배분 중단 (인증 오류): {}", msg);
+                        break;
+                    }
+                    Err(e) => tracing::warn!("마진 배분 실패: {}", e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(margin_balance: i64, maint_margin: i64, max_withdraw: i64) -> FuturesMarginSummary {
+        FuturesMarginSummary {
+            wallet_balance: Decimal::from(margin_balance),
+            margin_balance: Decimal::from(margin_balance),
+            maint_margin: Decimal::from(maint_margin),
+            initial_margin: Decimal::ZERO,
+            max_withdraw_amount: Decimal::from(max_withdraw),
+        }
+    }
+
+    #[test]
+    fn test_plan_rebalance() {
+        let params = MarginAllocatorParams {
+            spot_reserve: Decimal::from(50),
+            ..Default::default()
+        };
+
+        // 포지션 없음
+        assert!(
+            plan_rebalance(
+                &params,
+                &summary(100, 0, 100),
+                Decimal::from(1000),
+                Decimal::ZERO
+            )
+            .is_empty()
+        );
+
+        // 비율 0.2 > 0.1: 목표 마진 10 / 0.05 = 200, 150 부족 -> 현물 60(예비 50 제외) + 펀딩 90
+        let plan = plan_rebalance(
+            &params,
+            &summary(50, 10, 0),
+            Decimal::from(110),
+            Decimal::from(500),
+        );
+        assert_eq!(
+            plan,
+            vec![
+                PlannedTransfer {
+                    from: WalletType::Spot,
+                    to: WalletType::UsdmFutures,
+                    amount: Decimal::from(60),
+                },
+                PlannedTransfer {
+                    from: WalletType::Funding,
+                    to: WalletType::UsdmFutures,
+                    amount: Decimal::from(90),
+                },
+            ]
+        );
+
+        // 범위 안
+        assert!(
+            plan_rebalance(
+                &params,
+                &summary(200, 10, 100),
+                Decimal::from(1000),
+                Decimal::ZERO
+            )
+            .is_empty()
+        );
+
+        // 비율 0.01 < 0.02: 목표 200, 800 남음 -> 출금 가능액 300까지만
+        let plan = plan_rebalance(
+            &params,
+            &summary(1000, 10, 300),
+            Decimal::ZERO,
+            Decimal::ZERO,
+        );
+        assert_eq!(
+            plan,
+            vec![PlannedTransfer {
+                from: WalletType::UsdmFutures,
+                to: WalletType::Spot,
+                amount: Decimal::from(300),
+            }]
+        );
+    }
+}
@@ -6,29 +6,36 @@
 //! - `order_client`: 주문 클라이언트 트레이트 및 HTTP 구현
 //! - `spot_api`: Spot 거래 관련 API
 //! - `futures_api`: Futures 거래 관련 API
+//! - `wallet_api`: 현물/선물/펀딩 지갑 간 자금 이동 (universal transfer)
+//! - `margin_allocator`: 선물 지갑 마진 비율 자동 유지
 //! - `price_feed`: 실시간 가격 피드 (WebSocket)
 //! - `user_stream`: User Data Stream (WebSocket)
 //! - `trader`: BinanceTrader 메인 구조체 및 트레이트 구현
 
 pub mod futures_api;
+pub mod margin_allocator;
 pub mod order_client;
 pub mod price_feed;
 pub mod spot_api;
 pub mod trader;
 pub mod types;
 pub mod user_stream;
+pub mod wallet_api;
 
 // 공개 API
 pub use futures_api::BinanceFuturesApi;
+pub use margin_allocator::{MarginAllocator, MarginAllocatorParams, PlannedTransfer};
 pub use order_client::{BinanceOrderClient, HttpBinanceOrderClient};
 pub use price_feed::BinancePriceFeed;
 pub use spot_api::BinanceSpotApi;
 pub use trader::BinanceTrader;
 pub use types::{
-    clamp_quantity_with_filter, HedgedPair, LotSizeFilter, OrderResponse,
-    PlaceFuturesOrderOptions, PlaceOrderOptions, PriceState,
+    clamp_quantity_with_filter, floor_to_step, format_decimal, parse_order_ack,
+    parse_order_status, FuturesMarginSummary, HedgedPair, LotSizeFilter, PlaceOrderOptions,
+    PriceState,
 };
 pub use user_stream::{
     BalanceInfo, BalanceUpdate, ExecutionReport, OutboundAccountPosition, UserDataEvent,
 };
+pub use wallet_api::{BinanceWalletApi, WalletType};
 
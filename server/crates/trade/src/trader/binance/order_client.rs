@@ -1,35 +1,29 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
-use tracing::info;
+use reqwest::Method;
+use tracing::{debug, error, info, warn};
 
 use exchanges::BinanceClient;
-use exchanges::binance::{generate_signature, get_timestamp};
-use interface::ExchangeError;
-
-use super::types::{OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions};
+use exchanges::binance::{api_error, generate_signature, get_timestamp};
+use exchanges::error::{retry_after, truncate_body};
+use exchanges::rate_limit::{EndpointClass, RateLimiter};
+use interface::{
+    Decimal, ExchangeError, ExchangeId, Fill, OrderAck, OrderRequest, OrderType, TimeInForce,
+};
 
-const SPOT_BASE_URL: &str = "https://api.binance.com";
-const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
+use super::types::{PlaceOrderOptions, format_decimal, parse_order_ack, parse_user_trades};
 
 /// BinanceTrader가 의존하는 주문 클라이언트 트레이트. 나중에 WebSocket 기반 구현체를 추가할 수 있다.
 #[async_trait]
 pub trait BinanceOrderClient: Send + Sync {
     async fn place_spot_order(
         &self,
-        symbol: &str,
-        side: &str,
-        qty: f64,
-        price: Option<f64>,
+        request: &OrderRequest,
         options: PlaceOrderOptions,
-    ) -> Result<OrderResponse, ExchangeError>;
+    ) -> Result<OrderAck, ExchangeError>;
 
-    async fn place_futures_order(
-        &self,
-        symbol: &str,
-        side: &str,
-        qty: f64,
-        price: Option<f64>,
-        options: PlaceFuturesOrderOptions,
-    ) -> Result<OrderResponse, ExchangeError>;
+    async fn place_futures_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError>;
 
     async fn cancel_spot_order(&self, symbol: &str, order_id: &str) -> Result<(), ExchangeError>;
 
@@ -37,6 +31,141 @@ pub trait BinanceOrderClient: Send + Sync {
     -> Result<(), ExchangeError>;
 }
 
+/// 주문 요청을 Binance 쿼리 파라미터로 변환 (timestamp/recvWindow 제외)
+/// 응답 타입은 spot은 체결 내역이 포함된 FULL, futures는 RESULT로 요청한다.
+/// (futures에는 FULL이 없어 체결 내역은 주문 후 /fapi/v1/userTrades로 따로 조회한다)
+fn order_params(request: &OrderRequest, futures: bool) -> Result<String, ExchangeError> {
+    let mut params = format!(
+        "symbol={}&side={}&quantity={}",
+        request.symbol,
+        request.side,
+        format_decimal(request.quantity)
+    );
+
+    match request.order_type {
+        OrderType::Market => params.push_str("&type=MARKET"),
+        OrderType::Limit => {
+            let price = request
+                .price
+                .ok_or_else(|| ExchangeError::Other("Limit order requires a price".to_string()))?;
+            let tif = request.time_in_force.unwrap_or(TimeInForce::Gtc);
+            // 메이커 전용 주문: spot은 LIMIT_MAKER 타입, futures는 GTX
+            let (order_type, tif) = match (tif, futures) {
+                (TimeInForce::PostOnly, false) => ("LIMIT_MAKER", None),
+                (TimeInForce::PostOnly, true) => ("LIMIT", Some("GTX")),
+                (TimeInForce::Gtc, _) => ("LIMIT", Some("GTC")),
+                (TimeInForce::Ioc, _) => ("LIMIT", Some("IOC")),
+                (TimeInForce::Fok, _) => ("LIMIT", Some("FOK")),
+            };
+            params.push_str(&format!("&type={}", order_type));
+            if let Some(tif) = tif {
+                params.push_str(&format!("&timeInForce={}", tif));
+            }
+            params.push_str(&format!("&price={}", format_decimal(price)));
+        }
+    }
+
+    if let Some(client_order_id) = &request.client_order_id {
+        params.push_str(&format!("&newClientOrderId={}", client_order_id));
+    }
+
+    if futures {
+        if request.reduce_only {
+            params.push_str("&reduceOnly=true");
+        }
+        params.push_str("&newOrderRespType=RESULT");
+    } else {
+        params.push_str("&newOrderRespType=FULL");
+    }
+
+    Ok(params)
+}
+
+/// 응답을 받지 못한 주문을 클라이언트 주문 ID로 다시 조회하는 횟수와 간격
+const RECONCILE_ATTEMPTS: u32 = 3;
+const RECONCILE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 선물 주문의 종료 상태와 체결 내역을 다시 조회하는 횟수와 간격
+const SETTLE_ATTEMPTS: u32 = 10;
+const SETTLE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 호가창에 남지 않고 곧바로 체결 또는 취소되는 주문인지 (시장가, IOC, FOK)
+fn settles_immediately(request: &OrderRequest) -> bool {
+    match request.order_type {
+        OrderType::Market => true,
+        OrderType::Limit => matches!(
+            request.time_in_force,
+            Some(TimeInForce::Ioc) | Some(TimeInForce::Fok)
+        ),
+    }
+}
+
+/// 체결 내역 수량이 체결 수량을 모두 덮는지
+fn fills_cover(order: &OrderAck) -> bool {
+    order.fills.iter().map(|f| f.qty).sum::<Decimal>() >= order.executed_qty
+}
+
+/// 주문 API 종류 (spot /api/v3, futures /fapi/v1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OrderMarket {
+    Spot,
+    Futures,
+}
+
+impl OrderMarket {
+    fn order_endpoint(self) -> &'static str {
+        match self {
+            OrderMarket::Spot => "/api/v3/order",
+            OrderMarket::Futures => "/fapi/v1/order",
+        }
+    }
+
+    fn order_class(self) -> EndpointClass {
+        match self {
+            OrderMarket::Spot => EndpointClass::SpotOrder,
+            OrderMarket::Futures => EndpointClass::FuturesOrder,
+        }
+    }
+
+    fn query_class(self) -> EndpointClass {
+        match self {
+            OrderMarket::Spot => EndpointClass::Spot,
+            OrderMarket::Futures => EndpointClass::Futures,
+        }
+    }
+
+    /// GET order weight
+    fn query_weight(self) -> u32 {
+        match self {
+            OrderMarket::Spot => 4,
+            OrderMarket::Futures => 1,
+        }
+    }
+}
+
+/// 새 클라이언트 주문 ID ("arb" + uuid, Binance 허용 길이 36자 이내)
+fn new_client_order_id() -> String {
+    format!("arb{}", uuid::Uuid::new_v4().simple())
+}
+
+/// 클라이언트 주문 ID가 없으면 붙인다 (응답을 받지 못했을 때 이 ID로 주문을 조회한다)
+fn with_client_order_id(request: &OrderRequest) -> OrderRequest {
+    match request.client_order_id {
+        Some(_) => request.clone(),
+        None => request.clone().with_client_order_id(new_client_order_id()),
+    }
+}
+
+/// 주문 전송 단계 에러 변환
+/// 연결 자체가 안 된 경우를 빼면 요청이 거래소에 닿았을 수 있으므로 UnknownStatus로 바꾼다.
+fn order_transport_error(e: ExchangeError) -> ExchangeError {
+    match e {
+        ExchangeError::Timeout(msg) => ExchangeError::UnknownStatus(msg),
+        ExchangeError::Http(e) if !e.is_connect() => ExchangeError::UnknownStatus(e.to_string()),
+        other => other,
+    }
+}
+
 /// HTTP 기반으로 Binance Spot/Futures 주문을 보내는 구현체
 pub struct HttpBinanceOrderClient {
     spot_client: BinanceClient,
@@ -50,166 +179,335 @@ impl HttpBinanceOrderClient {
             futures_client,
         }
     }
-}
 
-#[async_trait]
-impl BinanceOrderClient for HttpBinanceOrderClient {
-    async fn place_spot_order(
+    fn client(&self, market: OrderMarket) -> &BinanceClient {
+        match market {
+            OrderMarket::Spot => &self.spot_client,
+            OrderMarket::Futures => &self.futures_client,
+        }
+    }
+
+    /// 서명 요청을 보내고 성공 응답 본문을 돌려준다
+    /// order가 true면 주문 전송으로 보고, 실행 여부를 알 수 없는 실패(응답 타임아웃, 5xx)를
+    /// UnknownStatus로 돌려준다.
+    #[allow(clippy::too_many_arguments)]
+    async fn send_signed(
         &self,
-        symbol: &str,
-        side: &str,
-        qty: f64,
-        _price: Option<f64>,
-        options: PlaceOrderOptions,
-    ) -> Result<OrderResponse, ExchangeError> {
-        let api_key = self
-            .spot_client
+        market: OrderMarket,
+        method: Method,
+        endpoint: &str,
+        params: &str,
+        class: EndpointClass,
+        weight: u32,
+        order: bool,
+    ) -> Result<String, ExchangeError> {
+        let client = self.client(market);
+        let api_key = client
             .api_key
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
-        let api_secret = self
-            .spot_client
+            .ok_or_else(|| ExchangeError::Auth("API key not set".to_string()))?;
+        let api_secret = client
             .api_secret
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
-
-        let endpoint = if options.test {
-            "/api/v3/order/test"
-        } else {
-            "/api/v3/order"
-        };
+            .ok_or_else(|| ExchangeError::Auth("API secret not set".to_string()))?;
 
         let timestamp = get_timestamp();
-        let qty_str = format!("{:.8}", qty);
-        let query_string = format!(
-            "symbol={}&side={}&type=MARKET&quantity={}&timestamp={}&recvWindow=50000",
-            symbol, side, qty_str, timestamp
-        );
-        info!("place_spot_order query_string: {}", query_string);
+        let query_string = format!("{}&timestamp={}&recvWindow=50000", params, timestamp);
         let signature = generate_signature(&query_string, api_secret);
 
+        let base_url = match market {
+            OrderMarket::Spot => &client.endpoints.spot_rest,
+            OrderMarket::Futures => &client.endpoints.futures_rest,
+        };
         let url = format!(
             "{}{}?{}&signature={}",
-            SPOT_BASE_URL, endpoint, query_string, signature
+            base_url, endpoint, query_string, signature
         );
 
-        let response = self
-            .spot_client
-            .http
-            .post(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                class,
+                weight,
+                client
+                    .http
+                    .request(method, &url)
+                    .header("X-MBX-APIKEY", api_key.as_str()),
+            )
             .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+            .map_err(|e| if order { order_transport_error(e) } else { e })?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
-        info!("place_spot_order response: {}", response_text);
-
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Spot order API error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            if order && status.is_server_error() {
+                return Err(ExchangeError::UnknownStatus(format!(
+                    "status {}, response: {}",
+                    status,
+                    truncate_body(&response_text)
+                )));
+            }
+            return Err(api_error(status, retry_after, &response_text));
         }
+        Ok(response_text)
+    }
 
-        let order: OrderResponse = serde_json::from_str(&response_text)
+    /// 주문 전송 후 응답을 OrderAck로 변환
+    async fn submit_order(
+        &self,
+        market: OrderMarket,
+        endpoint: &str,
+        request: &OrderRequest,
+        params: &str,
+    ) -> Result<OrderAck, ExchangeError> {
+        info!("place {:?} order params: {}", market, params);
+        let response_text = self
+            .send_signed(
+                market,
+                Method::POST,
+                endpoint,
+                params,
+                market.order_class(),
+                1,
+                true,
+            )
+            .await?;
+        info!("place {:?} order response: {}", market, response_text);
+
+        let raw: serde_json::Value = serde_json::from_str(&response_text)
             .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))?;
+        parse_order_ack(raw, request)
+    }
 
-        // 거래 기록 저장 (test 모드가 아닐 때만)
-        if !options.test {
-            crate::record::save_trade_record_spot_order(
-                "binance",
-                symbol,
-                side,
-                qty,
-                &query_string,
-                &order,
+    /// 클라이언트 주문 ID로 주문 조회 (없으면 None): GET /api/v3/order, /fapi/v1/order
+    async fn query_order(
+        &self,
+        market: OrderMarket,
+        request: &OrderRequest,
+        client_order_id: &str,
+    ) -> Result<Option<OrderAck>, ExchangeError> {
+        let params = format!(
+            "symbol={}&origClientOrderId={}",
+            request.symbol, client_order_id
+        );
+        let response_text = match self
+            .send_signed(
+                market,
+                Method::GET,
+                market.order_endpoint(),
+                &params,
+                market.query_class(),
+                market.query_weight(),
                 false,
             )
-            .await;
-        }
+            .await
+        {
+            Ok(text) => text,
+            // -2013: Order does not exist
+            Err(ExchangeError::ExchangeReject { code, .. }) if code == "-2013" => return Ok(None),
+            Err(e) => return Err(e),
+        };
 
-        Ok(order)
+        let raw: serde_json::Value = serde_json::from_str(&response_text)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))?;
+        parse_order_ack(raw, request).map(Some)
     }
 
-    async fn place_futures_order(
+    /// 실행 여부를 알 수 없는 주문을 클라이언트 주문 ID로 확인
+    /// 접수됐으면 그 주문을 돌려주고, 끝까지 찾지 못하면 UnknownStatus를 돌려준다
+    /// (나중에 처리될 수도 있으므로 같은 주문을 다시 넣으면 안 된다).
+    async fn reconcile_order(
         &self,
-        symbol: &str,
-        side: &str,
-        qty: f64,
-        _price: Option<f64>,
-        options: PlaceFuturesOrderOptions,
-    ) -> Result<OrderResponse, ExchangeError> {
-        let api_key = self
-            .futures_client
-            .api_key
-            .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
-        let api_secret = self
-            .futures_client
-            .api_secret
-            .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
-
-        let endpoint = "/fapi/v1/order";
-
-        let timestamp = get_timestamp();
-        let qty_str = format!("{:.8}", qty);
-        let mut query_string = format!(
-            "symbol={}&side={}&type=MARKET&quantity={}&timestamp={}&recvWindow=50000",
-            symbol, side, qty_str, timestamp
+        market: OrderMarket,
+        request: &OrderRequest,
+        cause: String,
+    ) -> Result<OrderAck, ExchangeError> {
+        let client_order_id = request.client_order_id.as_deref().unwrap_or_default();
+        warn!(
+            "{:?} order {} status unknown ({}), looking it up",
+            market, client_order_id, cause
         );
+        for attempt in 1..=RECONCILE_ATTEMPTS {
+            tokio::time::sleep(RECONCILE_INTERVAL).await;
+            match self.query_order(market, request, client_order_id).await {
+                Ok(Some(order)) => {
+                    warn!(
+                        "{:?} order {} was accepted as {} ({:?})",
+                        market, client_order_id, order.order_id, order.status
+                    );
+                    return Ok(order);
+                }
+                Ok(None) => debug!(
+                    "{:?} order {} not found (attempt {})",
+                    market, client_order_id, attempt
+                ),
+                Err(e) => warn!(
+                    "Failed to look up {:?} order {} (attempt {}): {}",
+                    market, client_order_id, attempt, e
+                ),
+            }
+        }
+        Err(ExchangeError::UnknownStatus(format!(
+            "{:?} order {} on {} not found after {}; check it before placing again",
+            market, client_order_id, request.symbol, cause
+        )))
+    }
 
-        info!("place_futures_order query_string: {}", query_string);
+    /// 선물 주문 하나의 체결 내역 조회: GET /fapi/v1/userTrades?symbol=&orderId=
+    async fn fetch_futures_fills(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<Vec<Fill>, ExchangeError> {
+        let params = format!("symbol={}&orderId={}", symbol, order_id);
+        let response_text = self
+            .send_signed(
+                OrderMarket::Futures,
+                Method::GET,
+                "/fapi/v1/userTrades",
+                &params,
+                EndpointClass::Futures,
+                5,
+                false,
+            )
+            .await?;
+        parse_user_trades(&response_text)
+    }
 
-        if options.reduce_only {
-            query_string.push_str("&reduceOnly=true");
+    /// RESULT 응답 이후 선물 주문의 최종 상태와 체결 내역을 채운다
+    /// 시장가/IOC/FOK 주문은 NEW, 체결 0으로 응답한 직후 체결될 수 있으므로 종료 상태가 될 때까지
+    /// 다시 조회하고, 체결 수량만큼 userTrades에 잡힐 때까지 체결 내역을 조회한다.
+    async fn settle_futures_order(&self, request: &OrderRequest, mut order: OrderAck) -> OrderAck {
+        let client_order_id = request.client_order_id.as_deref().unwrap_or_default();
+
+        let mut attempt = 0;
+        while settles_immediately(request) && !order.status.is_final() && attempt < SETTLE_ATTEMPTS
+        {
+            attempt += 1;
+            tokio::time::sleep(SETTLE_INTERVAL).await;
+            match self
+                .query_order(OrderMarket::Futures, request, client_order_id)
+                .await
+            {
+                Ok(Some(latest)) => order = latest,
+                Ok(None) => debug!(
+                    "Futures order {} not visible yet (attempt {})",
+                    client_order_id, attempt
+                ),
+                Err(e) => warn!(
+                    "Failed to look up futures order {} (attempt {}): {}",
+                    client_order_id, attempt, e
+                ),
+            }
+        }
+        if settles_immediately(request) && !order.status.is_final() {
+            warn!(
+                "Futures order {} still {:?} after {} lookups",
+                order.order_id, order.status, SETTLE_ATTEMPTS
+            );
         }
 
-        let signature = generate_signature(&query_string, api_secret);
+        if order.executed_qty.is_zero() {
+            return order;
+        }
 
-        let url = format!(
-            "{}{}?{}&signature={}",
-            FUTURES_BASE_URL, endpoint, query_string, signature
+        // RESULT 응답에는 체결 내역이 없으므로 체결 조회로 체결가/수수료/메이커 여부를 가져온다
+        for attempt in 1..=SETTLE_ATTEMPTS {
+            match self
+                .fetch_futures_fills(&order.symbol, &order.order_id)
+                .await
+            {
+                Ok(fills) => {
+                    order.fills = fills;
+                    if fills_cover(&order) {
+                        return order;
+                    }
+                    debug!(
+                        "Futures fills for {} incomplete (attempt {})",
+                        order.order_id, attempt
+                    );
+                }
+                Err(e) => warn!(
+                    "Failed to fetch Binance futures fills for {} (attempt {}): {}",
+                    order.order_id, attempt, e
+                ),
+            }
+            if attempt < SETTLE_ATTEMPTS {
+                tokio::time::sleep(SETTLE_INTERVAL).await;
+            }
+        }
+        error!(
+            "Binance futures fills for {} cover only part of executed qty {}; fee and price are incomplete",
+            order.order_id, order.executed_qty
         );
+        order
+    }
+}
+
+#[async_trait]
+impl BinanceOrderClient for HttpBinanceOrderClient {
+    async fn place_spot_order(
+        &self,
+        request: &OrderRequest,
+        options: PlaceOrderOptions,
+    ) -> Result<OrderAck, ExchangeError> {
+        let request = with_client_order_id(request);
+        let params = order_params(&request, false)?;
+        let endpoint = if options.test {
+            "/api/v3/order/test"
+        } else {
+            OrderMarket::Spot.order_endpoint()
+        };
 
-        let response = self
-            .futures_client
-            .http
-            .post(&url)
-            .header("X-MBX-APIKEY", api_key.as_str())
-            .send()
+        let order = match self
+            .submit_order(OrderMarket::Spot, endpoint, &request, &params)
             .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        {
+            Err(ExchangeError::UnknownStatus(cause)) if !options.test => {
+                self.reconcile_order(OrderMarket::Spot, &request, cause)
+                    .await?
+            }
+            result => result?,
+        };
 
-        let status = response.status();
-        let response_text = response.text().await?;
+        // 거래 기록 저장 (test 모드가 아닐 때만)
+        if !options.test {
+            crate::record::save_trade_record_spot_order(
+                "binance", &request, &params, &order, false,
+            )
+            .await;
+        }
 
-        info!("place_futures_order response: {}", response_text);
+        Ok(order)
+    }
 
-        if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Futures order API error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
-        }
+    async fn place_futures_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError> {
+        let request = with_client_order_id(request);
+        let params = order_params(&request, true)?;
 
-        let order: OrderResponse = serde_json::from_str(&response_text)
-            .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))?;
+        let order = match self
+            .submit_order(
+                OrderMarket::Futures,
+                OrderMarket::Futures.order_endpoint(),
+                &request,
+                &params,
+            )
+            .await
+        {
+            Err(ExchangeError::UnknownStatus(cause)) => {
+                self.reconcile_order(OrderMarket::Futures, &request, cause)
+                    .await?
+            }
+            result => result?,
+        };
+
+        let order = self.settle_futures_order(&request, order).await;
 
         // 거래 기록 저장
         crate::record::save_trade_record_futures_order(
-            "binance",
-            symbol,
-            side,
-            qty,
-            &query_string,
-            &order,
-            options.reduce_only,
+            "binance", &request, &params, &order,
             false, // is_liquidation: reduce_only는 정상 포지션 청산이지 강제 청산이 아님
         )
         .await;
@@ -219,7 +517,7 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
 
     async fn cancel_spot_order(&self, _symbol: &str, _order_id: &str) -> Result<(), ExchangeError> {
         // TODO: 구현 필요
-        Err(ExchangeError::Other("Not implemented".to_string()))
+        Err(ExchangeError::NotImplemented("cancel order".to_string()))
     }
 
     async fn cancel_futures_order(
@@ -228,6 +526,113 @@ impl BinanceOrderClient for HttpBinanceOrderClient {
         _order_id: &str,
     ) -> Result<(), ExchangeError> {
         // TODO: 구현 필요
-        Err(ExchangeError::Other("Not implemented".to_string()))
+        Err(ExchangeError::NotImplemented("cancel order".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use interface::{OrderSide, OrderStatus};
+
+    #[test]
+    fn test_client_order_id_and_transport_error() {
+        let request = OrderRequest::market("BTCUSDT", OrderSide::Buy, Decimal::ONE);
+        let id = with_client_order_id(&request).client_order_id.unwrap();
+        assert!(id.starts_with("arb") && id.len() <= 36);
+        assert!(
+            order_params(&with_client_order_id(&request), false)
+                .unwrap()
+                .contains("newClientOrderId=arb")
+        );
+
+        let named = request.with_client_order_id("mine");
+        assert_eq!(
+            with_client_order_id(&named).client_order_id.as_deref(),
+            Some("mine")
+        );
+
+        // 전송 후 타임아웃은 실행 여부를 알 수 없고, 로컬 한도 대기는 보내지 않은 것
+        assert!(matches!(
+            order_transport_error(ExchangeError::Timeout("read".into())),
+            ExchangeError::UnknownStatus(_)
+        ));
+        assert!(matches!(
+            order_transport_error(ExchangeError::RateLimited { retry_after: None }),
+            ExchangeError::RateLimited { .. }
+        ));
+    }
+
+    #[test]
+    fn test_settles_immediately_and_fills_cover() {
+        let market = OrderRequest::market("BTCUSDT", OrderSide::Buy, Decimal::ONE);
+        assert!(settles_immediately(&market));
+        let limit =
+            |tif| OrderRequest::limit("BTCUSDT", OrderSide::Buy, Decimal::ONE, Decimal::TEN, tif);
+        assert!(settles_immediately(&limit(TimeInForce::Ioc)));
+        assert!(!settles_immediately(&limit(TimeInForce::Gtc)));
+
+        let fill = Fill {
+            price: Decimal::TEN,
+            qty: Decimal::new(5, 1),
+            fee: Decimal::new(1, 3),
+            fee_asset: "USDT".to_string(),
+            is_maker: false,
+        };
+        let mut order = OrderAck {
+            exchange: ExchangeId::Binance,
+            symbol: "BTCUSDT".to_string(),
+            order_id: "1".to_string(),
+            client_order_id: None,
+            side: OrderSide::Buy,
+            status: OrderStatus::Filled,
+            executed_qty: Decimal::ONE,
+            avg_price: None,
+            fills: vec![fill.clone()],
+            raw: serde_json::Value::Null,
+        };
+        // userTrades가 아직 일부만 잡힌 경우
+        assert!(!fills_cover(&order));
+        order.fills.push(fill);
+        assert!(fills_cover(&order));
+    }
+
+    #[test]
+    fn test_order_params() {
+        let market = OrderRequest::market("BTCUSDT", OrderSide::Buy, Decimal::new(30, 2));
+        assert_eq!(
+            order_params(&market, false).unwrap(),
+            "symbol=BTCUSDT&side=BUY&quantity=0.3&type=MARKET&newOrderRespType=FULL"
+        );
+
+        let close =
+            OrderRequest::market("BTCUSDT", OrderSide::Sell, Decimal::new(3, 1)).reduce_only();
+        assert_eq!(
+            order_params(&close, true).unwrap(),
+            "symbol=BTCUSDT&side=SELL&quantity=0.3&type=MARKET&reduceOnly=true&newOrderRespType=RESULT"
+        );
+
+        let maker = OrderRequest::limit(
+            "ETHUSDT",
+            OrderSide::Sell,
+            Decimal::ONE,
+            Decimal::new(250010, 2),
+            TimeInForce::PostOnly,
+        );
+        assert_eq!(
+            order_params(&maker, false).unwrap(),
+            "symbol=ETHUSDT&side=SELL&quantity=1&type=LIMIT_MAKER&price=2500.1&newOrderRespType=FULL"
+        );
+        assert_eq!(
+            order_params(&maker, true).unwrap(),
+            "symbol=ETHUSDT&side=SELL&quantity=1&type=LIMIT&timeInForce=GTX&price=2500.1&newOrderRespType=RESULT"
+        );
+
+        let mut no_price = maker.clone();
+        no_price.price = None;
+        assert!(matches!(
+            order_params(&no_price, false),
+            Err(ExchangeError::Other(_))
+        ));
     }
 }
@@ -6,15 +6,13 @@ use futures_util::StreamExt;
 use tracing::{info, warn};
 
 use exchanges::BinanceClient;
-use interface::ExchangeError;
+use exchanges::binance::api_error;
+use exchanges::error::check_status;
+use exchanges::rate_limit::{EndpointClass, RateLimiter};
+use interface::{Decimal, ExchangeError, ExchangeId};
 
 use super::types::PriceState;
 
-const SPOT_BASE_URL: &str = "https://api.binance.com";
-const FUTURES_BASE_URL: &str = "https://fapi.binance.com";
-const SPOT_WS_URL: &str = "wss://stream.binance.com:9443/ws";
-const FUTURES_WS_URL: &str = "wss://fstream.binance.com/ws";
-
 /// Binance Price Feed: WebSocket 가격 스트림 관리
 pub struct BinancePriceFeed {
     price_state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
@@ -33,35 +31,38 @@ impl BinancePriceFeed {
 
     /// 특정 심볼에 대한 WebSocket 리스너 시작
     /// 스팟 ticker와 선물 markPrice를 동시에 구독
-    pub fn start_symbol(&self, symbol: &str) {
+    /// (1000PEPEUSDT 선물처럼 스팟/선물 심볼이 다를 수 있음)
+    pub fn start_symbol(&self, spot_symbol: &str, futures_symbol: &str) {
         let price_state = Arc::clone(&self.price_state);
 
         // 스팟 ticker WebSocket
         let spot_state = Arc::clone(&price_state);
-        let spot_symbol = symbol.to_string();
+        let spot_sym = spot_symbol.to_string();
+        let spot_ws = self.spot_client.endpoints.spot_ws.clone();
         tokio::spawn(async move {
-            Self::start_spot_websocket(&spot_symbol, spot_state).await;
+            Self::start_spot_websocket(&spot_ws, &spot_sym, spot_state).await;
         });
 
         // 선물 markPrice WebSocket
         let fut_state = Arc::clone(&price_state);
-        let fut_symbol = symbol.to_string();
+        let fut_symbol = futures_symbol.to_string();
+        let futures_ws = self.futures_client.endpoints.futures_ws.clone();
         tokio::spawn(async move {
-            Self::start_futures_websocket(&fut_symbol, fut_state).await;
+            Self::start_futures_websocket(&futures_ws, &fut_symbol, fut_state).await;
         });
 
-        info!("WebSocket 리스너 시작: {}", symbol);
+        info!("WebSocket 리스너 시작: {} / {}", spot_symbol, futures_symbol);
     }
 
     /// 스팟 현재가 조회 (메모리에서 읽기, 없으면 HTTP 폴백)
-    pub async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    pub async fn get_spot_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         // 먼저 메모리에서 읽기 시도
         {
             let state_map = self.price_state.read().await;
-            if let Some(price_state) = state_map.get(symbol) {
-                if let Some(price) = price_state.spot_price {
-                    return Ok(price);
-                }
+            if let Some(price_state) = state_map.get(symbol)
+                && let Some(price) = price_state.spot_price
+            {
+                return Ok(price);
             }
         }
 
@@ -70,28 +71,29 @@ impl BinancePriceFeed {
             "WebSocket에서 스팟 가격을 찾을 수 없어 HTTP로 조회합니다 (symbol: {})",
             symbol
         );
-        let url = format!("{}/api/v3/ticker/price?symbol={}", SPOT_BASE_URL, symbol);
+        let url = format!("{}/api/v3/ticker/price?symbol={}", self.spot_client.endpoints.spot_rest, symbol);
 
         #[derive(Debug, serde::Deserialize)]
         struct PriceResponse {
             price: String,
         }
 
-        let response: PriceResponse = self
-            .spot_client
-            .http
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
-            .json()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("Failed to parse price: {}", e)))?;
+        let response: PriceResponse =
+            check_status(
+                RateLimiter::global()
+                    .send(ExchangeId::Binance, EndpointClass::Spot, 2, self.spot_client.http.get(&url))
+                    .await?,
+                api_error,
+            )
+                .await?
+                .json()
+                .await
+                .map_err(|e| ExchangeError::Other(format!("Failed to parse price: {}", e)))?;
 
         let price = response
             .price
-            .parse::<f64>()
-            .map_err(|e| ExchangeError::Other(format!("Failed to parse price as f64: {}", e)))?;
+            .parse::<Decimal>()
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse price as decimal: {}", e)))?;
 
         // HTTP로 가져온 가격도 메모리에 저장
         {
@@ -107,14 +109,14 @@ impl BinancePriceFeed {
     }
 
     /// 선물 마크 가격 조회 (메모리에서 읽기, 없으면 HTTP 폴백)
-    pub async fn get_futures_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    pub async fn get_futures_mark_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         // 먼저 메모리에서 읽기 시도
         {
             let state_map = self.price_state.read().await;
-            if let Some(price_state) = state_map.get(symbol) {
-                if let Some(price) = price_state.futures_mark_price {
-                    return Ok(price);
-                }
+            if let Some(price_state) = state_map.get(symbol)
+                && let Some(price) = price_state.futures_mark_price
+            {
+                return Ok(price);
             }
         }
 
@@ -125,7 +127,7 @@ impl BinancePriceFeed {
         );
         let url = format!(
             "{}/fapi/v1/premiumIndex?symbol={}",
-            FUTURES_BASE_URL, symbol
+            self.futures_client.endpoints.futures_rest, symbol
         );
 
         #[derive(Debug, serde::Deserialize)]
@@ -134,19 +136,20 @@ impl BinancePriceFeed {
             mark_price: String,
         }
 
-        let response: MarkPriceResponse = self
-            .futures_client
-            .http
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?
-            .json()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("Failed to parse mark price: {}", e)))?;
-
-        let price = response.mark_price.parse::<f64>().map_err(|e| {
-            ExchangeError::Other(format!("Failed to parse mark price as f64: {}", e))
+        let response: MarkPriceResponse =
+            check_status(
+                RateLimiter::global()
+                    .send(ExchangeId::Binance, EndpointClass::Futures, 1, self.futures_client.http.get(&url))
+                    .await?,
+                api_error,
+            )
+                .await?
+                .json()
+                .await
+                .map_err(|e| ExchangeError::Other(format!("Failed to parse mark price: {}", e)))?;
+
+        let price = response.mark_price.parse::<Decimal>().map_err(|e| {
+            ExchangeError::Other(format!("Failed to parse mark price as decimal: {}", e))
         })?;
 
         // HTTP로 가져온 가격도 메모리에 저장
@@ -164,12 +167,13 @@ impl BinancePriceFeed {
 
     /// 스팟 ticker WebSocket 연결 및 수신
     async fn start_spot_websocket(
+        ws_base: &str,
         symbol: &str,
         state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
     ) {
         let symbol_lower = symbol.to_lowercase();
         let stream_name = format!("{}@ticker", symbol_lower);
-        let url = format!("{}/{}", SPOT_WS_URL, stream_name);
+        let url = format!("{}/ws/{}", ws_base, stream_name);
 
         loop {
             match Self::connect_spot_websocket(&url, symbol, state.clone()).await {
@@ -193,12 +197,13 @@ impl BinancePriceFeed {
 
     /// 선물 markPrice WebSocket 연결 및 수신
     async fn start_futures_websocket(
+        ws_base: &str,
         symbol: &str,
         state: Arc<TokioRwLock<HashMap<String, PriceState>>>,
     ) {
         let symbol_lower = symbol.to_lowercase();
         let stream_name = format!("{}@markPrice", symbol_lower);
-        let url = format!("{}/{}", FUTURES_WS_URL, stream_name);
+        let url = format!("{}/ws/{}", ws_base, stream_name);
 
         loop {
             match Self::connect_futures_websocket(&url, symbol, state.clone()).await {
@@ -322,7 +327,7 @@ impl BinancePriceFeed {
             return Ok(());
         }
 
-        let price: f64 = ticker.last_price.parse().map_err(|e| {
+        let price: Decimal = ticker.last_price.parse().map_err(|e| {
             ExchangeError::Other(format!(
                 "가격 파싱 실패: {} (price: {})",
                 e, ticker.last_price
@@ -361,7 +366,7 @@ impl BinancePriceFeed {
             return Ok(());
         }
 
-        let price: f64 = mark_price_data.mark_price.parse().map_err(|e| {
+        let price: Decimal = mark_price_data.mark_price.parse().map_err(|e| {
             ExchangeError::Other(format!(
                 "가격 파싱 실패: {} (price: {})",
                 e, mark_price_data.mark_price
@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 use std::sync::RwLock;
 
+use exchanges::binance::api_error;
+use exchanges::decimal::parse_decimal;
+use exchanges::error::retry_after;
+use exchanges::rate_limit::{EndpointClass, RateLimiter};
 use exchanges::{AssetExchange, BinanceClient};
-use interface::ExchangeError;
+use interface::{Decimal, ExchangeError, ExchangeId};
 
 use super::types::{clamp_quantity_with_filter, LotSizeFilter};
 
-const SPOT_BASE_URL: &str = "https://api.binance.com";
-
 /// Binance Spot API: Spot 주문, exchangeInfo, LOT_SIZE 캐시 관리
 pub struct BinanceSpotApi {
     client: BinanceClient,
@@ -24,25 +26,23 @@ impl BinanceSpotApi {
 
     /// 스팟 exchangeInfo를 로드하여 LOT_SIZE 필터를 캐시에 저장
     pub async fn load_exchange_info(&self) -> Result<(), ExchangeError> {
-        let url = format!("{}/api/v3/exchangeInfo", SPOT_BASE_URL);
+        let url = format!("{}/api/v3/exchangeInfo", self.client.endpoints.spot_rest);
 
-        let response = self
-            .client
-            .http
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Spot,
+                20,
+                self.client.http.get(&url),
+            )
+            .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Spot exchangeInfo API error: status {}, response: {}",
-                status,
-                response_text.chars().take(200).collect::<String>()
-            )));
+            return Err(api_error(status, retry_after, &response_text));
         }
 
         let resp: serde_json::Value = serde_json::from_str(&response_text)
@@ -65,20 +65,20 @@ impl BinanceSpotApi {
                             let min_qty = filter
                                 .get("minQty")
                                 .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<f64>().ok())
-                                .unwrap_or(0.0);
+                                .and_then(parse_decimal)
+                                .unwrap_or(Decimal::ZERO);
 
                             let max_qty = filter
                                 .get("maxQty")
                                 .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<f64>().ok())
-                                .unwrap_or(f64::MAX);
+                                .and_then(parse_decimal)
+                                .unwrap_or(Decimal::MAX);
 
                             let step_size = filter
                                 .get("stepSize")
                                 .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<f64>().ok())
-                                .unwrap_or(1.0);
+                                .and_then(parse_decimal)
+                                .unwrap_or(Decimal::ONE);
 
                             cache.insert(
                                 symbol.clone(),
@@ -105,7 +105,7 @@ impl BinanceSpotApi {
     }
 
     /// 스팟 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
-    pub fn clamp_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    pub fn clamp_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
         if let Some(filter) = self.get_lot_size(symbol) {
             clamp_quantity_with_filter(filter, qty)
         } else {
@@ -118,7 +118,7 @@ impl BinanceSpotApi {
     }
 
     /// 스팟 잔고 조회
-    pub async fn get_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
+    pub async fn get_balance(&self, asset: &str) -> Result<Decimal, ExchangeError> {
         let assets = self
             .client
             .fetch_spots()
@@ -129,7 +129,7 @@ impl BinanceSpotApi {
             .iter()
             .find(|a| a.currency == asset)
             .map(|a| a.available)
-            .unwrap_or(0.0);
+            .unwrap_or(Decimal::ZERO);
 
         Ok(balance)
     }
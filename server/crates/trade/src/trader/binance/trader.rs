@@ -1,17 +1,24 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
-use interface::ExchangeError;
+use exchanges::instrument::{counterpart, resolve_symbol};
+use interface::{
+    Decimal, ExchangeError, ExchangeId, Instrument, InstrumentKind, OrderAck, OrderRequest,
+    OrderSide,
+};
+use rust_decimal::prelude::FromPrimitive;
 
 use crate::trader::{FuturesExchangeTrader, SpotExchangeTrader};
 
 use super::futures_api::BinanceFuturesApi;
+use super::margin_allocator::{MarginAllocator, MarginAllocatorParams};
 use super::order_client::{BinanceOrderClient, HttpBinanceOrderClient};
 use super::price_feed::BinancePriceFeed;
 use super::spot_api::BinanceSpotApi;
-use super::types::{HedgedPair, OrderResponse, PlaceFuturesOrderOptions, PlaceOrderOptions};
+use super::types::{FuturesMarginSummary, HedgedPair, PlaceOrderOptions};
 use super::user_stream::{BinanceUserStream, UserDataEvent};
-use exchanges::BinanceClient;
+use super::wallet_api::{BinanceWalletApi, WalletType};
+use exchanges::{BinanceClient, FeeExchange};
 
 pub struct BinanceTrader {
     pub order_client: Arc<dyn BinanceOrderClient>,
@@ -19,6 +26,7 @@ pub struct BinanceTrader {
     pub futures: Arc<BinanceFuturesApi>,
     pub price_feed: Arc<BinancePriceFeed>,
     pub user_stream: Option<Arc<BinanceUserStream>>,
+    pub wallet: Arc<BinanceWalletApi>,
 }
 
 impl BinanceTrader {
@@ -38,6 +46,7 @@ impl BinanceTrader {
             spot_client.clone(),
             futures_client.clone(),
         ));
+        let wallet = Arc::new(BinanceWalletApi::new(spot_client.clone()));
         let user_stream = Some(Arc::new(BinanceUserStream::new(spot_client)));
 
         Ok(Self {
@@ -46,6 +55,7 @@ impl BinanceTrader {
             futures,
             price_feed,
             user_stream,
+            wallet,
         })
     }
 
@@ -56,22 +66,22 @@ impl BinanceTrader {
 
     /// 특정 심볼에 대한 WebSocket 리스너 시작
     /// 스팟 ticker와 선물 markPrice를 동시에 구독
-    pub fn start_websocket_listener(&self, symbol: &str) {
-        self.price_feed.start_symbol(symbol);
+    pub fn start_websocket_listener(&self, spot_symbol: &str, futures_symbol: &str) {
+        self.price_feed.start_symbol(spot_symbol, futures_symbol);
     }
 
     /// 스팟 현재가 조회 (메모리에서 읽기, 없으면 HTTP 폴백)
-    pub async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    pub async fn get_spot_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         self.price_feed.get_spot_price(symbol).await
     }
 
     /// 선물 마크 가격 조회 (메모리에서 읽기, 없으면 HTTP 폴백)
-    pub async fn get_futures_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    pub async fn get_futures_mark_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         self.price_feed.get_futures_mark_price(symbol).await
     }
 
     /// 스팟 잔고 조회
-    pub async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
+    pub async fn get_spot_balance(&self, asset: &str) -> Result<Decimal, ExchangeError> {
         self.spot.get_balance(asset).await
     }
 
@@ -83,94 +93,155 @@ impl BinanceTrader {
         self.spot.client().get_trade_fee_for_symbol(symbol).await
     }
 
+    /// 실제 거래할 레그(현물/선물)의 수수료 조회 (VIP 등급, BNB 할인 반영)
+    pub async fn get_trade_fee(
+        &self,
+        symbol: &str,
+        kind: InstrumentKind,
+    ) -> Result<interface::FeeInfo, ExchangeError> {
+        match kind {
+            InstrumentKind::Spot => self.spot.client().fetch_trade_fee(symbol, kind).await,
+            _ => self.futures.client().fetch_trade_fee(symbol, kind).await,
+        }
+    }
+
     /// 선물 잔고 조회 (USDT 마진)
-    pub async fn get_futures_balance(&self) -> Result<f64, ExchangeError> {
+    pub async fn get_futures_balance(&self) -> Result<Decimal, ExchangeError> {
         self.futures.get_balance().await
     }
 
-    /// 심볼에서 베이스 자산 추출 (예: "BTCUSDT" -> "BTC")
-    pub fn base_asset_from_symbol(symbol: &str) -> String {
-        if symbol.ends_with("USDT") {
-            symbol[..symbol.len() - 4].to_string()
-        } else if symbol.ends_with("USD") {
-            symbol[..symbol.len() - 3].to_string()
-        } else {
-            symbol.to_string()
-        }
+    /// 펀딩 지갑 잔고 조회
+    pub async fn get_funding_balance(&self, asset: &str) -> Result<Decimal, ExchangeError> {
+        self.wallet.get_funding_balance(asset).await
+    }
+
+    /// 선물 계정 마진 요약 조회 (마진 비율 등)
+    pub async fn get_futures_margin_summary(&self) -> Result<FuturesMarginSummary, ExchangeError> {
+        self.futures.get_margin_summary().await
+    }
+
+    /// 현물/선물/펀딩 지갑 간 자금 이동 (예: 현물 USDT를 선물 마진으로)
+    pub async fn transfer(
+        &self,
+        from: WalletType,
+        to: WalletType,
+        asset: &str,
+        amount: Decimal,
+    ) -> Result<u64, ExchangeError> {
+        self.wallet.transfer(from, to, asset, amount).await
+    }
+
+    /// 선물 지갑 마진 비율을 유지하는 배분기 생성 (spawn으로 백그라운드 실행)
+    pub fn margin_allocator(&self, params: MarginAllocatorParams) -> MarginAllocator {
+        MarginAllocator::new(
+            self.spot.clone(),
+            self.futures.clone(),
+            self.wallet.clone(),
+            params,
+        )
+    }
+
+    /// 선물 심볼을 (스팟, 선물) Instrument 쌍으로 해석 (exchangeInfo를 먼저 로드해야 한다)
+    /// 스팟 심볼은 실제 상장 목록에서 찾는다.
+    /// 예: "BTCUSDT" -> (BTCUSDT, BTCUSDT), "1000PEPEUSDT" -> (PEPEUSDT, 1000PEPEUSDT),
+    ///     "1000SATSUSDT" -> (1000SATSUSDT, 1000SATSUSDT)
+    pub fn resolve_instruments(
+        &self,
+        symbol: &str,
+    ) -> Result<(Instrument, Instrument), ExchangeError> {
+        let futures = resolve_symbol(ExchangeId::Binance, InstrumentKind::Perp, symbol)
+            .filter(|f| self.futures.get_lot_size(&f.native_symbol).is_some())
+            .ok_or_else(|| {
+                ExchangeError::Other(format!("Unsupported Binance symbol: {}", symbol))
+            })?;
+        let spot = counterpart(&futures, InstrumentKind::Spot, |native| {
+            self.spot.get_lot_size(native).is_some()
+        })
+        .ok_or_else(|| {
+            ExchangeError::Other(format!("No spot market for Binance symbol: {}", symbol))
+        })?;
+        Ok((spot, futures))
     }
 
     /// 스팟 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
     /// exchangeInfo에서 가져온 실제 LOT_SIZE 필터를 사용
-    pub fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    pub fn clamp_spot_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
         self.spot.clamp_quantity(symbol, qty)
     }
 
     /// 선물 수량을 거래소 규칙에 맞게 조정 (LOT_SIZE)
     /// exchangeInfo에서 가져온 실제 LOT_SIZE 필터를 사용
-    pub fn clamp_futures_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    pub fn clamp_futures_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
         self.futures.clamp_quantity(symbol, qty)
     }
 
-    /// target_net_qty 근처에서 스팟/선물 둘 다 LOT_SIZE를 만족하는 쌍을 찾는다.
+    /// target_net_qty(base 단위) 근처에서 스팟/선물 둘 다 LOT_SIZE를 만족하는 쌍을 찾는다.
+    /// 선물 수량은 선물 심볼 단위이므로 multiplier(1000PEPE 등)로 환산해서 비교한다.
     /// spot_fee_rate: 스팟 수수료율 (maker 또는 taker 중 선택)
     pub fn find_hedged_pair(
         &self,
-        symbol: &str,
-        target_net_qty: f64,
+        spot: &Instrument,
+        futures: &Instrument,
+        target_net_qty: Decimal,
         spot_fee_rate: f64,
     ) -> Option<HedgedPair> {
-        if target_net_qty <= 0.0 {
+        if target_net_qty <= Decimal::ZERO {
+            return None;
+        }
+
+        let multiplier = Decimal::from(futures.multiplier);
+        let spot_fee_rate = Decimal::from_f64(spot_fee_rate)?;
+        let one_minus_fee = Decimal::ONE - spot_fee_rate;
+        if one_minus_fee <= Decimal::ZERO {
             return None;
         }
 
         // 선물 LOT_SIZE filter에서 stepSize를 가져와서 "한 스텝씩 줄여가며 탐색"에 사용
-        let fut_lot = self.futures.get_lot_size(symbol)?;
-        let fut_step = if fut_lot.step_size > 0.0 {
-            fut_lot.step_size
-        } else {
-            // stepSize가 0이면 격자 정보가 없으니 그냥 한 번만 시도
-            0.0
-        };
+        // (stepSize가 0이면 격자 정보가 없으니 그냥 한 번만 시도)
+        let fut_lot = self.futures.get_lot_size(&futures.native_symbol)?;
+        let fut_step = fut_lot.step_size.max(Decimal::ZERO);
 
         // 1) 먼저 target_net_qty를 기준으로 "선물 수량 후보"를 만든다.
         //    (선물 LOT_SIZE에 맞게 클램프)
-        let mut fut_candidate = self.clamp_futures_quantity(symbol, target_net_qty);
-        if fut_candidate <= 0.0 {
+        let mut fut_candidate =
+            self.clamp_futures_quantity(&futures.native_symbol, target_net_qty / multiplier);
+        if fut_candidate <= Decimal::ZERO {
             return None;
         }
 
         // 허용 오차: 스팟/선물 스텝 중 더 작은 값의 절반 정도
         let spot_step = self
             .spot
-            .get_lot_size(symbol)
+            .get_lot_size(&spot.native_symbol)
             .map(|f| f.step_size)
-            .unwrap_or(fut_step.max(1e-8)); // 그래도 0은 피하기
+            .unwrap_or((fut_step * multiplier).max(Decimal::new(1, 8))); // 그래도 0은 피하기
 
-        let tol = spot_step.min(fut_step.max(spot_step)).abs() * 0.5;
+        let tol = spot_step.min((fut_step * multiplier).max(spot_step)).abs() / Decimal::TWO;
 
         // 2) fut_candidate를 기준으로, 이에 맞는 스팟 주문 수량을 찾는다.
         //    안 맞으면 선물 수량을 한 step씩 줄여가며 재시도.
         let max_iters = 50;
         for _ in 0..max_iters {
-            // 이 선물 수량을 "정확히" 덮고 싶다면, 스팟 순수량 == fut_candidate 여야 함.
-            // spot_net = spot_order * (1 - fee) ⇒ spot_order = fut_candidate / (1 - fee)
-            let ideal_spot_order = fut_candidate / (1.0 - spot_fee_rate);
+            // 이 선물 수량을 "정확히" 덮고 싶다면, 스팟 순수량 == fut_candidate * multiplier 여야 함.
+            // spot_net = spot_order * (1 - fee) ⇒ spot_order = fut_base / (1 - fee)
+            let fut_base_qty = fut_candidate * multiplier;
+            let ideal_spot_order = fut_base_qty / one_minus_fee;
 
-            if !ideal_spot_order.is_finite() || ideal_spot_order <= 0.0 {
+            if ideal_spot_order <= Decimal::ZERO {
                 break;
             }
 
             // 스팟 LOT_SIZE에 맞게 주문 수량 클램프
-            let spot_order_qty = self.clamp_spot_quantity(symbol, ideal_spot_order);
-            if spot_order_qty <= 0.0 {
+            let spot_order_qty = self.clamp_spot_quantity(&spot.native_symbol, ideal_spot_order);
+            if spot_order_qty <= Decimal::ZERO {
                 break;
             }
 
             // 클램프 후 "예상 스팟 순수량"
-            let spot_net_qty_est = spot_order_qty * (1.0 - spot_fee_rate);
+            let spot_net_qty_est = spot_order_qty * one_minus_fee;
 
             // 이 조합에서의 예상 델타
-            let delta = spot_net_qty_est - fut_candidate;
+            let delta = spot_net_qty_est - fut_base_qty;
 
             // 델타가 허용 오차 내면 이 쌍을 채택
             if delta.abs() <= tol {
@@ -183,14 +254,14 @@ impl BinanceTrader {
             }
 
             // 더 안 맞으면 선물 수량을 한 step 줄여서 다시 시도
-            if fut_step <= 0.0 {
+            if fut_step.is_zero() {
                 // step 정보가 없으면 더 이상 줄일 수 없음
                 break;
             }
 
             let next_fut = fut_candidate - fut_step;
-            let next_fut = self.clamp_futures_quantity(symbol, next_fut);
-            if next_fut <= 0.0 || (next_fut - fut_candidate).abs() < 1e-12 {
+            let next_fut = self.clamp_futures_quantity(&futures.native_symbol, next_fut);
+            if next_fut <= Decimal::ZERO || next_fut == fut_candidate {
                 break;
             }
             fut_candidate = next_fut;
@@ -212,43 +283,39 @@ impl BinanceTrader {
     /// 레거시 호환성을 위한 정적 메서드 (deprecated)
     /// 실제로는 clamp_spot_quantity 또는 clamp_futures_quantity를 사용해야 함
     #[deprecated(note = "Use clamp_spot_quantity or clamp_futures_quantity instead")]
-    pub fn clamp_quantity(_symbol: &str, qty: f64) -> f64 {
+    pub fn clamp_quantity(_symbol: &str, qty: Decimal) -> Decimal {
         // 하위 호환성을 위해 간단한 구현 유지
         // 실제 사용 시에는 인스턴스 메서드를 사용해야 함
-        let step = 0.001;
-        (qty / step).floor() * step
+        super::types::floor_to_step(qty, Decimal::new(1, 3))
     }
 
     /// 스팟 시장가 주문
-    pub async fn place_spot_order(
+    pub async fn market_spot_order(
         &self,
         symbol: &str,
-        side: &str, // "BUY" or "SELL"
-        quantity: f64,
+        side: OrderSide,
+        quantity: Decimal,
         test: bool,
-    ) -> Result<OrderResponse, ExchangeError> {
+    ) -> Result<OrderAck, ExchangeError> {
         self.order_client
-            .place_spot_order(symbol, side, quantity, None, PlaceOrderOptions { test })
+            .place_spot_order(
+                &OrderRequest::market(symbol, side, quantity),
+                PlaceOrderOptions { test },
+            )
             .await
     }
 
     /// 선물 시장가 주문
-    pub async fn place_futures_order(
+    pub async fn market_futures_order(
         &self,
         symbol: &str,
-        side: &str, // "BUY" or "SELL"
-        quantity: f64,
+        side: OrderSide,
+        quantity: Decimal,
         reduce_only: bool,
-    ) -> Result<OrderResponse, ExchangeError> {
-        self.order_client
-            .place_futures_order(
-                symbol,
-                side,
-                quantity,
-                None,
-                PlaceFuturesOrderOptions { reduce_only },
-            )
-            .await
+    ) -> Result<OrderAck, ExchangeError> {
+        let mut request = OrderRequest::market(symbol, side, quantity);
+        request.reduce_only = reduce_only;
+        self.order_client.place_futures_order(&request).await
     }
 
     /// User Data Stream 시작 및 이벤트 수신
@@ -272,27 +339,21 @@ impl SpotExchangeTrader for BinanceTrader {
         self.load_spot_exchange_info().await
     }
 
-    async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    async fn get_spot_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         self.get_spot_price(symbol).await
     }
 
-    fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
         self.clamp_spot_quantity(symbol, qty)
     }
 
-    async fn buy_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
-        self.order_client
-            .place_spot_order(symbol, "BUY", qty, None, PlaceOrderOptions { test: false })
-            .await
-    }
-
-    async fn sell_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
+    async fn place_spot_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError> {
         self.order_client
-            .place_spot_order(symbol, "SELL", qty, None, PlaceOrderOptions { test: false })
+            .place_spot_order(request, PlaceOrderOptions { test: false })
             .await
     }
 
-    async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
+    async fn get_spot_balance(&self, asset: &str) -> Result<Decimal, ExchangeError> {
         self.get_spot_balance(asset).await
     }
 }
@@ -312,47 +373,15 @@ impl FuturesExchangeTrader for BinanceTrader {
         self.futures.ensure_setup(symbol, leverage, isolated).await
     }
 
-    async fn get_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    async fn get_mark_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         self.get_futures_mark_price(symbol).await
     }
 
-    fn clamp_futures_quantity(&self, symbol: &str, qty: f64) -> f64 {
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
         self.clamp_futures_quantity(symbol, qty)
     }
 
-    async fn buy_futures(
-        &self,
-        symbol: &str,
-        qty: f64,
-        reduce_only: bool,
-    ) -> Result<OrderResponse, ExchangeError> {
-        self.order_client
-            .place_futures_order(
-                symbol,
-                "BUY",
-                qty,
-                None,
-                PlaceFuturesOrderOptions { reduce_only },
-            )
-            .await
-    }
-
-    async fn sell_futures(
-        &self,
-        symbol: &str,
-        qty: f64,
-        reduce_only: bool,
-    ) -> Result<OrderResponse, ExchangeError> {
-        self.order_client
-            .place_futures_order(
-                symbol,
-                "SELL",
-                qty,
-                None,
-                PlaceFuturesOrderOptions { reduce_only },
-            )
-            .await
+    async fn place_futures_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError> {
+        self.order_client.place_futures_order(request).await
     }
-
 }
-
@@ -1,15 +1,138 @@
+use exchanges::decimal::parse_decimal;
+use interface::{
+    Decimal, ExchangeError, ExchangeId, Fill, OrderAck, OrderRequest, OrderSide, OrderStatus,
+};
 use serde::{Deserialize, Serialize};
 
-/// 주문 응답
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OrderResponse {
-    pub symbol: String,
-    pub order_id: Option<u64>,
-    pub client_order_id: Option<String>,
-    pub executed_qty: Option<String>,
-    pub status: Option<String>,
-    #[serde(flatten)]
-    pub extra: serde_json::Value,
+/// Binance 주문 응답 원본 (spot `newOrderRespType=FULL`, futures `newOrderRespType=RESULT`)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct BinanceOrderResponse {
+    symbol: Option<String>,
+    order_id: Option<u64>,
+    client_order_id: Option<String>,
+    side: Option<String>,
+    status: Option<String>,
+    executed_qty: Option<String>,
+    avg_price: Option<String>, // futures 전용
+    #[serde(rename = "cummulativeQuoteQty")]
+    cummulative_quote_qty: Option<String>, // spot 전용
+    fills: Vec<BinanceFill>,   // spot 전용
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceFill {
+    price: String,
+    qty: String,
+    commission: String,
+    commission_asset: String,
+}
+
+/// GET /fapi/v1/userTrades 응답 항목 (선물 체결 내역)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceUserTrade {
+    price: String,
+    qty: String,
+    commission: String,
+    commission_asset: String,
+    maker: bool,
+}
+
+/// 선물 주문 체결 내역(/fapi/v1/userTrades?orderId=)을 Fill 목록으로 변환
+/// RESULT 응답에는 fills가 없어 주문 후 따로 조회한다.
+pub fn parse_user_trades(body: &str) -> Result<Vec<Fill>, ExchangeError> {
+    let trades: Vec<BinanceUserTrade> = serde_json::from_str(body)
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse user trades: {}", e)))?;
+    Ok(trades
+        .iter()
+        .filter_map(|t| {
+            Some(Fill {
+                price: parse_decimal(&t.price)?,
+                qty: parse_decimal(&t.qty)?,
+                fee: parse_decimal(&t.commission)?,
+                fee_asset: t.commission_asset.clone(),
+                is_maker: t.maker,
+            })
+        })
+        .collect())
+}
+
+/// Binance 주문 상태 문자열 변환
+pub fn parse_order_status(status: &str) -> OrderStatus {
+    match status {
+        "PARTIALLY_FILLED" => OrderStatus::PartiallyFilled,
+        "FILLED" => OrderStatus::Filled,
+        "CANCELED" | "PENDING_CANCEL" => OrderStatus::Canceled,
+        "REJECTED" => OrderStatus::Rejected,
+        "EXPIRED" | "EXPIRED_IN_MATCH" => OrderStatus::Expired,
+        _ => OrderStatus::New,
+    }
+}
+
+/// Binance 주문 응답을 공통 OrderAck로 변환
+/// (테스트 주문 엔드포인트는 빈 객체를 주므로 요청 값으로 채운다)
+pub fn parse_order_ack(
+    raw: serde_json::Value,
+    request: &OrderRequest,
+) -> Result<OrderAck, ExchangeError> {
+    let resp: BinanceOrderResponse = serde_json::from_value(raw.clone())
+        .map_err(|e| ExchangeError::Other(format!("Failed to parse order response: {}", e)))?;
+
+    // 주문 즉시 체결된 fills는 들어온 주문이 호가를 소진한 것이므로 항상 테이커
+    let fills = resp
+        .fills
+        .iter()
+        .filter_map(|f| {
+            Some(Fill {
+                price: parse_decimal(&f.price)?,
+                qty: parse_decimal(&f.qty)?,
+                fee: parse_decimal(&f.commission)?,
+                fee_asset: f.commission_asset.clone(),
+                is_maker: false,
+            })
+        })
+        .collect();
+
+    let executed_qty = resp
+        .executed_qty
+        .as_deref()
+        .and_then(parse_decimal)
+        .unwrap_or(Decimal::ZERO);
+    let avg_price = resp
+        .avg_price
+        .as_deref()
+        .and_then(parse_decimal)
+        .filter(|p| !p.is_zero())
+        .or_else(|| {
+            let quote = resp
+                .cummulative_quote_qty
+                .as_deref()
+                .and_then(parse_decimal)?;
+            (!executed_qty.is_zero()).then(|| quote / executed_qty)
+        });
+
+    Ok(OrderAck {
+        exchange: ExchangeId::Binance,
+        symbol: resp.symbol.unwrap_or_else(|| request.symbol.clone()),
+        order_id: resp.order_id.map(|id| id.to_string()).unwrap_or_default(),
+        client_order_id: resp.client_order_id,
+        side: match resp.side.as_deref() {
+            Some("BUY") => OrderSide::Buy,
+            Some("SELL") => OrderSide::Sell,
+            _ => request.side,
+        },
+        status: resp
+            .status
+            .as_deref()
+            .map(parse_order_status)
+            .unwrap_or(OrderStatus::New),
+        executed_qty,
+        avg_price,
+        fills,
+        raw,
+    })
 }
 
 /// 주문 옵션 (Spot 주문용)
@@ -18,25 +141,19 @@ pub struct PlaceOrderOptions {
     pub test: bool,
 }
 
-/// 주문 옵션 (Futures 주문용)
-#[derive(Debug, Clone, Default)]
-pub struct PlaceFuturesOrderOptions {
-    pub reduce_only: bool,
-}
-
 /// Binance LOT_SIZE 필터 정보
 #[derive(Debug, Clone, Copy)]
 pub struct LotSizeFilter {
-    pub min_qty: f64,
-    pub max_qty: f64,
-    pub step_size: f64,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    pub step_size: Decimal,
 }
 
 /// 실시간 가격 상태 (WebSocket에서 업데이트)
 #[derive(Debug, Clone, Default)]
 pub struct PriceState {
-    pub spot_price: Option<f64>,
-    pub futures_mark_price: Option<f64>,
+    pub spot_price: Option<Decimal>,
+    pub futures_mark_price: Option<Decimal>,
     pub last_updated: Option<std::time::SystemTime>,
 }
 
@@ -44,42 +161,208 @@ pub struct PriceState {
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct HedgedPair {
     /// 스팟 주문에 실제로 넣을 수량 (LOT_SIZE 만족)
-    pub spot_order_qty: f64,
-    /// 선물 주문에 실제로 넣을 수량 (LOT_SIZE 만족)
-    pub fut_order_qty: f64,
+    pub spot_order_qty: Decimal,
+    /// 선물 주문에 실제로 넣을 수량 (LOT_SIZE 만족, 선물 심볼 단위. 1000PEPE 선물이면 1 = 1000 PEPE)
+    pub fut_order_qty: Decimal,
     /// 수수료 반영 후 예상 스팟 순수량
-    pub spot_net_qty_est: f64,
-    /// 예상 잔여 델타 (spot_net - fut)
-    pub delta_est: f64,
+    pub spot_net_qty_est: Decimal,
+    /// 예상 잔여 델타 (spot_net - fut * multiplier, base 단위)
+    pub delta_est: Decimal,
 }
 
-/// LOT_SIZE 필터를 사용하여 수량을 clamp하는 헬퍼 함수
-pub fn clamp_quantity_with_filter(filter: LotSizeFilter, qty: f64) -> f64 {
-    const BASE_PRECISION: u32 = 8;
+/// USDⓈ-M 선물 계정 마진 요약 (GET /fapi/v2/account)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FuturesMarginSummary {
+    /// 지갑 잔고 (미실현 손익 제외)
+    pub wallet_balance: Decimal,
+    /// 마진 잔고 (지갑 잔고 + 미실현 손익)
+    pub margin_balance: Decimal,
+    /// 포지션 유지 증거금 합계
+    pub maint_margin: Decimal,
+    /// 포지션/주문 개시 증거금 합계
+    pub initial_margin: Decimal,
+    /// 선물 지갑에서 빼낼 수 있는 최대 금액
+    pub max_withdraw_amount: Decimal,
+}
+
+impl FuturesMarginSummary {
+    /// Binance 마진 비율 (유지 증거금 / 마진 잔고). 1 이상이면 청산
+    /// 포지션이 없으면 None
+    pub fn margin_ratio(&self) -> Option<Decimal> {
+        if self.maint_margin <= Decimal::ZERO {
+            return None;
+        }
+        if self.margin_balance <= Decimal::ZERO {
+            return Some(Decimal::ONE);
+        }
+        Some(self.maint_margin / self.margin_balance)
+    }
+}
 
-    if qty <= 0.0 {
-        return 0.0;
+/// value를 step(stepSize/tickSize)의 배수로 내림. step이 0 이하이면 그대로 반환
+pub fn floor_to_step(value: Decimal, step: Decimal) -> Decimal {
+    if step <= Decimal::ZERO {
+        return value;
     }
+    ((value / step).floor() * step).normalize()
+}
 
-    // 1) precision 잘라내기 (floor)
-    let pow = 10f64.powi(BASE_PRECISION as i32);
-    let mut qty = (qty * pow).floor() / pow;
+/// 주문 쿼리 스트링용 수량/가격 문자열 (지수 표기/부동소수점 잔여 자릿수 없이 그대로)
+pub fn format_decimal(value: Decimal) -> String {
+    value.normalize().to_string()
+}
 
-    // 2) stepSize 처리
-    if filter.step_size > 0.0 {
-        let steps = (qty / filter.step_size).floor();
-        qty = steps * filter.step_size;
+/// LOT_SIZE 필터를 사용하여 수량을 clamp하는 헬퍼 함수
+pub fn clamp_quantity_with_filter(filter: LotSizeFilter, qty: Decimal) -> Decimal {
+    if qty <= Decimal::ZERO {
+        return Decimal::ZERO;
     }
 
-    // 3) minQty 미만이면 invalid → 0이 아니라 "그냥 에러"로 처리해야 맞음
+    // 1) stepSize 처리 (Decimal 연산이라 부동소수점 오차 없이 정확히 내림)
+    let mut qty = floor_to_step(qty, filter.step_size);
+
+    // 2) minQty 미만이면 invalid → 0이 아니라 "그냥 에러"로 처리해야 맞음
     if qty < filter.min_qty {
-        return 0.0; // ← but ideally, return Err(...)
+        return Decimal::ZERO; // ← but ideally, return Err(...)
     }
 
-    // 4) maxQty clamp
+    // 3) maxQty clamp
     if qty > filter.max_qty {
         qty = filter.max_qty;
     }
 
     qty
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn filter(min: &str, max: &str, step: &str) -> LotSizeFilter {
+        LotSizeFilter {
+            min_qty: Decimal::from_str(min).unwrap(),
+            max_qty: Decimal::from_str(max).unwrap(),
+            step_size: Decimal::from_str(step).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_clamp_quantity_exact() {
+        let f = filter("0.00100000", "9000.00000000", "0.00100000");
+
+        // f64였다면 0.1 + 0.2 = 0.30000000000000004
+        let qty = Decimal::new(1, 1) + Decimal::new(2, 1);
+        assert_eq!(clamp_quantity_with_filter(f, qty).to_string(), "0.3");
+
+        let qty = Decimal::from_str("1.23456789").unwrap();
+        assert_eq!(clamp_quantity_with_filter(f, qty).to_string(), "1.234");
+
+        // minQty 미만은 0, maxQty 초과는 maxQty로 제한
+        assert!(clamp_quantity_with_filter(f, Decimal::new(5, 4)).is_zero());
+        assert_eq!(
+            clamp_quantity_with_filter(f, Decimal::new(10_000, 0)),
+            f.max_qty
+        );
+    }
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(
+            format_decimal(Decimal::from_str("0.30000000").unwrap()),
+            "0.3"
+        );
+        assert_eq!(format_decimal(Decimal::from_str("1000").unwrap()), "1000");
+        assert_eq!(format_decimal(Decimal::new(12, 6)), "0.000012");
+    }
+
+    #[test]
+    fn test_parse_order_ack() {
+        let request = OrderRequest::market("BTCUSDT", OrderSide::Buy, Decimal::new(3, 3));
+
+        // spot FULL 응답: fills 기준 평균가와 수수료
+        let raw = serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": 28,
+            "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+            "status": "FILLED",
+            "side": "BUY",
+            "executedQty": "0.00300000",
+            "cummulativeQuoteQty": "300.30000000",
+            "fills": [
+                {"price": "100000.00", "qty": "0.002", "commission": "0.000002", "commissionAsset": "BTC"},
+                {"price": "100300.00", "qty": "0.001", "commission": "0.000001", "commissionAsset": "BTC"}
+            ]
+        });
+        let ack = parse_order_ack(raw, &request).unwrap();
+        assert_eq!(ack.order_id, "28");
+        assert_eq!(ack.status, OrderStatus::Filled);
+        assert_eq!(
+            ack.average_fill_price(),
+            Some(Decimal::new(1001, 1) * Decimal::ONE_THOUSAND)
+        );
+        assert_eq!(ack.fees().get("BTC"), Some(&Decimal::new(3, 6)));
+
+        // futures RESULT 응답: fills 없이 avgPrice만 온다 (체결 내역은 parse_user_trades로 채운다)
+        let raw = serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": 29,
+            "status": "FILLED",
+            "side": "SELL",
+            "executedQty": "0.003",
+            "avgPrice": "100010.5"
+        });
+        let ack = parse_order_ack(raw, &request).unwrap();
+        assert_eq!(ack.side, OrderSide::Sell);
+        assert!(ack.fills.is_empty());
+        assert_eq!(
+            ack.average_fill_price(),
+            Some(Decimal::from_str("100010.5").unwrap())
+        );
+
+        // 테스트 주문은 빈 응답
+        let ack = parse_order_ack(serde_json::json!({}), &request).unwrap();
+        assert_eq!(ack.symbol, "BTCUSDT");
+        assert_eq!(ack.status, OrderStatus::New);
+        assert_eq!(ack.average_fill_price(), None);
+    }
+
+    #[test]
+    fn test_parse_user_trades() {
+        let body = r#"[
+            {"buyer":false,"commission":"0.10001050","commissionAsset":"USDT","id":698759,
+             "maker":false,"orderId":29,"price":"100010.5","qty":"0.002","quoteQty":"200.021",
+             "realizedPnl":"0","side":"SELL","positionSide":"BOTH","symbol":"BTCUSDT","time":1569514978020},
+            {"buyer":false,"commission":"0.02000200","commissionAsset":"USDT","id":698760,
+             "maker":true,"orderId":29,"price":"100010.0","qty":"0.001","quoteQty":"100.01",
+             "realizedPnl":"0","side":"SELL","positionSide":"BOTH","symbol":"BTCUSDT","time":1569514978021}
+        ]"#;
+        let fills = parse_user_trades(body).unwrap();
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].price, Decimal::from_str("100010.5").unwrap());
+        assert_eq!(fills[0].qty, Decimal::new(2, 3));
+        assert_eq!(fills[0].fee, Decimal::from_str("0.1000105").unwrap());
+        assert_eq!(fills[0].fee_asset, "USDT");
+        assert!(!fills[0].is_maker);
+        assert!(fills[1].is_maker);
+
+        assert!(parse_user_trades("{}").is_err());
+    }
+
+    #[test]
+    fn test_floor_to_step() {
+        let tick = Decimal::from_str("0.01").unwrap();
+        assert_eq!(
+            floor_to_step(Decimal::from_str("101.239").unwrap(), tick).to_string(),
+            "101.23"
+        );
+        assert_eq!(
+            floor_to_step(Decimal::from_str("7.5").unwrap(), Decimal::ONE).to_string(),
+            "7"
+        );
+        assert_eq!(
+            floor_to_step(Decimal::new(3, 1), Decimal::ZERO),
+            Decimal::new(3, 1)
+        );
+    }
+}
@@ -4,12 +4,10 @@ use serde::{Deserialize, Serialize};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{error, info, warn};
 
-use exchanges::binance::{generate_signature, get_timestamp};
+use exchanges::binance::{generate_signature, get_timestamp, map_error_code};
 use exchanges::BinanceClient;
 use interface::ExchangeError;
 
-const WS_API_URL: &str = "wss://ws-api.binance.com/ws-api/v3";
-
 /// Binance User Stream: User Data Stream WebSocket 관리
 pub struct BinanceUserStream {
     spot_client: BinanceClient,
@@ -48,20 +46,21 @@ impl BinanceUserStream {
             .spot_client
             .api_key
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API key not set".to_string()))?;
+            .ok_or_else(|| ExchangeError::Auth("API key not set".to_string()))?;
         let api_secret = self
             .spot_client
             .api_secret
             .as_ref()
-            .ok_or_else(|| ExchangeError::Other("API secret not set".to_string()))?;
+            .ok_or_else(|| ExchangeError::Auth("API secret not set".to_string()))?;
 
-        let (ws_stream, _) = connect_async(WS_API_URL)
+        let ws_api_url = &self.spot_client.endpoints.ws_api;
+        let (ws_stream, _) = connect_async(ws_api_url.as_str())
             .await
             .map_err(|e| ExchangeError::Other(format!("WebSocket 연결 실패: {}", e)))?;
 
         let (mut write, mut read) = ws_stream.split();
 
-        info!("User Data Stream WebSocket 연결 성공: {}", WS_API_URL);
+        info!("User Data Stream WebSocket 연결 성공: {}", ws_api_url);
 
         // 구독 요청 전송
         let _request_id = Self::subscribe_user_data_stream(&mut write, api_key, api_secret).await?;
@@ -75,10 +74,11 @@ impl BinanceUserStream {
                         .map_err(|e| ExchangeError::Other(format!("응답 파싱 실패: {}", e)))?;
 
                     if let Some(error) = response.error {
-                        return Err(ExchangeError::Other(format!(
-                            "구독 실패: code={:?}, msg={:?}",
-                            error.code, error.msg
-                        )));
+                        let msg = error.msg.unwrap_or_default();
+                        return Err(match error.code {
+                            Some(code) => map_error_code(code.into(), &msg),
+                            None => ExchangeError::Other(format!("구독 실패: msg={}", msg)),
+                        });
                     }
 
                     if let Some(result) = response.result {
@@ -207,10 +207,10 @@ impl BinanceUserStream {
         }
 
         // 직접 이벤트 형식인 경우
-        if let Ok(event) = serde_json::from_str::<serde_json::Value>(text) {
-            if let Some(parsed_event) = Self::parse_user_data_event(event) {
-                event_handler(parsed_event);
-            }
+        if let Ok(event) = serde_json::from_str::<serde_json::Value>(text)
+            && let Some(parsed_event) = Self::parse_user_data_event(event)
+        {
+            event_handler(parsed_event);
         }
 
         Ok(())
@@ -365,6 +365,7 @@ pub struct ExecutionReport {
 
 /// User Data Stream 이벤트 타입
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum UserDataEvent {
     ExecutionReport(ExecutionReport),
     OutboundAccountPosition(OutboundAccountPosition),
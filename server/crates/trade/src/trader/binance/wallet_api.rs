@@ -0,0 +1,203 @@
+use std::fmt;
+
+use exchanges::BinanceClient;
+use exchanges::binance::{api_error, generate_signature, get_timestamp};
+use exchanges::decimal::parse_decimal;
+use exchanges::error::retry_after;
+use exchanges::rate_limit::{EndpointClass, RateLimiter};
+use interface::{Decimal, ExchangeError, ExchangeId};
+use serde::Deserialize;
+
+use super::types::format_decimal;
+
+/// Binance 계정 내 지갑 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalletType {
+    /// 현물 지갑
+    Spot,
+    /// USDⓈ-M 선물 지갑
+    UsdmFutures,
+    /// 펀딩 지갑 (P2P, 입금 기본 지갑 등)
+    Funding,
+}
+
+impl WalletType {
+    fn code(&self) -> &'static str {
+        match self {
+            WalletType::Spot => "MAIN",
+            WalletType::UsdmFutures => "UMFUTURE",
+            WalletType::Funding => "FUNDING",
+        }
+    }
+
+    /// universal transfer의 type 파라미터 (예: 현물 -> 선물 "MAIN_UMFUTURE")
+    pub fn transfer_type(from: WalletType, to: WalletType) -> Option<String> {
+        if from == to {
+            return None;
+        }
+        Some(format!("{}_{}", from.code(), to.code()))
+    }
+}
+
+impl fmt::Display for WalletType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            WalletType::Spot => "spot",
+            WalletType::UsdmFutures => "usdm-futures",
+            WalletType::Funding => "funding",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UniversalTransferResponse {
+    tran_id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct FundingAsset {
+    asset: String,
+    free: String,
+}
+
+/// Binance Wallet API: 현물/선물/펀딩 지갑 간 자금 이동 (universal transfer)
+pub struct BinanceWalletApi {
+    client: BinanceClient,
+}
+
+impl BinanceWalletApi {
+    pub fn new(client: BinanceClient) -> Self {
+        Self { client }
+    }
+
+    /// 서명된 SAPI POST 요청 후 응답 본문 반환
+    async fn signed_post(&self, endpoint: &str, params: &str) -> Result<String, ExchangeError> {
+        let api_key = self
+            .client
+            .api_key
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Auth("API key not set".to_string()))?;
+        let api_secret = self
+            .client
+            .api_secret
+            .as_ref()
+            .ok_or_else(|| ExchangeError::Auth("API secret not set".to_string()))?;
+
+        let query_string = format!("{}&timestamp={}&recvWindow=50000", params, get_timestamp());
+        let signature = generate_signature(&query_string, api_secret);
+        let url = format!(
+            "{}{}?{}&signature={}",
+            self.client.endpoints.sapi_rest, endpoint, query_string, signature
+        );
+
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Binance,
+                EndpointClass::Wallet,
+                1,
+                self.client
+                    .http
+                    .post(&url)
+                    .header("X-MBX-APIKEY", api_key.as_str()),
+            )
+            .await?;
+
+        let status = response.status();
+        let retry_after = retry_after(response.headers());
+        let response_text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(api_error(status, retry_after, &response_text));
+        }
+        Ok(response_text)
+    }
+
+    /// 지갑 간 자금 이동 (POST /sapi/v1/asset/transfer), 반환값은 tranId
+    pub async fn transfer(
+        &self,
+        from: WalletType,
+        to: WalletType,
+        asset: &str,
+        amount: Decimal,
+    ) -> Result<u64, ExchangeError> {
+        let transfer_type = WalletType::transfer_type(from, to).ok_or_else(|| {
+            ExchangeError::Other(format!("Cannot transfer from {} to itself", from))
+        })?;
+        if amount <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(format!(
+                "Transfer amount must be positive: {}",
+                amount
+            )));
+        }
+
+        let params = format!(
+            "type={}&asset={}&amount={}",
+            transfer_type,
+            asset,
+            format_decimal(amount)
+        );
+        let body = self.signed_post("/sapi/v1/asset/transfer", &params).await?;
+        let resp: UniversalTransferResponse = serde_json::from_str(&body).map_err(|e| {
+            ExchangeError::Other(format!("Failed to parse transfer response: {}", e))
+        })?;
+
+        tracing::info!(
+            "Binance 지갑 이동: {} {} {} -> {} (tranId {})",
+            amount,
+            asset,
+            from,
+            to,
+            resp.tran_id
+        );
+        Ok(resp.tran_id)
+    }
+
+    /// 펀딩 지갑 잔고 (POST /sapi/v1/asset/get-funding-asset)
+    pub async fn get_funding_balance(&self, asset: &str) -> Result<Decimal, ExchangeError> {
+        let body = self
+            .signed_post(
+                "/sapi/v1/asset/get-funding-asset",
+                &format!("asset={}", asset),
+            )
+            .await?;
+        let assets: Vec<FundingAsset> = serde_json::from_str(&body)
+            .map_err(|e| ExchangeError::Other(format!("Failed to parse funding assets: {}", e)))?;
+
+        Ok(assets
+            .iter()
+            .find(|a| a.asset == asset)
+            .and_then(|a| parse_decimal(&a.free))
+            .unwrap_or(Decimal::ZERO))
+    }
+
+    pub fn client(&self) -> &BinanceClient {
+        &self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_type() {
+        assert_eq!(
+            WalletType::transfer_type(WalletType::Spot, WalletType::UsdmFutures).as_deref(),
+            Some("MAIN_UMFUTURE")
+        );
+        assert_eq!(
+            WalletType::transfer_type(WalletType::UsdmFutures, WalletType::Funding).as_deref(),
+            Some("UMFUTURE_FUNDING")
+        );
+        assert_eq!(
+            WalletType::transfer_type(WalletType::Funding, WalletType::Spot).as_deref(),
+            Some("FUNDING_MAIN")
+        );
+        assert_eq!(
+            WalletType::transfer_type(WalletType::Spot, WalletType::Spot),
+            None
+        );
+    }
+}
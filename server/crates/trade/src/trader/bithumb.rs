@@ -5,22 +5,32 @@ use hmac::{Hmac, Mac};
 use serde::Deserialize;
 use serde_json::Value;
 use sha2::Sha512;
-use tracing::{info, warn};
+use tracing::{debug, warn};
 
+use exchanges::decimal::parse_decimal;
 use exchanges::{
     AssetExchange,
-    bithumb::{self, BASE_URL, BithumbClient},
+    bithumb::{self, BithumbClient},
+    error::retry_after,
+    instrument::resolve_symbol,
+    rate_limit::{EndpointClass, RateLimiter},
+};
+use interface::{
+    Decimal, ExchangeError, ExchangeId, Fill, Instrument, InstrumentKind, OrderAck, OrderRequest,
+    OrderSide, OrderStatus, OrderType, TimeInForce,
 };
-use interface::ExchangeError;
 
-use super::{OrderResponse, SpotExchangeTrader};
+use super::SpotExchangeTrader;
+use super::binance::{floor_to_step, format_decimal};
 
 type HmacSha512 = Hmac<Sha512>;
 
 const MARKET_BUY_ENDPOINT: &str = "/trade/market_buy";
 const MARKET_SELL_ENDPOINT: &str = "/trade/market_sell";
+const LIMIT_ORDER_ENDPOINT: &str = "/trade/place";
+const ORDER_DETAIL_ENDPOINT: &str = "/info/order_detail";
 const TICKER_ENDPOINT: &str = "/public/ticker";
-const DEFAULT_STEP_SIZE: f64 = 0.0001;
+const DEFAULT_STEP_SIZE: Decimal = Decimal::from_parts(1, 0, 0, false, 4);
 
 #[async_trait]
 impl SpotExchangeTrader for BithumbTrader {
@@ -29,14 +39,16 @@ impl SpotExchangeTrader for BithumbTrader {
         Ok(())
     }
 
-    async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
+    async fn get_spot_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
         self.fetch_price(symbol).await
     }
 
-    fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64 {
-        let step = Self::step_size_for(&symbol.to_uppercase());
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Decimal) -> Decimal {
+        let step = Self::resolve(symbol)
+            .map(|instrument| Self::step_size_for(&instrument))
+            .unwrap_or(DEFAULT_STEP_SIZE);
         let clamped = Self::clamp_quantity(qty, step);
-        if clamped <= 0.0 {
+        if clamped <= Decimal::ZERO {
             warn!(
                 "Quantity too small after clamp for {} (step {}). Requested: {}",
                 symbol, step, qty
@@ -45,24 +57,18 @@ impl SpotExchangeTrader for BithumbTrader {
         clamped
     }
 
-    async fn buy_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
-        self.place_market_order(symbol, qty, MARKET_BUY_ENDPOINT)
-            .await
-    }
-
-    async fn sell_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError> {
-        self.place_market_order(symbol, qty, MARKET_SELL_ENDPOINT)
-            .await
+    async fn place_spot_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError> {
+        self.place_order(request).await
     }
 
-    async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError> {
+    async fn get_spot_balance(&self, asset: &str) -> Result<Decimal, ExchangeError> {
         let assets = self.client.fetch_spots().await?;
         let target = asset.to_uppercase();
         Ok(assets
             .iter()
             .find(|a| a.currency == target)
             .map(|a| a.available)
-            .unwrap_or(0.0))
+            .unwrap_or(Decimal::ZERO))
     }
 }
 
@@ -89,43 +95,25 @@ impl BithumbTrader {
         })
     }
 
-    fn split_symbol(symbol: &str) -> Result<(String, String), ExchangeError> {
-        let cleaned = symbol.replace('-', "").to_uppercase();
-        for quote in ["KRW", "USDT", "BTC", "USD"] {
-            if cleaned.ends_with(quote) && cleaned.len() > quote.len() {
-                let base = cleaned[..cleaned.len() - quote.len()].to_string();
-                if base.is_empty() {
-                    break;
-                }
-                return Ok((base, quote.to_string()));
-            }
-        }
-        Err(ExchangeError::Other(format!(
-            "Unsupported Bithumb symbol: {}",
-            symbol
-        )))
-    }
-
-    fn build_pair(symbol: &str) -> Result<String, ExchangeError> {
-        let (base, quote) = Self::split_symbol(symbol)?;
-        Ok(format!("{}_{}", base, quote))
+    /// "BTC-KRW", "BTC_KRW", "BTC" 등 입력 심볼을 빗썸 상품으로 해석
+    fn resolve(symbol: &str) -> Result<Instrument, ExchangeError> {
+        resolve_symbol(ExchangeId::Bithumb, InstrumentKind::Spot, symbol)
+            .ok_or_else(|| ExchangeError::Other(format!("Unsupported Bithumb symbol: {}", symbol)))
     }
 
-    fn step_size_for(symbol: &str) -> f64 {
-        if symbol.ends_with("KRW") {
+    fn step_size_for(instrument: &Instrument) -> Decimal {
+        if instrument.quote == "KRW" {
             DEFAULT_STEP_SIZE
         } else {
-            0.000001
+            Decimal::new(1, 6)
         }
     }
 
-    fn clamp_quantity(qty: f64, step: f64) -> f64 {
-        if qty <= 0.0 {
-            return 0.0;
+    fn clamp_quantity(qty: Decimal, step: Decimal) -> Decimal {
+        if qty <= Decimal::ZERO {
+            return Decimal::ZERO;
         }
-        let steps = (qty / step).floor();
-        let clamped = steps * step;
-        if clamped.is_finite() { clamped } else { 0.0 }
+        floor_to_step(qty, step)
     }
 
     fn sign_request(
@@ -144,42 +132,36 @@ impl BithumbTrader {
     async fn post_private(&self, endpoint: &str, params: &str) -> Result<Value, ExchangeError> {
         let nonce = Utc::now().timestamp_micros().to_string();
         let signature = self.sign_request(endpoint, params, &nonce)?;
-        let url = format!("{}{}", BASE_URL, endpoint);
-
-        info!("post_private url: {}", url);
-
-        let response = self
-            .http
-            .post(&url)
-            .header("Api-Key", &self.api_key)
-            .header("Api-Sign", signature)
-            .header("Api-Nonce", &nonce)
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(params.to_string())
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+        let url = format!("{}{}", self.client.endpoints().rest, endpoint);
+
+        debug!("post_private url: {}", url);
+
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Bithumb,
+                EndpointClass::Spot,
+                1,
+                self.http
+                    .post(&url)
+                    .header("Api-Key", &self.api_key)
+                    .header("Api-Sign", signature)
+                    .header("Api-Nonce", &nonce)
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .body(params.to_string()),
+            )
+            .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let body = response.text().await?;
 
-        info!("post_private response: {}", body);
+        debug!("post_private response: {}", body);
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb API HTTP error: status {}, response: {}",
-                status,
-                body.chars().take(200).collect::<String>()
-            )));
-        }
-
-        #[derive(Deserialize)]
-        struct PrivateResponse {
-            status: String,
-            data: Option<Value>,
+            return Err(bithumb::api_error(status, retry_after, &body));
         }
 
-        let parsed: PrivateResponse = serde_json::from_str(&body).map_err(|e| {
+        let parsed: Value = serde_json::from_str(&body).map_err(|e| {
             ExchangeError::Other(format!(
                 "Failed to parse Bithumb response: {}, payload: {}",
                 e,
@@ -187,97 +169,134 @@ impl BithumbTrader {
             ))
         })?;
 
-        if parsed.status != "0000" {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb API error: status {}, response: {}",
-                parsed.status,
-                body.chars().take(200).collect::<String>()
-            )));
+        let status = parsed.get("status").and_then(|v| v.as_str()).unwrap_or("");
+        if status != "0000" {
+            return Err(bithumb::parse_error_body(&body)
+                .unwrap_or_else(|| bithumb::map_error_code(status, "")));
         }
 
-        Ok(parsed.data.unwrap_or(Value::Null))
+        // 주문 응답은 order_id가 최상위에, 조회 응답은 data 아래에 온다
+        Ok(parsed)
     }
 
-    async fn place_market_order(
-        &self,
-        symbol: &str,
-        qty: f64,
-        endpoint: &str,
-    ) -> Result<OrderResponse, ExchangeError> {
-        if qty <= 0.0 {
-            return Err(ExchangeError::Other(
+    async fn place_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError> {
+        let qty = request.quantity;
+        if qty <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(
                 "Quantity must be positive".to_string(),
             ));
         }
 
-        let (base, quote) = Self::split_symbol(symbol)?;
-        let params = format!(
-            "order_currency={}&payment_currency={}&units={:.8}",
-            base, quote, qty
+        let instrument = Self::resolve(&request.symbol)?;
+        let mut params = format!(
+            "order_currency={}&payment_currency={}&units={}",
+            instrument.base,
+            instrument.quote,
+            format_decimal(qty)
         );
 
-        let data = self.post_private(endpoint, &params).await?;
+        let endpoint = match request.order_type {
+            OrderType::Market => match request.side {
+                OrderSide::Buy => MARKET_BUY_ENDPOINT,
+                OrderSide::Sell => MARKET_SELL_ENDPOINT,
+            },
+            OrderType::Limit => {
+                // 빗썸 지정가 주문은 GTC만 지원
+                if !matches!(request.time_in_force, None | Some(TimeInForce::Gtc)) {
+                    return Err(ExchangeError::NotImplemented(format!(
+                        "Bithumb time in force {:?}",
+                        request.time_in_force
+                    )));
+                }
+                let price = request.price.ok_or_else(|| {
+                    ExchangeError::Other("Limit order requires a price".to_string())
+                })?;
+                let order_type = match request.side {
+                    OrderSide::Buy => "bid",
+                    OrderSide::Sell => "ask",
+                };
+                params.push_str(&format!(
+                    "&price={}&type={}",
+                    format_decimal(price),
+                    order_type
+                ));
+                LIMIT_ORDER_ENDPOINT
+            }
+        };
 
-        let order_id = data
+        let body = self.post_private(endpoint, &params).await?;
+        let order_id = body
             .get("order_id")
+            .or_else(|| body.get("data").and_then(|d| d.get("order_id")))
             .and_then(|v| v.as_str())
-            .and_then(|s| s.parse::<u64>().ok());
-        let status = data
-            .get("status")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-        let executed_qty = data
-            .get("order_qty")
-            .or_else(|| data.get("units"))
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+            .ok_or_else(|| ExchangeError::Other(format!("Missing order_id: {}", body)))?
+            .to_string();
 
-        let order_response = OrderResponse {
-            symbol: symbol.to_string(),
+        let mut order = OrderAck {
+            exchange: ExchangeId::Bithumb,
+            symbol: request.symbol.clone(),
             order_id,
-            client_order_id: data
-                .get("order_id")
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string()),
-            executed_qty,
-            status,
-            extra: data,
+            client_order_id: None,
+            side: request.side,
+            status: OrderStatus::New,
+            executed_qty: Decimal::ZERO,
+            avg_price: None,
+            fills: Vec::new(),
+            raw: body,
         };
 
+        // 주문 응답에는 체결 정보가 없으므로 주문 상세에서 체결가/수수료를 가져온다
+        match self.fetch_order_detail(&instrument, &order.order_id).await {
+            Ok(detail) => apply_order_detail(&mut order, &detail),
+            Err(e) => warn!(
+                "Failed to fetch Bithumb order detail for {}: {}",
+                order.order_id, e
+            ),
+        }
+
         // 거래 기록 저장
-        crate::record::save_trade_record_bithumb_order(
-            "bithumb",
-            symbol,
-            endpoint,
-            qty,
-            &params,
-            &order_response,
-            false,
-        )
-        .await;
-
-        Ok(order_response)
+        crate::record::save_trade_record_spot_order("bithumb", request, &params, &order, false)
+            .await;
+
+        Ok(order)
+    }
+
+    async fn fetch_order_detail(
+        &self,
+        instrument: &Instrument,
+        order_id: &str,
+    ) -> Result<Value, ExchangeError> {
+        let params = format!(
+            "order_id={}&order_currency={}&payment_currency={}",
+            order_id, instrument.base, instrument.quote
+        );
+        let body = self.post_private(ORDER_DETAIL_ENDPOINT, &params).await?;
+        Ok(body.get("data").cloned().unwrap_or(Value::Null))
     }
 
-    async fn fetch_price(&self, symbol: &str) -> Result<f64, ExchangeError> {
-        let pair = Self::build_pair(symbol)?;
-        let url = format!("{}{}/{}", BASE_URL, TICKER_ENDPOINT, pair);
-        let response = self
-            .http
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| ExchangeError::Other(format!("HTTP error: {}", e)))?;
+    async fn fetch_price(&self, symbol: &str) -> Result<Decimal, ExchangeError> {
+        let pair = Self::resolve(symbol)?.native_symbol;
+        let url = format!(
+            "{}{}/{}",
+            self.client.endpoints().rest,
+            TICKER_ENDPOINT,
+            pair
+        );
+        let response = RateLimiter::global()
+            .send(
+                ExchangeId::Bithumb,
+                EndpointClass::Spot,
+                1,
+                self.http.get(&url),
+            )
+            .await?;
 
         let status = response.status();
+        let retry_after = retry_after(response.headers());
         let body = response.text().await?;
 
         if !status.is_success() {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb ticker error: status {}, response: {}",
-                status,
-                body.chars().take(200).collect::<String>()
-            )));
+            return Err(bithumb::api_error(status, retry_after, &body));
         }
 
         #[derive(Deserialize)]
@@ -301,16 +320,84 @@ impl BithumbTrader {
         })?;
 
         if parsed.status != "0000" {
-            return Err(ExchangeError::Other(format!(
-                "Bithumb ticker API error: status {}",
-                parsed.status
-            )));
+            return Err(bithumb::map_error_code(&parsed.status, ""));
         }
 
         parsed
             .data
             .closing_price
-            .parse::<f64>()
+            .parse::<Decimal>()
             .map_err(|e| ExchangeError::Other(format!("Invalid closing_price: {}", e)))
     }
 }
+
+/// /info/order_detail 응답의 체결 내역(contract)을 OrderAck에 반영
+/// 주문 직후 조회한 체결은 주문이 호가를 소진한 것이므로 모두 테이커로 본다.
+fn apply_order_detail(order: &mut OrderAck, detail: &Value) {
+    let str_field = |v: &Value, key: &str| v.get(key).and_then(|v| v.as_str()).map(str::to_string);
+
+    order.fills = detail
+        .get("contract")
+        .and_then(|v| v.as_array())
+        .map(|contracts| {
+            contracts
+                .iter()
+                .filter_map(|c| {
+                    Some(Fill {
+                        price: parse_decimal(&str_field(c, "price")?)?,
+                        qty: parse_decimal(&str_field(c, "units")?)?,
+                        fee: str_field(c, "fee")
+                            .and_then(|f| parse_decimal(&f))
+                            .unwrap_or_default(),
+                        fee_asset: str_field(c, "fee_currency").unwrap_or_default(),
+                        is_maker: false,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    order.executed_qty = order.fills.iter().map(|f| f.qty).sum();
+
+    order.status = match detail.get("order_status").and_then(|v| v.as_str()) {
+        Some("Completed") => OrderStatus::Filled,
+        Some("Cancel") => OrderStatus::Canceled,
+        _ if !order.executed_qty.is_zero() => OrderStatus::PartiallyFilled,
+        _ => OrderStatus::New,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_order_detail() {
+        let mut order = OrderAck {
+            exchange: ExchangeId::Bithumb,
+            symbol: "BTC_KRW".to_string(),
+            order_id: "C0101000007408440032".to_string(),
+            client_order_id: None,
+            side: OrderSide::Buy,
+            status: OrderStatus::New,
+            executed_qty: Decimal::ZERO,
+            avg_price: None,
+            fills: Vec::new(),
+            raw: Value::Null,
+        };
+        let detail = serde_json::json!({
+            "order_status": "Completed",
+            "contract": [
+                {"price": "8601000", "units": "0.005", "fee_currency": "KRW", "fee": "107.51"},
+                {"price": "8602000", "units": "0.002", "fee_currency": "KRW", "fee": "43.01"}
+            ]
+        });
+
+        apply_order_detail(&mut order, &detail);
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.executed_qty, Decimal::new(7, 3));
+        // (8601000 * 0.005 + 8602000 * 0.002) / 0.007
+        let expected = (Decimal::new(43005, 0) + Decimal::new(17204, 0)) / Decimal::new(7, 3);
+        assert_eq!(order.average_fill_price(), Some(expected));
+        assert_eq!(order.fees().get("KRW"), Some(&Decimal::new(15052, 2)));
+    }
+}
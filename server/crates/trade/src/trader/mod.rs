@@ -1,21 +1,33 @@
 pub mod binance;
 pub mod bithumb;
+pub mod transfer;
 
 use async_trait::async_trait;
-use interface::ExchangeError;
+use interface::{Decimal, ExchangeError, OrderAck, OrderRequest, OrderSide};
 
-pub use binance::{BinanceTrader, OrderResponse};
+pub use binance::BinanceTrader;
 pub use bithumb::BithumbTrader;
 
 /// 프리미엄 거래소(spot)를 제어하기 위한 공통 인터페이스.
 #[async_trait]
 pub trait SpotExchangeTrader: Send + Sync {
     async fn ensure_exchange_info(&self) -> Result<(), ExchangeError>;
-    async fn get_spot_price(&self, symbol: &str) -> Result<f64, ExchangeError>;
-    fn clamp_spot_quantity(&self, symbol: &str, qty: f64) -> f64;
-    async fn buy_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError>;
-    async fn sell_spot(&self, symbol: &str, qty: f64) -> Result<OrderResponse, ExchangeError>;
-    async fn get_spot_balance(&self, asset: &str) -> Result<f64, ExchangeError>;
+    async fn get_spot_price(&self, symbol: &str) -> Result<Decimal, ExchangeError>;
+    fn clamp_spot_quantity(&self, symbol: &str, qty: Decimal) -> Decimal;
+    async fn place_spot_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError>;
+    async fn get_spot_balance(&self, asset: &str) -> Result<Decimal, ExchangeError>;
+
+    /// 시장가 매수
+    async fn buy_spot(&self, symbol: &str, qty: Decimal) -> Result<OrderAck, ExchangeError> {
+        self.place_spot_order(&OrderRequest::market(symbol, OrderSide::Buy, qty))
+            .await
+    }
+
+    /// 시장가 매도
+    async fn sell_spot(&self, symbol: &str, qty: Decimal) -> Result<OrderAck, ExchangeError> {
+        self.place_spot_order(&OrderRequest::market(symbol, OrderSide::Sell, qty))
+            .await
+    }
 }
 
 /// 헤지 거래소(선물)를 제어하기 위한 공통 인터페이스.
@@ -28,18 +40,31 @@ pub trait FuturesExchangeTrader: Send + Sync {
         leverage: u32,
         isolated: bool,
     ) -> Result<(), ExchangeError>;
-    async fn get_mark_price(&self, symbol: &str) -> Result<f64, ExchangeError>;
-    fn clamp_futures_quantity(&self, symbol: &str, qty: f64) -> f64;
+    async fn get_mark_price(&self, symbol: &str) -> Result<Decimal, ExchangeError>;
+    fn clamp_futures_quantity(&self, symbol: &str, qty: Decimal) -> Decimal;
+    async fn place_futures_order(&self, request: &OrderRequest) -> Result<OrderAck, ExchangeError>;
+
+    /// 시장가 매수 (reduce_only면 숏 포지션 청산)
     async fn buy_futures(
         &self,
         symbol: &str,
-        qty: f64,
+        qty: Decimal,
         reduce_only: bool,
-    ) -> Result<OrderResponse, ExchangeError>;
+    ) -> Result<OrderAck, ExchangeError> {
+        let mut request = OrderRequest::market(symbol, OrderSide::Buy, qty);
+        request.reduce_only = reduce_only;
+        self.place_futures_order(&request).await
+    }
+
+    /// 시장가 매도 (reduce_only면 롱 포지션 청산)
     async fn sell_futures(
         &self,
         symbol: &str,
-        qty: f64,
+        qty: Decimal,
         reduce_only: bool,
-    ) -> Result<OrderResponse, ExchangeError>;
+    ) -> Result<OrderAck, ExchangeError> {
+        let mut request = OrderRequest::market(symbol, OrderSide::Sell, qty);
+        request.reduce_only = reduce_only;
+        self.place_futures_order(&request).await
+    }
 }
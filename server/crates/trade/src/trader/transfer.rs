@@ -0,0 +1,577 @@
+//! 거래소 간 코인 이동 (출금 신청, 출금 상태 추적, 입금 내역 조회)
+//!
+//! 출금 주소는 화이트리스트 설정 파일에 등록된 것만 사용한다.
+//! 호출하는 쪽은 주소가 아니라 화이트리스트 항목 이름(label)과 수량만 넘긴다.
+//!
+//! 출금은 클라이언트 출금 ID를 만들어 신청 전에 Pending 기록을 먼저 저장한다.
+//! 신청 응답을 받지 못하면(타임아웃 등) 같은 출금을 다시 보내지 않고 그 ID로 접수 여부를 확인한다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use exchanges::{TransferExchange, WalletStatusExchange};
+use interface::{
+    Decimal, ExchangeError, ExchangeId, NetworkStatus, TransferDirection, TransferRecord,
+    TransferStatus, WalletStatus, WithdrawRequest,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::record::save_transfer_record_safe;
+
+/// 화이트리스트 파일 경로 환경변수 (기본값: "withdraw_whitelist.json")
+pub const WHITELIST_PATH_ENV: &str = "WITHDRAW_WHITELIST";
+const DEFAULT_WHITELIST_PATH: &str = "withdraw_whitelist.json";
+
+/// 출금 허용 주소 한 개
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WhitelistEntry {
+    /// 항목 이름 (예: "bithumb-usdt-trx")
+    pub label: String,
+    /// 출금하는 거래소
+    pub from: ExchangeId,
+    pub currency: String,
+    /// 출금 거래소 표기 네트워크 (예: Binance "TRX", Bithumb "TRX")
+    pub network: String,
+    pub address: String,
+    /// XRP destination tag, EOS memo 등
+    #[serde(default)]
+    pub memo: Option<String>,
+    /// 거래소 최소 출금 수량과 별도로 지정하는 최소 수량
+    #[serde(default)]
+    pub min_amount: Option<Decimal>,
+    /// 거래소별 추가 파라미터 (예: Bithumb 트래블룰 exchange_name, receiver_type)
+    #[serde(default)]
+    pub extra: BTreeMap<String, String>,
+}
+
+/// 출금 허용 주소 목록
+#[derive(Debug, Clone, Default)]
+pub struct WithdrawWhitelist {
+    entries: Vec<WhitelistEntry>,
+}
+
+impl WithdrawWhitelist {
+    pub fn new(entries: Vec<WhitelistEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// JSON 배열에서 로드
+    pub fn from_json(json: &str) -> Result<Self, ExchangeError> {
+        let entries: Vec<WhitelistEntry> = serde_json::from_str(json)
+            .map_err(|e| ExchangeError::Other(format!("Invalid withdraw whitelist: {}", e)))?;
+        Ok(Self::new(entries))
+    }
+
+    /// WITHDRAW_WHITELIST 경로(기본값 withdraw_whitelist.json)의 파일에서 로드
+    pub fn load() -> Result<Self, ExchangeError> {
+        let path =
+            env::var(WHITELIST_PATH_ENV).unwrap_or_else(|_| DEFAULT_WHITELIST_PATH.to_string());
+        let json = std::fs::read_to_string(&path).map_err(|e| {
+            ExchangeError::Other(format!("Failed to read withdraw whitelist {}: {}", path, e))
+        })?;
+        Self::from_json(&json)
+    }
+
+    pub fn entries(&self) -> &[WhitelistEntry] {
+        &self.entries
+    }
+
+    /// 출금 거래소와 항목 이름으로 찾기
+    pub fn find(&self, from: ExchangeId, label: &str) -> Option<&WhitelistEntry> {
+        self.entries
+            .iter()
+            .find(|e| e.from == from && e.label == label)
+    }
+}
+
+/// 화이트리스트 항목과 거래소 지갑 상태로 출금 요청을 만든다.
+/// 네트워크가 막혀 있거나 최소/최대 수량, 수수료 조건을 만족하지 않으면 에러.
+pub fn build_withdraw_request(
+    entry: &WhitelistEntry,
+    amount: Decimal,
+    wallet: Option<&WalletStatus>,
+) -> Result<WithdrawRequest, ExchangeError> {
+    if amount <= Decimal::ZERO {
+        return Err(ExchangeError::InvalidQuantity(format!(
+            "Withdraw amount must be positive: {}",
+            amount
+        )));
+    }
+    if let Some(min) = entry.min_amount
+        && amount < min
+    {
+        return Err(ExchangeError::InvalidQuantity(format!(
+            "{} {} is below whitelist minimum {} for {}",
+            amount, entry.currency, min, entry.label
+        )));
+    }
+
+    let network = wallet
+        .and_then(|w| {
+            w.networks
+                .iter()
+                .find(|n| n.network.eq_ignore_ascii_case(&entry.network))
+        })
+        .ok_or_else(|| {
+            ExchangeError::Other(format!(
+                "Network {} not available for {} on {:?}",
+                entry.network, entry.currency, entry.from
+            ))
+        })?;
+    check_network_limits(network, &entry.currency, amount)?;
+
+    Ok(WithdrawRequest {
+        currency: entry.currency.clone(),
+        network: network.network.clone(),
+        address: entry.address.clone(),
+        memo: entry.memo.clone(),
+        amount,
+        extra: entry.extra.clone(),
+        client_id: None,
+    })
+}
+
+/// 클라이언트 출금 ID 생성 (Binance withdrawOrderId 등)
+fn new_client_id() -> String {
+    format!("wd{}", uuid::Uuid::new_v4().simple())
+}
+
+/// 출금 신청 전에 저장할 기록 (거래소 ID를 받기 전이라 클라이언트 출금 ID를 ID로 쓴다)
+fn pending_record(from: ExchangeId, request: &WithdrawRequest, client_id: &str) -> TransferRecord {
+    TransferRecord {
+        exchange: from,
+        direction: TransferDirection::Withdrawal,
+        id: client_id.to_string(),
+        currency: request.currency.clone(),
+        network: request.network.clone(),
+        address: request.address.clone(),
+        memo: request.memo.clone(),
+        amount: request.amount,
+        fee: None,
+        txid: None,
+        status: TransferStatus::Pending,
+        created_at: Utc::now(),
+        client_id: Some(client_id.to_string()),
+    }
+}
+
+fn check_network_limits(
+    network: &NetworkStatus,
+    currency: &str,
+    amount: Decimal,
+) -> Result<(), ExchangeError> {
+    if !network.withdraw_enabled {
+        return Err(ExchangeError::Other(format!(
+            "Withdrawal suspended for {} on {}",
+            currency, network.network
+        )));
+    }
+    if let Some(min) = network.withdraw_min
+        && amount < min
+    {
+        return Err(ExchangeError::InvalidQuantity(format!(
+            "{} {} is below exchange minimum {} on {}",
+            amount, currency, min, network.network
+        )));
+    }
+    if let Some(max) = network.withdraw_max
+        && max > Decimal::ZERO
+        && amount > max
+    {
+        return Err(ExchangeError::InvalidQuantity(format!(
+            "{} {} exceeds exchange maximum {} on {}",
+            amount, currency, max, network.network
+        )));
+    }
+    if let Some(fee) = network.withdraw_fee
+        && amount <= fee
+    {
+        return Err(ExchangeError::InvalidQuantity(format!(
+            "{} {} does not cover withdrawal fee {} on {}",
+            amount, currency, fee, network.network
+        )));
+    }
+    Ok(())
+}
+
+struct Venue {
+    transfer: Arc<dyn TransferExchange>,
+    wallet: Arc<dyn WalletStatusExchange>,
+}
+
+/// 화이트리스트 기반 출금 실행기
+/// 모든 출금/입금 기록은 전역 입출금 Repository(transfer_records)에 저장된다.
+pub struct TransferManager {
+    whitelist: WithdrawWhitelist,
+    venues: HashMap<ExchangeId, Venue>,
+}
+
+impl TransferManager {
+    pub fn new(whitelist: WithdrawWhitelist) -> Self {
+        Self {
+            whitelist,
+            venues: HashMap::new(),
+        }
+    }
+
+    /// 출금/입금에 사용할 거래소 클라이언트 등록 (인증 클라이언트여야 함)
+    pub fn with_exchange<C>(mut self, client: C) -> Self
+    where
+        C: TransferExchange + WalletStatusExchange + 'static,
+    {
+        let client = Arc::new(client);
+        self.venues.insert(
+            TransferExchange::id(client.as_ref()),
+            Venue {
+                transfer: client.clone(),
+                wallet: client,
+            },
+        );
+        self
+    }
+
+    pub fn whitelist(&self) -> &WithdrawWhitelist {
+        &self.whitelist
+    }
+
+    fn venue(&self, exchange: ExchangeId) -> Result<&Venue, ExchangeError> {
+        self.venues
+            .get(&exchange)
+            .ok_or_else(|| ExchangeError::NotImplemented(format!("transfer on {:?}", exchange)))
+    }
+
+    /// 화이트리스트 항목(label)으로 출금 신청
+    pub async fn withdraw(
+        &self,
+        from: ExchangeId,
+        label: &str,
+        amount: Decimal,
+    ) -> Result<TransferRecord, ExchangeError> {
+        let entry = self.whitelist.find(from, label).ok_or_else(|| {
+            ExchangeError::Other(format!(
+                "Withdraw address {} is not whitelisted for {:?}",
+                label, from
+            ))
+        })?;
+        let venue = self.venue(from)?;
+
+        let statuses = venue.wallet.fetch_wallet_status().await?;
+        let wallet = statuses
+            .iter()
+            .find(|w| w.currency.eq_ignore_ascii_case(&entry.currency));
+        let mut request = build_withdraw_request(entry, amount, wallet)?;
+        let client_id = new_client_id();
+        request.client_id = Some(client_id.clone());
+
+        tracing::info!(
+            "출금 신청: {:?} {} {} ({}) -> {} [{}]",
+            from,
+            request.amount,
+            request.currency,
+            request.network,
+            entry.label,
+            client_id
+        );
+        // 응답을 못 받아도 신청 사실이 남도록 먼저 저장
+        let mut pending = pending_record(from, &request, &client_id);
+        save_transfer_record_safe(&pending).await;
+
+        match venue.transfer.withdraw(&request).await {
+            Ok(record) => {
+                save_transfer_record_safe(&record).await;
+                Ok(record)
+            }
+            // 요청이 거래소에 닿았는지 알 수 없다. 다시 보내면 두 번 출금될 수 있으므로 접수 여부만 확인한다
+            Err(e) if e.is_retryable() || matches!(e, ExchangeError::UnknownStatus(_)) => {
+                tracing::warn!("출금 신청 응답 없음 ({}), 접수 여부 확인: {}", client_id, e);
+                match self.reconcile_withdrawal(from, &client_id).await {
+                    Ok(Some(record)) => Ok(record),
+                    Ok(None) | Err(_) => Err(ExchangeError::UnknownStatus(format!(
+                        "Withdrawal {} on {:?} may or may not have been submitted ({}); \
+                         check with reconcile_withdrawal before retrying",
+                        client_id, from, e
+                    ))),
+                }
+            }
+            Err(e) => {
+                pending.status = TransferStatus::Failed;
+                save_transfer_record_safe(&pending).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 클라이언트 출금 ID로 거래소에 접수된 출금을 찾아 기록 갱신 (접수되지 않았으면 None)
+    pub async fn reconcile_withdrawal(
+        &self,
+        from: ExchangeId,
+        client_id: &str,
+    ) -> Result<Option<TransferRecord>, ExchangeError> {
+        let record = self
+            .venue(from)?
+            .transfer
+            .find_withdrawal_by_client_id(client_id)
+            .await?;
+        if let Some(record) = &record {
+            save_transfer_record_safe(record).await;
+        }
+        Ok(record)
+    }
+
+    /// 출금 상태를 한 번 조회하고 기록 갱신 (거래소 내역에 아직 없으면 None)
+    pub async fn poll_withdrawal(
+        &self,
+        from: ExchangeId,
+        id: &str,
+    ) -> Result<Option<TransferRecord>, ExchangeError> {
+        let record = self.venue(from)?.transfer.fetch_withdrawal(id).await?;
+        if let Some(record) = &record {
+            save_transfer_record_safe(record).await;
+        }
+        Ok(record)
+    }
+
+    /// 출금이 완료/실패/취소될 때까지 interval 간격으로 조회 (timeout을 넘기면 Timeout 에러)
+    /// 신청 직후 내역에 아직 없는 출금은 대기 중으로 보고 계속 조회한다.
+    pub async fn wait_withdrawal(
+        &self,
+        from: ExchangeId,
+        id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<TransferRecord, ExchangeError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            match self.poll_withdrawal(from, id).await {
+                Ok(Some(record)) if record.status.is_final() => return Ok(record),
+                Ok(Some(_)) => {}
+                Ok(None) => {
+                    tracing::debug!("출금 {}이 아직 내역에 없음 (대기)", id);
+                }
+                Err(e) if e.is_retryable() => {
+                    tracing::warn!("출금 상태 조회 실패 (재시도): {}", e);
+                }
+                Err(e) => return Err(e),
+            }
+            if tokio::time::Instant::now() + interval > deadline {
+                return Err(ExchangeError::Timeout(format!(
+                    "Withdrawal {} on {:?} not finished within {:?}",
+                    id, from, timeout
+                )));
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// start 이후 입금 내역 조회 후 기록
+    pub async fn fetch_deposits(
+        &self,
+        exchange: ExchangeId,
+        currency: &str,
+        start: DateTime<Utc>,
+    ) -> Result<Vec<TransferRecord>, ExchangeError> {
+        let records = self
+            .venue(exchange)?
+            .transfer
+            .fetch_deposits(currency, start)
+            .await?;
+        for record in &records {
+            save_transfer_record_safe(record).await;
+        }
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::RawQuery;
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+    use exchanges::BinanceClient;
+    use exchanges::endpoint::Endpoints;
+    use serde_json::{Value, json};
+
+    const WHITELIST: &str = r#"[
+        {"label": "bithumb-xrp", "from": "Binance", "currency": "XRP", "network": "XRP",
+         "address": "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh", "memo": "101286922", "min_amount": "20"}
+    ]"#;
+
+    fn network(withdraw_enabled: bool) -> NetworkStatus {
+        NetworkStatus {
+            network: "XRP".to_string(),
+            deposit_enabled: true,
+            withdraw_enabled,
+            deposit_fee: None,
+            withdraw_fee: Some(Decimal::new(2, 1)),
+            deposit_min: None,
+            withdraw_min: Some(Decimal::new(25, 0)),
+            withdraw_max: Some(Decimal::new(1_000_000, 0)),
+        }
+    }
+
+    fn wallet(withdraw_enabled: bool) -> WalletStatus {
+        WalletStatus {
+            exchange: ExchangeId::Binance,
+            currency: "XRP".to_string(),
+            networks: vec![network(withdraw_enabled)],
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_withdraw_request() {
+        let whitelist = WithdrawWhitelist::from_json(WHITELIST).unwrap();
+        let entry = whitelist.find(ExchangeId::Binance, "bithumb-xrp").unwrap();
+        assert!(whitelist.find(ExchangeId::Bithumb, "bithumb-xrp").is_none());
+
+        let request = build_withdraw_request(entry, Decimal::new(30, 0), Some(&wallet(true)));
+        let request = request.unwrap();
+        assert_eq!(request.address, "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh");
+        assert_eq!(request.memo.as_deref(), Some("101286922"));
+
+        // 화이트리스트 최소 수량, 거래소 최소 수량
+        for amount in [Decimal::new(10, 0), Decimal::new(22, 0)] {
+            assert!(matches!(
+                build_withdraw_request(entry, amount, Some(&wallet(true))),
+                Err(ExchangeError::InvalidQuantity(_))
+            ));
+        }
+        // 출금 중단, 네트워크 정보 없음
+        assert!(build_withdraw_request(entry, Decimal::new(30, 0), Some(&wallet(false))).is_err());
+        assert!(build_withdraw_request(entry, Decimal::new(30, 0), None).is_err());
+    }
+
+    /// 쿼리 문자열에서 값 하나 꺼내기
+    fn query_value(query: &str, key: &str) -> Option<String> {
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))
+            .map(str::to_string)
+    }
+
+    /// Binance SAPI 지갑 엔드포인트를 흉내내는 로컬 목 서버
+    /// apply_times_out이면 출금 신청은 504로 응답하지만 거래소에는 접수된 것으로 본다.
+    /// 출금 ID 조회는 첫 번째에 빈 목록을 준다 (신청 직후 내역 반영 지연).
+    async fn start_mock_binance(apply_times_out: bool) -> String {
+        let history_polls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let app = Router::new()
+            .route(
+                "/binance/sapi/v1/capital/config/getall",
+                get(|| async {
+                    Json(json!([{
+                        "coin": "XRP",
+                        "depositAllEnable": true,
+                        "withdrawAllEnable": true,
+                        "networkList": [{
+                            "network": "XRP", "coin": "XRP",
+                            "depositEnable": true, "withdrawEnable": true,
+                            "withdrawFee": "0.2", "withdrawMin": "25", "withdrawMax": "1000000"
+                        }]
+                    }]))
+                }),
+            )
+            .route(
+                "/binance/sapi/v1/capital/withdraw/apply",
+                post(move |RawQuery(query): RawQuery| async move {
+                    let query = query.unwrap_or_default();
+                    assert!(query.contains("address=rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh"));
+                    assert!(query.contains("addressTag=101286922"));
+                    assert!(query.contains("network=XRP"));
+                    assert!(query.contains("withdrawOrderId=wd"));
+                    assert!(query.contains("signature="));
+                    if apply_times_out {
+                        return (axum::http::StatusCode::GATEWAY_TIMEOUT, Json(Value::Null));
+                    }
+                    (
+                        axum::http::StatusCode::OK,
+                        Json(json!({"id": "7213fea8e94b4a5593d507237e5a555b"})),
+                    )
+                }),
+            )
+            .route(
+                "/binance/sapi/v1/capital/withdraw/history",
+                get(move |RawQuery(query): RawQuery| async move {
+                    let query = query.unwrap_or_default();
+                    let client_id = query_value(&query, "withdrawOrderId");
+                    if client_id.is_none()
+                        && history_polls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0
+                    {
+                        return Json(json!([]));
+                    }
+                    Json(json!([{
+                        "id": "7213fea8e94b4a5593d507237e5a555b", "amount": "30",
+                        "transactionFee": "0.2", "coin": "XRP", "status": 6,
+                        "address": "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh", "addressTag": "101286922",
+                        "txId": "5A0C4C1B", "applyTime": "2024-05-01 03:00:00", "network": "XRP",
+                        "withdrawOrderId": client_id
+                    }]))
+                }),
+            )
+            .fallback(|| async { (axum::http::StatusCode::NOT_FOUND, Json(Value::Null)) });
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    fn mock_manager(base_url: &str) -> TransferManager {
+        let mut client = BinanceClient::with_endpoints(Endpoints::mock(base_url).binance);
+        client.api_key = Some("mock-key".to_string());
+        client.api_secret = Some("mock-secret".to_string());
+
+        TransferManager::new(WithdrawWhitelist::from_json(WHITELIST).unwrap()).with_exchange(client)
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_against_mock_server() {
+        let manager = mock_manager(&start_mock_binance(false).await);
+
+        // 화이트리스트에 없는 주소는 요청 전에 거부
+        assert!(
+            manager
+                .withdraw(ExchangeId::Binance, "unknown", Decimal::new(30, 0))
+                .await
+                .is_err()
+        );
+
+        let record = manager
+            .withdraw(ExchangeId::Binance, "bithumb-xrp", Decimal::new(30, 0))
+            .await
+            .unwrap();
+        assert_eq!(record.id, "7213fea8e94b4a5593d507237e5a555b");
+        assert_eq!(record.status, TransferStatus::Pending);
+        assert!(record.client_id.as_deref().unwrap().starts_with("wd"));
+
+        let done = manager
+            .wait_withdrawal(
+                ExchangeId::Binance,
+                &record.id,
+                Duration::from_millis(10),
+                Duration::from_secs(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(done.status, TransferStatus::Completed);
+        assert_eq!(done.txid.as_deref(), Some("5A0C4C1B"));
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_reconciles_after_timeout() {
+        let manager = mock_manager(&start_mock_binance(true).await);
+
+        // 신청 응답은 504지만 클라이언트 출금 ID로 접수된 출금을 찾는다
+        let record = manager
+            .withdraw(ExchangeId::Binance, "bithumb-xrp", Decimal::new(30, 0))
+            .await
+            .unwrap();
+        assert_eq!(record.id, "7213fea8e94b4a5593d507237e5a555b");
+        assert!(record.client_id.as_deref().unwrap().starts_with("wd"));
+    }
+}